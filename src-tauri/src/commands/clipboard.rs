@@ -1,11 +1,14 @@
 use crate::services::database::{
     clear_clipboard_history as db_clear_clipboard_history,
-    delete_clipboard_item as db_delete_clipboard_item, get_clipboard_count,
-    get_clipboard_item_by_id, limit_clipboard_history, move_clipboard_item_to_top,
+    delete_clipboard_item as db_delete_clipboard_item, delete_items as db_delete_items,
+    get_clipboard_count, count_items_by_type as db_count_items_by_type,
+    get_clipboard_item_by_id, move_clipboard_item_to_top,
     move_clipboard_item_by_id as db_move_clipboard_item_by_id,
-    query_clipboard_items, update_clipboard_item as db_update_clipboard_item,
-    toggle_pin_clipboard_item as db_toggle_pin,
-    ClipboardItem, PaginatedResult, QueryParams,
+    query_clipboard_items, query_clipboard_items_after, query_clipboard_items_stream, update_clipboard_item as db_update_clipboard_item,
+    toggle_pin_clipboard_item as db_toggle_pin, set_item_note as db_set_item_note,
+    query_pinned_items, reorder_pinned, set_item_forced_format as db_set_item_forced_format,
+    set_item_local_only as db_set_item_local_only,
+    ClipboardBatch, ClipboardItem, PaginatedResult, QueryParams,
 };
 use crate::services::paste::FilesData;
 use std::path::Path;
@@ -45,13 +48,24 @@ pub async fn get_clipboard_history(
     limit: Option<i64>,
     search: Option<String>,
     content_type: Option<String>,
+    with_highlights: Option<bool>,
 ) -> Result<PaginatedResult<ClipboardItem>, String> {
     let result = tokio::task::spawn_blocking(move || {
+        let offset = offset.unwrap_or(0);
+        // 只在加载首页时记录搜索词，翻页不重复记录
+        if offset == 0 {
+            if let Some(term) = search.as_deref() {
+                crate::services::clipboard::record_search(term);
+            }
+        }
         let params = QueryParams {
-            offset: offset.unwrap_or(0),
+            offset,
             limit: limit.unwrap_or(50),
             search,
             content_type,
+            created_before: None,
+            with_highlights: with_highlights.unwrap_or(false),
+            exclude_local_only: false,
         };
         query_clipboard_items(params)
     })
@@ -64,6 +78,60 @@ pub async fn get_clipboard_history(
     Ok(PaginatedResult::new(result.total_count, items, result.offset, result.limit))
 }
 
+// 按游标分批获取匹配的剪贴板历史（用于全量搜索、导出等，cursor 为上一批返回的 next_cursor）
+#[tauri::command]
+pub async fn get_clipboard_history_batch(
+    cursor: Option<i64>,
+    batch_size: Option<usize>,
+    search: Option<String>,
+    content_type: Option<String>,
+    exclude_local_only: Option<bool>,
+) -> Result<ClipboardBatch, String> {
+    let mut batch = tokio::task::spawn_blocking(move || {
+        let params = QueryParams {
+            search,
+            content_type,
+            exclude_local_only: exclude_local_only.unwrap_or(false),
+            ..Default::default()
+        };
+        query_clipboard_items_after(&params, cursor, batch_size.unwrap_or(200))
+    })
+    .await
+    .map_err(|e| format!("任务执行失败: {}", e))??;
+
+    fill_file_exists(&mut batch.items);
+    Ok(batch)
+}
+
+// 按正则扫描全部匹配的历史，分批读取而不一次加载全部内容
+// 返回内容匹配的项 ID（按 id 倒序），达到 max_results 后提前结束
+#[tauri::command]
+pub async fn scan_clipboard_history(
+    pattern: String,
+    search: Option<String>,
+    content_type: Option<String>,
+    max_results: Option<usize>,
+) -> Result<Vec<i64>, String> {
+    let regex = regex::Regex::new(&pattern).map_err(|e| format!("无效的正则表达式: {}", e))?;
+    let max_results = max_results.unwrap_or(1000);
+    tokio::task::spawn_blocking(move || {
+        let params = QueryParams {
+            search,
+            content_type,
+            ..Default::default()
+        };
+        let mut ids = Vec::new();
+        query_clipboard_items_stream(params, 500, |items| {
+            ids.extend(items.iter().filter(|item| regex.is_match(&item.content)).map(|item| item.id));
+            ids.len() < max_results
+        })?;
+        ids.truncate(max_results);
+        Ok(ids)
+    })
+    .await
+    .map_err(|e| format!("任务执行失败: {}", e))?
+}
+
 // 另存图片
 #[tauri::command]
 pub async fn save_image_from_path(
@@ -102,6 +170,78 @@ pub fn get_clipboard_total_count() -> Result<i64, String> {
     get_clipboard_count()
 }
 
+// 获取最近搜索词（最新的排在最前）
+#[tauri::command]
+pub fn get_recent_searches() -> Vec<String> {
+    crate::services::clipboard::get_recent_searches()
+}
+
+// 清空最近搜索词
+#[tauri::command]
+pub fn clear_recent_searches() -> Result<(), String> {
+    crate::services::clipboard::clear_recent_searches()
+}
+
+// 获取剪贴板监听状态（运行状态、捕获统计与最近错误）
+#[tauri::command]
+pub fn get_monitor_status() -> crate::services::clipboard::MonitorStatus {
+    crate::services::clipboard::get_monitor_status()
+}
+
+// 将当前系统剪贴板内容记录到历史（与列表首项相同时返回 None）
+#[tauri::command]
+pub async fn capture_current_clipboard() -> Result<Option<i64>, String> {
+    tokio::task::spawn_blocking(crate::services::clipboard::capture_current_clipboard)
+        .await
+        .map_err(|e| format!("任务执行失败: {}", e))?
+}
+
+// 设置剪贴板项备注
+#[tauri::command]
+pub fn set_item_note(id: i64, note: Option<String>) -> Result<(), String> {
+    db_set_item_note(id, note)
+}
+
+// 按内容类型统计剪贴板项数量（用于筛选栏显示）
+#[tauri::command]
+pub fn count_items_by_type(search: Option<String>) -> Result<std::collections::HashMap<String, u64>, String> {
+    db_count_items_by_type(search)
+}
+
+// 冻结最新一条剪贴板项（本次会话置顶，重启后还原）
+#[tauri::command]
+pub fn freeze_top_item(app: tauri::AppHandle) -> Result<Option<i64>, String> {
+    use tauri::Emitter;
+    let frozen = crate::services::database::freeze_top_item()?;
+    if frozen.is_some() {
+        let _ = app.emit("clipboard-updated", ());
+    }
+    Ok(frozen)
+}
+
+// 解除全部会话冻结
+#[tauri::command]
+pub fn unfreeze_session_items(app: tauri::AppHandle) -> Result<usize, String> {
+    use tauri::Emitter;
+    let count = crate::services::database::unfreeze_session_items()?;
+    if count > 0 {
+        let _ = app.emit("clipboard-updated", ());
+    }
+    Ok(count)
+}
+
+// 激活或启动剪贴板项的来源应用
+#[tauri::command]
+pub fn open_item_source_app(id: i64) -> Result<crate::services::system::source_app::SourceAppAction, String> {
+    crate::services::system::source_app::open_item_source_app(id)
+}
+
+// 获取剪贴板历史统计（用于统计视图）
+#[tauri::command]
+pub fn get_history_stats() -> Result<crate::services::database::HistoryStats, String> {
+    crate::services::database::get_history_stats()
+}
+
 // 移动剪贴板项到顶部（粘贴后置顶使用）
 #[tauri::command]
 pub fn move_clipboard_item(id: i64) -> Result<(), String> {
@@ -114,10 +254,36 @@ pub fn move_clipboard_item_by_id(from_id: i64, to_id: i64) -> Result<(), String>
     db_move_clipboard_item_by_id(from_id, to_id)
 }
 
+// 设置剪贴板项的强制粘贴格式（format 为空时清除）
+#[tauri::command]
+pub fn set_item_forced_format(id: i64, format: Option<String>) -> Result<(), String> {
+    db_set_item_forced_format(id, format.as_deref().filter(|f| !f.is_empty()))
+}
+
+// 标记剪贴板项为仅本机（导出与同步时排除）
+#[tauri::command]
+pub fn set_item_local_only(id: i64, local_only: bool) -> Result<(), String> {
+    db_set_item_local_only(id, local_only)
+}
+
+// 按手动顺序重排置顶项（未列出的置顶项保持相对顺序排在最后）
+#[tauri::command]
+pub fn reorder_pinned_items(ordered_ids: Vec<i64>) -> Result<(), String> {
+    reorder_pinned(&ordered_ids)
+}
+
+// 按手动顺序获取全部置顶项
+#[tauri::command]
+pub fn get_pinned_items() -> Result<Vec<ClipboardItem>, String> {
+    let mut items = query_pinned_items()?;
+    fill_file_exists(&mut items);
+    Ok(items)
+}
+
 // 应用历史记录数量限制
 #[tauri::command]
 pub fn apply_history_limit(limit: u64) -> Result<(), String> {
-    limit_clipboard_history(limit)
+    crate::services::clipboard::enforce_history_limit(limit).map(|_| ())
 }
 
 // 粘贴参数
@@ -141,11 +307,7 @@ pub fn paste_content(params: PasteParams, app: tauri::AppHandle) -> Result<(), S
     };
     use crate::services::paste::PasteFormat;
 
-    let paste_format = params.format.as_ref().and_then(|f| match f.as_str() {
-        "plain" => Some(PasteFormat::PlainText),
-        "formatted" => Some(PasteFormat::WithFormat),
-        _ => None,
-    });
+    let paste_format = params.format.as_deref().and_then(PasteFormat::from_key);
 
     // 根据参数类型处理粘贴
     if let Some(clipboard_id) = params.clipboard_id {
@@ -162,21 +324,7 @@ pub fn paste_content(params: PasteParams, app: tauri::AppHandle) -> Result<(), S
             .ok_or_else(|| format!("收藏项不存在: {}", favorite_id))?;
 
         // 将收藏项转换为剪贴板项格式
-        let item = ClipboardItem {
-            id: 0,
-            content: favorite.content,
-            html_content: favorite.html_content,
-            content_type: favorite.content_type,
-            image_id: favorite.image_id,
-            item_order: favorite.item_order,
-            is_pinned: false,
-            paste_count: 0,
-            source_app: None,
-            source_icon_hash: None,
-            char_count: favorite.char_count,
-            created_at: favorite.created_at,
-            updated_at: favorite.updated_at,
-        };
+        let item = ClipboardItem::from(favorite);
 
         if paste_format.is_some() {
             paste_favorite_item_with_format(&item, &favorite_id, paste_format)?;
@@ -195,6 +343,78 @@ pub fn paste_content(params: PasteParams, app: tauri::AppHandle) -> Result<(), S
     Ok(())
 }
 
+// 合并粘贴多个剪贴板项，ids 按用户选择顺序传入
+#[tauri::command]
+pub fn paste_items_combined(
+    ids: Vec<i64>,
+    options: Option<crate::services::paste::combined::CombineOptions>,
+    app: tauri::AppHandle,
+) -> Result<(), String> {
+    let options = options.unwrap_or_default();
+    crate::services::paste::combined::paste_items_combined(&ids, &options)?;
+
+    if !crate::get_window_state().is_pinned {
+        if let Some(window) = crate::get_main_window(&app) {
+            crate::hide_main_window(&window);
+        }
+    }
+    Ok(())
+}
+
+// 激活指定窗口后粘贴剪贴板项（按标题或句柄定位，确认获得焦点后才粘贴）
+#[tauri::command]
+pub fn paste_to_window(
+    target: crate::services::paste::target::WindowTarget,
+    id: i64,
+    timeout_ms: Option<u64>,
+) -> Result<(), String> {
+    crate::services::paste::target::paste_to_window(&target, id, timeout_ms)
+}
+
+// 获取最近粘贴的剪贴板项
+#[tauri::command]
+pub fn get_recently_pasted(n: Option<usize>) -> Result<Vec<ClipboardItem>, String> {
+    let mut items = crate::services::paste::recent::get_recently_pasted(n.unwrap_or(5))?;
+    fill_file_exists(&mut items);
+    Ok(items)
+}
+
+// 保存剪贴板宏（同名覆盖）
+#[tauri::command]
+pub fn save_macro(name: String, item_ids: Vec<i64>, after_paste: Option<String>) -> Result<(), String> {
+    crate::services::paste::macros::save_macro(&name, item_ids, after_paste)
+}
+
+// 获取所有剪贴板宏
+#[tauri::command]
+pub fn list_macros() -> Vec<crate::services::paste::macros::ClipboardMacro> {
+    crate::services::paste::macros::list_macros()
+}
+
+// 删除剪贴板宏
+#[tauri::command]
+pub fn delete_macro(name: String) -> Result<(), String> {
+    crate::services::paste::macros::delete_macro(&name)
+}
+
+// 开始回放剪贴板宏（从第一项开始）
+#[tauri::command]
+pub fn start_macro(name: String) -> Result<crate::services::paste::macros::MacroPlayback, String> {
+    crate::services::paste::macros::start_macro(&name)
+}
+
+// 停止回放剪贴板宏
+#[tauri::command]
+pub fn stop_macro() {
+    crate::services::paste::macros::stop_macro()
+}
+
+// 获取当前宏回放状态
+#[tauri::command]
+pub fn get_macro_playback() -> Option<crate::services::paste::macros::MacroPlayback> {
+    crate::services::paste::macros::get_macro_playback()
+}
+
 // 删除单个剪贴板项
 #[tauri::command]
 pub fn delete_clipboard_item(id: i64) -> Result<(), String> {
@@ -205,6 +425,19 @@ pub fn delete_clipboard_item(id: i64) -> Result<(), String> {
     result
 }
 
+// 按过滤条件批量删除剪贴板项（不删除置顶项）
+#[tauri::command]
+pub fn delete_clipboard_items(filter: QueryParams, app: tauri::AppHandle) -> Result<u64, String> {
+    use tauri::Emitter;
+
+    let deleted = db_delete_items(filter)?;
+    if deleted > 0 {
+        crate::services::clipboard::clear_last_content_cache();
+        let _ = app.emit("clipboard-updated", ());
+    }
+    Ok(deleted)
+}
+
 // 清空剪贴板历史
 #[tauri::command]
 pub fn clear_clipboard_history() -> Result<(), String> {
@@ -227,6 +460,15 @@ pub fn update_clipboard_item_cmd(id: i64, content: String) -> Result<(), String>
     db_update_clipboard_item(id, content)
 }
 
+// 编辑文本项内容（富文本、图片与文件项会被拒绝）
+#[tauri::command]
+pub fn update_item_text(id: i64, new_text: String, app: tauri::AppHandle) -> Result<(), String> {
+    crate::services::database::update_item_text(id, &new_text)?;
+    use tauri::Emitter;
+    let _ = app.emit("clipboard-updated", ());
+    Ok(())
+}
+
 // 切换剪贴板项置顶状态
 #[tauri::command]
 pub fn toggle_pin_clipboard_item(id: i64) -> Result<bool, String> {
@@ -288,6 +530,12 @@ pub fn copy_clipboard_item(id: i64) -> Result<(), String> {
     set_clipboard_from_item(&item.content_type, &item.content, &item.html_content, true)
 }
 
+// 对剪贴板项应用文本转换后复制到剪贴板（不粘贴），是否记录为新项由设置决定
+#[tauri::command]
+pub fn copy_item_as(id: i64, transform: crate::services::clipboard::TextTransform) -> Result<(), String> {
+    crate::services::clipboard::copy_item_as(id, transform)
+}
+
 // 直接粘贴文本
 #[tauri::command]
 pub fn paste_text_direct(text: String, app: tauri::AppHandle) -> Result<(), String> {
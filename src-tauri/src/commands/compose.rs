@@ -0,0 +1,62 @@
+// 组合粘贴命令
+
+use crate::services::paste::combined::CombineOptions;
+use crate::services::paste::compose::{self, ComposeEntry};
+
+// 创建窗口需在异步命令中进行，同步命令会在 Windows 上死锁
+#[tauri::command]
+pub async fn open_compose_window(app: tauri::AppHandle) -> Result<(), String> {
+    crate::windows::compose_window::open_compose_window(&app)
+}
+
+#[tauri::command]
+pub fn get_compose_entries() -> Vec<ComposeEntry> {
+    compose::compose_entries()
+}
+
+// 按传入顺序加入剪贴板项
+#[tauri::command]
+pub fn compose_add_items(ids: Vec<i64>) -> Result<Vec<ComposeEntry>, String> {
+    compose::compose_add_items(&ids)
+}
+
+// 加入手动输入的片段
+#[tauri::command]
+pub fn compose_add_text(text: String) -> Result<Vec<ComposeEntry>, String> {
+    compose::compose_add_text(text)
+}
+
+#[tauri::command]
+pub fn compose_update_entry(key: u64, text: String) -> Result<Vec<ComposeEntry>, String> {
+    compose::compose_update(key, text)
+}
+
+#[tauri::command]
+pub fn compose_remove_entry(key: u64) -> Result<Vec<ComposeEntry>, String> {
+    compose::compose_remove(key)
+}
+
+#[tauri::command]
+pub fn compose_move_entry(key: u64, to_index: usize) -> Result<Vec<ComposeEntry>, String> {
+    compose::compose_move(key, to_index)
+}
+
+#[tauri::command]
+pub fn compose_clear() -> Vec<ComposeEntry> {
+    compose::compose_clear()
+}
+
+#[tauri::command]
+pub fn compose_preview(options: Option<CombineOptions>) -> Result<String, String> {
+    compose::compose_preview(&options.unwrap_or_default())
+}
+
+// 粘贴组合结果，text 为最终编辑后的文本（未提供时按选项组合）
+#[tauri::command]
+pub fn paste_compose(
+    text: Option<String>,
+    options: Option<CombineOptions>,
+    app: tauri::AppHandle,
+) -> Result<(), String> {
+    compose::paste_compose(&app, text, &options.unwrap_or_default())
+}
@@ -63,6 +63,85 @@ pub fn dm_change_storage_path(app: tauri::AppHandle, payload: ChangePathPayload)
     Ok(new_dir.to_string_lossy().to_string())
 }
 
+// 迁移数据库到新目录（复制校验后切换，失败时回退）
+#[tauri::command]
+pub async fn dm_move_database(payload: ChangePathPayload) -> Result<String, String> {
+    let path = std::path::PathBuf::from(payload.new_path);
+    let new_dir = tauri::async_runtime::spawn_blocking(move || {
+        crate::services::data_management::relocate::move_database(path)
+    }).await.map_err(|e| e.to_string())??;
+    Ok(new_dir.to_string_lossy().to_string())
+}
+
+// 检查数据库完整性
+#[tauri::command]
+pub async fn dm_check_database_integrity() -> Result<crate::services::data_management::integrity::IntegrityReport, String> {
+    tauri::async_runtime::spawn_blocking(crate::services::data_management::integrity::check_database_integrity)
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+// 修复损坏的数据库（原文件备份到 backups 目录）
+#[tauri::command]
+pub async fn dm_repair_database() -> Result<crate::services::data_management::integrity::RepairReport, String> {
+    tauri::async_runtime::spawn_blocking(crate::services::data_management::integrity::repair_database)
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+// 启动检查发现数据库损坏时询问是否修复，返回是否已修复
+#[tauri::command]
+pub async fn dm_prompt_repair_database_if_needed(app: tauri::AppHandle) -> Result<bool, String> {
+    use crate::services::data_management::integrity;
+    use tauri_plugin_dialog::{DialogExt, MessageDialogButtons};
+
+    let Some(report) = integrity::startup_integrity_report() else {
+        return Ok(false);
+    };
+    // 每次启动只询问一次
+    integrity::dismiss_startup_integrity_report();
+
+    let is_zh = crate::services::get_settings().language.starts_with("zh");
+    let detail = report.errors.first().cloned().unwrap_or_default();
+    let message = if is_zh {
+        format!("检测到数据库已损坏：\n{}\n\n是否尝试修复？修复会保留可读取的记录，损坏的原文件将备份到数据目录的 backups 文件夹。", detail)
+    } else {
+        format!("The database appears to be corrupted:\n{}\n\nTry to repair it? Readable records will be kept and the corrupted file will be backed up to the backups folder in the data directory.", detail)
+    };
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let should_repair = app
+            .dialog()
+            .message(message)
+            .buttons(MessageDialogButtons::OkCancel)
+            .blocking_show();
+        if !should_repair {
+            return Ok(false);
+        }
+
+        let (repaired, result_message) = match integrity::repair_database() {
+            Ok(repair) if is_zh => (true, format!(
+                "修复完成，已恢复 {} 条记录，跳过 {} 处损坏区域。\n原文件备份：{}",
+                repair.recovered_rows, repair.skipped_segments, repair.backup_path
+            )),
+            Ok(repair) => (true, format!(
+                "Repair finished: {} records recovered, {} damaged regions skipped.\nOriginal file backup: {}",
+                repair.recovered_rows, repair.skipped_segments, repair.backup_path
+            )),
+            Err(e) if is_zh => (false, format!("修复数据库失败：{}", e)),
+            Err(e) => (false, format!("Failed to repair the database: {}", e)),
+        };
+        let _ = app
+            .dialog()
+            .message(result_message)
+            .buttons(MessageDialogButtons::Ok)
+            .blocking_show();
+        Ok(repaired)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
 #[tauri::command]
 pub fn dm_reset_storage_path_to_default(app: tauri::AppHandle, payload: ResetPathPayload) -> Result<String, String> {
     let dir = crate::services::data_management::reset_storage_dir_to_default(&payload.mode)?;
@@ -70,18 +149,50 @@ pub fn dm_reset_storage_path_to_default(app: tauri::AppHandle, payload: ResetPat
     Ok(dir.to_string_lossy().to_string())
 }
 
+// 在后台线程执行，避免阻塞界面并允许中途取消
 #[tauri::command]
-pub fn dm_export_data_zip(payload: ExportPayload) -> Result<String, String> {
+pub async fn dm_export_data_zip(payload: ExportPayload) -> Result<String, String> {
     let path = std::path::PathBuf::from(payload.target_path);
-    let out = crate::services::data_management::export_data_zip(path)?;
+    let out = tauri::async_runtime::spawn_blocking(move || {
+        crate::services::data_management::export_data_zip(path)
+    }).await.map_err(|e| e.to_string())??;
     Ok(out.to_string_lossy().to_string())
 }
 
 #[tauri::command]
-pub fn dm_import_data_zip(payload: ImportPayload) -> Result<String, String> {
+pub async fn dm_import_data_zip(payload: ImportPayload) -> Result<String, String> {
     let zip = std::path::PathBuf::from(payload.zip_path);
-    let result = crate::services::data_management::import_data_zip(zip, &payload.mode)?;
-    Ok(result)
+    tauri::async_runtime::spawn_blocking(move || {
+        crate::services::data_management::import_data_zip(zip, &payload.mode)
+    }).await.map_err(|e| e.to_string())?
+}
+
+// 取消正在进行的导入/导出
+#[tauri::command]
+pub fn dm_cancel_history_io() {
+    crate::services::data_management::cancel_history_io();
+}
+
+// 重建派生数据（预览、字符数），进度通过 rebuild-derived-progress 事件上报
+#[tauri::command]
+pub async fn dm_rebuild_derived_data(
+    app: tauri::AppHandle,
+    kinds: Vec<crate::services::data_management::rebuild::DerivedKind>,
+) -> Result<crate::services::data_management::rebuild::RebuildReport, String> {
+    use tauri::Emitter;
+
+    let report = tauri::async_runtime::spawn_blocking(move || {
+        crate::services::data_management::rebuild::rebuild_derived_data(&kinds)
+    }).await.map_err(|e| e.to_string())??;
+    if report.updated > 0 {
+        let _ = app.emit("clipboard-updated", ());
+    }
+    Ok(report)
+}
+
+#[tauri::command]
+pub fn dm_cancel_rebuild_derived_data() {
+    crate::services::data_management::rebuild::cancel_rebuild_derived_data();
 }
 
 #[tauri::command]
@@ -25,12 +25,22 @@ pub fn reload_settings() -> Result<AppSettings, String> {
     Ok(settings)
 }
 
+// 保存前端提交的设置：只覆盖提交的设置项，其余保持当前值
 #[tauri::command]
-pub fn save_settings(mut settings: AppSettings, app: tauri::AppHandle) -> Result<(), String> {
+pub fn save_settings(settings: Value, app: tauri::AppHandle) -> Result<(), String> {
+    let merged = get_settings().merged_with(settings)?;
+    apply_settings(merged, app)
+}
+
+// 应用完整的设置（重新注册快捷键、同步监听与窗口状态）
+pub fn apply_settings(mut settings: AppSettings, app: tauri::AppHandle) -> Result<(), String> {
     let old_settings = get_settings();
     let clipboard_monitor_changed = old_settings.clipboard_monitor != settings.clipboard_monitor;
     let edge_hide_changed = old_settings.edge_hide_enabled != settings.edge_hide_enabled;
     let quickpaste_enabled_changed = old_settings.quickpaste_enabled != settings.quickpaste_enabled;
+    let preview_length_changed = old_settings.list_preview_length != settings.list_preview_length;
+    let hotkeys_turned_on = !old_settings.hotkeys_enabled && settings.hotkeys_enabled;
+    let incognito_changed = old_settings.incognito_mode != settings.incognito_mode;
     
     if edge_hide_changed && !settings.edge_hide_enabled {
         settings.edge_snap_position = None;
@@ -39,6 +49,10 @@ pub fn save_settings(mut settings: AppSettings, app: tauri::AppHandle) -> Result
     
     update_settings(settings.clone())?;
     
+    if hotkeys_turned_on {
+        crate::hotkey::clear_runtime_suspension();
+    }
+    
     if let Err(e) = crate::hotkey::reload_from_settings() {
         eprintln!("重新加载快捷键失败: {}", e);
     }
@@ -56,6 +70,19 @@ pub fn save_settings(mut settings: AppSettings, app: tauri::AppHandle) -> Result
         }));
     }
     
+    if incognito_changed {
+        crate::services::clipboard::set_incognito(settings.incognito_mode);
+        crate::windows::tray::update_tray_tooltip(&app);
+        use tauri::Emitter;
+        let _ = app.emit("incognito-changed", serde_json::json!({ "enabled": settings.incognito_mode }));
+    }
+    
+    if preview_length_changed {
+        if let Err(e) = crate::services::database::clipboard::reset_previews() {
+            eprintln!("重置列表预览失败: {}", e);
+        }
+    }
+    
     if quickpaste_enabled_changed {
         if settings.quickpaste_enabled {
             let app_clone = app.clone();
@@ -78,10 +105,72 @@ pub fn save_settings(mut settings: AppSettings, app: tauri::AppHandle) -> Result
     Ok(())
 }
 
+// 保存当前设置为配置方案
+#[tauri::command]
+pub fn save_profile(name: String) -> Result<(), String> {
+    crate::services::settings::profiles::save_profile(&name)
+}
+
+// 加载配置方案（通过 apply_settings 应用，快捷键会重新注册）
+#[tauri::command]
+pub fn load_profile(name: String, app: tauri::AppHandle) -> Result<(), String> {
+    use tauri::Emitter;
+
+    let profile = crate::services::settings::profiles::read_profile(&name)?;
+    apply_settings(profile, app.clone())?;
+    let _ = app.emit("settings-changed", serde_json::json!({ "profile": name }));
+    Ok(())
+}
+
+// 列出配置方案
+#[tauri::command]
+pub fn list_profiles() -> Result<Vec<String>, String> {
+    crate::services::settings::profiles::list_profiles()
+}
+
+// 删除配置方案
+#[tauri::command]
+pub fn delete_profile(name: String) -> Result<(), String> {
+    crate::services::settings::profiles::delete_profile(&name)
+}
+
+// 导出全部设置（含快捷键）到单个文件，include_profiles 为 true 时一并导出配置方案
+#[tauri::command]
+pub fn export_settings_bundle(path: String, include_profiles: Option<bool>) -> Result<(), String> {
+    crate::services::settings::bundle::export_settings_bundle(std::path::Path::new(&path), include_profiles.unwrap_or(false))
+}
+
+// 导入设置包：先校验快捷键，存在无效、系统保留或重复的组合时不应用；apply 为 false 时仅返回预览报告
+#[tauri::command]
+pub fn import_settings_bundle(
+    path: String,
+    apply: bool,
+    app: tauri::AppHandle,
+) -> Result<crate::services::settings::bundle::BundleImportReport, String> {
+    use crate::services::settings::bundle::{read_settings_bundle, write_bundle_profiles};
+    use tauri::Emitter;
+
+    let (parsed, mut report) = read_settings_bundle(std::path::Path::new(&path))?;
+    if !apply || !report.conflicts.is_empty() {
+        return Ok(report);
+    }
+    report.warnings.extend(write_bundle_profiles(&parsed));
+    apply_settings(parsed.settings, app.clone())?;
+    report.applied = true;
+    let _ = app.emit("settings-changed", serde_json::json!({ "imported": true }));
+    Ok(report)
+}
+
+// 校验一组设置中的快捷键，返回存在问题的组合（空列表表示全部有效）
+#[tauri::command]
+pub fn validate_hotkey_settings(settings: AppSettings) -> Vec<crate::hotkey::ShortcutStatus> {
+    crate::services::system::hotkey_import::validate_settings_shortcuts(&settings)
+}
+
 #[tauri::command]
 pub fn reset_settings_to_default(app: tauri::AppHandle) -> Result<(), String> {
     let defaults = AppSettings::default();
-    save_settings(defaults, app)
+    apply_settings(defaults, app)
 }
 
 #[tauri::command]
@@ -226,13 +315,106 @@ pub fn get_shortcut_status(id: String) -> Option<crate::hotkey::ShortcutStatus>
     crate::hotkey::get_shortcut_status(&id)
 }
 
+// 获取热键诊断信息
+#[tauri::command]
+pub fn get_hotkey_diagnostics() -> crate::hotkey::HotkeyDiagnostics {
+    crate::hotkey::hotkey_diagnostics()
+}
+
+// 临时覆盖单个快捷键组合（仅本次运行有效），返回重新注册后的状态
+#[tauri::command]
+pub fn push_shortcut_override(id: String, shortcut: String) -> Result<Option<crate::hotkey::ShortcutStatus>, String> {
+    crate::hotkey::push_shortcut_override(&id, &shortcut)
+}
+
+// 移除最近一次临时覆盖，恢复上一层覆盖或已保存的组合
+#[tauri::command]
+pub fn pop_shortcut_override(id: String) -> Result<Option<crate::hotkey::ShortcutStatus>, String> {
+    crate::hotkey::pop_shortcut_override(&id)
+}
+
+// 将数字快捷键绑定到指定剪贴板项，item_id 为空时解除绑定
+#[tauri::command]
+pub fn bind_number_to_item(num: u8, item_id: Option<i64>, app: tauri::AppHandle) -> Result<(), String> {
+    use tauri::Emitter;
+
+    let pinned = crate::hotkey::bind_number_to_item(num, item_id)?;
+    let _ = app.emit("settings-changed", serde_json::json!({
+        "numberItemBindings": get_settings().number_item_bindings
+    }));
+    if pinned {
+        let _ = app.emit("clipboard-updated", ());
+    }
+    Ok(())
+}
+
+// 快捷键冲突时推荐可用的替代组合
+#[tauri::command]
+pub fn suggest_alternative_shortcut(shortcut: String) -> Option<String> {
+    crate::hotkey::suggest_alternative(&shortcut)
+}
+
+// 检查组合是否与系统保留快捷键冲突，返回系统动作名称
+#[tauri::command]
+pub fn check_system_shortcut_conflict(shortcut: String) -> Option<String> {
+    crate::hotkey::is_reserved_system_shortcut(&shortcut)
+}
+
+// 从其他剪贴板管理器的快捷键导出内容导入设置，apply 为 false 时仅返回预览报告
+#[tauri::command]
+pub fn import_hotkey_settings(
+    content: String,
+    apply: Option<bool>,
+) -> Result<crate::services::system::hotkey_import::HotkeyImportReport, String> {
+    use crate::services::system::hotkey_import::{apply_to_settings, build_import_report};
+
+    let (report, accepted) = build_import_report(&content, &get_settings());
+    if apply.unwrap_or(false) && !accepted.is_empty() {
+        crate::services::settings::update_with(|settings| apply_to_settings(settings, &accepted))?;
+        if let Err(e) = crate::hotkey::reload_from_settings() {
+            eprintln!("重新加载快捷键失败: {}", e);
+        }
+    }
+    Ok(report)
+}
+
+// 不按键直接执行快捷键对应的动作（用于测试快捷键功能），失败时返回 { code, message }
+#[tauri::command]
+pub fn trigger_shortcut_action(id: String) -> Result<(), crate::services::system::hotkey_error::HotkeyError> {
+    crate::hotkey::trigger_shortcut_action(&id)
+}
+
+// 校验排除文本的正则规则，返回无效的规则
+#[tauri::command]
+pub fn validate_exclusion_patterns(patterns: Vec<String>) -> Vec<crate::services::clipboard::InvalidPattern> {
+    crate::services::clipboard::validate_exclusion_patterns(&patterns)
+}
+
+// 获取快捷键规范化后的形式（用于调试符号键映射）
+#[tauri::command]
+pub fn normalize_shortcut(shortcut: String) -> String {
+    crate::hotkey::normalize_shortcut(&shortcut)
+}
+
+// 拆分快捷键为修饰键与按键（用于界面按键帽显示）
+#[tauri::command]
+pub fn parse_shortcut_parts(shortcut: String) -> Result<crate::hotkey::ShortcutParts, String> {
+    crate::hotkey::parse_shortcut_parts(&shortcut)
+}
+
+// 获取快捷键的规范形式（用于比较与去重）
+#[tauri::command]
+pub fn canonical_shortcut(shortcut: String) -> Result<String, String> {
+    crate::hotkey::canonical_shortcut(&shortcut)
+}
+
 // 切换剪贴板监听状态
 pub fn toggle_clipboard_monitor(app: &tauri::AppHandle) -> Result<(), String> {
     let mut settings = get_settings();
     settings.clipboard_monitor = !settings.clipboard_monitor;
     let enabled = settings.clipboard_monitor;
     
-    let result = save_settings(settings, app.clone());
+    let result = apply_settings(settings, app.clone());
     if crate::services::low_memory::is_low_memory_mode() {
         let _ = crate::windows::tray::native_menu::update_native_menu(app);
     }
@@ -243,8 +425,47 @@ pub fn toggle_clipboard_monitor(app: &tauri::AppHandle) -> Result<(), String> {
     result
 }
 
-// 切换格式粘贴状态
+// 设置无痕模式：开启后不记录任何复制内容，快捷键与已有历史照常可用
+pub fn apply_incognito(app: &tauri::AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = get_settings();
+    settings.incognito_mode = enabled;
+    update_settings(settings)?;
+    crate::services::clipboard::set_incognito(enabled);
+
+    use tauri::Emitter;
+    let _ = app.emit("incognito-changed", serde_json::json!({ "enabled": enabled }));
+    let _ = app.emit("settings-changed", serde_json::json!({ "incognitoMode": enabled }));
+
+    crate::windows::tray::update_tray_tooltip(app);
+    if crate::services::low_memory::is_low_memory_mode() {
+        let _ = crate::windows::tray::native_menu::update_native_menu(app);
+    }
+
+    let message = if enabled { "无痕模式已开启，复制内容将不会被记录" } else { "无痕模式已关闭" };
+    let _ = crate::services::notification::show_notification(app, "QuickClipboard", message);
+    Ok(())
+}
+
+// 切换无痕模式
+pub fn toggle_incognito(app: &tauri::AppHandle) -> Result<(), String> {
+    apply_incognito(app, !crate::services::clipboard::is_incognito())
+}
+
+#[tauri::command]
+pub fn set_incognito(enabled: bool, app: tauri::AppHandle) -> Result<(), String> {
+    apply_incognito(&app, enabled)
+}
+
+#[tauri::command]
+pub fn is_incognito() -> bool {
+    crate::services::clipboard::is_incognito()
+}
+
+// 切换格式粘贴状态（常驻切换并保存设置，同时取消未使用的临时翻转）
 pub fn toggle_paste_with_format(app: &tauri::AppHandle) -> Result<(), String> {
+    use crate::services::paste::format_toggle;
+
+    format_toggle::consume_momentary();
     let mut settings = get_settings();
     settings.paste_with_format = !settings.paste_with_format;
     let enabled = settings.paste_with_format;
@@ -254,7 +475,8 @@ pub fn toggle_paste_with_format(app: &tauri::AppHandle) -> Result<(), String> {
         "pasteWithFormat": settings.paste_with_format
     }));
     
-    let result = save_settings(settings, app.clone());
+    let result = apply_settings(settings, app.clone());
+    format_toggle::emit_state();
     if crate::services::low_memory::is_low_memory_mode() {
         let _ = crate::windows::tray::native_menu::update_native_menu(app);
     }
@@ -265,6 +487,29 @@ pub fn toggle_paste_with_format(app: &tauri::AppHandle) -> Result<(), String> {
     result
 }
 
+// 快捷键触发的格式切换：按设置常驻切换，或仅翻转下一次粘贴
+pub fn toggle_paste_with_format_by_hotkey(app: &tauri::AppHandle) -> Result<(), String> {
+    use crate::services::paste::format_toggle::{self, ToggleMode};
+
+    if ToggleMode::from_setting(&get_settings().paste_format_toggle_mode) == ToggleMode::Sticky {
+        return toggle_paste_with_format(app);
+    }
+    let state = format_toggle::toggle_momentary();
+    let message = match (state.momentary, state.enabled) {
+        (true, true) => "下一次粘贴将保留格式",
+        (true, false) => "下一次粘贴将不带格式",
+        (false, _) => "已取消临时格式切换",
+    };
+    let _ = crate::services::notification::show_notification(app, "QuickClipboard", message);
+    Ok(())
+}
+
+// 获取下一次粘贴实际使用的格式状态（含临时翻转）
+#[tauri::command]
+pub fn get_paste_with_format() -> bool {
+    crate::services::paste::format_toggle::paste_with_format()
+}
+
 // 保存窗口位置
 #[tauri::command]
 pub fn save_window_position(x: i32, y: i32) -> Result<(), String> {
@@ -328,10 +573,13 @@ pub fn is_admin_task_ready() -> bool {
 // 以管理员权限重启程序
 #[tauri::command]
 pub fn restart_as_admin(app: tauri::AppHandle) -> Result<(), String> {
+    // 先释放快捷键，避免新进程启动时与尚未退出的本进程冲突
+    crate::hotkey::shutdown_hotkey_manager();
     if crate::services::system::try_elevate_and_restart() {
         app.exit(0);
         Ok(())
     } else {
+        crate::hotkey::resume_hotkey_manager();
         Err("请求管理员权限失败，用户取消了UAC提示".to_string())
     }
 }
@@ -163,3 +163,15 @@ pub fn exit_low_memory_mode(app: tauri::AppHandle) -> Result<(), String> {
 pub fn is_low_memory_mode() -> bool {
     crate::services::low_memory::is_low_memory_mode()
 }
+
+// 列出待执行的定时操作
+#[tauri::command]
+pub fn list_pending_timers() -> Vec<crate::services::system::timers::PendingTimer> {
+    crate::services::system::timers::list_pending_timers()
+}
+
+// 取消定时操作，返回是否找到该定时器
+#[tauri::command]
+pub fn cancel_timer(id: u64) -> bool {
+    crate::services::system::timers::cancel_timer(id)
+}
@@ -135,4 +135,21 @@ pub async fn check_updates_and_open_window(app: AppHandle) -> Result<bool, Strin
     crate::windows::updater_window::check_updates_and_open_window(&app).await
 }
 
+// 隐藏便捷粘贴窗口（按设置的延迟）
+#[tauri::command]
+pub fn hide_quickpaste_window(app: AppHandle) -> Result<(), String> {
+    crate::windows::quickpaste::schedule_hide_quickpaste_window(&app);
+    Ok(())
+}
+
+// 便捷粘贴窗口有交互（切换选中项、鼠标悬停），重置空闲自动隐藏计时
+#[tauri::command]
+pub fn quickpaste_interaction() {
+    crate::windows::quickpaste::touch_interaction();
+}
 
+// 上报便捷粘贴窗口当前显示的项（数字快捷键按显示顺序粘贴）
+#[tauri::command]
+pub fn set_quickpaste_visible_items(items: Vec<Option<crate::windows::quickpaste::VisibleEntry>>) {
+    crate::windows::quickpaste::set_visible_entries(items);
+}
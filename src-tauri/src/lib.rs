@@ -63,7 +63,6 @@ pub fn run() {
                 show_main_window(&window);
             }
         }))
-        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_opener::init())
@@ -100,21 +99,59 @@ pub fn run() {
                 commands::emit_clipboard_updated,
                 commands::emit_quick_texts_updated,
                 commands::get_clipboard_history,
+                commands::get_clipboard_history_batch,
+                commands::scan_clipboard_history,
                 commands::get_clipboard_total_count,
+                commands::get_monitor_status,
+                commands::get_recent_searches,
+                commands::clear_recent_searches,
+                commands::count_items_by_type,
+                commands::get_history_stats,
+                commands::open_item_source_app,
+                commands::freeze_top_item,
+                commands::unfreeze_session_items,
+                commands::set_item_note,
+                commands::capture_current_clipboard,
                 commands::get_clipboard_item_by_id_cmd,
                 commands::update_clipboard_item_cmd,
+                commands::update_item_text,
                 commands::toggle_pin_clipboard_item,
                 commands::paste_text_direct,
                 commands::paste_image_file,
                 commands::move_clipboard_item,
                 commands::move_clipboard_item_by_id,
+                commands::reorder_pinned_items,
+                commands::set_item_forced_format,
+                commands::set_item_local_only,
+                commands::get_pinned_items,
                 commands::apply_history_limit,
                 commands::paste_content,
+                commands::paste_items_combined,
+                commands::paste_to_window,
+                commands::open_compose_window,
+                commands::get_compose_entries,
+                commands::compose_add_items,
+                commands::compose_add_text,
+                commands::compose_update_entry,
+                commands::compose_remove_entry,
+                commands::compose_move_entry,
+                commands::compose_clear,
+                commands::compose_preview,
+                commands::paste_compose,
                 commands::delete_clipboard_item,
+                commands::delete_clipboard_items,
+                commands::get_recently_pasted,
+                commands::save_macro,
+                commands::list_macros,
+                commands::delete_macro,
+                commands::start_macro,
+                commands::stop_macro,
+                commands::get_macro_playback,
                 commands::clear_clipboard_history,
                 commands::save_image_from_path,
                 commands::copy_image_to_clipboard,
                 commands::copy_clipboard_item,
+                commands::copy_item_as,
                 commands::resolve_image_path,
                 commands::get_favorites_history,
                 commands::get_favorites_total_count,
@@ -134,6 +171,13 @@ pub fn run() {
                 commands::reload_settings,
                 commands::save_settings,
                 commands::reset_settings_to_default,
+                commands::save_profile,
+                commands::load_profile,
+                commands::list_profiles,
+                commands::delete_profile,
+                commands::export_settings_bundle,
+                commands::import_settings_bundle,
+                commands::validate_hotkey_settings,
                 commands::get_settings_cmd,
                 commands::set_edge_hide_enabled,
                 commands::get_all_windows_info_cmd,
@@ -153,6 +197,21 @@ pub fn run() {
                 commands::is_hotkeys_enabled,
                 commands::get_shortcut_statuses,
                 commands::get_shortcut_status,
+                commands::normalize_shortcut,
+                commands::canonical_shortcut,
+                commands::parse_shortcut_parts,
+                commands::get_paste_with_format,
+                commands::validate_exclusion_patterns,
+                commands::set_incognito,
+                commands::is_incognito,
+                commands::suggest_alternative_shortcut,
+                commands::check_system_shortcut_conflict,
+                commands::import_hotkey_settings,
+                commands::trigger_shortcut_action,
+                commands::get_hotkey_diagnostics,
+                commands::push_shortcut_override,
+                commands::pop_shortcut_override,
+                commands::bind_number_to_item,
                 commands::save_window_position,
                 commands::save_window_size,
                 commands::save_quickpaste_window_size,
@@ -160,9 +219,16 @@ pub fn run() {
                 commands::dm_get_default_storage_path,
                 commands::dm_check_target_has_data,
                 commands::dm_change_storage_path,
+                commands::dm_move_database,
+                commands::dm_check_database_integrity,
+                commands::dm_repair_database,
+                commands::dm_prompt_repair_database_if_needed,
                 commands::dm_reset_storage_path_to_default,
                 commands::dm_export_data_zip,
                 commands::dm_import_data_zip,
+                commands::dm_cancel_history_io,
+                commands::dm_rebuild_derived_data,
+                commands::dm_cancel_rebuild_derived_data,
                 commands::dm_reset_all_data,
                 commands::dm_list_backups,
                 commands::set_mouse_position,
@@ -178,12 +244,17 @@ pub fn run() {
                 commands::enter_low_memory_mode,
                 commands::exit_low_memory_mode,
                 commands::is_low_memory_mode,
+                commands::list_pending_timers,
+                commands::cancel_timer,
                 commands::play_sound,
                 commands::play_beep,
                 commands::play_copy_sound,
                 commands::play_paste_sound,
                 commands::play_scroll_sound,
                 commands::reload_all_windows,
+                commands::hide_quickpaste_window,
+                commands::quickpaste_interaction,
+                commands::set_quickpaste_visible_items,
                 commands::check_updates_and_open_window,
                 windows::plugins::context_menu::commands::show_context_menu,
                 windows::plugins::context_menu::commands::get_context_menu_options,
@@ -253,7 +324,7 @@ pub fn run() {
 
                 let db_path_buf = get_data_directory()?.join("quickclipboard.db");
                 let db_path_str = db_path_buf.to_str().ok_or("数据库路径无效")?;
-                if let Err(e1) = services::database::init_database(db_path_str) {
+                let open_result = services::database::init_database(db_path_str).or_else(|e1| {
                     if let Some(dir) = db_path_buf.parent() {
                         for name in ["quickclipboard.db-wal", "quickclipboard.db-shm"] {
                             let p = dir.join(name);
@@ -261,8 +332,10 @@ pub fn run() {
                         }
                     }
                     services::database::init_database(db_path_str)
-                        .map_err(|e2| format!("数据库初始化失败(已尝试清理 wal/shm): {} -> {}", e1, e2))?;
-                }
+                        .map_err(|e2| format!("数据库初始化失败(已尝试清理 wal/shm): {} -> {}", e1, e2))
+                });
+                // 数据库损坏时不中断启动，由主窗口提示修复
+                services::data_management::integrity::check_database_on_startup(open_result);
                 let _ = services::database::connection::with_connection(|conn| {
                     conn.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);")
                 });
@@ -281,14 +354,18 @@ pub fn run() {
                         }
                     }
                 }
-                let _ = services::database::limit_clipboard_history(settings.history_limit);
+                let _ = services::clipboard::enforce_history_limit(settings.history_limit);
                 
                 utils::init_screen_utils(app.handle().clone());
+                // 全局快捷键插件在受限环境中可能初始化失败，失败时降级为仅托盘操作而不是终止启动
+                if let Err(e) = app.handle().plugin(tauri_plugin_global_shortcut::Builder::new().build()) {
+                    eprintln!("全局快捷键插件初始化失败: {}", e);
+                }
                 hotkey::init_hotkey_manager(app.handle().clone(), window.clone());
                 input_monitor::init_input_monitor(window.clone());
                 init_edge_monitor(window.clone());
                 setup_tray(app.handle())?;
-                hotkey::reload_from_settings()?;
+                hotkey::reload_with_reason(hotkey::ReloadReason::Startup)?;
                 input_monitor::start_monitoring();
                 windows::plugins::context_menu::init();
                 windows::plugins::input_dialog::init();
@@ -300,6 +377,7 @@ pub fn run() {
                 #[cfg(feature = "gpu-image-viewer")]
                 windows::native_pin_window::setup_event_listener(app.handle());
                 focus::start_focus_listener(app.handle().clone());
+                services::system::system_theme::start_system_theme_listener(app.handle().clone());
 
                 #[cfg(feature = "screenshot-suite")]
                 {
@@ -308,6 +386,7 @@ pub fn run() {
                     }
                 }
 
+                services::clipboard::set_incognito(settings.incognito_mode);
                 if settings.clipboard_monitor {
                     let _ = start_clipboard_monitor();
                 }
@@ -335,6 +414,9 @@ pub fn run() {
                         api.prevent_exit();
                     }
                 }
+                tauri::RunEvent::Exit => {
+                    hotkey::shutdown_hotkey_manager();
+                }
                 tauri::RunEvent::WindowEvent { label, event: tauri::WindowEvent::Destroyed, .. } => {
                     if label == "main" && !services::low_memory::is_low_memory_mode() {
                         app.exit(0);
@@ -14,7 +14,10 @@ pub struct ClipboardContent {
     pub content_type: ContentType,
     pub text: Option<String>,
     pub html: Option<String>,
+    pub rtf: Option<String>,
     pub files: Option<Vec<String>>,
+    // 组合项附带的图片（mixed_content_mode 为 "composite" 时由同时捕获的图片合并而来）
+    pub image: Option<String>,
 }
 
 // 保存剪贴板图片到缓存目录
@@ -82,11 +85,16 @@ impl ClipboardContent {
                     content_type: ContentType::Files,
                     text: Some(files.join("\n")),
                     html: None,
+                    rtf: None,
                     files: Some(files),
+                    image: None,
                 }]);
             }
         }
         
+        // 获取RTF（与文本/HTML一起保存）
+        let rtf = ctx.get_rich_text().ok().filter(|r| !r.trim().is_empty());
+        
         // 获取HTML（富文本）
         if let Ok(html) = ctx.get_html() {
             if !html.trim().is_empty() {
@@ -96,7 +104,9 @@ impl ClipboardContent {
                     content_type: ContentType::RichText,
                     text,
                     html: Some(html),
+                    rtf,
                     files: None,
+                    image: None,
                 });
             }
         } else if let Ok(text) = ctx.get_text() {
@@ -106,20 +116,28 @@ impl ClipboardContent {
                     content_type: ContentType::Text,
                     text: Some(text),
                     html: None,
+                    rtf,
                     files: None,
+                    image: None,
                 });
             }
         }
         
-        // 获取图片
-        if let Ok(rust_image) = ctx.get_image() {
-            if let Ok(image_path) = save_clipboard_image(rust_image) {
-                results.push(ClipboardContent {
-                    content_type: ContentType::Files,
-                    text: Some(image_path.clone()),
-                    html: None,
-                    files: Some(vec![image_path]),
-                });
+        // 获取图片（优先文本且已有文本时不再读取，避免保存无用的图片文件）
+        let mixed_mode = crate::services::get_settings().mixed_content_mode;
+        let has_text = results.iter().any(|r| r.text.as_ref().map(|t| !t.trim().is_empty()).unwrap_or(false));
+        if !(mixed_mode == "prefer_text" && has_text) {
+            if let Ok(rust_image) = ctx.get_image() {
+                if let Ok(image_path) = save_clipboard_image(rust_image) {
+                    results.push(ClipboardContent {
+                        content_type: ContentType::Files,
+                        text: Some(image_path.clone()),
+                        html: None,
+                        rtf: None,
+                        files: Some(vec![image_path]),
+                        image: None,
+                    });
+                }
             }
         }
 
@@ -138,7 +156,7 @@ impl ClipboardContent {
             });
         }
 
-        Ok(results)
+        Ok(resolve_mixed_content(results, &mixed_mode))
     }
     
     // 计算内容的哈希值
@@ -172,9 +190,169 @@ impl ClipboardContent {
     }
 }
 
+fn is_captured_image(content: &ClipboardContent) -> bool {
+    content.content_type == ContentType::Files
+        && content.files.as_ref().map(|f| f.len() == 1 && is_image_path(&f[0])).unwrap_or(false)
+}
+
+// 同时捕获到文本与图片时按设置处理：
+// "separate" 分别保存（默认），"prefer_image"/"prefer_text" 只保留一种，"composite" 合并为附带图片的文本项
+pub fn resolve_mixed_content(mut results: Vec<ClipboardContent>, mode: &str) -> Vec<ClipboardContent> {
+    let text_index = results.iter().position(|r| r.content_type != ContentType::Files);
+    let image_index = results.iter().position(is_captured_image);
+    let (Some(text_index), Some(image_index)) = (text_index, image_index) else {
+        return results;
+    };
+
+    match mode {
+        "prefer_image" => {
+            results.remove(text_index);
+        }
+        "prefer_text" => {
+            results.remove(image_index);
+        }
+        "composite" => {
+            let image = results.remove(image_index);
+            let text_index = if image_index < text_index { text_index - 1 } else { text_index };
+            results[text_index].image = image.files.and_then(|f| f.into_iter().next());
+        }
+        _ => {}
+    }
+    results
+}
+
+// 获取剪贴板当前提供的格式名称（按写入顺序，第一个为主格式）
+#[cfg(target_os = "windows")]
+pub fn get_clipboard_format_names() -> Vec<String> {
+    use windows::Win32::System::DataExchange::{
+        CloseClipboard, EnumClipboardFormats, GetClipboardFormatNameW, OpenClipboard,
+    };
+
+    let mut names = Vec::new();
+    unsafe {
+        if OpenClipboard(None).is_err() {
+            return names;
+        }
+
+        let mut format = EnumClipboardFormats(0);
+        while format != 0 {
+            let name = match standard_format_name(format) {
+                Some(name) => name.to_string(),
+                None => {
+                    let mut buf = [0u16; 256];
+                    let len = GetClipboardFormatNameW(format, &mut buf);
+                    if len > 0 {
+                        String::from_utf16_lossy(&buf[..len as usize])
+                    } else {
+                        format!("#{}", format)
+                    }
+                }
+            };
+            names.push(name);
+            format = EnumClipboardFormats(format);
+        }
+
+        let _ = CloseClipboard();
+    }
+    names
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn get_clipboard_format_names() -> Vec<String> {
+    ClipboardContext::new()
+        .and_then(|ctx| ctx.available_formats())
+        .unwrap_or_default()
+}
+
+// 系统预定义格式没有注册名称，使用常量名
+#[cfg(target_os = "windows")]
+fn standard_format_name(format: u32) -> Option<&'static str> {
+    match format {
+        1 => Some("CF_TEXT"),
+        2 => Some("CF_BITMAP"),
+        3 => Some("CF_METAFILEPICT"),
+        7 => Some("CF_OEMTEXT"),
+        8 => Some("CF_DIB"),
+        13 => Some("CF_UNICODETEXT"),
+        14 => Some("CF_ENHMETAFILE"),
+        15 => Some("CF_HDROP"),
+        16 => Some("CF_LOCALE"),
+        17 => Some("CF_DIBV5"),
+        _ => None,
+    }
+}
+
+// 主格式或全部格式都在忽略列表中时跳过存储
+pub fn is_ignored_by_format(formats: &[String], ignore_list: &[String]) -> bool {
+    let is_ignored = |name: &String| {
+        ignore_list
+            .iter()
+            .any(|ignored| !ignored.trim().is_empty() && ignored.trim().eq_ignore_ascii_case(name))
+    };
+
+    match formats.first() {
+        Some(primary) => is_ignored(primary) || formats.iter().all(is_ignored),
+        None => false,
+    }
+}
+
 // 检查路径是否是图片文件
 fn is_image_path(path: &str) -> bool {
     let lower = path.to_lowercase();
     lower.ends_with(".png") || lower.ends_with(".jpg") || lower.ends_with(".jpeg") 
         || lower.ends_with(".gif") || lower.ends_with(".webp") || lower.ends_with(".bmp")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(value: &str) -> ClipboardContent {
+        ClipboardContent {
+            content_type: ContentType::Text,
+            text: Some(value.to_string()),
+            html: None,
+            rtf: None,
+            files: None,
+            image: None,
+        }
+    }
+
+    fn image(path: &str) -> ClipboardContent {
+        ClipboardContent {
+            content_type: ContentType::Files,
+            text: Some(path.to_string()),
+            html: None,
+            rtf: None,
+            files: Some(vec![path.to_string()]),
+            image: None,
+        }
+    }
+
+    #[test]
+    fn test_resolve_mixed_content_modes() {
+        let mixed = || vec![text("hello"), image("clipboard_images/abc.png")];
+
+        assert_eq!(resolve_mixed_content(mixed(), "separate").len(), 2);
+
+        let preferred = resolve_mixed_content(mixed(), "prefer_image");
+        assert_eq!(preferred.len(), 1);
+        assert_eq!(preferred[0].content_type, ContentType::Files);
+
+        let preferred = resolve_mixed_content(mixed(), "prefer_text");
+        assert_eq!(preferred.len(), 1);
+        assert_eq!(preferred[0].text.as_deref(), Some("hello"));
+
+        let composite = resolve_mixed_content(mixed(), "composite");
+        assert_eq!(composite.len(), 1);
+        assert_eq!(composite[0].content_type, ContentType::Text);
+        assert_eq!(composite[0].image.as_deref(), Some("clipboard_images/abc.png"));
+    }
+
+    #[test]
+    fn test_resolve_mixed_content_single_kind_unchanged() {
+        let only_text = resolve_mixed_content(vec![text("hello")], "composite");
+        assert_eq!(only_text.len(), 1);
+        assert!(only_text[0].image.is_none());
+    }
+}
@@ -19,6 +19,12 @@ impl ContentType {
         }
     }
 
+    // 移除附加类型（主类型保留）
+    pub fn remove_type(&mut self, type_name: &str) {
+        let primary = self.types.first().cloned();
+        self.types.retain(|t| t != type_name || Some(t) == primary.as_ref());
+    }
+
     pub fn to_db_string(&self) -> String {
         self.types.join(",")
     }
@@ -79,6 +85,15 @@ mod tests {
         assert!(ct.has_type("link"));
     }
 
+    #[test]
+    fn test_remove_type_keeps_primary() {
+        let mut ct = ContentType::from_db_string("text,link,image");
+        ct.remove_type("link");
+        assert_eq!(ct.to_db_string(), "text,image");
+        ct.remove_type("text");
+        assert_eq!(ct.to_db_string(), "text,image");
+    }
+
     #[test]
     fn test_matches_filter() {
         let ct = ContentType::from_db_string("rich_text,link");
@@ -0,0 +1,61 @@
+// 复制为：对剪贴板项的文本应用转换后写入系统剪贴板（不粘贴），供在其他地方使用
+// 转换结果默认不记录到历史（写入前预设去重哈希，监听忽略这次写入）；
+// 开启 copy_as_store_result 后作为新的文本项保存，原项保持不变
+
+use super::processor::{retag_text_links, ProcessedContent};
+use super::transform::TextTransform;
+
+// 计算转换结果：富文本只转换其纯文本部分，图片和文件不支持转换
+fn transform_item_text(content_type: &str, content: &str, transform: TextTransform) -> Result<String, String> {
+    if content_type.contains("image") || content_type.contains("file") {
+        return Err("只能对文本内容应用转换".into());
+    }
+    let text = transform.apply(content);
+    if text.is_empty() {
+        return Err("转换后的内容为空".into());
+    }
+    Ok(text)
+}
+
+pub fn copy_item_as(id: i64, transform: TextTransform) -> Result<(), String> {
+    let item = crate::services::database::get_clipboard_item_by_id(id)?
+        .ok_or_else(|| format!("剪贴板项不存在: {}", id))?;
+    let text = transform_item_text(&item.content_type, &item.content, transform)?;
+
+    crate::services::paste::set_clipboard_from_item("text", &text, &None, true)?;
+
+    if crate::get_settings().copy_as_store_result {
+        let result = ProcessedContent {
+            content_type: retag_text_links("text", &text),
+            content: text,
+            html_content: None,
+            rtf_content: None,
+            image_id: None,
+            source_app: item.source_app,
+            source_icon_hash: item.source_icon_hash,
+            source_formats: Vec::new(),
+        };
+        super::storage::store_clipboard_item(result)?;
+
+        use tauri::Emitter;
+        if let Some(app) = super::get_app_handle() {
+            let _ = app.emit("clipboard-updated", ());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_item_text() {
+        assert_eq!(transform_item_text("text", "  Hello  ", TextTransform::Trim).unwrap(), "Hello");
+        // 富文本按纯文本内容转换
+        assert_eq!(transform_item_text("rich_text", "Mixed Case", TextTransform::Uppercase).unwrap(), "MIXED CASE");
+        assert!(transform_item_text("image", "C:\\a.png", TextTransform::Trim).is_err());
+        assert!(transform_item_text("file", "files:[]", TextTransform::Trim).is_err());
+        assert!(transform_item_text("text", " \n ", TextTransform::Trim).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+// 文本排除规则：匹配任一正则的文本不会被记录到历史（如访问令牌）
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use regex::Regex;
+use serde::Serialize;
+
+// 无效的排除规则
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InvalidPattern {
+    pub index: usize,
+    pub pattern: String,
+    pub message: String,
+}
+
+// 已编译的排除规则（记录来源以便设置变更时重新编译）
+#[derive(Default)]
+struct ExclusionSet {
+    source: Vec<String>,
+    regexes: Vec<Regex>,
+}
+
+impl ExclusionSet {
+    // 编译规则，无效的规则被跳过并返回错误信息；空白规则忽略
+    fn compile(patterns: &[String]) -> (Self, Vec<InvalidPattern>) {
+        let mut regexes = Vec::new();
+        let mut invalid = Vec::new();
+        for (index, pattern) in patterns.iter().enumerate() {
+            if pattern.trim().is_empty() {
+                continue;
+            }
+            match Regex::new(pattern) {
+                Ok(re) => regexes.push(re),
+                Err(e) => invalid.push(InvalidPattern {
+                    index,
+                    pattern: pattern.clone(),
+                    message: e.to_string(),
+                }),
+            }
+        }
+        (Self { source: patterns.to_vec(), regexes }, invalid)
+    }
+
+    fn is_match(&self, text: &str) -> bool {
+        self.regexes.iter().any(|re| re.is_match(text))
+    }
+}
+
+static EXCLUSIONS: Lazy<Mutex<ExclusionSet>> = Lazy::new(|| Mutex::new(ExclusionSet::default()));
+
+// 检查文本是否匹配排除规则，规则与上次不同时才重新编译
+pub fn is_text_excluded(text: &str, patterns: &[String]) -> bool {
+    if patterns.is_empty() {
+        return false;
+    }
+    let mut set = EXCLUSIONS.lock();
+    if set.source != patterns {
+        let (compiled, invalid) = ExclusionSet::compile(patterns);
+        for error in &invalid {
+            eprintln!("排除规则 #{} 无效（{}）: {}", error.index + 1, error.pattern, error.message);
+        }
+        *set = compiled;
+    }
+    set.is_match(text)
+}
+
+// 校验排除规则，返回无效的规则（用于设置界面提示）
+pub fn validate_exclusion_patterns(patterns: &[String]) -> Vec<InvalidPattern> {
+    ExclusionSet::compile(patterns).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patterns(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_matching_and_non_matching_copy() {
+        let (set, invalid) = ExclusionSet::compile(&patterns(&[r"^ghp_[A-Za-z0-9]+$"]));
+        assert!(invalid.is_empty());
+        assert!(set.is_match("ghp_abcDEF123"));
+        assert!(!set.is_match("see ghp_abc in the docs"));
+        assert!(!set.is_match("hello world"));
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_reported_and_skipped() {
+        let list = patterns(&["(unclosed", "", r"^\d{6}$"]);
+        let (set, invalid) = ExclusionSet::compile(&list);
+        assert_eq!(invalid.len(), 1);
+        assert_eq!(invalid[0].index, 0);
+        assert_eq!(invalid[0].pattern, "(unclosed");
+        assert!(set.is_match("123456"));
+        assert!(!set.is_match("(unclosed"));
+        assert_eq!(validate_exclusion_patterns(&list), invalid);
+    }
+
+    #[test]
+    fn test_cached_set_recompiles_on_change() {
+        assert!(is_text_excluded("secret-1", &patterns(&["^secret-"])));
+        assert!(!is_text_excluded("secret-1", &patterns(&["^token-"])));
+        assert!(!is_text_excluded("secret-1", &[]));
+    }
+}
@@ -0,0 +1,31 @@
+// 自动清理：按历史数量限制删除旧记录、合并连拍图片时删除被取代的图片，并通过 clipboard-item-expired 事件通知前端
+// 每次清理的 ID 合并为一个事件发送，与手动删除区分开
+
+use serde::Serialize;
+use tauri::Emitter;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpiredItems {
+    pub ids: Vec<i64>,
+    // 触发清理的原因："history_limit"（超出历史数量限制）或 "image_coalesce"（被连拍的新图片取代）
+    pub reason: &'static str,
+}
+
+// 应用历史数量限制，返回被清理的记录 ID
+pub fn enforce_history_limit(limit: u64) -> Result<Vec<i64>, String> {
+    let ids = crate::services::database::limit_clipboard_history(limit)?;
+    emit_expired(&ids, "history_limit");
+    Ok(ids)
+}
+
+// 发送已自动清理的记录，ids 为空时不发送
+pub fn emit_expired(ids: &[i64], reason: &'static str) {
+    if ids.is_empty() {
+        return;
+    }
+    if let Some(app) = super::get_app_handle() {
+        let payload = ExpiredItems { ids: ids.to_vec(), reason };
+        let _ = app.emit("clipboard-item-expired", payload);
+    }
+}
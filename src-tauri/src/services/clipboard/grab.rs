@@ -0,0 +1,87 @@
+// 抓取选中文本：模拟 Ctrl+C，等待剪贴板发生变化后立即保存（用于选中后不会自动写入剪贴板的应用）
+
+use std::time::{Duration, Instant};
+
+// 轮询剪贴板变化的间隔
+const POLL_INTERVAL: Duration = Duration::from_millis(15);
+// 检测到变化后等待来源应用写完全部格式
+const SETTLE_DELAY: Duration = Duration::from_millis(30);
+
+// 在超时前轮询 read，值与 before 不同时返回 true；无论 read 返回什么都会在超时后结束
+fn wait_for_change<T, F>(before: &T, timeout: Duration, poll: Duration, mut read: F) -> bool
+where
+    T: PartialEq,
+    F: FnMut() -> T,
+{
+    let deadline = Instant::now() + timeout;
+    loop {
+        if read() != *before {
+            return true;
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return false;
+        }
+        std::thread::sleep(poll.min(deadline - now));
+    }
+}
+
+// 剪贴板变化标识：Windows 使用系统序列号（任何写入都会递增）
+#[cfg(windows)]
+pub(super) fn clipboard_marker() -> u64 {
+    u64::from(unsafe { windows::Win32::System::DataExchange::GetClipboardSequenceNumber() })
+}
+
+// 其他平台比较当前内容的哈希
+#[cfg(not(windows))]
+pub(super) fn clipboard_marker() -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    if let Ok(contents) = super::capture::ClipboardContent::capture() {
+        for content in &contents {
+            content.calculate_hash().hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+// 模拟复制当前选中内容并保存，返回新保存项的 ID（与列表首项相同时为 None）
+pub fn grab_selection() -> Result<Option<i64>, String> {
+    let settings = crate::services::get_settings();
+    let timeout = Duration::from_millis(settings.grab_selection_timeout_ms);
+
+    let before = clipboard_marker();
+    crate::services::paste::keyboard::simulate_copy()?;
+    if !wait_for_change(&before, timeout, POLL_INTERVAL, clipboard_marker) {
+        return Err(format!("{} 毫秒内剪贴板未变化，可能没有选中内容", timeout.as_millis()));
+    }
+    std::thread::sleep(SETTLE_DELAY);
+    super::capture_current_clipboard()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_wait_for_change_detects_change() {
+        let reads = Cell::new(0);
+        let changed = wait_for_change(&1u64, Duration::from_secs(1), Duration::ZERO, || {
+            reads.set(reads.get() + 1);
+            if reads.get() >= 3 { 2 } else { 1 }
+        });
+        assert!(changed);
+        assert_eq!(reads.get(), 3);
+    }
+
+    #[test]
+    fn test_wait_for_change_times_out() {
+        let started = Instant::now();
+        let changed = wait_for_change(&1u64, Duration::from_millis(40), Duration::from_millis(5), || 1);
+        assert!(!changed);
+        assert!(started.elapsed() >= Duration::from_millis(40));
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+}
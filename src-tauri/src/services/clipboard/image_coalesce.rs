@@ -0,0 +1,138 @@
+// 图片连拍合并：时间窗口内连续捕获的相似图片（如截图工具连续写入多帧）只保留最后一张
+
+use parking_lot::Mutex;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+// 最近一次保存的图片
+#[derive(Debug, Clone, Copy)]
+struct RecentImage {
+    hash: u64,
+    item_id: i64,
+    at: Instant,
+}
+
+static LAST_IMAGE: Mutex<Option<RecentImage>> = Mutex::new(None);
+
+// 差值哈希（dHash）：缩放为 9x8 灰度图，逐行比较相邻像素明暗，对轻微差异不敏感
+pub fn dhash(img: &image::DynamicImage) -> u64 {
+    let small = image::imageops::resize(&img.to_luma8(), 9, 8, image::imageops::FilterType::Triangle);
+    let mut hash = 0u64;
+    for y in 0..8 {
+        for x in 0..8 {
+            let left = small.get_pixel(x, y)[0];
+            let right = small.get_pixel(x + 1, y)[0];
+            hash = (hash << 1) | u64::from(left > right);
+        }
+    }
+    hash
+}
+
+// 两个哈希的相似度（0.0 ~ 1.0）
+pub fn similarity(a: u64, b: u64) -> f64 {
+    1.0 - f64::from((a ^ b).count_ones()) / 64.0
+}
+
+// 哈希中 0 或 1 的位数少于该值时视为低信息量（纯色、渐变等图片的 dHash 接近全 0 或全 1）
+const MIN_HASH_BITS: u32 = 8;
+
+// 低信息量的哈希无法区分不同图片，不参与合并
+fn is_distinctive(hash: u64) -> bool {
+    let ones = hash.count_ones();
+    ones >= MIN_HASH_BITS && ones <= 64 - MIN_HASH_BITS
+}
+
+// 判断新图片是否应与上一张合并，返回被合并的项 ID
+fn coalesce_target(
+    previous: Option<&RecentImage>,
+    hash: u64,
+    now: Instant,
+    window: Duration,
+    threshold: f64,
+) -> Option<i64> {
+    let previous = previous?;
+    if !is_distinctive(hash) || !is_distinctive(previous.hash) {
+        return None;
+    }
+    let within_window = now.saturating_duration_since(previous.at) <= window;
+    (within_window && similarity(previous.hash, hash) >= threshold).then_some(previous.item_id)
+}
+
+// 计算图片文件的哈希，无法读取时返回 None（不参与合并）
+pub fn hash_image_file(path: &Path) -> Option<u64> {
+    image::open(path).ok().map(|img| dhash(&img))
+}
+
+// 按设置查找应被新图片取代的上一张图片；合并窗口为 0 时不合并
+pub fn find_coalesce_target(hash: u64) -> Option<i64> {
+    let settings = crate::get_settings();
+    if settings.image_coalesce_window_ms == 0 {
+        return None;
+    }
+    coalesce_target(
+        LAST_IMAGE.lock().as_ref(),
+        hash,
+        Instant::now(),
+        Duration::from_millis(settings.image_coalesce_window_ms),
+        settings.image_coalesce_similarity,
+    )
+}
+
+// 记录新保存的图片，作为下一次比较的基准
+pub fn record_image(hash: u64, item_id: i64) {
+    *LAST_IMAGE.lock() = Some(RecentImage { hash, item_id, at: Instant::now() });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{DynamicImage, Rgba, RgbaImage};
+
+    // 水平渐变图片，offset 用于制造轻微差异
+    fn gradient(offset: u8) -> DynamicImage {
+        DynamicImage::ImageRgba8(RgbaImage::from_fn(64, 48, |x, y| {
+            let v = ((x * 4) as u8).wrapping_add(offset).saturating_add((y / 8) as u8);
+            Rgba([v, v, v, 255])
+        }))
+    }
+
+    #[test]
+    fn test_similar_images_hash_close() {
+        let a = dhash(&gradient(0));
+        let b = dhash(&gradient(2));
+        assert!(similarity(a, b) >= 0.9);
+
+        let flipped = DynamicImage::ImageRgba8(image::imageops::flip_horizontal(&gradient(0).to_rgba8()));
+        assert!(similarity(a, dhash(&flipped)) < 0.5);
+    }
+
+    #[test]
+    fn test_coalesce_within_window_only() {
+        const HASH: u64 = 0xF0F0_F0F0_F0F0_F0F0;
+        let start = Instant::now();
+        let previous = RecentImage { hash: HASH, item_id: 7, at: start };
+        let window = Duration::from_millis(1500);
+
+        let soon = start + Duration::from_millis(500);
+        assert_eq!(coalesce_target(Some(&previous), HASH, soon, window, 0.95), Some(7));
+        // 超出时间窗口
+        let late = start + Duration::from_millis(2000);
+        assert_eq!(coalesce_target(Some(&previous), HASH, late, window, 0.95), None);
+        // 差异过大（16 位不同，相似度 0.75）
+        assert_eq!(coalesce_target(Some(&previous), HASH ^ 0xFFFF, soon, window, 0.95), None);
+        assert_eq!(coalesce_target(None, HASH, soon, window, 0.95), None);
+    }
+
+    #[test]
+    fn test_low_entropy_images_never_coalesce() {
+        // 纯色与渐变图片的 dHash 为 0，不同内容的此类图片哈希相同
+        assert_eq!(dhash(&gradient(0)), 0);
+
+        let start = Instant::now();
+        let previous = RecentImage { hash: 0, item_id: 7, at: start };
+        let soon = start + Duration::from_millis(100);
+        assert_eq!(coalesce_target(Some(&previous), 0, soon, Duration::from_millis(1500), 0.95), None);
+        let previous = RecentImage { hash: u64::MAX, item_id: 7, at: start };
+        assert_eq!(coalesce_target(Some(&previous), u64::MAX, soon, Duration::from_millis(1500), 0.95), None);
+    }
+}
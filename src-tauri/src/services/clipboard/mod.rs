@@ -3,6 +3,15 @@ mod capture;
 mod processor;
 mod storage;
 mod content_type;
+mod transform;
+mod exclusion;
+mod image_coalesce;
+mod grab;
+mod monitor_health;
+mod recent_searches;
+mod quick_note;
+mod copy_as;
+mod expiry;
 
 pub use monitor::{
     start_clipboard_monitor, 
@@ -13,6 +22,19 @@ pub use monitor::{
     set_last_hash_text,
     set_last_hash_files,
     set_last_hash_file,
+    set_last_hash_composite,
     clear_last_content_cache,
+    capture_current_clipboard,
+    set_incognito,
+    is_incognito,
 };
+pub use transform::{TextTransform, apply_pipeline};
+pub use grab::grab_selection;
+pub use monitor_health::{get_monitor_status, MonitorStatus};
+pub use recent_searches::{record_search, get_recent_searches, clear_recent_searches};
+pub use processor::retag_text_links;
+pub use quick_note::save_quick_note;
+pub use copy_as::copy_item_as;
+pub use expiry::enforce_history_limit;
+pub use exclusion::{is_text_excluded, validate_exclusion_patterns, InvalidPattern};
 
@@ -1,29 +1,57 @@
-use super::capture::ClipboardContent;
+use super::capture::{get_clipboard_format_names, is_ignored_by_format, ClipboardContent};
+use super::monitor_health;
 use super::processor::process_content;
 use super::storage::store_clipboard_item;
 use clipboard_rs::{
-    ClipboardHandler, ClipboardWatcher, ClipboardWatcherContext,
+    ClipboardHandler, ClipboardWatcher, ClipboardWatcherContext, WatcherShutdown,
 };
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use parking_lot::Mutex;
 use once_cell::sync::Lazy;
 use std::thread;
+use std::time::Duration;
 
 static IS_RUNNING: AtomicBool = AtomicBool::new(false);
 
 static GENERATION: AtomicU64 = AtomicU64::new(0);
 
+// 无痕模式：监听保持运行（OCR 粘贴等依赖剪贴板通知的功能照常工作），但不保存任何复制内容
+static INCOGNITO: AtomicBool = AtomicBool::new(false);
+
+// 轮询兜底的最小间隔，避免设置过小导致频繁读取剪贴板
+const MIN_POLL_INTERVAL_MS: u64 = 500;
+// 轮询关闭时检查设置的间隔
+const POLL_DISABLED_CHECK: Duration = Duration::from_secs(1);
+
+// 剪贴板变化的发现途径
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChangeSource {
+    Listener,
+    Poll,
+}
+
+pub fn set_incognito(enabled: bool) {
+    INCOGNITO.store(enabled, Ordering::SeqCst);
+}
+
+pub fn is_incognito() -> bool {
+    INCOGNITO.load(Ordering::SeqCst)
+}
+
 // 监听器状态
 struct MonitorState {
     watcher_handle: Option<thread::JoinHandle<()>>,
     current_generation: u64,
+    // 当前监听线程的停止通道，停止时用于结束 start_watch
+    watcher_shutdown: Option<WatcherShutdown>,
 }
 
 static MONITOR_STATE: Lazy<Arc<Mutex<MonitorState>>> = Lazy::new(|| {
     Arc::new(Mutex::new(MonitorState {
         watcher_handle: None,
         current_generation: 0,
+        watcher_shutdown: None,
     }))
 });
 
@@ -59,14 +87,61 @@ impl ClipboardHandler for ClipboardMonitorManager {
             return;
         }
         
-        if let Err(e) = handle_clipboard_change() {
-            if !e.contains("重复内容") {
-                eprintln!("处理剪贴板内容失败: {}", e);
-            }
+        report_change_result(handle_clipboard_change(ChangeSource::Listener));
+    }
+}
+
+// 只记录读取阶段的失败；成功由保存线程在实际保存后记录
+fn report_change_result(result: Result<(), String>) {
+    match result {
+        Ok(()) => {}
+        Err(e) if e.contains("重复内容") => {}
+        Err(e) => {
+            eprintln!("处理剪贴板内容失败: {}", e);
+            monitor_health::record_failure(&e);
+        }
+    }
+}
+
+// 轮询兜底：按设置的间隔检查剪贴板是否变化，变化时走与监听相同的处理流程。
+// 监听已处理的变化在哈希比对时被识别为相同内容，不会重复保存
+fn run_poll_fallback(generation: u64) {
+    let mut last_marker = super::grab::clipboard_marker();
+    while IS_RUNNING.load(Ordering::Relaxed) && GENERATION.load(Ordering::Relaxed) == generation {
+        let interval_ms = crate::services::get_settings().clipboard_poll_interval_ms;
+        if interval_ms == 0 {
+            thread::sleep(POLL_DISABLED_CHECK);
+            continue;
+        }
+        thread::sleep(Duration::from_millis(interval_ms.max(MIN_POLL_INTERVAL_MS)));
+        if !IS_RUNNING.load(Ordering::Relaxed) || GENERATION.load(Ordering::Relaxed) != generation {
+            break;
+        }
+
+        let marker = super::grab::clipboard_marker();
+        if marker == last_marker {
+            continue;
         }
+        last_marker = marker;
+        report_change_result(handle_clipboard_change(ChangeSource::Poll));
     }
 }
 
+// 与上次内容哈希比对，返回新内容的下标并更新缓存；与上次完全相同时为空
+fn take_new_content_indices(last_hashes: &mut Vec<String>, current_hashes: Vec<String>) -> Vec<usize> {
+    if *last_hashes == current_hashes {
+        return Vec::new();
+    }
+    let fresh = current_hashes
+        .iter()
+        .enumerate()
+        .filter(|(_, hash)| !last_hashes.contains(hash))
+        .map(|(index, _)| index)
+        .collect();
+    *last_hashes = current_hashes;
+    fresh
+}
+
 pub fn start_clipboard_monitor() -> Result<(), String> {
     if IS_RUNNING.compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst).is_err() {
         return Ok(());
@@ -81,15 +156,26 @@ pub fn start_clipboard_monitor() -> Result<(), String> {
     let mut state = MONITOR_STATE.lock();
     state.current_generation = new_generation;
     state.watcher_handle = None;
+    if let Some(shutdown) = state.watcher_shutdown.take() {
+        shutdown.stop();
+    }
     
     let handle = thread::spawn(move || {
-        if let Err(e) = run_clipboard_monitor(new_generation) {
-            eprintln!("剪贴板监听错误: {}", e);
+        let error = match run_clipboard_monitor(new_generation) {
+            Ok(()) => "剪贴板监听已意外停止".to_string(),
+            Err(e) => {
+                eprintln!("剪贴板监听错误: {}", e);
+                e
+            }
+        };
+        // 仍是当前监听且未被主动停止时，线程退出属于故障（如剪贴板被其他程序长期占用）
+        if GENERATION.load(Ordering::SeqCst) == new_generation && IS_RUNNING.swap(false, Ordering::SeqCst) {
+            monitor_health::record_failure(&error);
         }
-        IS_RUNNING.store(false, Ordering::SeqCst);
     });
     
     state.watcher_handle = Some(handle);
+    thread::spawn(move || run_poll_fallback(new_generation));
     Ok(())
 }
 
@@ -101,6 +187,10 @@ pub fn stop_clipboard_monitor() -> Result<(), String> {
         
         let mut state = MONITOR_STATE.lock();
         state.watcher_handle = None;
+        // 结束 start_watch，否则旧的监听线程会一直存在
+        if let Some(shutdown) = state.watcher_shutdown.take() {
+            shutdown.stop();
+        }
     }
     Ok(())
 }
@@ -113,12 +203,24 @@ fn run_clipboard_monitor(generation: u64) -> Result<(), String> {
     let manager = ClipboardMonitorManager::new(generation)?;
     let mut watcher = ClipboardWatcherContext::new()
         .map_err(|e| format!("创建剪贴板监听器失败: {}", e))?;
-    let _ = watcher.add_handler(manager).start_watch();
+    watcher.add_handler(manager);
+    {
+        let mut state = MONITOR_STATE.lock();
+        // 启动前已被停止或重新启动，不再进入监听
+        if !IS_RUNNING.load(Ordering::SeqCst) || state.current_generation != generation {
+            return Ok(());
+        }
+        state.watcher_shutdown = Some(watcher.get_shutdown_channel());
+    }
+    watcher.start_watch();
     Ok(())
 }
 
-fn handle_clipboard_change() -> Result<(), String> {
-    crate::AppSounds::play_copy_immediate();
+fn handle_clipboard_change(source: ChangeSource) -> Result<(), String> {
+    // 轮询发现的变化可能已由监听处理，仅在确认保存后播放提示音
+    if source == ChangeSource::Listener {
+        crate::AppSounds::play_copy_immediate();
+    }
     // 检查应用过滤
     let settings = crate::services::get_settings();
 
@@ -138,57 +240,83 @@ fn handle_clipboard_change() -> Result<(), String> {
     ) {
         return Ok(());
     }
+
+    // 枚举当前格式：用于格式忽略列表，并随项保存
+    let formats = get_clipboard_format_names();
+    if !settings.ignored_clipboard_formats.is_empty()
+        && is_ignored_by_format(&formats, &settings.ignored_clipboard_formats)
+    {
+        return Ok(());
+    }
     
     let contents = ClipboardContent::capture()?;
     if contents.is_empty() {
         return Ok(());
     }
     
-    // 计算所有内容的哈希
+    // 计算所有内容的哈希，在同一次加锁中比对并更新缓存，
+    // 监听与轮询同时发现同一次变化时只有先到的一方保存
     let current_hashes: Vec<String> = contents.iter().map(|c| c.calculate_hash()).collect();
-    
-    // 检查是否与上次完全相同
-    {
-        let last_hashes = LAST_CONTENT_HASHES.lock();
-        if *last_hashes == current_hashes {
-            return Ok(());
-        }
-    }
-    
-    // 过滤出新内容
-    let new_contents: Vec<_> = contents.into_iter()
-        .filter(|c| {
-            let hash = c.calculate_hash();
-            let last_hashes = LAST_CONTENT_HASHES.lock();
-            !last_hashes.contains(&hash)
-        })
+    let fresh = take_new_content_indices(&mut LAST_CONTENT_HASHES.lock(), current_hashes);
+    let new_contents: Vec<_> = contents
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| fresh.contains(index))
+        .map(|(_, content)| content)
         .collect();
     
-    {
-        let mut last_hashes = LAST_CONTENT_HASHES.lock();
-        *last_hashes = current_hashes;
-    }
-    
     if new_contents.is_empty() {
         return Ok(());
     }
     
+    // OCR 粘贴：识别文本写入剪贴板后直接粘贴到之前的窗口
+    let ocr_text = if crate::services::paste::ocr_paste::is_pending() {
+        new_contents.iter().find_map(|c| c.text.clone())
+    } else {
+        None
+    };
+    let ocr_text = ocr_text.filter(|_| crate::services::paste::ocr_paste::take_pending());
+    let new_contents = if is_incognito() || (ocr_text.is_some() && !settings.ocr_paste_keep_history) {
+        Vec::new()
+    } else {
+        new_contents
+    };
+    
     thread::spawn(move || {
-        let mut any_stored = false;
+        if let Some(text) = ocr_text {
+            if let Err(e) = crate::services::paste::ocr_paste::paste_recognized_text(&text) {
+                eprintln!("粘贴OCR识别文本失败: {}", e);
+            }
+        }
+        
+        let mut stored_count = 0;
+        let mut failed = false;
         for content in new_contents {
             match process_content(content) {
-                Ok(processed) => {
+                Ok(mut processed) => {
+                    processed.source_formats = formats.clone();
                     match store_clipboard_item(processed) {
-                        Ok(_) => any_stored = true,
-                        Err(e) if e.contains("重复内容") || e.contains("已禁止保存图片") => {}
-                        Err(e) => eprintln!("存储剪贴板内容失败: {}", e),
+                        Ok(_) => stored_count += 1,
+                        Err(e) if e.contains("重复内容") || e.contains("已禁止保存图片") || e.contains("内容匹配排除规则") => {}
+                        Err(e) => {
+                            eprintln!("存储剪贴板内容失败: {}", e);
+                            monitor_health::record_failure(&e);
+                            failed = true;
+                        }
                     }
                 }
-                Err(e) => eprintln!("处理剪贴板内容失败: {}", e),
+                Err(e) => {
+                    eprintln!("处理剪贴板内容失败: {}", e);
+                    monitor_health::record_failure(&e);
+                    failed = true;
+                }
             }
         }
         
-        if any_stored {
+        if !failed {
+            monitor_health::record_success(stored_count);
+        }
+        if stored_count > 0 {
             let _ = emit_clipboard_updated();
             crate::AppSounds::play_copy_on_success();
         }
@@ -197,6 +325,111 @@ fn handle_clipboard_change() -> Result<(), String> {
     Ok(())
 }
 
+// 手动捕获当前剪贴板（用于来源应用写入剪贴板但未触发通知的情况）
+// 遵循应用过滤与格式忽略规则；与列表首项相同时不重复保存，返回新保存项的ID
+pub fn capture_current_clipboard() -> Result<Option<i64>, String> {
+    if is_incognito() {
+        return Ok(None);
+    }
+    let settings = crate::services::get_settings();
+
+    if !crate::services::system::is_current_app_allowed(
+        settings.app_filter_enabled,
+        &settings.app_filter_mode,
+        &settings.app_filter_list,
+    ) {
+        return Ok(None);
+    }
+
+    let formats = get_clipboard_format_names();
+    if !settings.ignored_clipboard_formats.is_empty()
+        && is_ignored_by_format(&formats, &settings.ignored_clipboard_formats)
+    {
+        return Ok(None);
+    }
+
+    let contents = ClipboardContent::capture()?;
+    if contents.is_empty() {
+        return Ok(None);
+    }
+
+    let current_hashes: Vec<String> = contents.iter().map(|c| c.calculate_hash()).collect();
+    let top_hash = top_item_hash()?;
+
+    let mut stored_id = None;
+    for (content, hash) in contents.into_iter().zip(current_hashes.iter()) {
+        if top_hash.as_deref() == Some(hash.as_str()) {
+            continue;
+        }
+        let mut processed = process_content(content)?;
+        processed.source_formats = formats.clone();
+        match store_clipboard_item(processed) {
+            Ok(id) => stored_id = Some(id),
+            Err(e) if e.contains("重复内容") || e.contains("已禁止保存图片") || e.contains("内容匹配排除规则") => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    // 同步哈希缓存，避免随后到达的剪贴板通知重复记录
+    *LAST_CONTENT_HASHES.lock() = current_hashes;
+
+    if stored_id.is_some() {
+        monitor_health::record_success(1);
+        let _ = emit_clipboard_updated();
+    }
+    Ok(stored_id)
+}
+
+// 列表首项的内容哈希（与 ClipboardContent::calculate_hash 的计算方式一致）
+fn top_item_hash() -> Result<Option<String>, String> {
+    use crate::services::database::{query_clipboard_items, get_clipboard_item_by_id, QueryParams};
+
+    let items = query_clipboard_items(QueryParams {
+        offset: 0,
+        limit: 1,
+        search: None,
+        content_type: None,
+        created_before: None,
+        with_highlights: false,
+        exclude_local_only: false,
+    })?;
+    let Some(top) = items.items.first() else {
+        return Ok(None);
+    };
+    let Some(item) = get_clipboard_item_by_id(top.id)? else {
+        return Ok(None);
+    };
+
+    if item.content.starts_with("files:") {
+        Ok(hash_files_content(&item.content))
+    } else {
+        Ok(Some(hash_text(&item.content)))
+    }
+}
+
+fn hash_text(text: &str) -> String {
+    use sha2::{Sha256, Digest};
+    format!("{:x}", Sha256::digest(text.as_bytes()))
+}
+
+// 按 files: 内容中的路径计算哈希
+fn hash_files_content(content: &str) -> Option<String> {
+    use sha2::{Sha256, Digest};
+
+    let json_str = content.strip_prefix("files:")?;
+    let json = serde_json::from_str::<serde_json::Value>(json_str).ok()?;
+    let mut hasher = Sha256::new();
+    if let Some(files) = json["files"].as_array() {
+        for file in files {
+            if let Some(path) = file["path"].as_str() {
+                let normalized = crate::services::normalize_path_for_hash(path);
+                hasher.update(normalized.as_bytes());
+            }
+        }
+    }
+    Some(format!("{:x}", hasher.finalize()))
+}
+
 static APP_HANDLE: Lazy<Arc<Mutex<Option<tauri::AppHandle>>>> = Lazy::new(|| {
     Arc::new(Mutex::new(None))
 });
@@ -223,10 +456,7 @@ fn emit_clipboard_updated() -> Result<(), String> {
 
 // 预设哈希缓存（文本类型）
 pub fn set_last_hash_text(text: &str) {
-    use sha2::{Sha256, Digest};
-    let mut hasher = Sha256::new();
-    hasher.update(text.as_bytes());
-    let hash = format!("{:x}", hasher.finalize());
+    let hash = hash_text(text);
     
     let mut last_hashes = LAST_CONTENT_HASHES.lock();
     *last_hashes = vec![hash];
@@ -234,25 +464,9 @@ pub fn set_last_hash_text(text: &str) {
 
 // 预设哈希缓存（文件类型）
 pub fn set_last_hash_files(content: &str) {
-    use sha2::{Sha256, Digest};
-    
-    if let Some(json_str) = content.strip_prefix("files:") {
-        if let Ok(json) = serde_json::from_str::<serde_json::Value>(json_str) {
-            let mut hasher = Sha256::new();
-            
-            if let Some(files) = json["files"].as_array() {
-                for file in files {
-                    if let Some(path) = file["path"].as_str() {
-                        let normalized = crate::services::normalize_path_for_hash(path);
-                        hasher.update(normalized.as_bytes());
-                    }
-                }
-            }
-            
-            let hash = format!("{:x}", hasher.finalize());
-            let mut last_hashes = LAST_CONTENT_HASHES.lock();
-            *last_hashes = vec![hash];
-        }
+    if let Some(hash) = hash_files_content(content) {
+        let mut last_hashes = LAST_CONTENT_HASHES.lock();
+        *last_hashes = vec![hash];
     }
 }
 
@@ -269,3 +483,38 @@ pub fn set_last_hash_file(file_path: &str) {
     *last_hashes = vec![hash];
 }
 
+
+// 预设哈希缓存（组合项：文本与图片同时写入剪贴板）
+pub fn set_last_hash_composite(text: &str, image_path: &str) {
+    use sha2::{Sha256, Digest};
+    
+    let text_hash = format!("{:x}", Sha256::digest(text.as_bytes()));
+    let normalized = crate::services::normalize_path_for_hash(image_path);
+    let image_hash = format!("{:x}", Sha256::digest(normalized.as_bytes()));
+    
+    let mut last_hashes = LAST_CONTENT_HASHES.lock();
+    *last_hashes = vec![text_hash, image_hash];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hashes(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_take_new_content_indices() {
+        let mut last = Vec::new();
+        assert_eq!(take_new_content_indices(&mut last, hashes(&["a", "b"])), vec![0, 1]);
+        assert_eq!(last, hashes(&["a", "b"]));
+
+        // 监听已处理的同一次变化，轮询再次发现时不重复保存
+        assert!(take_new_content_indices(&mut last, hashes(&["a", "b"])).is_empty());
+
+        // 仅保存新增的部分
+        assert_eq!(take_new_content_indices(&mut last, hashes(&["b", "c"])), vec![1]);
+        assert_eq!(last, hashes(&["b", "c"]));
+    }
+}
@@ -0,0 +1,172 @@
+// 剪贴板监听健康状态：记录捕获统计与最近错误，连续失败时通知界面并尝试重启监听
+
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::time::Duration;
+
+// 连续失败达到该次数时发送 monitor-error 事件并尝试重启监听
+const FAILURE_THRESHOLD: u32 = 3;
+// 恢复前最多自动重启的次数，超过后只报告错误
+const MAX_RESTART_ATTEMPTS: u32 = 3;
+// 重启前等待，给占用剪贴板的程序释放的时间
+const RESTART_DELAY: Duration = Duration::from_millis(500);
+
+// 监听状态（get_monitor_status 返回值与 monitor-error 事件内容）
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonitorStatus {
+    pub running: bool,
+    // 最近一次保存新内容的时间（毫秒时间戳）
+    pub last_capture_at: Option<i64>,
+    pub last_error: Option<String>,
+    pub total_captured: u64,
+    pub consecutive_failures: u32,
+    // 本轮故障中已自动重启的次数
+    pub restart_attempts: u32,
+}
+
+// 一次失败后的处理
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FailureAction {
+    None,
+    Report,
+    ReportAndRestart,
+}
+
+#[derive(Debug, Default)]
+struct HealthTracker {
+    last_capture_at: Option<i64>,
+    last_error: Option<String>,
+    total_captured: u64,
+    consecutive_failures: u32,
+    restart_attempts: u32,
+}
+
+impl HealthTracker {
+    // 成功读取剪贴板（captured 为新保存的项数，重复内容为 0），连续失败计数清零
+    fn record_success(&mut self, captured: u64, now: i64) {
+        if captured > 0 {
+            self.total_captured += captured;
+            self.last_capture_at = Some(now);
+        }
+        self.consecutive_failures = 0;
+        self.restart_attempts = 0;
+    }
+
+    fn record_failure(&mut self, error: &str) -> FailureAction {
+        self.last_error = Some(error.to_string());
+        self.consecutive_failures += 1;
+        if self.consecutive_failures % FAILURE_THRESHOLD != 0 {
+            return FailureAction::None;
+        }
+        if self.restart_attempts < MAX_RESTART_ATTEMPTS {
+            self.restart_attempts += 1;
+            FailureAction::ReportAndRestart
+        } else {
+            FailureAction::Report
+        }
+    }
+
+    fn snapshot(&self, running: bool) -> MonitorStatus {
+        MonitorStatus {
+            running,
+            last_capture_at: self.last_capture_at,
+            last_error: self.last_error.clone(),
+            total_captured: self.total_captured,
+            consecutive_failures: self.consecutive_failures,
+            restart_attempts: self.restart_attempts,
+        }
+    }
+}
+
+static HEALTH: Mutex<HealthTracker> = Mutex::new(HealthTracker {
+    last_capture_at: None,
+    last_error: None,
+    total_captured: 0,
+    consecutive_failures: 0,
+    restart_attempts: 0,
+});
+
+pub fn get_monitor_status() -> MonitorStatus {
+    HEALTH.lock().snapshot(super::is_monitor_running())
+}
+
+pub fn record_success(captured: u64) {
+    HEALTH.lock().record_success(captured, chrono::Local::now().timestamp_millis());
+}
+
+// 记录失败；连续失败时通知界面，并在限定次数内重新打开剪贴板监听
+pub fn record_failure(error: &str) {
+    let action = HEALTH.lock().record_failure(error);
+    if action == FailureAction::None {
+        return;
+    }
+    emit_monitor_error();
+    if action == FailureAction::ReportAndRestart {
+        std::thread::spawn(restart_monitor);
+    }
+}
+
+fn emit_monitor_error() {
+    use tauri::Emitter;
+    if let Some(app) = super::get_app_handle() {
+        let _ = app.emit("monitor-error", get_monitor_status());
+    }
+}
+
+fn restart_monitor() {
+    std::thread::sleep(RESTART_DELAY);
+    // 等待期间用户关闭了监听则不再重启
+    if !crate::get_settings().clipboard_monitor {
+        return;
+    }
+    eprintln!("剪贴板监听连续失败，正在重新启动监听");
+    let _ = super::stop_clipboard_monitor();
+    if let Err(e) = super::start_clipboard_monitor() {
+        eprintln!("重新启动剪贴板监听失败: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_failures_report_and_restart() {
+        let mut tracker = HealthTracker::default();
+        assert_eq!(tracker.record_failure("剪贴板被占用"), FailureAction::None);
+        assert_eq!(tracker.record_failure("剪贴板被占用"), FailureAction::None);
+        assert_eq!(tracker.record_failure("剪贴板被占用"), FailureAction::ReportAndRestart);
+        assert_eq!(tracker.consecutive_failures, 3);
+        assert_eq!(tracker.restart_attempts, 1);
+
+        // 重启次数用尽后只报告
+        for _ in 0..(FAILURE_THRESHOLD * (MAX_RESTART_ATTEMPTS - 1)) {
+            tracker.record_failure("剪贴板被占用");
+        }
+        assert_eq!(tracker.restart_attempts, MAX_RESTART_ATTEMPTS);
+        for _ in 0..(FAILURE_THRESHOLD - 1) {
+            assert_eq!(tracker.record_failure("剪贴板被占用"), FailureAction::None);
+        }
+        assert_eq!(tracker.record_failure("剪贴板被占用"), FailureAction::Report);
+    }
+
+    #[test]
+    fn test_success_resets_failures_and_counts_captures() {
+        let mut tracker = HealthTracker::default();
+        tracker.record_failure("剪贴板被占用");
+        tracker.record_failure("剪贴板被占用");
+        tracker.record_success(0, 1000);
+        assert_eq!(tracker.consecutive_failures, 0);
+        // 重复内容不计入捕获
+        assert_eq!(tracker.last_capture_at, None);
+
+        tracker.record_success(2, 2000);
+        let status = tracker.snapshot(true);
+        assert!(status.running);
+        assert_eq!(status.total_captured, 2);
+        assert_eq!(status.last_capture_at, Some(2000));
+        // 保留最近的错误信息供界面查看
+        assert_eq!(status.last_error.as_deref(), Some("剪贴板被占用"));
+    }
+}
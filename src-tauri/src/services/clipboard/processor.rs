@@ -35,10 +35,13 @@ struct FileClipboardData {
 pub struct ProcessedContent {
     pub content: String,              
     pub html_content: Option<String>, 
+    pub rtf_content: Option<String>,
     pub content_type: String,         
     pub image_id: Option<String>,
     pub source_app: Option<String>,      
     pub source_icon_hash: Option<String>,
+    // 捕获时剪贴板提供的格式名称（由监听器在捕获时填入）
+    pub source_formats: Vec<String>,
 }
 
 // 处理剪贴板内容，将原始数据转换为可存储的格式
@@ -58,13 +61,17 @@ pub fn process_content(content: ClipboardContent) -> Result<ProcessedContent, St
                 ct.add_type("link");
             }
             
+            let composite_image_id = attach_composite_image(&mut ct, content.image.as_deref());
+            
             Ok(ProcessedContent {
                 content: text,
                 html_content: None,
+                rtf_content: content.rtf,
                 content_type: ct.to_db_string(),
-                image_id: None,
+                image_id: composite_image_id,
                 source_app,
                 source_icon_hash,
+                source_formats: Vec::new(),
             })
         }
         
@@ -81,16 +88,23 @@ pub fn process_content(content: ClipboardContent) -> Result<ProcessedContent, St
                     ct.add_type("link");
                 }
                 
-                let (processed_html, image_ids) = process_html_images(&html)?;
+                let (processed_html, mut image_ids) = process_html_images(&html)?;
+                // 组合项的图片放在首位，粘贴时据此取出
+                if let Some(composite_id) = attach_composite_image(&mut ct, content.image.as_deref()) {
+                    image_ids.retain(|id| id != &composite_id);
+                    image_ids.insert(0, composite_id);
+                }
                 let image_id = if image_ids.is_empty() { None } else { Some(image_ids.join(",")) };
                 
                 Ok(ProcessedContent {
                     content: text,
                     html_content: Some(processed_html),
+                    rtf_content: content.rtf,
                     content_type: ct.to_db_string(),
                     image_id,
                     source_app,
                     source_icon_hash,
+                    source_formats: Vec::new(),
                 })
             }
         
@@ -122,10 +136,12 @@ pub fn process_content(content: ClipboardContent) -> Result<ProcessedContent, St
             Ok(ProcessedContent {
                 content: format!("files:{}", json_str),
                 html_content: None,
+                rtf_content: None,
                 content_type: ct.to_db_string(),
                 image_id,
                 source_app,
                 source_icon_hash,
+                source_formats: Vec::new(),
             })
         }
     }
@@ -232,6 +248,16 @@ fn collect_file_info(file_paths: &[String]) -> Result<Vec<FileInfo>, String> {
     Ok(file_infos)
 }
 
+// 文本内容变化后重新标记链接类型（保留其他附加类型）
+pub fn retag_text_links(content_type: &str, text: &str) -> String {
+    let mut ct = ContentType::from_db_string(content_type);
+    ct.remove_type("link");
+    if is_url(text) || contains_links(text) {
+        ct.add_type("link");
+    }
+    ct.to_db_string()
+}
+
 // 检测字符串是否是URL
 fn is_url(text: &str) -> bool {
     let trimmed = text.trim();
@@ -447,6 +473,13 @@ fn calculate_image_id(data: &[u8]) -> String {
     hash[..16].to_string()
 }
 
+// 为组合项标记附带的图片类型并返回图片ID
+fn attach_composite_image(ct: &mut ContentType, image_path: Option<&str>) -> Option<String> {
+    let image_id = image_path.and_then(extract_image_id_from_path)?;
+    ct.add_type("image");
+    Some(image_id)
+}
+
 fn extract_image_id_from_path(path_str: &str) -> Option<String> {
     if path_str.starts_with("clipboard_images/") || path_str.starts_with("clipboard_images\\") {
         let p = std::path::Path::new(path_str);
@@ -0,0 +1,48 @@
+// 快速笔记：不经过系统剪贴板，直接将输入的文本作为文本项保存到历史
+
+use super::processor::{retag_text_links, ProcessedContent};
+
+// 规范化笔记文本：统一换行符并去除首尾空白，空内容返回 None
+fn normalize_note(text: &str) -> Option<String> {
+    let text = text.replace("\r\n", "\n");
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+// 保存快速笔记，返回新保存项的 ID（内容为空时为 None）
+pub fn save_quick_note(text: &str) -> Result<Option<i64>, String> {
+    let Some(content) = normalize_note(text) else {
+        return Ok(None);
+    };
+
+    let item = ProcessedContent {
+        content_type: retag_text_links("text", &content),
+        content,
+        html_content: None,
+        rtf_content: None,
+        image_id: None,
+        source_app: None,
+        source_icon_hash: None,
+        source_formats: Vec::new(),
+    };
+    let id = super::storage::store_clipboard_item(item)?;
+
+    use tauri::Emitter;
+    if let Some(app) = super::get_app_handle() {
+        let _ = app.emit("clipboard-updated", ());
+    }
+    Ok(Some(id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_note() {
+        assert_eq!(normalize_note("  记得买牛奶 \n"), Some("记得买牛奶".to_string()));
+        assert_eq!(normalize_note("第一行\r\n第二行"), Some("第一行\n第二行".to_string()));
+        assert_eq!(normalize_note(" \r\n\t "), None);
+        assert_eq!(normalize_note(""), None);
+    }
+}
@@ -0,0 +1,99 @@
+// 最近搜索词（最新的排在最前，去重并限制数量，持久化到 store）
+
+use crate::services::store;
+use parking_lot::Mutex;
+use std::time::{Duration, Instant};
+
+const STORE_KEY_RECENT_SEARCHES: &str = "recent_searches";
+const MAX_RECENT_SEARCHES: usize = 20;
+// 边输入边搜索时，短时间内相互为前缀的搜索词视为同一次搜索，只保留最后一个
+const TYPING_MERGE_WINDOW: Duration = Duration::from_secs(3);
+
+// 上一次记录的时间
+static LAST_RECORDED_AT: Mutex<Option<Instant>> = Mutex::new(None);
+
+// 是否为同一次输入过程中的搜索词（继续输入或删除字符）
+fn is_typing_continuation(previous: &str, term: &str) -> bool {
+    let previous = previous.to_lowercase();
+    let term = term.to_lowercase();
+    term.starts_with(&previous) || previous.starts_with(&term)
+}
+
+// 将搜索词加入列表：不区分大小写去重，merge_latest 时替换仍在输入中的最新一项
+fn push_term(list: &mut Vec<String>, term: &str, merge_latest: bool, max: usize) {
+    if merge_latest && list.first().is_some_and(|latest| is_typing_continuation(latest, term)) {
+        list.remove(0);
+    }
+    let lower = term.to_lowercase();
+    list.retain(|existing| existing.to_lowercase() != lower);
+    list.insert(0, term.to_string());
+    list.truncate(max);
+}
+
+// 记录一次执行的搜索（空白词和匹配排除规则的词不记录）
+pub fn record_search(term: &str) {
+    let term = term.trim();
+    if term.is_empty() {
+        return;
+    }
+    let settings = crate::services::get_settings();
+    if super::is_text_excluded(term, &settings.excluded_text_patterns) {
+        return;
+    }
+
+    let now = Instant::now();
+    let merge_latest = LAST_RECORDED_AT
+        .lock()
+        .replace(now)
+        .is_some_and(|at| now.saturating_duration_since(at) <= TYPING_MERGE_WINDOW);
+
+    let mut terms = get_recent_searches();
+    push_term(&mut terms, term, merge_latest, MAX_RECENT_SEARCHES);
+    let _ = store::set(STORE_KEY_RECENT_SEARCHES, &terms);
+}
+
+pub fn get_recent_searches() -> Vec<String> {
+    store::get::<Vec<String>>(STORE_KEY_RECENT_SEARCHES).unwrap_or_default()
+}
+
+pub fn clear_recent_searches() -> Result<(), String> {
+    *LAST_RECORDED_AT.lock() = None;
+    store::set(STORE_KEY_RECENT_SEARCHES, &Vec::<String>::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_term_dedupes_and_caps() {
+        let mut list = Vec::new();
+        push_term(&mut list, "alpha", false, 3);
+        push_term(&mut list, "beta", false, 3);
+        push_term(&mut list, "ALPHA", false, 3);
+        assert_eq!(list, vec!["ALPHA", "beta"]);
+
+        push_term(&mut list, "gamma", false, 3);
+        push_term(&mut list, "delta", false, 3);
+        assert_eq!(list, vec!["delta", "gamma", "ALPHA"]);
+    }
+
+    #[test]
+    fn test_typing_merges_into_latest() {
+        let mut list = vec!["older".to_string()];
+        push_term(&mut list, "he", false, 10);
+        push_term(&mut list, "hell", true, 10);
+        push_term(&mut list, "hello", true, 10);
+        assert_eq!(list, vec!["hello", "older"]);
+
+        // 删除字符同样视为同一次输入
+        push_term(&mut list, "help", true, 10);
+        assert_eq!(list, vec!["help", "hello", "older"]);
+        push_term(&mut list, "hel", true, 10);
+        assert_eq!(list, vec!["hel", "hello", "older"]);
+
+        // 超出合并时间窗口后作为新的搜索词
+        push_term(&mut list, "he", false, 10);
+        assert_eq!(list, vec!["he", "hel", "hello", "older"]);
+    }
+}
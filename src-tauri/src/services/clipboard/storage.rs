@@ -1,6 +1,9 @@
 use super::processor::ProcessedContent;
+use super::transform::{apply_pipeline, TextTransform};
+use super::exclusion::is_text_excluded;
+use super::image_coalesce;
 use crate::services::database::connection::with_connection;
-use crate::services::database::clipboard::limit_clipboard_history;
+use crate::services::database::clipboard::make_preview;
 use crate::services::settings::get_settings;
 use rusqlite::params;
 use chrono;
@@ -20,69 +23,158 @@ fn calculate_char_count(content: &str, content_type: &str) -> Option<i64> {
     }
 }
 
-pub fn store_clipboard_item(content: ProcessedContent) -> Result<i64, String> {
+pub fn store_clipboard_item(mut content: ProcessedContent) -> Result<i64, String> {
     let settings = get_settings();
     
     if !settings.save_images && is_image_type(&content.content_type) {
         return Err("已禁止保存图片".to_string());
     }
+
+    if is_text_type(&content.content_type)
+        && is_text_excluded(&content.content, &settings.excluded_text_patterns)
+    {
+        return Err("内容匹配排除规则".to_string());
+    }
+
+    let original_content = apply_store_transforms(&mut content, &settings.store_transforms)
+        .filter(|_| settings.store_transforms_preserve_original);
+
+    // 连拍图片：与时间窗口内的上一张相似时取代它
+    let image_hash = captured_image_hash(&content, settings.image_coalesce_window_ms);
+    let coalesce_target = image_hash.and_then(image_coalesce::find_coalesce_target);
     
     let result = with_connection(|conn| {
         let now = chrono::Local::now().timestamp();
         
+        // 相同内容被替换时保留其备注与仅本机标记，并累加复制次数
+        let mut carried_note: Option<String> = None;
+        let mut copy_count: i64 = 1;
+        let mut local_only = false;
         match check_and_handle_duplicate(&content, conn, now) {
-            Ok(Some(existing_id)) => {
-                return Ok(existing_id);
+            Ok(DuplicateCheck::Replaced { note, copy_count: previous_count, local_only: previous_local_only }) => {
+                carried_note = note;
+                copy_count = previous_count.saturating_add(1);
+                local_only = previous_local_only;
             }
-            Ok(None) => {}
+            Ok(DuplicateCheck::NotFound) => {}
             Err(e) => {
                 eprintln!("检查重复内容失败: {}", e);
             }
         }
+
+        // 被合并的上一张图片（置顶项不合并），其备注由新图片继承
+        let mut coalesced_id: Option<i64> = None;
+        if let Some(target) = coalesce_target {
+            let previous: Option<(bool, Option<String>)> = conn
+                .query_row(
+                    "SELECT is_pinned, note FROM clipboard WHERE id = ?",
+                    params![target],
+                    |row| Ok((row.get(0)?, row.get(1)?)),
+                )
+                .ok();
+            if let Some((false, note)) = previous {
+                carried_note = carried_note.or(note);
+                coalesced_id = Some(target);
+            }
+        }
         
         let max_order: i64 = conn
             .query_row("SELECT COALESCE(MAX(item_order), 0) FROM clipboard", [], |row| row.get(0))
             .unwrap_or(0);
         let new_order = max_order + 1;
         let char_count = calculate_char_count(&content.content, &content.content_type);
+        let preview = make_preview(&content.content, &content.content_type, settings.list_preview_length);
+        let source_formats = if content.source_formats.is_empty() {
+            None
+        } else {
+            serde_json::to_string(&content.source_formats).ok()
+        };
         
         conn.execute(
-            "INSERT INTO clipboard (content, html_content, content_type, image_id, item_order, source_app, source_icon_hash, char_count, created_at, updated_at) 
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            "INSERT INTO clipboard (content, preview, html_content, rtf_content, content_type, image_id, item_order, source_app, source_icon_hash, char_count, note, source_formats, original_content, copy_count, local_only, created_at, updated_at) 
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)",
             params![
                 content.content,
+                preview,
                 content.html_content,
+                content.rtf_content,
                 content.content_type,
                 content.image_id,
                 new_order,
                 content.source_app,
                 content.source_icon_hash,
                 char_count,
+                carried_note,
+                source_formats,
+                original_content,
+                copy_count,
+                local_only,
                 now,
                 now
             ],
         )?;
         
-        Ok(conn.last_insert_rowid())
+        Ok((conn.last_insert_rowid(), coalesced_id))
     });
     
     match result {
-        Ok(id) => {
-            let _ = limit_clipboard_history(settings.history_limit);
+        Ok((id, coalesced_id)) => {
+            if let Some(previous) = coalesced_id {
+                match crate::services::database::delete_clipboard_item(previous) {
+                    Ok(()) => super::expiry::emit_expired(&[previous], "image_coalesce"),
+                    Err(e) => eprintln!("合并连拍图片失败: {}", e),
+                }
+            }
+            if let Some(hash) = image_hash {
+                image_coalesce::record_image(hash, id);
+            }
+            let _ = super::expiry::enforce_history_limit(settings.history_limit);
             Ok(id)
         },
         Err(e) => Err(e),
     }
 }
 
+// 捕获的单张图片的感知哈希（仅在启用合并时计算）
+fn captured_image_hash(content: &ProcessedContent, coalesce_window_ms: u64) -> Option<u64> {
+    if coalesce_window_ms == 0 || content.content_type != "image" {
+        return None;
+    }
+    let image_id = content.image_id.as_deref()?;
+    let path = crate::services::get_data_directory()
+        .ok()?
+        .join("clipboard_images")
+        .join(format!("{}.png", image_id));
+    image_coalesce::hash_image_file(&path)
+}
+
+// 对文本类内容应用存储转换流水线，内容发生变化时返回原始文本。
+// 转换后为空时保留原文，避免把仅含空白的复制存为空项
+fn apply_store_transforms(content: &mut ProcessedContent, pipeline: &[TextTransform]) -> Option<String> {
+    if pipeline.is_empty() || !is_text_type(&content.content_type) {
+        return None;
+    }
+    let transformed = apply_pipeline(&content.content, pipeline);
+    if transformed == content.content || transformed.is_empty() {
+        return None;
+    }
+    Some(std::mem::replace(&mut content.content, transformed))
+}
+
+// 去重结果：删除旧的相同内容时带回其备注、复制次数与仅本机标记
+enum DuplicateCheck {
+    NotFound,
+    Replaced { note: Option<String>, copy_count: i64, local_only: bool },
+}
+
 // 智能去重
 fn check_and_handle_duplicate(
     content: &ProcessedContent,
     conn: &rusqlite::Connection,
     now: i64,
-) -> Result<Option<i64>, rusqlite::Error> {
+) -> Result<DuplicateCheck, rusqlite::Error> {
     let mut stmt = conn.prepare(
-        "SELECT id, content, content_type 
+        "SELECT id, content, content_type, note, copy_count, local_only 
          FROM clipboard 
          ORDER BY created_at DESC 
          LIMIT 100"
@@ -93,11 +185,14 @@ fn check_and_handle_duplicate(
             row.get::<_, i64>(0)?,      // id
             row.get::<_, String>(1)?,   // content
             row.get::<_, String>(2)?,   // content_type
+            row.get::<_, Option<String>>(3)?,   // note
+            row.get::<_, i64>(4)?,      // copy_count
+            row.get::<_, i64>(5)? != 0, // local_only
         ))
     })?;
     
     for item in recent_items {
-        let (db_id, db_content, db_type) = item?;
+        let (db_id, db_content, db_type, db_note, db_copy_count, db_local_only) = item?;
 
         let is_text_same = if is_text_type(&content.content_type) && is_text_type(&db_type) {
             content.content == db_content
@@ -112,10 +207,10 @@ fn check_and_handle_duplicate(
         }
         
         conn.execute("DELETE FROM clipboard WHERE id = ?", params![db_id])?;
-        return Ok(None);
+        return Ok(DuplicateCheck::Replaced { note: db_note, copy_count: db_copy_count, local_only: db_local_only });
     }
     
-    Ok(None)
+    Ok(DuplicateCheck::NotFound)
 }
 
 
@@ -0,0 +1,137 @@
+// 存储时的文本转换流水线：按设置中的顺序依次对新复制的文本应用转换
+
+use serde::{Deserialize, Serialize};
+
+// 制表符转换为空格时的宽度
+const TAB_WIDTH: usize = 4;
+
+// 可用的文本转换
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TextTransform {
+    // 去除首尾空白
+    Trim,
+    // 去除每行末尾的空白
+    TrimTrailingWhitespace,
+    // 制表符转换为空格
+    TabsToSpaces,
+    // 统一换行符为 \n
+    NormalizeLineEndings,
+    // 多个连续空行合并为一个
+    CollapseBlankLines,
+    // 删除空行
+    RemoveEmptyLines,
+    Lowercase,
+    Uppercase,
+}
+
+impl TextTransform {
+    pub fn apply(self, text: &str) -> String {
+        match self {
+            Self::Trim => text.trim().to_string(),
+            Self::TrimTrailingWhitespace => map_lines(text, |line| line.trim_end()),
+            Self::TabsToSpaces => text.replace('\t', &" ".repeat(TAB_WIDTH)),
+            Self::NormalizeLineEndings => text.replace("\r\n", "\n").replace('\r', "\n"),
+            Self::CollapseBlankLines => {
+                let mut result: Vec<&str> = Vec::new();
+                for (line, ending) in split_lines(text) {
+                    let blank = line.trim().is_empty();
+                    let previous_blank = result
+                        .len()
+                        .checked_sub(2)
+                        .map(|i| result[i].trim().is_empty())
+                        .unwrap_or(false);
+                    if blank && previous_blank {
+                        continue;
+                    }
+                    result.push(line);
+                    result.push(ending);
+                }
+                result.concat()
+            }
+            Self::RemoveEmptyLines => split_lines(text)
+                .filter(|(line, _)| !line.trim().is_empty())
+                .map(|(line, ending)| format!("{}{}", line, ending))
+                .collect::<String>(),
+            Self::Lowercase => text.to_lowercase(),
+            Self::Uppercase => text.to_uppercase(),
+        }
+    }
+}
+
+// 按行拆分，保留每行原有的换行符（\r\n 或 \n，最后一行可能没有）
+fn split_lines(text: &str) -> impl Iterator<Item = (&str, &str)> {
+    text.split_inclusive('\n').map(|segment| {
+        if let Some(line) = segment.strip_suffix("\r\n") {
+            (line, "\r\n")
+        } else if let Some(line) = segment.strip_suffix('\n') {
+            (line, "\n")
+        } else {
+            (segment, "")
+        }
+    })
+}
+
+fn map_lines<F>(text: &str, f: F) -> String
+where
+    F: Fn(&str) -> &str,
+{
+    split_lines(text)
+        .map(|(line, ending)| format!("{}{}", f(line), ending))
+        .collect()
+}
+
+// 按顺序应用全部转换
+pub fn apply_pipeline(text: &str, pipeline: &[TextTransform]) -> String {
+    pipeline
+        .iter()
+        .fold(text.to_string(), |current, transform| transform.apply(&current))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_trailing_whitespace_keeps_line_endings() {
+        assert_eq!(
+            TextTransform::TrimTrailingWhitespace.apply("a  \r\nb\t\nc "),
+            "a\r\nb\nc"
+        );
+    }
+
+    #[test]
+    fn test_collapse_and_remove_blank_lines() {
+        let text = "a\n\n\n  \nb\n";
+        assert_eq!(TextTransform::CollapseBlankLines.apply(text), "a\n\nb\n");
+        assert_eq!(TextTransform::RemoveEmptyLines.apply(text), "a\nb\n");
+    }
+
+    #[test]
+    fn test_pipeline_order_matters() {
+        let text = "\tx \n";
+        // 先转换制表符再去除首尾空白
+        assert_eq!(
+            apply_pipeline(text, &[TextTransform::TabsToSpaces, TextTransform::Trim]),
+            "x"
+        );
+        // 先去除行尾空白再转换制表符，行首缩进保留
+        assert_eq!(
+            apply_pipeline(text, &[TextTransform::TrimTrailingWhitespace, TextTransform::TabsToSpaces]),
+            "    x\n"
+        );
+        assert_eq!(apply_pipeline(text, &[]), text);
+    }
+
+    #[test]
+    fn test_normalize_line_endings() {
+        assert_eq!(TextTransform::NormalizeLineEndings.apply("a\r\nb\rc"), "a\nb\nc");
+    }
+
+    #[test]
+    fn test_transform_serde_names() {
+        let parsed: Vec<TextTransform> =
+            serde_json::from_str(r#"["trim_trailing_whitespace","tabs_to_spaces"]"#).unwrap();
+        assert_eq!(parsed, vec![TextTransform::TrimTrailingWhitespace, TextTransform::TabsToSpaces]);
+    }
+}
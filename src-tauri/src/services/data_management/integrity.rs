@@ -0,0 +1,486 @@
+// 数据库完整性检查与损坏修复：启动时快速检查，损坏时由主窗口提示修复
+// 修复流程：备份损坏的原文件 → 逐表读取可读的行写入新文件 → 校验新文件 → 替换原文件
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use rusqlite::types::Value;
+use rusqlite::Connection;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use super::get_current_storage_dir;
+use crate::services::database::connection::{close_database, with_connection};
+use crate::services::database::init_database;
+
+const DB_FILE: &str = "quickclipboard.db";
+const RECOVERING_FILE: &str = "quickclipboard.recovering.db";
+// integrity_check 最多返回的错误条数
+const MAX_INTEGRITY_ERRORS: usize = 20;
+
+// 启动检查发现的问题，修复成功或用户处理后清除
+static STARTUP_REPORT: Lazy<Mutex<Option<IntegrityReport>>> = Lazy::new(|| Mutex::new(None));
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityReport {
+    pub ok: bool,
+    pub errors: Vec<String>,
+}
+
+impl IntegrityReport {
+    fn failed(error: String) -> Self {
+        Self { ok: false, errors: vec![error] }
+    }
+}
+
+// 单表的恢复结果
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableRecovery {
+    pub table: String,
+    pub recovered: usize,
+    // 因损坏而跳过的区段数
+    pub skipped_segments: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepairReport {
+    // 损坏原文件的备份位置
+    pub backup_path: String,
+    pub tables: Vec<TableRecovery>,
+    pub recovered_rows: usize,
+    pub skipped_segments: usize,
+}
+
+// 执行完整性检查；quick 为 true 时使用 quick_check（跳过索引内容比对，适合启动时）
+// 严重损坏时 PRAGMA 本身会报错，同样视为检查未通过
+fn run_integrity_check(conn: &Connection, quick: bool) -> IntegrityReport {
+    let pragma = if quick { "quick_check" } else { "integrity_check" };
+    let result = conn
+        .prepare(&format!("PRAGMA {}({})", pragma, MAX_INTEGRITY_ERRORS))
+        .and_then(|mut stmt| stmt.query_map([], |row| row.get::<_, String>(0))?.collect::<Result<Vec<_>, _>>());
+    match result {
+        Ok(rows) if rows.len() == 1 && rows[0] == "ok" => IntegrityReport { ok: true, errors: Vec::new() },
+        Ok(rows) => IntegrityReport { ok: false, errors: rows },
+        Err(e) => IntegrityReport::failed(e.to_string()),
+    }
+}
+
+// 检查当前数据库的完整性（数据库未能打开时同样视为未通过，以便进行修复）
+pub fn check_database_integrity() -> Result<IntegrityReport, String> {
+    Ok(with_connection(|conn| Ok(run_integrity_check(conn, false))).unwrap_or_else(IntegrityReport::failed))
+}
+
+// 启动时检查：open_result 为数据库打开结果，打开失败或检查未通过时记录下来，不中断启动
+pub fn check_database_on_startup(open_result: Result<(), String>) {
+    let report = match open_result {
+        Err(e) => IntegrityReport::failed(e),
+        Ok(()) => with_connection(|conn| Ok(run_integrity_check(conn, true))).unwrap_or_else(IntegrityReport::failed),
+    };
+    if !report.ok {
+        eprintln!("数据库完整性检查未通过: {:?}", report.errors);
+        *STARTUP_REPORT.lock() = Some(report);
+    }
+}
+
+// 启动检查发现的问题（无问题时为 None）
+pub fn startup_integrity_report() -> Option<IntegrityReport> {
+    STARTUP_REPORT.lock().clone()
+}
+
+pub fn dismiss_startup_integrity_report() {
+    *STARTUP_REPORT.lock() = None;
+}
+
+fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+// 原数据库中的 (类型, 名称, 建表语句)；sqlite_master 不可读时返回空，表结构由 init_database 重新创建
+fn read_schema(src: &Connection) -> Vec<(String, String, String)> {
+    let result = src
+        .prepare("SELECT type, name, sql FROM sqlite_master WHERE sql IS NOT NULL AND name NOT LIKE 'sqlite_%'")
+        .and_then(|mut stmt| {
+            stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+                .collect::<Result<Vec<_>, _>>()
+        });
+    result.unwrap_or_default()
+}
+
+// 从 after 之后读取第一行：Ok(true) 有可读的行，Ok(false) 已无剩余行，Err 表示该位置的数据损坏
+fn row_readable_after(src: &Connection, probe_sql: &str, after: i64) -> Result<bool, rusqlite::Error> {
+    let mut stmt = src.prepare_cached(probe_sql)?;
+    let mut rows = stmt.query([after])?;
+    match rows.next()? {
+        Some(row) => {
+            for i in 0..row.as_ref().column_count() {
+                row.get::<_, Value>(i)?;
+            }
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+// 读取在 failed_after 之后出错时，找到可以继续读取的最小位置：先按倍数向后探测越过损坏区域，
+// 再二分回退以尽量少丢行；之后已无可读的行时返回 None
+fn next_readable_position(src: &Connection, probe_sql: &str, failed_after: i64) -> Option<i64> {
+    let mut bad = failed_after;
+    for step in 0..63 {
+        let candidate = failed_after.saturating_add(1i64 << step);
+        match row_readable_after(src, probe_sql, candidate) {
+            Err(_) => bad = candidate,
+            Ok(false) => return None,
+            Ok(true) => {
+                let mut good = candidate;
+                while good - bad > 1 {
+                    let mid = bad + (good - bad) / 2;
+                    if row_readable_after(src, probe_sql, mid).is_err() {
+                        bad = mid;
+                    } else {
+                        good = mid;
+                    }
+                }
+                return Some(good);
+            }
+        }
+        if candidate == i64::MAX {
+            break;
+        }
+    }
+    None
+}
+
+// 从 after 之后顺序复制，返回最后复制的 rowid 与读取错误（全部复制完成时为 None）；
+// 写入新文件失败不属于源数据损坏，作为外层错误直接返回
+fn copy_rows_after(
+    src: &Connection,
+    dst: &Connection,
+    select_sql: &str,
+    insert_sql: &str,
+    mut after: i64,
+    recovery: &mut TableRecovery,
+) -> Result<(i64, Option<rusqlite::Error>), rusqlite::Error> {
+    let mut insert = dst.prepare_cached(insert_sql)?;
+    let mut select = match src.prepare(select_sql) {
+        Ok(select) => select,
+        Err(e) => return Ok((after, Some(e))),
+    };
+    let mut rows = match select.query([after]) {
+        Ok(rows) => rows,
+        Err(e) => return Ok((after, Some(e))),
+    };
+    loop {
+        let values = match rows.next() {
+            Ok(Some(row)) => (0..row.as_ref().column_count())
+                .map(|i| row.get::<_, Value>(i))
+                .collect::<Result<Vec<_>, _>>(),
+            Ok(None) => return Ok((after, None)),
+            Err(e) => return Ok((after, Some(e))),
+        };
+        let values = match values {
+            Ok(values) => values,
+            Err(e) => return Ok((after, Some(e))),
+        };
+        let Value::Integer(rowid) = values[0] else { continue };
+        insert.execute(rusqlite::params_from_iter(values))?;
+        recovery.recovered += 1;
+        after = rowid;
+    }
+}
+
+// 逐行复制一张表，跳过读取失败的区域
+fn recover_table(src: &Connection, dst: &Connection, table: &str) -> Result<TableRecovery, rusqlite::Error> {
+    let columns: Vec<String> = dst
+        .prepare(&format!("PRAGMA table_info({})", quote_ident(table)))?
+        .query_map([], |row| row.get::<_, String>(1))?
+        .collect::<Result<_, _>>()?;
+    let column_list = columns.iter().map(|c| quote_ident(c)).collect::<Vec<_>>().join(", ");
+    let select_sql = format!(
+        "SELECT rowid, {} FROM {} WHERE rowid > ?1 ORDER BY rowid",
+        column_list,
+        quote_ident(table)
+    );
+    let probe_sql = format!("{} LIMIT 1", select_sql);
+    let insert_sql = format!(
+        "INSERT OR IGNORE INTO {} (rowid, {}) VALUES ({})",
+        quote_ident(table),
+        column_list,
+        vec!["?"; columns.len() + 1].join(", ")
+    );
+
+    let mut recovery = TableRecovery { table: table.to_string(), ..Default::default() };
+    let mut after = i64::MIN;
+    loop {
+        let (last, error) = copy_rows_after(src, dst, &select_sql, &insert_sql, after, &mut recovery)?;
+        let Some(error) = error else { break };
+        eprintln!("恢复表 {} 时跳过损坏区域（rowid > {}）: {}", table, last, error);
+        recovery.skipped_segments += 1;
+        match next_readable_position(src, &probe_sql, last) {
+            Some(next) => after = next,
+            None => break,
+        }
+    }
+    Ok(recovery)
+}
+
+// 恢复自增序列，避免已删除项的 ID 被重新使用（原序列不可读时按已恢复的最大 ID 继续）
+fn restore_sequences(src: &Connection, dst: &Connection) -> Result<(), rusqlite::Error> {
+    let sequences: Vec<(String, i64)> = src
+        .prepare("SELECT name, seq FROM sqlite_sequence")
+        .and_then(|mut stmt| stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?.collect())
+        .unwrap_or_default();
+    for (name, seq) in sequences {
+        dst.execute("UPDATE sqlite_sequence SET seq = MAX(seq, ?2) WHERE name = ?1", rusqlite::params![name, seq])?;
+    }
+    Ok(())
+}
+
+// 将 src 中可读的数据写入空数据库 dst
+fn recover_into(src: &Connection, dst: &Connection) -> Result<Vec<TableRecovery>, rusqlite::Error> {
+    let schema = read_schema(src);
+    let mut tables = Vec::new();
+    for (kind, name, sql) in &schema {
+        if kind != "table" || sql.to_ascii_uppercase().starts_with("CREATE VIRTUAL") {
+            continue;
+        }
+        dst.execute_batch(sql)?;
+        tables.push(name.clone());
+    }
+
+    dst.execute_batch("BEGIN;")?;
+    let result = tables
+        .iter()
+        .map(|table| recover_table(src, dst, table))
+        .collect::<Result<Vec<_>, _>>()
+        .and_then(|results| restore_sequences(src, dst).map(|_| results));
+    let results = match result {
+        Ok(results) => results,
+        Err(e) => {
+            let _ = dst.execute_batch("ROLLBACK;");
+            return Err(e);
+        }
+    };
+    dst.execute_batch("COMMIT;")?;
+
+    // 索引与触发器在数据写入后创建，失败时由 init_database 按当前结构补建
+    for (kind, _, sql) in &schema {
+        if kind == "index" || kind == "trigger" {
+            let _ = dst.execute_batch(sql);
+        }
+    }
+    Ok(results)
+}
+
+fn remove_db_files(dir: &Path, name: &str) {
+    for suffix in ["", "-wal", "-shm"] {
+        let _ = fs::remove_file(dir.join(format!("{}{}", name, suffix)));
+    }
+}
+
+// 备份损坏的原文件（含 WAL），返回备份的数据库文件路径
+fn backup_corrupt_files(dir: &Path) -> Result<std::path::PathBuf, String> {
+    let backups = dir.join("backups");
+    fs::create_dir_all(&backups).map_err(|e| format!("创建备份目录失败: {}", e))?;
+    let name = format!("quickclipboard-corrupt-{}.db", chrono::Local::now().format("%Y%m%d-%H%M%S"));
+    for suffix in ["", "-wal"] {
+        let src = dir.join(format!("{}{}", DB_FILE, suffix));
+        if src.exists() {
+            fs::copy(&src, backups.join(format!("{}{}", name, suffix)))
+                .map_err(|e| format!("备份损坏的数据库失败: {}", e))?;
+        }
+    }
+    Ok(backups.join(name))
+}
+
+// 备份原文件后恢复数据并替换原文件，返回备份路径与各表的恢复结果
+// 任一步骤失败时保证原数据库文件仍在原位置（必要时从备份还原）
+fn repair_files(dir: &Path) -> Result<(std::path::PathBuf, Vec<TableRecovery>), String> {
+    let db_path = dir.join(DB_FILE);
+    let recovering_path = dir.join(RECOVERING_FILE);
+    let backup_path = backup_corrupt_files(dir)?;
+    remove_db_files(dir, RECOVERING_FILE);
+
+    let recovered = (|| -> Result<Vec<TableRecovery>, String> {
+        let src = Connection::open(&db_path).map_err(|e| format!("打开原数据库失败: {}", e))?;
+        let dst = Connection::open(&recovering_path).map_err(|e| format!("创建新数据库失败: {}", e))?;
+        let tables = recover_into(&src, &dst).map_err(|e| format!("恢复数据失败: {}", e))?;
+        let report = run_integrity_check(&dst, false);
+        if !report.ok {
+            return Err(format!("恢复后的数据库仍未通过检查: {}", report.errors.join("; ")));
+        }
+        Ok(tables)
+    })();
+    let tables = match recovered {
+        Ok(tables) => tables,
+        Err(e) => {
+            remove_db_files(dir, RECOVERING_FILE);
+            return Err(e);
+        }
+    };
+
+    // 直接用恢复的文件覆盖原文件，替换成功后再删除原文件遗留的 WAL/SHM
+    if let Err(e) = fs::rename(&recovering_path, &db_path) {
+        remove_db_files(dir, RECOVERING_FILE);
+        if !db_path.exists() {
+            let _ = fs::copy(&backup_path, &db_path);
+        }
+        return Err(format!("替换数据库文件失败（原文件已备份至 {}）: {}", backup_path.display(), e));
+    }
+    for suffix in ["-wal", "-shm"] {
+        let _ = fs::remove_file(dir.join(format!("{}{}", DB_FILE, suffix)));
+    }
+    Ok((backup_path, tables))
+}
+
+// 修复当前数据库：保留可读的数据写入新文件并替换原文件，原文件备份到 backups 目录
+pub fn repair_database() -> Result<RepairReport, String> {
+    let dir = get_current_storage_dir()?;
+    let db_path = dir.join(DB_FILE);
+    if !db_path.exists() {
+        return Err("数据库文件不存在".into());
+    }
+    let db_path_str = db_path.to_str().ok_or("数据库路径无效")?.to_string();
+
+    close_database();
+    let repaired = repair_files(&dir);
+    // 无论修复是否成功都重新打开数据库连接
+    let reopened = init_database(&db_path_str);
+    let (backup_path, tables) = repaired?;
+    reopened?;
+    dismiss_startup_integrity_report();
+
+    Ok(RepairReport {
+        backup_path: backup_path.to_string_lossy().to_string(),
+        recovered_rows: tables.iter().map(|t| t.recovered).sum(),
+        skipped_segments: tables.iter().map(|t| t.skipped_segments).sum(),
+        tables,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROWS: i64 = 1000;
+
+    fn create_source(conn: &Connection) {
+        conn.execute_batch(
+            "PRAGMA page_size = 4096;
+             CREATE TABLE clipboard (id INTEGER PRIMARY KEY AUTOINCREMENT, content TEXT NOT NULL, item_order INTEGER);",
+        )
+        .unwrap();
+        let mut stmt = conn.prepare("INSERT INTO clipboard (content, item_order) VALUES (?1, ?2)").unwrap();
+        for i in 0..ROWS {
+            stmt.execute(rusqlite::params![format!("条目 {} {}", i, "x".repeat(200)), i]).unwrap();
+        }
+        // 索引在数据之后创建，数据页集中在文件前部
+        conn.execute_batch("CREATE INDEX idx_clipboard_order ON clipboard(item_order);").unwrap();
+    }
+
+    fn count(conn: &Connection) -> i64 {
+        conn.query_row("SELECT COUNT(*) FROM clipboard", [], |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn test_integrity_check_on_healthy_database() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_source(&conn);
+        let report = run_integrity_check(&conn, false);
+        assert!(report.ok);
+        assert!(report.errors.is_empty());
+    }
+
+    #[test]
+    fn test_recover_healthy_database_keeps_everything() {
+        let src = Connection::open_in_memory().unwrap();
+        create_source(&src);
+        src.execute("DELETE FROM clipboard WHERE id % 10 = 0", []).unwrap();
+        let dst = Connection::open_in_memory().unwrap();
+        let tables = recover_into(&src, &dst).unwrap();
+
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].recovered as i64, ROWS - ROWS / 10);
+        assert_eq!(tables[0].skipped_segments, 0);
+        assert_eq!(count(&dst), count(&src));
+        // 保留原 rowid，新插入的行继续递增
+        let max_id: i64 = dst.query_row("SELECT MAX(id) FROM clipboard", [], |row| row.get(0)).unwrap();
+        assert_eq!(max_id, ROWS - 1);
+        dst.execute("INSERT INTO clipboard (content) VALUES ('新条目')", []).unwrap();
+        assert_eq!(dst.last_insert_rowid(), ROWS + 1);
+        // 索引一并重建
+        let indexes: i64 = dst
+            .query_row("SELECT COUNT(*) FROM sqlite_master WHERE type = 'index' AND name = 'idx_clipboard_order'", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(indexes, 1);
+    }
+
+    #[test]
+    fn test_recover_skips_corrupted_page() {
+        let path = std::env::temp_dir().join(format!("quickclipboard_integrity_test_{}.db", std::process::id()));
+        let _ = fs::remove_file(&path);
+        {
+            let conn = Connection::open(&path).unwrap();
+            create_source(&conn);
+        }
+        // 用无效数据覆盖中间的一个数据页
+        let pages = fs::metadata(&path).unwrap().len() / 4096;
+        let mut bytes = fs::read(&path).unwrap();
+        let start = (pages / 2 * 4096) as usize;
+        bytes[start..start + 4096].fill(0xFF);
+        fs::write(&path, bytes).unwrap();
+
+        let src = Connection::open(&path).unwrap();
+        assert!(!run_integrity_check(&src, true).ok);
+        let dst = Connection::open_in_memory().unwrap();
+        let tables = recover_into(&src, &dst).unwrap();
+        drop(src);
+        let _ = fs::remove_file(&path);
+
+        let recovered = tables[0].recovered as i64;
+        assert!(tables[0].skipped_segments >= 1);
+        // 只丢失损坏页上的行，其前后的数据都能恢复
+        assert!(recovered < ROWS && recovered > ROWS * 9 / 10, "recovered {}", recovered);
+        assert_eq!(count(&dst), recovered);
+        assert!(run_integrity_check(&dst, false).ok);
+    }
+
+    fn temp_storage_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("quickclipboard_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let conn = Connection::open(dir.join(DB_FILE)).unwrap();
+        create_source(&conn);
+        dir
+    }
+
+    #[test]
+    fn test_repair_files_replaces_database() {
+        let dir = temp_storage_dir("repair_ok");
+        let (backup_path, tables) = repair_files(&dir).unwrap();
+
+        assert!(backup_path.exists());
+        assert!(!dir.join(RECOVERING_FILE).exists());
+        assert_eq!(tables[0].recovered as i64, ROWS);
+        let conn = Connection::open(dir.join(DB_FILE)).unwrap();
+        assert_eq!(count(&conn), ROWS);
+        drop(conn);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_repair_files_keeps_database_when_backup_fails() {
+        let dir = temp_storage_dir("repair_backup_fail");
+        // backups 被同名文件占用，无法创建备份目录
+        fs::write(dir.join("backups"), b"").unwrap();
+
+        assert!(repair_files(&dir).is_err());
+        let conn = Connection::open(dir.join(DB_FILE)).unwrap();
+        assert_eq!(count(&conn), ROWS);
+        assert!(run_integrity_check(&conn, false).ok);
+        drop(conn);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,104 @@
+// 导出时排除仅本机的剪贴板项：生成删除这些项后的数据库副本，并找出只被它们引用的图片
+
+use rusqlite::Connection;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn split_image_ids(value: &str) -> impl Iterator<Item = &str> {
+    value.split(',').map(str::trim).filter(|id| !id.is_empty())
+}
+
+fn collect_image_ids(conn: &Connection, sql: &str) -> Result<HashSet<String>, rusqlite::Error> {
+    let mut stmt = conn.prepare(sql)?;
+    let values: Vec<String> = stmt.query_map([], |row| row.get(0))?.collect::<Result<_, _>>()?;
+    Ok(values.iter().flat_map(|v| split_image_ids(v)).map(String::from).collect())
+}
+
+// 只被仅本机项引用的图片 ID（其他记录或收藏仍在使用的图片照常导出）
+fn local_only_image_ids(conn: &Connection) -> Result<HashSet<String>, rusqlite::Error> {
+    let local = collect_image_ids(conn, "SELECT image_id FROM clipboard WHERE local_only = 1 AND image_id IS NOT NULL")?;
+    if local.is_empty() {
+        return Ok(local);
+    }
+    let mut shared = collect_image_ids(conn, "SELECT image_id FROM clipboard WHERE local_only = 0 AND image_id IS NOT NULL")?;
+    shared.extend(collect_image_ids(conn, "SELECT image_id FROM favorites WHERE image_id IS NOT NULL")?);
+    Ok(local.difference(&shared).cloned().collect())
+}
+
+// 删除仅本机项并整理数据库，确保已删除的内容不会残留在空闲页中
+fn strip_local_only(conn: &Connection) -> Result<HashSet<String>, rusqlite::Error> {
+    let excluded_images = local_only_image_ids(conn)?;
+    conn.execute("DELETE FROM clipboard WHERE local_only = 1", [])?;
+    conn.execute_batch("VACUUM;")?;
+    Ok(excluded_images)
+}
+
+// 导出用的数据库：不存在仅本机项时返回 None 直接导出原文件，
+// 否则返回临时副本路径与需要跳过的图片 ID，调用方负责删除副本
+pub(super) fn prepare_export_database(db_path: &Path) -> Result<Option<(PathBuf, HashSet<String>)>, String> {
+    let has_local_only = {
+        let conn = Connection::open(db_path).map_err(|e| format!("打开数据库失败: {}", e))?;
+        conn.query_row("SELECT EXISTS(SELECT 1 FROM clipboard WHERE local_only = 1)", [], |row| row.get::<_, bool>(0))
+            .map_err(|e| format!("读取仅本机标记失败: {}", e))?
+    };
+    if !has_local_only {
+        return Ok(None);
+    }
+
+    let temp_path = std::env::temp_dir().join(format!(
+        "quickclipboard_export_{}.db",
+        chrono::Local::now().format("%Y%m%d%H%M%S%3f")
+    ));
+    fs::copy(db_path, &temp_path).map_err(|e| format!("复制数据库失败: {}", e))?;
+    let result = Connection::open(&temp_path)
+        .and_then(|conn| strip_local_only(&conn))
+        .map_err(|e| format!("排除仅本机项失败: {}", e));
+    match result {
+        Ok(excluded_images) => Ok(Some((temp_path, excluded_images))),
+        Err(e) => {
+            let _ = fs::remove_file(&temp_path);
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE clipboard (id INTEGER PRIMARY KEY, content TEXT, image_id TEXT, local_only INTEGER NOT NULL DEFAULT 0);
+             CREATE TABLE favorites (id TEXT PRIMARY KEY, content TEXT, image_id TEXT);
+             INSERT INTO clipboard (content, image_id, local_only) VALUES
+                ('公开', NULL, 0),
+                ('私有文本', NULL, 1),
+                ('私有图片', 'img_a', 1),
+                ('私有富文本', 'img_b, img_c', 1),
+                ('公开图片', 'img_b', 0);
+             INSERT INTO favorites (id, content, image_id) VALUES ('f1', '收藏', 'img_c');",
+        )
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_shared_images_are_kept() {
+        let conn = test_db();
+        let ids = local_only_image_ids(&conn).unwrap();
+        assert_eq!(ids, HashSet::from(["img_a".to_string()]));
+    }
+
+    #[test]
+    fn test_strip_local_only() {
+        let conn = test_db();
+        strip_local_only(&conn).unwrap();
+        let remaining: Vec<String> = conn
+            .prepare("SELECT content FROM clipboard ORDER BY id").unwrap()
+            .query_map([], |row| row.get(0)).unwrap()
+            .collect::<Result<_, _>>().unwrap();
+        assert_eq!(remaining, vec!["公开", "公开图片"]);
+    }
+}
@@ -1,4 +1,5 @@
-use std::{fs, path::{Path, PathBuf}, time::SystemTime};
+use std::{collections::HashSet, fs, path::{Path, PathBuf}, time::SystemTime};
+use std::sync::atomic::{AtomicBool, Ordering};
 use chrono::Local;
 use serde::Serialize;
 
@@ -8,6 +9,58 @@ use crate::services::database::{init_database};
 use crate::services::database::connection::{close_database, with_connection};
 use crate::services::system::hotkey::reload_from_settings;
 
+pub mod rebuild;
+pub mod relocate;
+pub mod integrity;
+mod local_only;
+
+// 导入导出进度上报与取消
+const HISTORY_IO_BATCH_SIZE: usize = 500;
+
+static HISTORY_IO_CANCELLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryIoProgress {
+    pub stage: String,
+    pub processed: usize,
+    pub total: usize,
+}
+
+pub fn cancel_history_io() {
+    HISTORY_IO_CANCELLED.store(true, Ordering::SeqCst);
+}
+
+fn begin_history_io() {
+    HISTORY_IO_CANCELLED.store(false, Ordering::SeqCst);
+}
+
+fn check_history_io_cancelled() -> Result<(), String> {
+    if HISTORY_IO_CANCELLED.load(Ordering::SeqCst) {
+        return Err("操作已取消".into());
+    }
+    Ok(())
+}
+
+fn emit_history_io_progress(stage: &str, processed: usize, total: usize) {
+    use tauri::Emitter;
+    if let Some(app) = crate::services::clipboard::get_app_handle() {
+        let _ = app.emit("history-io-progress", HistoryIoProgress {
+            stage: stage.to_string(),
+            processed,
+            total,
+        });
+    }
+}
+
+fn count_files(dir: &Path) -> usize {
+    let Ok(entries) = fs::read_dir(dir) else { return 0 };
+    entries.flatten().map(|e| {
+        let p = e.path();
+        if p.is_dir() { count_files(&p) } else { 1 }
+    }).sum()
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct TargetDataInfo {
     pub has_data: bool,
@@ -282,11 +335,20 @@ pub fn import_data_zip(zip_path: PathBuf, mode: &str) -> Result<String, String>
         return Err("导入文件不存在".into());
     }
 
+    begin_history_io();
     let temp_root = std::env::temp_dir().join(format!("quickclipboard_import_{}", fastrand::u32(..)));
     fs::create_dir_all(&temp_root).map_err(|e| e.to_string())?;
     let file = fs::File::open(&zip_path).map_err(|e| format!("打开导入文件失败: {}", e))?;
     let mut archive = zip::ZipArchive::new(file).map_err(|e| format!("读取压缩包失败: {}", e))?;
-    for i in 0..archive.len() {
+    let total_entries = archive.len();
+    for i in 0..total_entries {
+        if i % HISTORY_IO_BATCH_SIZE == 0 {
+            if let Err(e) = check_history_io_cancelled() {
+                let _ = fs::remove_dir_all(&temp_root);
+                return Err(e);
+            }
+            emit_history_io_progress("extract", i, total_entries);
+        }
         let mut f = archive.by_index(i).map_err(|e| e.to_string())?;
         let name = f.name().to_string();
         if name.contains("..") { continue; }
@@ -299,6 +361,7 @@ pub fn import_data_zip(zip_path: PathBuf, mode: &str) -> Result<String, String>
             std::io::copy(&mut f, &mut out).map_err(|e| e.to_string())?;
         }
     }
+    emit_history_io_progress("extract", total_entries, total_entries);
 
     let imported_db = temp_root.join("quickclipboard.db");
     let imported_images = temp_root.join("clipboard_images");
@@ -319,7 +382,7 @@ pub fn import_data_zip(zip_path: PathBuf, mode: &str) -> Result<String, String>
             } else {
                 get_settings()
             };
-            if crate::services::is_portable_build() || std::env::current_exe().ok().and_then(|e| e.parent().map(|p| p.join("portable.txt").exists())).unwrap_or(false) {
+            if is_portable_install() {
                 new_settings.use_custom_storage = false;
                 new_settings.custom_storage_path = None;
             }
@@ -397,7 +460,10 @@ pub fn import_data_zip(zip_path: PathBuf, mode: &str) -> Result<String, String>
             }
 
             if imported_db.exists() {
-                merge_database(&imported_db)?;
+                if let Err(e) = merge_database(&imported_db, true) {
+                    let _ = fs::remove_dir_all(&temp_root);
+                    return Err(e);
+                }
             }
 
             let _ = fs::remove_dir_all(&temp_root);
@@ -424,6 +490,15 @@ fn safe_move_item(src: &Path, dst: &Path) -> Result<(), String> {
     Ok(())
 }
 
+// 便携版（编译时指定或程序目录下存在 portable.txt）
+fn is_portable_install() -> bool {
+    crate::services::is_portable_build()
+        || std::env::current_exe()
+            .ok()
+            .and_then(|e| e.parent().map(|p| p.join("portable.txt").exists()))
+            .unwrap_or(false)
+}
+
 pub fn get_default_data_dir() -> Result<PathBuf, String> {
     let settings_path = SettingsStorage::get_settings_path()?;
     settings_path.parent().map(|p| p.to_path_buf()).ok_or("无法获取默认数据目录".to_string())
@@ -435,7 +510,7 @@ pub fn get_current_storage_dir() -> Result<PathBuf, String> {
 
 // mode: "source_only" | "target_only" | "merge"
 pub fn change_storage_dir(new_dir: PathBuf, mode: &str) -> Result<PathBuf, String> {
-    if crate::services::is_portable_build() || std::env::current_exe().ok().and_then(|e| e.parent().map(|p| p.join("portable.txt").exists())).unwrap_or(false) {
+    if is_portable_install() {
         return Err("便携版不支持更改存储路径".into());
     }
     if !new_dir.exists() { fs::create_dir_all(&new_dir).map_err(|e| e.to_string())?; }
@@ -495,7 +570,9 @@ fn merge_dir_no_overwrite(src: &Path, dst: &Path) -> Result<(), String> {
     Ok(())
 }
 
-fn merge_database(src_db: &Path) -> Result<(), String> {
+// track_progress 为 true 时分批导入剪贴板记录，并在批次之间上报进度、检查取消标记
+fn merge_database(src_db: &Path, track_progress: bool) -> Result<(), String> {
+    let mut cancelled = false;
     with_connection(|conn| {
         let import_path = src_db.to_str().ok_or(rusqlite::Error::InvalidPath("bad path".into()))?;
         conn.execute("ATTACH DATABASE ?1 AS importdb", [import_path])?;
@@ -510,20 +587,82 @@ fn merge_database(src_db: &Path) -> Result<(), String> {
              SELECT id, title, content, html_content, content_type, image_id, group_name, item_order, created_at, updated_at FROM importdb.favorites",
             [],
         );
-        let _ = conn.execute(
-            "INSERT INTO clipboard (content, html_content, content_type, image_id, created_at, updated_at)
-             SELECT content, html_content, content_type, image_id, created_at, updated_at FROM importdb.clipboard",
-            [],
-        );
-
+        let merged = if track_progress {
+            merge_clipboard_in_batches(conn)
+        } else {
+            let _ = conn.execute(
+                "INSERT INTO clipboard (content, html_content, content_type, image_id, created_at, updated_at)
+                 SELECT content, html_content, content_type, image_id, created_at, updated_at FROM importdb.clipboard",
+                [],
+            );
+            Ok(false)
+        };
+
+        // 批次导入失败时也要分离 importdb，否则之后的导入都会因别名已存在而失败
         let _ = conn.execute("DETACH DATABASE importdb", []);
         reorder_clipboard_by_time(conn);
-        
+        cancelled = merged?;
+
         Ok(())
     })?;
+    if cancelled {
+        return Err("导入已取消，已导入的批次已保留".into());
+    }
     Ok(())
 }
 
+// 按 rowid 分批导入，每批一个事务；返回是否被取消
+fn merge_clipboard_in_batches(conn: &rusqlite::Connection) -> Result<bool, rusqlite::Error> {
+    let total: i64 = conn.query_row("SELECT COUNT(*) FROM importdb.clipboard", [], |row| row.get(0))?;
+    let total = total as usize;
+    let mut processed = 0usize;
+    let mut last_rowid: i64 = i64::MIN;
+    emit_history_io_progress("import", 0, total);
+
+    while processed < total {
+        if HISTORY_IO_CANCELLED.load(Ordering::SeqCst) {
+            return Ok(true);
+        }
+
+        conn.execute_batch("BEGIN")?;
+        let batch = (|| -> Result<(usize, i64), rusqlite::Error> {
+            let mut stmt = conn.prepare(
+                "SELECT rowid FROM importdb.clipboard WHERE rowid > ?1 ORDER BY rowid LIMIT ?2"
+            )?;
+            let rowids: Vec<i64> = stmt
+                .query_map(rusqlite::params![last_rowid, HISTORY_IO_BATCH_SIZE as i64], |row| row.get(0))?
+                .collect::<Result<_, _>>()?;
+            let Some(&max_rowid) = rowids.last() else { return Ok((0, last_rowid)) };
+            conn.execute(
+                "INSERT INTO clipboard (content, html_content, content_type, image_id, created_at, updated_at)
+                 SELECT content, html_content, content_type, image_id, created_at, updated_at
+                 FROM importdb.clipboard WHERE rowid > ?1 AND rowid <= ?2 ORDER BY rowid",
+                rusqlite::params![last_rowid, max_rowid],
+            )?;
+            Ok((rowids.len(), max_rowid))
+        })();
+
+        match batch {
+            Ok((0, _)) => {
+                conn.execute_batch("COMMIT")?;
+                break;
+            }
+            Ok((count, max_rowid)) => {
+                conn.execute_batch("COMMIT")?;
+                processed += count;
+                last_rowid = max_rowid;
+                emit_history_io_progress("import", processed.min(total), total);
+            }
+            Err(e) => {
+                let _ = conn.execute_batch("ROLLBACK");
+                return Err(e);
+            }
+        }
+    }
+
+    Ok(false)
+}
+
 fn reorder_clipboard_by_time(conn: &rusqlite::Connection) {
     if let Ok(mut stmt) = conn.prepare(
         "SELECT id FROM clipboard ORDER BY is_pinned DESC, created_at DESC"
@@ -540,7 +679,7 @@ fn reorder_clipboard_by_time(conn: &rusqlite::Connection) {
 }
 
 pub fn reset_storage_dir_to_default(mode: &str) -> Result<PathBuf, String> {
-    if crate::services::is_portable_build() || std::env::current_exe().ok().and_then(|e| e.parent().map(|p| p.join("portable.txt").exists())).unwrap_or(false) {
+    if is_portable_install() {
         return Err("便携版不支持重置存储路径".into());
     }
     let default_dir = get_default_data_dir()?;
@@ -665,7 +804,7 @@ fn change_storage_dir_internal(src_dir: &Path, dst_dir: &Path, mode: &str) -> Re
             if src_db.exists() {
                 if dst_db.exists() {
                     init_database(src_db.to_str().ok_or("数据库路径无效")?)?;
-                    merge_database(&dst_db)?;
+                    merge_database(&dst_db, false)?;
                     close_database();
                     fs::remove_file(&dst_db).map_err(|e| format!("删除目标数据库失败: {}", e))?;
                 }
@@ -688,12 +827,44 @@ fn change_storage_dir_internal(src_dir: &Path, dst_dir: &Path, mode: &str) -> Re
 }
 
 pub fn export_data_zip(target_path: PathBuf) -> Result<PathBuf, String> {
+    begin_history_io();
     let current_dir = get_current_storage_dir()?;
     let _ = crate::services::database::connection::with_connection(|conn| {
         conn.execute_batch("PRAGMA wal_checkpoint(FULL); PRAGMA wal_checkpoint(TRUNCATE);")
     });
     close_database();
 
+    let result = write_export_zip(&current_dir, &target_path);
+    if result.is_err() {
+        let _ = fs::remove_file(&target_path);
+    }
+
+    let db_path = current_dir.join("quickclipboard.db");
+    if db_path.exists() {
+        init_database(db_path.to_str().ok_or("数据库路径无效")?)?;
+    }
+
+    result.map(|_| target_path)
+}
+
+struct ExportProgress {
+    processed: usize,
+    total: usize,
+}
+
+impl ExportProgress {
+    // 每处理一个文件调用一次，按批次上报进度并检查取消
+    fn advance(&mut self) -> Result<(), String> {
+        self.processed += 1;
+        if self.processed % HISTORY_IO_BATCH_SIZE == 0 {
+            check_history_io_cancelled()?;
+            emit_history_io_progress("export", self.processed, self.total);
+        }
+        Ok(())
+    }
+}
+
+fn write_export_zip(current_dir: &Path, target_path: &Path) -> Result<(), String> {
     let images_dir = current_dir.join("clipboard_images");
     let image_library_dir = current_dir.join("image_library");
     let app_icons_dir = current_dir.join("app_icons");
@@ -702,50 +873,72 @@ pub fn export_data_zip(target_path: PathBuf) -> Result<PathBuf, String> {
     ];
     let settings_path = crate::services::settings::storage::SettingsStorage::get_settings_path()?;
 
+    let mut progress = ExportProgress {
+        processed: 0,
+        total: db_files.len() + 1 + count_files(&images_dir) + count_files(&image_library_dir) + count_files(&app_icons_dir),
+    };
+    emit_history_io_progress("export", 0, progress.total);
+
     if let Some(parent) = target_path.parent() { fs::create_dir_all(parent).map_err(|e| e.to_string())?; }
-    let file = fs::File::create(&target_path).map_err(|e| format!("创建导出文件失败: {}", e))?;
+    let file = fs::File::create(target_path).map_err(|e| format!("创建导出文件失败: {}", e))?;
     let mut zip = zip::ZipWriter::new(file);
     let options = zip::write::SimpleFileOptions::default()
         .compression_method(zip::CompressionMethod::Deflated)
         .unix_permissions(0o644);
 
+    // 仅本机项不导出：写入排除后的数据库副本，并跳过只被它们引用的图片
+    let mut excluded_images = HashSet::new();
     for name in &db_files {
         let src = current_dir.join(name);
         if src.exists() {
-            let mut f = fs::File::open(&src).map_err(|e| format!("读取文件失败: {}", e))?;
-            zip.start_file(name, options).map_err(|e| e.to_string())?;
-            std::io::copy(&mut f, &mut zip).map_err(|e| e.to_string())?;
+            let filtered = local_only::prepare_export_database(&src)?;
+            let source = filtered.as_ref().map(|(path, _)| path.as_path()).unwrap_or(&src);
+            let written = fs::File::open(source)
+                .map_err(|e| format!("读取文件失败: {}", e))
+                .and_then(|mut f| {
+                    zip.start_file(name, options).map_err(|e| e.to_string())?;
+                    std::io::copy(&mut f, &mut zip).map_err(|e| e.to_string())
+                });
+            if let Some((path, images)) = filtered {
+                let _ = fs::remove_file(&path);
+                excluded_images = images;
+            }
+            written?;
         }
+        progress.advance()?;
     }
 
-    fn add_dir_to_zip(base: &Path, dir: &Path, prefix: &str, zip: &mut zip::ZipWriter<fs::File>, options: zip::write::SimpleFileOptions) -> Result<(), String> {
+    fn add_dir_to_zip(base: &Path, dir: &Path, prefix: &str, zip: &mut zip::ZipWriter<fs::File>, options: zip::write::SimpleFileOptions, progress: &mut ExportProgress, excluded: &HashSet<String>) -> Result<(), String> {
         for entry in fs::read_dir(dir).map_err(|e| e.to_string())? {
             let entry = entry.map_err(|e| e.to_string())?;
             let path = entry.path();
             let rel = path.strip_prefix(base).map_err(|e| e.to_string())?;
             if path.is_dir() {
-                add_dir_to_zip(base, &path, prefix, zip, options)?;
+                add_dir_to_zip(base, &path, prefix, zip, options, progress, excluded)?;
+            } else if path.file_stem().and_then(|s| s.to_str()).is_some_and(|stem| excluded.contains(stem)) {
+                progress.advance()?;
             } else {
                 let zip_path = Path::new(prefix).join(rel);
                 let mut f = fs::File::open(&path).map_err(|e| format!("读取文件失败: {}", e))?;
                 let zip_name = zip_path.to_string_lossy();
                 zip.start_file(zip_name.as_ref(), options).map_err(|e| e.to_string())?;
                 std::io::copy(&mut f, zip).map_err(|e| e.to_string())?;
+                progress.advance()?;
             }
         }
         Ok(())
     }
 
     if images_dir.exists() {
-        add_dir_to_zip(&images_dir, &images_dir, "clipboard_images", &mut zip, options)?;
+        add_dir_to_zip(&images_dir, &images_dir, "clipboard_images", &mut zip, options, &mut progress, &excluded_images)?;
     }
 
     if image_library_dir.exists() {
-        add_dir_to_zip(&image_library_dir, &image_library_dir, "image_library", &mut zip, options)?;
+        add_dir_to_zip(&image_library_dir, &image_library_dir, "image_library", &mut zip, options, &mut progress, &HashSet::new())?;
     }
 
     if app_icons_dir.exists() {
-        add_dir_to_zip(&app_icons_dir, &app_icons_dir, "app_icons", &mut zip, options)?;
+        add_dir_to_zip(&app_icons_dir, &app_icons_dir, "app_icons", &mut zip, options, &mut progress, &HashSet::new())?;
     }
 
     if settings_path.exists() {
@@ -753,13 +946,10 @@ pub fn export_data_zip(target_path: PathBuf) -> Result<PathBuf, String> {
         zip.start_file("settings.json", options).map_err(|e| e.to_string())?;
         std::io::copy(&mut f, &mut zip).map_err(|e| e.to_string())?;
     }
+    progress.advance()?;
 
     zip.finish().map_err(|e| e.to_string())?;
+    emit_history_io_progress("export", progress.total, progress.total);
 
-    let db_path = current_dir.join("quickclipboard.db");
-    if db_path.exists() {
-        init_database(db_path.to_str().ok_or("数据库路径无效")?)?;
-    }
-
-    Ok(target_path)
+    Ok(())
 }
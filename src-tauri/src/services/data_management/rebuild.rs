@@ -0,0 +1,249 @@
+// 重建派生数据：按当前规则重新计算由内容派生的字段（导入旧数据库、表结构升级或调整预览长度后使用）
+// 分批执行，每批单独占用数据库连接，期间应用可正常读写；支持进度上报与取消
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::services::database::connection::with_connection;
+
+const REBUILD_BATCH_SIZE: i64 = 500;
+
+static REBUILD_RUNNING: AtomicBool = AtomicBool::new(false);
+static REBUILD_CANCELLED: AtomicBool = AtomicBool::new(false);
+
+// 可重建的派生数据
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DerivedKind {
+    // 列表预览（剪贴板历史）
+    Preview,
+    // 文本字符数（剪贴板历史与收藏）
+    CharCount,
+}
+
+impl DerivedKind {
+    // 需要更新的 (表, 字段, 计算表达式)
+    fn targets(self, preview_length: usize) -> Vec<(&'static str, &'static str, String)> {
+        match self {
+            Self::Preview => vec![(
+                "clipboard",
+                "preview",
+                format!(
+                    "CASE WHEN content_type IN ('text', 'rich_text', 'link') THEN substr(content, 1, {}) END",
+                    preview_length.max(1)
+                ),
+            )],
+            Self::CharCount => ["clipboard", "favorites"]
+                .into_iter()
+                .map(|table| {
+                    (table, "char_count", "CASE WHEN content_type LIKE '%text%' AND length(content) > 0 THEN length(content) END".to_string())
+                })
+                .collect(),
+        }
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            Self::Preview => "preview",
+            Self::CharCount => "charCount",
+        }
+    }
+}
+
+// 重建结果
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RebuildReport {
+    pub kinds: Vec<DerivedKind>,
+    // 已处理的行数（按表累计）
+    pub processed: usize,
+    // 实际更新的行数
+    pub updated: usize,
+    // 失败批次的错误信息，失败的批次跳过后继续
+    pub errors: Vec<String>,
+    pub cancelled: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RebuildProgress {
+    pub kind: DerivedKind,
+    pub processed: usize,
+    pub total: usize,
+}
+
+pub fn cancel_rebuild_derived_data() {
+    REBUILD_CANCELLED.store(true, Ordering::SeqCst);
+}
+
+fn emit_rebuild_progress(kind: DerivedKind, processed: usize, total: usize) {
+    use tauri::Emitter;
+    if let Some(app) = crate::services::clipboard::get_app_handle() {
+        let _ = app.emit("rebuild-derived-progress", RebuildProgress { kind, processed, total });
+    }
+}
+
+// 更新语句：只写入与重新计算结果不同的行，参数 ?1、?2 为 rowid 区间 (?1, ?2]
+fn update_sql(table: &str, column: &str, expr: &str) -> String {
+    format!(
+        "UPDATE {table} SET {column} = {expr} WHERE rowid > ?1 AND rowid <= ?2 AND {column} IS NOT ({expr})",
+        table = table,
+        column = column,
+        expr = expr
+    )
+}
+
+// 处理 last_rowid 之后的一批数据，返回 (本批行数, 更新行数, 本批最大 rowid)，没有剩余数据时返回 None
+fn rebuild_batch(conn: &Connection, table: &str, sql: &str, last_rowid: i64) -> Result<Option<(usize, usize, i64)>, rusqlite::Error> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT rowid FROM {} WHERE rowid > ?1 ORDER BY rowid LIMIT ?2",
+        table
+    ))?;
+    let rowids: Vec<i64> = stmt
+        .query_map(rusqlite::params![last_rowid, REBUILD_BATCH_SIZE], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+    let Some(&max_rowid) = rowids.last() else { return Ok(None) };
+    let updated = conn.execute(sql, rusqlite::params![last_rowid, max_rowid])?;
+    Ok(Some((rowids.len(), updated, max_rowid)))
+}
+
+// 读取下一批的 rowid 区间终点，用于失败后跳过该批
+fn next_batch_end(table: &str, last_rowid: i64) -> Option<i64> {
+    with_connection(|conn| {
+        conn.query_row(
+            &format!("SELECT MAX(rowid) FROM (SELECT rowid FROM {} WHERE rowid > ?1 ORDER BY rowid LIMIT ?2)", table),
+            rusqlite::params![last_rowid, REBUILD_BATCH_SIZE],
+            |row| row.get::<_, Option<i64>>(0),
+        )
+    })
+    .ok()
+    .flatten()
+}
+
+// 重建指定的派生数据，同一时间只允许一个重建任务
+pub fn rebuild_derived_data(kinds: &[DerivedKind]) -> Result<RebuildReport, String> {
+    if kinds.is_empty() {
+        return Err("未指定需要重建的数据".into());
+    }
+    if REBUILD_RUNNING.swap(true, Ordering::SeqCst) {
+        return Err("派生数据重建正在进行中".into());
+    }
+    REBUILD_CANCELLED.store(false, Ordering::SeqCst);
+
+    let result = run_rebuild(kinds);
+    REBUILD_RUNNING.store(false, Ordering::SeqCst);
+    result
+}
+
+fn run_rebuild(kinds: &[DerivedKind]) -> Result<RebuildReport, String> {
+    let preview_length = crate::services::get_settings().list_preview_length;
+    let mut report = RebuildReport::default();
+    for kind in kinds {
+        if !report.kinds.contains(kind) {
+            report.kinds.push(*kind);
+        }
+    }
+
+    for kind in report.kinds.clone() {
+        let targets = kind.targets(preview_length);
+        let mut total = 0usize;
+        for (table, _, _) in &targets {
+            let count: i64 = with_connection(|conn| {
+                conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| row.get(0))
+            })?;
+            total += count as usize;
+        }
+
+        let mut processed = 0usize;
+        emit_rebuild_progress(kind, 0, total);
+        for (table, column, expr) in &targets {
+            let sql = update_sql(table, column, expr);
+            let mut last_rowid = i64::MIN;
+            loop {
+                if REBUILD_CANCELLED.load(Ordering::SeqCst) {
+                    report.cancelled = true;
+                    return Ok(report);
+                }
+                match with_connection(|conn| rebuild_batch(conn, table, &sql, last_rowid)) {
+                    Ok(None) => break,
+                    Ok(Some((count, updated, max_rowid))) => {
+                        processed += count;
+                        report.processed += count;
+                        report.updated += updated;
+                        last_rowid = max_rowid;
+                    }
+                    Err(e) => {
+                        report.errors.push(format!("{}/{}: {}", kind.key(), table, e));
+                        // 跳过失败的批次继续处理
+                        match next_batch_end(table, last_rowid) {
+                            Some(max_rowid) => last_rowid = max_rowid,
+                            None => break,
+                        }
+                    }
+                }
+                emit_rebuild_progress(kind, processed.min(total), total);
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE clipboard (id INTEGER PRIMARY KEY, content TEXT NOT NULL, content_type TEXT NOT NULL, preview TEXT, char_count INTEGER);
+             INSERT INTO clipboard (content, content_type) VALUES ('hello world', 'text'), ('<b>粗体</b>', 'rich_text'), ('C:\\a.png', 'image'), ('', 'text');",
+        )
+        .unwrap();
+        conn
+    }
+
+    fn run_all(conn: &Connection, sql: &str) -> (usize, usize) {
+        let (mut processed, mut updated, mut last_rowid) = (0, 0, i64::MIN);
+        while let Some((count, changed, max_rowid)) = rebuild_batch(conn, "clipboard", sql, last_rowid).unwrap() {
+            processed += count;
+            updated += changed;
+            last_rowid = max_rowid;
+        }
+        (processed, updated)
+    }
+
+    #[test]
+    fn test_rebuild_preview() {
+        let conn = test_db();
+        let (table, column, expr) = DerivedKind::Preview.targets(5).remove(0);
+        let sql = update_sql(table, column, &expr);
+        // 非文本项的预览本就为空，不计入更新
+        assert_eq!(run_all(&conn, &sql), (4, 3));
+        // 再次执行时没有变化
+        assert_eq!(run_all(&conn, &sql), (4, 0));
+
+        let previews: Vec<Option<String>> = conn
+            .prepare("SELECT preview FROM clipboard ORDER BY id").unwrap()
+            .query_map([], |row| row.get(0)).unwrap()
+            .collect::<Result<_, _>>().unwrap();
+        assert_eq!(previews, vec![Some("hello".into()), Some("<b>粗体".into()), None, Some(String::new())]);
+    }
+
+    #[test]
+    fn test_rebuild_char_count() {
+        let conn = test_db();
+        let (table, column, expr) = DerivedKind::CharCount.targets(5).remove(0);
+        assert_eq!(table, "clipboard");
+        conn.execute("UPDATE clipboard SET char_count = 99", []).unwrap();
+        assert_eq!(run_all(&conn, &update_sql(table, column, &expr)), (4, 4));
+
+        let counts: Vec<Option<i64>> = conn
+            .prepare("SELECT char_count FROM clipboard ORDER BY id").unwrap()
+            .query_map([], |row| row.get(0)).unwrap()
+            .collect::<Result<_, _>>().unwrap();
+        // 按字符计数，非文本和空内容为空
+        assert_eq!(counts, vec![Some(11), Some(9), None, None]);
+    }
+}
@@ -0,0 +1,196 @@
+// 迁移数据库到新目录：先复制并校验，切换成功后再删除源文件，任一步骤失败都回退到原位置
+// 始终使用复制+删除，跨磁盘迁移与同盘迁移走同一流程
+
+use rusqlite::{Connection, OpenFlags};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{check_target_has_data, copy_dir_all, get_current_storage_dir, get_default_data_dir};
+use crate::services::database::connection::{close_database, with_connection};
+use crate::services::database::init_database;
+use crate::services::{get_settings, update_settings};
+
+const DB_FILE: &str = "quickclipboard.db";
+// 随数据库一起迁移的文件与目录（缩略图、贴图、图库、应用图标与备份）
+const RELOCATED_ITEMS: [&str; 6] = [DB_FILE, "clipboard_images", "pin_images", "image_library", "app_icons", "backups"];
+
+// 用于迁移前后比对的记录数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DbCounts {
+    clipboard: i64,
+    favorites: i64,
+}
+
+fn database_counts(conn: &Connection) -> Result<DbCounts, rusqlite::Error> {
+    let count = |table: &str| conn.query_row::<i64, _, _>(&format!("SELECT COUNT(*) FROM {}", table), [], |row| row.get(0));
+    Ok(DbCounts {
+        clipboard: count("clipboard")?,
+        favorites: count("favorites")?,
+    })
+}
+
+// 完整性检查并比对记录数
+fn verify_database(conn: &Connection, expected: DbCounts) -> Result<(), String> {
+    let integrity: String = conn
+        .query_row("PRAGMA integrity_check", [], |row| row.get(0))
+        .map_err(|e| format!("数据库完整性检查失败: {}", e))?;
+    if integrity != "ok" {
+        return Err(format!("迁移后的数据库已损坏: {}", integrity));
+    }
+    let actual = database_counts(conn).map_err(|e| format!("读取迁移后的数据库失败: {}", e))?;
+    if actual != expected {
+        return Err(format!(
+            "迁移后的记录数不一致（剪贴板 {}/{}，收藏 {}/{}）",
+            actual.clipboard, expected.clipboard, actual.favorites, expected.favorites
+        ));
+    }
+    Ok(())
+}
+
+// 目标不能与当前目录相同，也不能位于当前目录内（否则会复制到自身）
+fn validate_target(current_dir: &Path, new_dir: &Path) -> Result<(), String> {
+    if new_dir == current_dir {
+        return Err("新位置与当前存储位置相同，无需迁移".into());
+    }
+    if new_dir.starts_with(current_dir) {
+        return Err("新位置不能位于当前存储目录内".into());
+    }
+    Ok(())
+}
+
+fn copy_item(src: &Path, dst: &Path) -> Result<(), String> {
+    if src.is_dir() {
+        copy_dir_all(src, dst)
+    } else {
+        fs::copy(src, dst).map(|_| ()).map_err(|e| format!("复制文件失败: {}", e))
+    }
+}
+
+fn remove_item(path: &Path) -> std::io::Result<()> {
+    if path.is_dir() { fs::remove_dir_all(path) } else { fs::remove_file(path) }
+}
+
+fn remove_wal_files(dir: &Path) {
+    for name in ["quickclipboard.db-shm", "quickclipboard.db-wal"] {
+        let _ = fs::remove_file(dir.join(name));
+    }
+}
+
+// 迁移数据库及相关文件到 new_dir，返回新的存储目录
+pub fn move_database(new_dir: PathBuf) -> Result<PathBuf, String> {
+    if super::is_portable_install() {
+        return Err("便携版不支持更改存储路径".into());
+    }
+    let current_dir = get_current_storage_dir()?;
+    validate_target(&current_dir, &new_dir)?;
+    fs::create_dir_all(&new_dir).map_err(|e| format!("创建目标目录失败: {}", e))?;
+    if check_target_has_data(&new_dir)?.has_data {
+        return Err("目标位置已有数据，请使用更改存储位置并选择迁移方式".into());
+    }
+
+    // 将 WAL 写回主文件后记录迁移前的记录数
+    let expected = with_connection(|conn| {
+        conn.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);")?;
+        database_counts(conn)
+    })?;
+    close_database();
+
+    let current_db = current_dir.join(DB_FILE);
+    let reopen_current = || init_database(current_db.to_str().ok_or("数据库路径无效")?);
+
+    // 复制到新位置，失败时清理已复制的部分并重新打开原数据库
+    let mut copied: Vec<PathBuf> = Vec::new();
+    let copy_result = (|| -> Result<(), String> {
+        for name in RELOCATED_ITEMS {
+            let src = current_dir.join(name);
+            if !src.exists() {
+                continue;
+            }
+            let dst = new_dir.join(name);
+            copied.push(dst.clone());
+            copy_item(&src, &dst)?;
+        }
+        let conn = Connection::open_with_flags(new_dir.join(DB_FILE), OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(|e| format!("打开迁移后的数据库失败: {}", e))?;
+        verify_database(&conn, expected)
+    })();
+
+    let rollback = |copied: &[PathBuf], error: String| -> Result<PathBuf, String> {
+        for path in copied {
+            let _ = remove_item(path);
+        }
+        remove_wal_files(&new_dir);
+        match reopen_current() {
+            Ok(()) => Err(error),
+            Err(e) => Err(format!("{}；重新打开原数据库失败: {}", error, e)),
+        }
+    };
+    if let Err(e) = copy_result {
+        return rollback(&copied, e);
+    }
+
+    // 切换存储路径并打开新数据库，失败时恢复设置
+    let previous_settings = get_settings();
+    let mut settings = previous_settings.clone();
+    if get_default_data_dir().is_ok_and(|default_dir| default_dir == new_dir) {
+        settings.use_custom_storage = false;
+        settings.custom_storage_path = None;
+    } else {
+        settings.use_custom_storage = true;
+        settings.custom_storage_path = Some(new_dir.to_string_lossy().to_string());
+    }
+    let switch_result = update_settings(settings)
+        .and_then(|_| init_database(new_dir.join(DB_FILE).to_str().ok_or("数据库路径无效")?));
+    if let Err(e) = switch_result {
+        close_database();
+        let _ = update_settings(previous_settings);
+        return rollback(&copied, e);
+    }
+
+    // 新位置已生效，源文件删除失败不影响迁移结果
+    for name in RELOCATED_ITEMS {
+        let src = current_dir.join(name);
+        if src.exists() {
+            if let Err(e) = remove_item(&src) {
+                eprintln!("删除原位置的 {} 失败: {}", name, e);
+            }
+        }
+    }
+    remove_wal_files(&current_dir);
+
+    Ok(new_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE clipboard (id INTEGER PRIMARY KEY, content TEXT);
+             CREATE TABLE favorites (id TEXT PRIMARY KEY, content TEXT);
+             INSERT INTO clipboard (content) VALUES ('a'), ('b');
+             INSERT INTO favorites (id, content) VALUES ('f1', 'c');",
+        )
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_verify_database_compares_counts() {
+        let conn = test_db();
+        assert!(verify_database(&conn, DbCounts { clipboard: 2, favorites: 1 }).is_ok());
+        assert!(verify_database(&conn, DbCounts { clipboard: 3, favorites: 1 }).is_err());
+    }
+
+    #[test]
+    fn test_validate_target() {
+        let current = Path::new("/data/quickclipboard");
+        assert!(validate_target(current, Path::new("/data/quickclipboard")).is_err());
+        assert!(validate_target(current, Path::new("/data/quickclipboard/backups")).is_err());
+        assert!(validate_target(current, Path::new("/mnt/d/quickclipboard")).is_ok());
+        // 仅前缀相同的兄弟目录不算嵌套
+        assert!(validate_target(current, Path::new("/data/quickclipboard2")).is_ok());
+    }
+}
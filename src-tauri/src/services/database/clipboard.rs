@@ -1,43 +1,69 @@
-use super::models::{ClipboardItem, PaginatedResult, QueryParams};
+use super::models::{ClipboardBatch, ClipboardItem, HistoryStats, MostPastedItem, PaginatedResult, QueryParams};
 use super::connection::{with_connection, MAX_CONTENT_LENGTH};
-use crate::utils::{truncate_string, truncate_around_keyword, truncate_html};
+use crate::utils::{truncate_string, truncate_around_keyword, truncate_html, find_match_ranges};
 use rusqlite::{params, OptionalExtension};
-use std::collections::HashSet;
+use rusqlite::types::Value;
+use std::collections::{HashMap, HashSet};
 use chrono;
 
-// 计算文本字符数
-fn calculate_char_count(content: &str, content_type: &str) -> Option<i64> {
-    if content_type.contains("text") || content_type.contains("rich_text") {
-        let count = content.chars().count() as i64;
-        if count > 0 {
-            Some(count)
-        } else {
-            None
-        }
+// 列表预览适用的内容类型（与列表截断逻辑一致）
+const PREVIEW_CONTENT_TYPES_SQL: &str = "('text', 'rich_text', 'link')";
+
+fn is_preview_type(content_type: &str) -> bool {
+    matches!(content_type, "text" | "rich_text" | "link")
+}
+
+// 生成列表预览（前 N 个字符），非文本类型返回 None
+pub fn make_preview(content: &str, content_type: &str, length: usize) -> Option<String> {
+    if is_preview_type(content_type) {
+        Some(content.chars().take(length.max(1)).collect())
     } else {
         None
     }
 }
 
-// 异步更新缺失的字符数
-pub fn update_missing_char_counts(items: Vec<(i64, String, String)>) {
-    if items.is_empty() { return; }
+// 异步更新缺失的字符数（在数据库内计算，无需加载完整内容）
+pub fn update_missing_char_counts(ids: Vec<i64>) {
+    if ids.is_empty() { return; }
     
     std::thread::spawn(move || {
         let _ = with_connection(|conn| {
-            for (id, content, content_type) in items {
-                if let Some(char_count) = calculate_char_count(&content, &content_type) {
-                    conn.execute(
-                        "UPDATE clipboard SET char_count = ?1 WHERE id = ?2",
-                        params![char_count, id],
-                    )?;
-                }
+            for id in ids {
+                conn.execute(
+                    "UPDATE clipboard SET char_count = length(content) WHERE id = ?1 AND length(content) > 0",
+                    params![id],
+                )?;
             }
             Ok(())
         });
     });
 }
 
+// 异步补全旧数据缺失的预览
+fn update_missing_previews(ids: Vec<i64>, length: usize) {
+    if ids.is_empty() { return; }
+
+    std::thread::spawn(move || {
+        let _ = with_connection(|conn| {
+            for id in ids {
+                conn.execute(
+                    "UPDATE clipboard SET preview = substr(content, 1, ?1) WHERE id = ?2",
+                    params![length as i64, id],
+                )?;
+            }
+            Ok(())
+        });
+    });
+}
+
+// 清空所有预览（预览长度变更后按新长度重新生成）
+pub fn reset_previews() -> Result<(), String> {
+    with_connection(|conn| {
+        conn.execute("UPDATE clipboard SET preview = NULL WHERE preview IS NOT NULL", [])?;
+        Ok(())
+    })
+}
+
 // 按逗号拆分图片ID
 fn split_image_ids(s: &str) -> Vec<String> {
     s.split(',')
@@ -80,100 +106,122 @@ fn delete_image_files(image_ids: Vec<String>) -> Result<(), String> {
     Ok(())
 }
 
+// 构建剪贴板查询的过滤条件
+fn build_filter_clauses(params: &QueryParams) -> (Vec<&'static str>, Vec<Value>) {
+    let mut where_clauses = vec![];
+    let mut query_params: Vec<Value> = vec![];
+
+    if let Some(ref search) = params.search {
+        if !search.trim().is_empty() {
+            // 搜索同时匹配备注
+            where_clauses.push("(content LIKE ? OR note LIKE ?)");
+            query_params.push(Value::Text(format!("%{}%", search)));
+            query_params.push(Value::Text(format!("%{}%", search)));
+        }
+    }
+
+    if let Some(ref content_type) = params.content_type {
+        if content_type != "all" {
+            where_clauses.push("content_type LIKE ?");
+            query_params.push(Value::Text(format!("%{}%", content_type)));
+        }
+    }
+
+    if let Some(created_before) = params.created_before {
+        where_clauses.push("created_at < ?");
+        query_params.push(Value::Integer(created_before));
+    }
+
+    if params.exclude_local_only {
+        where_clauses.push("local_only = 0");
+    }
+
+    (where_clauses, query_params)
+}
+
 // 分页查询剪贴板历史
+// 无搜索时文本类内容返回预览列（完整内容通过 get_clipboard_item_by_id 加载），
+// 搜索时读取完整内容以便截取关键词附近的上下文
 pub fn query_clipboard_items(params: QueryParams) -> Result<PaginatedResult<ClipboardItem>, String> {
     let search_keyword = params.search.clone();
-    let has_filter = search_keyword.as_ref().map(|s| !s.trim().is_empty()).unwrap_or(false)
-        || params.content_type.as_ref().map(|t| t != "all").unwrap_or(false);
+    let (where_clauses, mut query_params) = build_filter_clauses(&params);
+    let is_searching = search_keyword.as_deref().map(|k| !k.trim().is_empty()).unwrap_or(false);
+    let preview_length = crate::services::get_settings().list_preview_length.max(1);
+    // 仅在搜索且显式请求时计算高亮区间，避免普通分页的额外开销
+    let highlight_keyword = if params.with_highlights && is_searching {
+        search_keyword.clone()
+    } else {
+        None
+    };
     
     with_connection(|conn| {
-        let mut where_clauses = vec![];
-        let mut query_params: Vec<Box<dyn rusqlite::ToSql>> = vec![];
-        
-        if let Some(ref search) = search_keyword {
-            if !search.trim().is_empty() {
-                where_clauses.push("content LIKE ?");
-                let search_pattern = format!("%{}%", search);
-                query_params.push(Box::new(search_pattern));
-            }
-        }
-        
-        if let Some(ref content_type) = params.content_type {
-            if content_type != "all" {
-                where_clauses.push("content_type LIKE ?");
-                let pattern = format!("%{}%", content_type);
-                query_params.push(Box::new(pattern));
-            }
-        }
-        
         let where_clause = if where_clauses.is_empty() {
             String::new()
         } else {
             format!("WHERE {}", where_clauses.join(" AND "))
         };
         
-        let total_count: i64 = if has_filter {
-            let count_sql = format!("SELECT COUNT(*) FROM clipboard {}", where_clause);
-            let count_params: Vec<Box<dyn rusqlite::ToSql>> = query_params.iter().map(|p| {
-                let val: Box<dyn rusqlite::ToSql> = match p.as_ref().to_sql() {
-                    Ok(rusqlite::types::ToSqlOutput::Borrowed(rusqlite::types::ValueRef::Text(s))) => {
-                        Box::new(String::from_utf8_lossy(s).to_string())
-                    }
-                    _ => Box::new("")
-                };
-                val
-            }).collect();
-            conn.query_row(
-                &count_sql,
-                rusqlite::params_from_iter(count_params.iter().map(|p| p.as_ref())),
-                |row| row.get(0)
-            )?
-        } else {
-            conn.query_row("SELECT COUNT(*) FROM clipboard", [], |row| row.get(0))?
-        };
+        // 总数与列表使用同一组过滤条件，供前端按窗口分页加载
+        let count_sql = format!("SELECT COUNT(*) FROM clipboard {}", where_clause);
+        let total_count: i64 = conn.query_row(
+            &count_sql,
+            rusqlite::params_from_iter(query_params.iter()),
+            |row| row.get(0)
+        )?;
         
         if total_count == 0 {
             return Ok(PaginatedResult::new(0, vec![], params.offset, params.limit));
         }
         
+        let content_column = if is_searching {
+            "content".to_string()
+        } else {
+            format!(
+                "COALESCE(preview, CASE WHEN content_type IN {} THEN substr(content, 1, {}) ELSE content END)",
+                PREVIEW_CONTENT_TYPES_SQL, preview_length
+            )
+        };
         let query_sql = format!(
-            "SELECT id, content, html_content, content_type, image_id, item_order, is_pinned, paste_count, source_app, source_icon_hash, created_at, updated_at, char_count 
+            "SELECT id, {}, html_content, content_type, image_id, item_order, is_pinned, paste_count, source_app, source_icon_hash, created_at, updated_at, char_count,
+                    preview IS NULL AND content_type IN {},
+                    CASE WHEN char_count IS NULL THEN length(content) END,
+                    note, forced_format, copy_count, local_only
              FROM clipboard 
              {} 
-             ORDER BY is_pinned DESC, item_order DESC, updated_at DESC 
+             ORDER BY is_pinned DESC, sort_order ASC, item_order DESC, updated_at DESC 
              LIMIT ? OFFSET ?",
-            where_clause
+            content_column, PREVIEW_CONTENT_TYPES_SQL, where_clause
         );
         
-        query_params.push(Box::new(params.limit));
-        query_params.push(Box::new(params.offset));
+        query_params.push(Value::Integer(params.limit));
+        query_params.push(Value::Integer(params.offset));
         
         let mut stmt = conn.prepare(&query_sql)?;
 
-        let mut items_to_update: Vec<(i64, String, String)> = vec![];
-        
         let items = stmt.query_map(
-            rusqlite::params_from_iter(query_params.iter().map(|p| p.as_ref())),
+            rusqlite::params_from_iter(query_params.iter()),
             |row| {
                 let id: i64 = row.get(0)?;
                 let content: String = row.get(1)?;
                 let html_content: Option<String> = row.get(2)?;
                 let content_type: String = row.get(3)?;
                 let char_count: Option<i64> = row.get(12)?;
+                let missing_preview: bool = row.get(13)?;
+                let full_length: Option<i64> = row.get(14)?;
                 
-                let (truncated_content, truncated_html) = if content_type == "text" || content_type == "rich_text" || content_type == "link" {
+                let (truncated_content, truncated_html) = if is_preview_type(&content_type) {
                     let truncated_content = if content.len() > MAX_CONTENT_LENGTH {
                         if let Some(ref keyword) = search_keyword {
                             if !keyword.trim().is_empty() {
-                                truncate_around_keyword(content.clone(), keyword, MAX_CONTENT_LENGTH)
+                                truncate_around_keyword(content, keyword, MAX_CONTENT_LENGTH)
                             } else {
-                                truncate_string(content.clone(), MAX_CONTENT_LENGTH)
+                                truncate_string(content, MAX_CONTENT_LENGTH)
                             }
                         } else {
-                            truncate_string(content.clone(), MAX_CONTENT_LENGTH)
+                            truncate_string(content, MAX_CONTENT_LENGTH)
                         }
                     } else {
-                        content.clone()
+                        content
                     };
                     
                     let truncated_html = html_content.map(|h| {
@@ -186,51 +234,173 @@ pub fn query_clipboard_items(params: QueryParams) -> Result<PaginatedResult<Clip
                     
                     (truncated_content, truncated_html)
                 } else {
-                    (content.clone(), html_content)
+                    (content, html_content)
                 };
 
-                let needs_char_count = content_type.contains("text") || content_type.contains("rich_text");
-                let final_char_count = if char_count.is_none() && needs_char_count && !content.is_empty() {
-                    Some(content.chars().count() as i64)
-                } else {
-                    char_count
-                };
+                let needs_char_count = char_count.is_none()
+                    && (content_type.contains("text") || content_type.contains("rich_text"))
+                    && full_length.unwrap_or(0) > 0;
+                let final_char_count = if needs_char_count { full_length } else { char_count };
+                let match_ranges = highlight_keyword
+                    .as_deref()
+                    .map(|keyword| find_match_ranges(&truncated_content, keyword));
                 
                 Ok((ClipboardItem {
                     id,
                     content: truncated_content,
                     html_content: truncated_html,
-                    content_type: content_type.clone(),
+                    rtf_content: None,
+                    content_type,
                     image_id: row.get(4)?,
                     item_order: row.get(5)?,
                     is_pinned: row.get::<_, i64>(6)? != 0,
                     paste_count: row.get(7)?,
+                    copy_count: row.get(17)?,
+                    local_only: row.get::<_, i64>(18)? != 0,
                     source_app: row.get(8)?,
                     source_icon_hash: row.get(9)?,
                     char_count: final_char_count,
+                    note: row.get(15)?,
+                    source_formats: None,
+                    original_content: None,
+                    forced_format: row.get(16)?,
+                    match_ranges,
                     created_at: row.get(10)?,
                     updated_at: row.get(11)?,
-                }, char_count.is_none() && needs_char_count, id, content, content_type))
+                }, needs_char_count, missing_preview))
             }
         )?
         .collect::<Result<Vec<_>, _>>()?;
         
         let mut result_items = vec![];
-        for (item, needs_update, id, content, content_type) in items {
-            if needs_update {
-                items_to_update.push((id, content, content_type));
+        let mut missing_char_counts: Vec<i64> = vec![];
+        let mut missing_previews: Vec<i64> = vec![];
+        for (item, needs_char_count, missing_preview) in items {
+            if needs_char_count {
+                missing_char_counts.push(item.id);
+            }
+            if missing_preview {
+                missing_previews.push(item.id);
             }
             result_items.push(item);
         }
 
-        if !items_to_update.is_empty() {
-            update_missing_char_counts(items_to_update);
-        }
+        update_missing_char_counts(missing_char_counts);
+        update_missing_previews(missing_previews, preview_length);
         
         Ok(PaginatedResult::new(total_count, result_items, params.offset, params.limit))
     })
 }
 
+// 流式查询单批最大条数
+pub const MAX_STREAM_BATCH_SIZE: usize = 1000;
+
+// 按游标查询一批剪贴板项（按 id 倒序，cursor 为上一批最后一项的 id）
+// 使用 id 作为游标而非 offset，遍历期间新增或删除记录不会导致重复或遗漏
+// 忽略 params 中的 offset/limit，返回完整内容供导出、正则扫描等场景使用
+pub fn query_clipboard_items_after(
+    params: &QueryParams,
+    cursor: Option<i64>,
+    batch_size: usize,
+) -> Result<ClipboardBatch, String> {
+    let batch_size = batch_size.clamp(1, MAX_STREAM_BATCH_SIZE);
+    let (mut where_clauses, mut query_params) = build_filter_clauses(params);
+    if let Some(cursor) = cursor {
+        where_clauses.push("id < ?");
+        query_params.push(Value::Integer(cursor));
+    }
+
+    with_connection(|conn| {
+        let where_clause = if where_clauses.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", where_clauses.join(" AND "))
+        };
+        let query_sql = format!(
+            "SELECT id, content, html_content, content_type, image_id, item_order, is_pinned, paste_count, source_app, source_icon_hash, created_at, updated_at, char_count, rtf_content, note, copy_count, local_only 
+             FROM clipboard 
+             {} 
+             ORDER BY id DESC 
+             LIMIT ?",
+            where_clause
+        );
+        // 多取一条用于判断是否还有下一批
+        query_params.push(Value::Integer(batch_size as i64 + 1));
+
+        let mut stmt = conn.prepare(&query_sql)?;
+        let mut items = stmt.query_map(
+            rusqlite::params_from_iter(query_params.iter()),
+            |row| {
+                Ok(ClipboardItem {
+                    id: row.get(0)?,
+                    content: row.get(1)?,
+                    html_content: row.get(2)?,
+                    rtf_content: row.get(13)?,
+                    content_type: row.get(3)?,
+                    image_id: row.get(4)?,
+                    item_order: row.get(5)?,
+                    is_pinned: row.get::<_, i64>(6)? != 0,
+                    paste_count: row.get(7)?,
+                    copy_count: row.get(15)?,
+                    local_only: row.get::<_, i64>(16)? != 0,
+                    source_app: row.get(8)?,
+                    source_icon_hash: row.get(9)?,
+                    char_count: row.get(12)?,
+                    note: row.get(14)?,
+                    source_formats: None,
+                    original_content: None,
+                    forced_format: None,
+                    match_ranges: None,
+                    created_at: row.get(10)?,
+                    updated_at: row.get(11)?,
+                })
+            }
+        )?
+        .collect::<Result<Vec<_>, _>>()?;
+
+        let next_cursor = if items.len() > batch_size {
+            items.truncate(batch_size);
+            items.last().map(|item| item.id)
+        } else {
+            None
+        };
+
+        Ok(ClipboardBatch { items, next_cursor })
+    })
+}
+
+// 流式遍历所有匹配项，每批回调一次；回调返回 false 时提前结束
+// 每批单独获取数据库连接，批次之间不长时间占用数据库锁
+// 返回已回调的总条数
+pub fn query_clipboard_items_stream<F>(
+    params: QueryParams,
+    batch_size: usize,
+    mut on_batch: F,
+) -> Result<u64, String>
+where
+    F: FnMut(Vec<ClipboardItem>) -> bool,
+{
+    let mut cursor = None;
+    let mut total = 0u64;
+
+    loop {
+        let batch = query_clipboard_items_after(&params, cursor, batch_size)?;
+        if batch.items.is_empty() {
+            break;
+        }
+        total += batch.items.len() as u64;
+        if !on_batch(batch.items) {
+            break;
+        }
+        match batch.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+
+    Ok(total)
+}
+
 // 获取剪贴板总数
 pub fn get_clipboard_count() -> Result<i64, String> {
     with_connection(|conn| {
@@ -238,6 +408,129 @@ pub fn get_clipboard_count() -> Result<i64, String> {
     })
 }
 
+// 可统计的内容类型（与 QueryParams.content_type 的筛选值一致）
+const COUNTABLE_CONTENT_TYPES: [&str; 4] = ["text", "image", "file", "link"];
+
+// 按内容类型统计数量（遵循搜索条件），单次查询完成；"all" 为总数
+pub fn count_items_by_type(search: Option<String>) -> Result<HashMap<String, u64>, String> {
+    let (where_clauses, mut query_params) = build_filter_clauses(&QueryParams {
+        offset: 0,
+        limit: 0,
+        search,
+        content_type: None,
+        created_before: None,
+        with_highlights: false,
+        exclude_local_only: false,
+    });
+    let where_clause = if where_clauses.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", where_clauses.join(" AND "))
+    };
+
+    // 与筛选条件相同使用 LIKE 匹配，多类型项会同时计入各自类型
+    let type_columns: Vec<&str> = COUNTABLE_CONTENT_TYPES
+        .iter()
+        .map(|_| "COALESCE(SUM(content_type LIKE ?), 0)")
+        .collect();
+    let sql = format!(
+        "SELECT COUNT(*), {} FROM clipboard {}",
+        type_columns.join(", "),
+        where_clause
+    );
+
+    let mut sql_params: Vec<Value> = COUNTABLE_CONTENT_TYPES
+        .iter()
+        .map(|t| Value::Text(format!("%{}%", t)))
+        .collect();
+    sql_params.append(&mut query_params);
+
+    with_connection(|conn| {
+        conn.query_row(&sql, rusqlite::params_from_iter(sql_params.iter()), |row| {
+            let mut counts = HashMap::new();
+            counts.insert("all".to_string(), row.get::<_, i64>(0)? as u64);
+            for (i, content_type) in COUNTABLE_CONTENT_TYPES.iter().enumerate() {
+                counts.insert(content_type.to_string(), row.get::<_, i64>(i + 1)? as u64);
+            }
+            Ok(counts)
+        })
+    })
+}
+
+// 最常粘贴项的预览长度
+const STATS_PREVIEW_LENGTH: usize = 100;
+
+// 汇总剪贴板历史统计
+pub fn get_history_stats() -> Result<HistoryStats, String> {
+    use chrono::{Datelike, Local, TimeZone};
+
+    let type_counts = count_items_by_type(None)?;
+    let total_count = type_counts.get("all").copied().unwrap_or(0);
+
+    // 今天零点与本周一零点（本地时间）
+    let today = Local::now().date_naive();
+    let start_of = |date: chrono::NaiveDate| {
+        date.and_hms_opt(0, 0, 0)
+            .and_then(|dt| Local.from_local_datetime(&dt).earliest())
+            .map(|dt| dt.timestamp())
+            .unwrap_or(0)
+    };
+    let today_start = start_of(today);
+    let week_start = start_of(today - chrono::Duration::days(today.weekday().num_days_from_monday() as i64));
+
+    with_connection(|conn| {
+        let page_count: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+        let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+
+        let (oldest_created_at, newest_created_at, copies_today, copies_this_week) = conn.query_row(
+            "SELECT MIN(created_at), MAX(created_at),
+                    COALESCE(SUM(created_at >= ?1), 0), COALESCE(SUM(created_at >= ?2), 0)
+             FROM clipboard",
+            params![today_start, week_start],
+            |row| Ok((
+                row.get::<_, Option<i64>>(0)?,
+                row.get::<_, Option<i64>>(1)?,
+                row.get::<_, i64>(2)? as u64,
+                row.get::<_, i64>(3)? as u64,
+            )),
+        )?;
+
+        let most_pasted = conn.query_row(
+            "SELECT id, content_type, content, paste_count FROM clipboard
+             WHERE paste_count > 0
+             ORDER BY paste_count DESC, id DESC
+             LIMIT 1",
+            [],
+            |row| {
+                let content_type: String = row.get(1)?;
+                let content: String = row.get(2)?;
+                Ok(MostPastedItem {
+                    id: row.get(0)?,
+                    preview: make_preview(&content, &content_type, STATS_PREVIEW_LENGTH),
+                    content_type,
+                    paste_count: row.get(3)?,
+                })
+            },
+        ).optional()?;
+
+        Ok(HistoryStats {
+            total_count,
+            type_counts,
+            db_size_bytes: (page_count * page_size).max(0) as u64,
+            oldest_created_at,
+            newest_created_at,
+            copies_today,
+            copies_this_week,
+            most_pasted,
+        })
+    })
+}
+
+// 解析存储的格式列表（JSON 数组），旧数据或解析失败时为 None
+fn parse_source_formats(raw: Option<String>) -> Option<Vec<String>> {
+    raw.and_then(|json| serde_json::from_str(&json).ok())
+}
+
 // 根据ID获取剪贴板项（完整内容，不截断）
 pub fn get_clipboard_item_by_id(id: i64) -> Result<Option<ClipboardItem>, String> {
     get_clipboard_item_by_id_with_limit(id, None)
@@ -247,7 +540,7 @@ pub fn get_clipboard_item_by_id(id: i64) -> Result<Option<ClipboardItem>, String
 pub fn get_clipboard_item_by_id_with_limit(id: i64, max_content_length: Option<usize>) -> Result<Option<ClipboardItem>, String> {
     with_connection(|conn| {
         conn.query_row(
-            "SELECT id, content, html_content, content_type, image_id, item_order, is_pinned, paste_count, source_app, source_icon_hash, created_at, updated_at, char_count 
+            "SELECT id, content, html_content, content_type, image_id, item_order, is_pinned, paste_count, source_app, source_icon_hash, created_at, updated_at, char_count, rtf_content, note, source_formats, original_content, forced_format, copy_count, local_only 
              FROM clipboard WHERE id = ?",
             params![id],
             |row| {
@@ -277,14 +570,22 @@ pub fn get_clipboard_item_by_id_with_limit(id: i64, max_content_length: Option<u
                     id: row.get(0)?,
                     content: final_content,
                     html_content,
+                    rtf_content: row.get(13)?,
                     content_type,
                     image_id: row.get(4)?,
                     item_order: row.get(5)?,
                     is_pinned: row.get::<_, i64>(6)? != 0,
                     paste_count: row.get(7)?,
+                    copy_count: row.get(18)?,
+                    local_only: row.get::<_, i64>(19)? != 0,
                     source_app: row.get(8)?,
                     source_icon_hash: row.get(9)?,
                     char_count: final_char_count,
+                    note: row.get(14)?,
+                    source_formats: parse_source_formats(row.get(15)?),
+                    original_content: row.get(16)?,
+                    forced_format: row.get(17)?,
+                    match_ranges: None,
                     created_at: row.get(10)?,
                     updated_at: row.get(11)?,
                 })
@@ -295,6 +596,51 @@ pub fn get_clipboard_item_by_id_with_limit(id: i64, max_content_length: Option<u
     })
 }
 
+// 设置剪贴板项备注（空白备注视为清除）
+pub fn set_item_note(id: i64, note: Option<String>) -> Result<(), String> {
+    let note = note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+    with_connection(|conn| {
+        let rows = conn.execute(
+            "UPDATE clipboard SET note = ?1 WHERE id = ?2",
+            params![note, id],
+        )?;
+        if rows == 0 { Err(rusqlite::Error::QueryReturnedNoRows) } else { Ok(()) }
+    }).map_err(|e| if e.contains("QueryReturnedNoRows") {
+        format!("剪贴板项不存在: {}", id)
+    } else { e })
+}
+
+// 设置剪贴板项的强制粘贴格式（None 表示清除）
+pub fn set_item_forced_format(id: i64, format: Option<&str>) -> Result<(), String> {
+    if let Some(key) = format {
+        if crate::services::paste::PasteFormat::from_key(key).is_none() {
+            return Err(format!("不支持的粘贴格式: {}", key));
+        }
+    }
+    with_connection(|conn| {
+        let rows = conn.execute(
+            "UPDATE clipboard SET forced_format = ?1 WHERE id = ?2",
+            params![format, id],
+        )?;
+        if rows == 0 { Err(rusqlite::Error::QueryReturnedNoRows) } else { Ok(()) }
+    }).map_err(|e| if e.contains("QueryReturnedNoRows") {
+        format!("剪贴板项不存在: {}", id)
+    } else { e })
+}
+
+// 设置剪贴板项是否仅本机（导出与同步时排除）
+pub fn set_item_local_only(id: i64, local_only: bool) -> Result<(), String> {
+    with_connection(|conn| {
+        let rows = conn.execute(
+            "UPDATE clipboard SET local_only = ?1 WHERE id = ?2",
+            params![local_only, id],
+        )?;
+        if rows == 0 { Err(rusqlite::Error::QueryReturnedNoRows) } else { Ok(()) }
+    }).map_err(|e| if e.contains("QueryReturnedNoRows") {
+        format!("剪贴板项不存在: {}", id)
+    } else { e })
+}
+
 pub fn increment_paste_count(id: i64) -> Result<(), String> {
     with_connection(|conn| {
         conn.execute(
@@ -305,13 +651,27 @@ pub fn increment_paste_count(id: i64) -> Result<(), String> {
     })
 }
 
-// 限制剪贴板历史数量（删除超出限制的旧记录）
-pub fn limit_clipboard_history(max_count: u64) -> Result<(), String> {
+// 超出数量限制的记录 ID（置顶项优先保留，其余按排序保留最新的 max_count 条）
+fn excess_item_ids(conn: &rusqlite::Connection, max_count: u64) -> Result<Vec<i64>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT id FROM clipboard WHERE id NOT IN (SELECT id FROM clipboard ORDER BY is_pinned DESC, item_order DESC, updated_at DESC LIMIT ?1) ORDER BY id",
+    )?;
+    let ids = stmt.query_map(params![max_count], |row| row.get(0))?.collect();
+    ids
+}
+
+// 限制剪贴板历史数量（删除超出限制的旧记录），返回被删除的记录 ID
+pub fn limit_clipboard_history(max_count: u64) -> Result<Vec<i64>, String> {
     if max_count >= 999999 {
-        return Ok(());
+        return Ok(Vec::new());
     }
     
-    let images_to_delete: Vec<String> = with_connection(|conn| {
+    let (removed_ids, images_to_delete) = with_connection(|conn| {
+        let removed_ids = excess_item_ids(conn, max_count)?;
+        if removed_ids.is_empty() {
+            return Ok((removed_ids, Vec::new()));
+        }
+
         let sql_ids = "SELECT image_id FROM clipboard WHERE id NOT IN (SELECT id FROM clipboard ORDER BY is_pinned DESC, item_order DESC, updated_at DESC LIMIT ?1) AND image_id IS NOT NULL AND image_id <> ''";
         let mut stmt = conn.prepare(sql_ids)?;
         let ids_iter = stmt.query_map(params![max_count], |row| row.get::<_, String>(0))?;
@@ -338,10 +698,11 @@ pub fn limit_clipboard_history(max_count: u64) -> Result<(), String> {
                 to_delete.push(iid);
             }
         }
-        Ok(to_delete)
+        Ok((removed_ids, to_delete))
     })?;
 
-    delete_image_files(images_to_delete)
+    delete_image_files(images_to_delete)?;
+    Ok(removed_ids)
 }
 
 // 删除单个剪贴板项
@@ -372,6 +733,50 @@ pub fn delete_clipboard_item(id: i64) -> Result<(), String> {
     delete_image_files(images_to_delete)
 }
 
+// 按过滤条件批量删除剪贴板项（不删除置顶项），返回删除数量
+pub fn delete_items(filter: QueryParams) -> Result<u64, String> {
+    let (mut where_clauses, query_params) = build_filter_clauses(&filter);
+    where_clauses.push("is_pinned = 0");
+    let where_clause = format!("WHERE {}", where_clauses.join(" AND "));
+
+    let (deleted, images_to_delete): (u64, Vec<String>) = with_connection(|conn| {
+        let sql_ids = format!(
+            "SELECT image_id FROM clipboard {} AND image_id IS NOT NULL AND image_id <> ''",
+            where_clause
+        );
+        let mut stmt = conn.prepare(&sql_ids)?;
+        let ids_iter = stmt.query_map(
+            rusqlite::params_from_iter(query_params.iter()),
+            |row| row.get::<_, String>(0),
+        )?;
+        let mut set: HashSet<String> = HashSet::new();
+        for r in ids_iter {
+            if let Ok(s) = r {
+                for iid in split_image_ids(&s) {
+                    set.insert(iid);
+                }
+            }
+        }
+        drop(stmt);
+
+        let deleted = conn.execute(
+            &format!("DELETE FROM clipboard {}", where_clause),
+            rusqlite::params_from_iter(query_params.iter()),
+        )?;
+
+        let mut to_delete = Vec::new();
+        for iid in set.into_iter() {
+            if !is_image_id_referenced(conn, &iid)? {
+                to_delete.push(iid);
+            }
+        }
+        Ok((deleted as u64, to_delete))
+    })?;
+
+    delete_image_files(images_to_delete)?;
+    Ok(deleted)
+}
+
 // 清空所有剪贴板历史
 pub fn clear_clipboard_history() -> Result<(), String> {
     let images_to_delete: Vec<String> = with_connection(|conn| {
@@ -460,6 +865,17 @@ pub fn move_clipboard_item_by_id(from_id: i64, to_id: i64) -> Result<(), String>
         if from_pinned != to_pinned {
             return Ok(());
         }
+        // 置顶项按手动顺序移动
+        if from_pinned == 1 {
+            let mut ids = pinned_ids_in_order(conn)?;
+            let from_idx = ids.iter().position(|id| *id == from_id)
+                .ok_or_else(|| rusqlite::Error::InvalidParameterName(format!("ID {} 不存在", from_id)))?;
+            let to_idx = ids.iter().position(|id| *id == to_id)
+                .ok_or_else(|| rusqlite::Error::InvalidParameterName(format!("ID {} 不存在", to_id)))?;
+            let moved = ids.remove(from_idx);
+            ids.insert(to_idx, moved);
+            return write_pinned_order(conn, &ids);
+        }
         
         let items: Vec<(i64, i64)> = conn.prepare("SELECT id, item_order FROM clipboard ORDER BY is_pinned DESC, item_order DESC, updated_at DESC")?
             .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
@@ -478,7 +894,7 @@ pub fn move_clipboard_item_by_id(from_id: i64, to_id: i64) -> Result<(), String>
 pub fn update_clipboard_item(id: i64, content: String) -> Result<(), String> {
     with_connection(|conn| {
         let rows = conn.execute(
-            "UPDATE clipboard SET content = ?1, updated_at = ?2 WHERE id = ?3",
+            "UPDATE clipboard SET content = ?1, preview = NULL, char_count = NULL, updated_at = ?2 WHERE id = ?3",
             params![content, chrono::Local::now().timestamp(), id],
         )?;
         if rows == 0 { Err(rusqlite::Error::QueryReturnedNoRows) } else { Ok(()) }
@@ -487,6 +903,42 @@ pub fn update_clipboard_item(id: i64, content: String) -> Result<(), String> {
     } else { e })
 }
 
+// 编辑文本项内容：重新生成预览、字符数与链接类型标记，并清除已失效的 RTF/HTML 格式与转换前的原始内容。
+// 富文本、图片与文件项不支持编辑
+pub fn update_item_text(id: i64, new_text: &str) -> Result<(), String> {
+    if new_text.is_empty() {
+        return Err("内容不能为空".to_string());
+    }
+    let item = get_clipboard_item_by_id(id)?
+        .ok_or_else(|| format!("剪贴板项不存在: {}", id))?;
+    let primary_type = item.content_type.split(',').next().unwrap_or(&item.content_type);
+    match primary_type {
+        "text" | "link" => {}
+        "rich_text" => return Err("富文本项不支持编辑".to_string()),
+        "image" | "file" => return Err("图片和文件项不支持编辑".to_string()),
+        other => return Err(format!("不支持编辑的内容类型: {}", other)),
+    }
+
+    // 旧数据中以 link 为主类型的项按文本项重新标记，链接被删除后不再保留 link 类型
+    let base_type = if primary_type == "link" {
+        item.content_type.replacen("link", "text", 1)
+    } else {
+        item.content_type.clone()
+    };
+    let content_type = crate::services::clipboard::retag_text_links(&base_type, new_text);
+    let preview = make_preview(new_text, &content_type, crate::services::get_settings().list_preview_length);
+    let char_count = new_text.chars().count() as i64;
+
+    with_connection(|conn| {
+        conn.execute(
+            "UPDATE clipboard SET content = ?1, preview = ?2, char_count = ?3, content_type = ?4, 
+             html_content = NULL, rtf_content = NULL, original_content = NULL, updated_at = ?5 WHERE id = ?6",
+            params![new_text, preview, char_count, content_type, chrono::Local::now().timestamp(), id],
+        )?;
+        Ok(())
+    })
+}
+
 // 切换剪贴板项的置顶状态（置顶时放到置顶区第一位，取消置顶时移到非置顶区第一位）
 pub fn toggle_pin_clipboard_item(id: i64) -> Result<bool, String> {
     with_connection(|conn| {
@@ -499,15 +951,162 @@ pub fn toggle_pin_clipboard_item(id: i64) -> Result<bool, String> {
             let max_pinned_order: i64 = conn.query_row(
                 "SELECT COALESCE(MAX(item_order), 0) FROM clipboard WHERE is_pinned = 1", [], |row| row.get(0)
             ).unwrap_or(0);
-            conn.execute("UPDATE clipboard SET is_pinned = 1, item_order = ?1, updated_at = ?2 WHERE id = ?3", params![max_pinned_order + 1, now, id])?;
+            conn.execute(
+                "UPDATE clipboard SET is_pinned = 1, item_order = ?1, updated_at = ?2, session_pin_order = NULL, sort_order = ?3 WHERE id = ?4",
+                params![max_pinned_order + 1, now, top_pinned_sort_order(conn)?, id],
+            )?;
             Ok(true)
         } else {
             let max_order: i64 = conn.query_row(
                 "SELECT COALESCE(MAX(item_order), 0) FROM clipboard WHERE is_pinned = 0", [], |row| row.get(0)
             ).unwrap_or(0);
-            conn.execute("UPDATE clipboard SET is_pinned = 0, item_order = ?1, updated_at = ?2 WHERE id = ?3", params![max_order + 1, now, id])?;
+            conn.execute("UPDATE clipboard SET is_pinned = 0, item_order = ?1, updated_at = ?2, session_pin_order = NULL, sort_order = NULL WHERE id = ?3", params![max_order + 1, now, id])?;
             Ok(false)
         }
     })
 }
 
+// 冻结最新一条未置顶项：本次会话内置顶，避免后续复制将其挤出历史；
+// 重启后自动还原到原位置。返回被冻结项的 ID（无可冻结项时返回 None）
+pub fn freeze_top_item() -> Result<Option<i64>, String> {
+    with_connection(|conn| {
+        let top: Option<(i64, i64)> = conn.query_row(
+            "SELECT id, item_order FROM clipboard WHERE is_pinned = 0 ORDER BY item_order DESC, updated_at DESC LIMIT 1",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        ).optional()?;
+        let Some((id, item_order)) = top else {
+            return Ok(None);
+        };
+
+        let max_pinned_order: i64 = conn.query_row(
+            "SELECT COALESCE(MAX(item_order), 0) FROM clipboard WHERE is_pinned = 1", [], |row| row.get(0)
+        ).unwrap_or(0);
+        conn.execute(
+            "UPDATE clipboard SET is_pinned = 1, item_order = ?1, session_pin_order = ?2, sort_order = ?3 WHERE id = ?4",
+            params![max_pinned_order + 1, item_order, top_pinned_sort_order(conn)?, id],
+        )?;
+        Ok(Some(id))
+    })
+}
+
+// 解除全部会话冻结，还原到冻结前的位置
+pub fn unfreeze_session_items() -> Result<usize, String> {
+    with_connection(|conn| {
+        let count = conn.execute(
+            "UPDATE clipboard SET is_pinned = 0, item_order = session_pin_order, session_pin_order = NULL, sort_order = NULL
+             WHERE session_pin_order IS NOT NULL",
+            [],
+        )?;
+        Ok(count)
+    })
+}
+
+// 置顶项手动排序值的间隔：插入到顶部时无需重排其余项
+pub const PINNED_SORT_GAP: i64 = 1024;
+
+// 合并手动顺序：ordered_ids 中的项依次排在最前，其余置顶项保持原有相对顺序排在其后
+// （只传入部分 ID 时不会打乱其他项）
+fn merge_pinned_order(current: &[i64], ordered_ids: &[i64]) -> Result<Vec<i64>, String> {
+    let mut seen = HashSet::new();
+    for id in ordered_ids {
+        if !current.contains(id) {
+            return Err(format!("剪贴板项未置顶或不存在: {}", id));
+        }
+        if !seen.insert(*id) {
+            return Err(format!("排序中存在重复项: {}", id));
+        }
+    }
+    let rest = current.iter().filter(|id| !seen.contains(id));
+    Ok(ordered_ids.iter().chain(rest).copied().collect())
+}
+
+// 当前置顶项的 ID（按手动顺序）
+fn pinned_ids_in_order(conn: &rusqlite::Connection) -> Result<Vec<i64>, rusqlite::Error> {
+    conn.prepare("SELECT id FROM clipboard WHERE is_pinned = 1 ORDER BY sort_order ASC, item_order DESC, updated_at DESC")?
+        .query_map([], |row| row.get(0))?
+        .collect()
+}
+
+// 按给定顺序重写置顶项排序值（单个事务内完成，排序值之间保留间隔）
+fn write_pinned_order(conn: &rusqlite::Connection, ids: &[i64]) -> Result<(), rusqlite::Error> {
+    let tx = conn.unchecked_transaction()?;
+    for (i, id) in ids.iter().enumerate() {
+        tx.execute(
+            "UPDATE clipboard SET sort_order = ?1 WHERE id = ?2",
+            params![(i as i64 + 1) * PINNED_SORT_GAP, id],
+        )?;
+    }
+    tx.commit()
+}
+
+// 新置顶项的排序值：排在现有置顶项之前
+fn top_pinned_sort_order(conn: &rusqlite::Connection) -> Result<i64, rusqlite::Error> {
+    let min: Option<i64> = conn.query_row(
+        "SELECT MIN(sort_order) FROM clipboard WHERE is_pinned = 1", [], |row| row.get(0)
+    )?;
+    Ok(min.map_or(PINNED_SORT_GAP, |min| min - PINNED_SORT_GAP))
+}
+
+// 按手动顺序重排置顶项，未列出的置顶项保持相对顺序排在最后
+pub fn reorder_pinned(ordered_ids: &[i64]) -> Result<(), String> {
+    with_connection(|conn| {
+        let current = pinned_ids_in_order(conn)?;
+        let merged = merge_pinned_order(&current, ordered_ids)
+            .map_err(rusqlite::Error::InvalidParameterName)?;
+        write_pinned_order(conn, &merged)
+    })
+}
+
+// 置顶项的 ID（按手动顺序，与列表查询中置顶区的顺序一致）
+pub fn get_pinned_order() -> Result<Vec<i64>, String> {
+    with_connection(|conn| pinned_ids_in_order(conn))
+}
+
+// 按手动顺序查询全部置顶项
+pub fn query_pinned_items() -> Result<Vec<ClipboardItem>, String> {
+    let ids = get_pinned_order()?;
+    let mut items = Vec::with_capacity(ids.len());
+    for id in ids {
+        if let Some(item) = get_clipboard_item_by_id(id)? {
+            items.push(item);
+        }
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_pinned_order_keeps_unlisted_items_stable() {
+        let current = [5, 3, 9, 1];
+        assert_eq!(merge_pinned_order(&current, &[1, 5, 3, 9]).unwrap(), vec![1, 5, 3, 9]);
+        // 只传入部分 ID：其余项按原顺序排在后面
+        assert_eq!(merge_pinned_order(&current, &[9]).unwrap(), vec![9, 5, 3, 1]);
+        assert_eq!(merge_pinned_order(&current, &[]).unwrap(), vec![5, 3, 9, 1]);
+    }
+
+    #[test]
+    fn test_merge_pinned_order_rejects_invalid_ids() {
+        assert!(merge_pinned_order(&[5, 3], &[7]).is_err());
+        assert!(merge_pinned_order(&[5, 3], &[3, 3]).is_err());
+    }
+
+    #[test]
+    fn test_excess_item_ids_keeps_pinned_and_newest() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE clipboard (id INTEGER PRIMARY KEY, is_pinned INTEGER NOT NULL DEFAULT 0, item_order INTEGER NOT NULL, updated_at INTEGER NOT NULL);
+             INSERT INTO clipboard (id, is_pinned, item_order, updated_at) VALUES
+                (1, 1, 1, 1),
+                (2, 0, 2, 2),
+                (3, 0, 3, 3),
+                (4, 0, 4, 4);",
+        )
+        .unwrap();
+        assert_eq!(excess_item_ids(&conn, 2).unwrap(), vec![2, 3]);
+        assert!(excess_item_ids(&conn, 4).unwrap().is_empty());
+    }
+}
@@ -191,6 +191,156 @@ fn create_tables(conn: &Connection) -> Result<(), String> {
             .map_err(|e| format!("添加剪贴板字符数量字段失败: {}", e))?;
     }
 
+    let rtf_content_exists = conn
+        .prepare("PRAGMA table_info(clipboard)")
+        .and_then(|mut stmt| {
+            let columns = stmt.query_map([], |row| Ok(row.get::<_, String>(1)?))?
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(columns.iter().any(|c| c == "rtf_content"))
+        })
+        .unwrap_or(false);
+    
+    if !rtf_content_exists {
+        conn.execute("ALTER TABLE clipboard ADD COLUMN rtf_content TEXT", [])
+            .map_err(|e| format!("添加RTF内容字段失败: {}", e))?;
+    }
+
+    let preview_exists = conn
+        .prepare("PRAGMA table_info(clipboard)")
+        .and_then(|mut stmt| {
+            let columns = stmt.query_map([], |row| Ok(row.get::<_, String>(1)?))?
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(columns.iter().any(|c| c == "preview"))
+        })
+        .unwrap_or(false);
+    
+    // 旧数据的预览在列表查询时按需补全
+    if !preview_exists {
+        conn.execute("ALTER TABLE clipboard ADD COLUMN preview TEXT", [])
+            .map_err(|e| format!("添加预览字段失败: {}", e))?;
+    }
+
+    let note_exists = conn
+        .prepare("PRAGMA table_info(clipboard)")
+        .and_then(|mut stmt| {
+            let columns = stmt.query_map([], |row| Ok(row.get::<_, String>(1)?))?
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(columns.iter().any(|c| c == "note"))
+        })
+        .unwrap_or(false);
+    
+    if !note_exists {
+        conn.execute("ALTER TABLE clipboard ADD COLUMN note TEXT", [])
+            .map_err(|e| format!("添加备注字段失败: {}", e))?;
+    }
+
+    let source_formats_exists = conn
+        .prepare("PRAGMA table_info(clipboard)")
+        .and_then(|mut stmt| {
+            let columns = stmt.query_map([], |row| Ok(row.get::<_, String>(1)?))?
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(columns.iter().any(|c| c == "source_formats"))
+        })
+        .unwrap_or(false);
+    
+    // 捕获时剪贴板提供的格式列表（JSON 数组）
+    if !source_formats_exists {
+        conn.execute("ALTER TABLE clipboard ADD COLUMN source_formats TEXT", [])
+            .map_err(|e| format!("添加来源格式字段失败: {}", e))?;
+    }
+
+    let original_content_exists = conn
+        .prepare("PRAGMA table_info(clipboard)")
+        .and_then(|mut stmt| {
+            let columns = stmt.query_map([], |row| Ok(row.get::<_, String>(1)?))?
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(columns.iter().any(|c| c == "original_content"))
+        })
+        .unwrap_or(false);
+    
+    // 存储时转换前的原始文本（开启保留原文时记录）
+    if !original_content_exists {
+        conn.execute("ALTER TABLE clipboard ADD COLUMN original_content TEXT", [])
+            .map_err(|e| format!("添加原始内容字段失败: {}", e))?;
+    }
+
+    let session_pin_exists = conn
+        .prepare("PRAGMA table_info(clipboard)")
+        .and_then(|mut stmt| {
+            let columns = stmt.query_map([], |row| Ok(row.get::<_, String>(1)?))?
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(columns.iter().any(|c| c == "session_pin_order"))
+        })
+        .unwrap_or(false);
+    
+    // 临时冻结（会话置顶）前的排序值，非空表示该项为本次会话冻结
+    if !session_pin_exists {
+        conn.execute("ALTER TABLE clipboard ADD COLUMN session_pin_order INTEGER", [])
+            .map_err(|e| format!("添加会话置顶字段失败: {}", e))?;
+    }
+    let sort_order_exists = conn
+        .prepare("PRAGMA table_info(clipboard)")
+        .and_then(|mut stmt| {
+            let columns = stmt.query_map([], |row| Ok(row.get::<_, String>(1)?))?
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(columns.iter().any(|c| c == "sort_order"))
+        })
+        .unwrap_or(false);
+    
+    // 置顶项的手动排序值（升序），非置顶项为空
+    if !sort_order_exists {
+        conn.execute("ALTER TABLE clipboard ADD COLUMN sort_order INTEGER", [])
+            .map_err(|e| format!("添加置顶排序字段失败: {}", e))?;
+    }
+    let forced_format_exists = conn
+        .prepare("PRAGMA table_info(clipboard)")
+        .and_then(|mut stmt| {
+            let columns = stmt.query_map([], |row| Ok(row.get::<_, String>(1)?))?
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(columns.iter().any(|c| c == "forced_format"))
+        })
+        .unwrap_or(false);
+    
+    // 强制粘贴格式（格式名），为空时按常规规则确定格式
+    if !forced_format_exists {
+        conn.execute("ALTER TABLE clipboard ADD COLUMN forced_format TEXT", [])
+            .map_err(|e| format!("添加强制粘贴格式字段失败: {}", e))?;
+    }
+    let copy_count_exists = conn
+        .prepare("PRAGMA table_info(clipboard)")
+        .and_then(|mut stmt| {
+            let columns = stmt.query_map([], |row| Ok(row.get::<_, String>(1)?))?
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(columns.iter().any(|c| c == "copy_count"))
+        })
+        .unwrap_or(false);
+    
+    // 复制次数：重复复制相同内容（去重合并）时累加，与粘贴次数分开统计
+    if !copy_count_exists {
+        conn.execute("ALTER TABLE clipboard ADD COLUMN copy_count INTEGER NOT NULL DEFAULT 1", [])
+            .map_err(|e| format!("添加复制次数字段失败: {}", e))?;
+    }
+    let local_only_exists = conn
+        .prepare("PRAGMA table_info(clipboard)")
+        .and_then(|mut stmt| {
+            let columns = stmt.query_map([], |row| Ok(row.get::<_, String>(1)?))?
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(columns.iter().any(|c| c == "local_only"))
+        })
+        .unwrap_or(false);
+    
+    // 仅本机：导出与同步时排除
+    if !local_only_exists {
+        conn.execute("ALTER TABLE clipboard ADD COLUMN local_only INTEGER NOT NULL DEFAULT 0", [])
+            .map_err(|e| format!("添加仅本机字段失败: {}", e))?;
+    }
+    // 会话置顶仅在本次运行期间有效，启动时还原
+    conn.execute(
+        "UPDATE clipboard SET is_pinned = 0, item_order = session_pin_order, session_pin_order = NULL, sort_order = NULL
+         WHERE session_pin_order IS NOT NULL",
+        [],
+    ).map_err(|e| format!("还原会话置顶项失败: {}", e))?;
+
     let fav_char_count_exists = conn
         .prepare("PRAGMA table_info(favorites)")
         .and_then(|mut stmt| {
@@ -205,8 +355,11 @@ fn create_tables(conn: &Connection) -> Result<(), String> {
             .map_err(|e| format!("添加收藏字符数量字段失败: {}", e))?;
     }
 
+    // 列表排序加入置顶项的手动排序值，旧索引不含 sort_order，替换为新索引
+    conn.execute("DROP INDEX IF EXISTS idx_clipboard_order", [])
+        .map_err(|e| format!("删除旧剪贴板排序索引失败: {}", e))?;
     conn.execute(
-        "CREATE INDEX IF NOT EXISTS idx_clipboard_order ON clipboard(is_pinned DESC, item_order DESC, updated_at DESC)",
+        "CREATE INDEX IF NOT EXISTS idx_clipboard_list_order ON clipboard(is_pinned DESC, sort_order ASC, item_order DESC, updated_at DESC)",
         [],
     ).map_err(|e| format!("创建剪贴板排序索引失败: {}", e))?;
     
@@ -220,6 +373,7 @@ fn create_tables(conn: &Connection) -> Result<(), String> {
         [],
     ).map_err(|e| format!("创建收藏索引失败: {}", e))?;
     migrate_favorites_auto_titles(conn);
+    migrate_pinned_sort_order(conn);
 
     Ok(())
 }
@@ -289,6 +443,30 @@ where
 }
 
 
+// 为尚无手动排序值的置顶项补齐排序值（按原有顺序排在已排序项之后）
+fn migrate_pinned_sort_order(conn: &Connection) {
+    use super::clipboard::PINNED_SORT_GAP;
+
+    let ids: Vec<i64> = match conn.prepare(
+        "SELECT id FROM clipboard WHERE is_pinned = 1 AND sort_order IS NULL ORDER BY item_order DESC, updated_at DESC"
+    ) {
+        Ok(mut stmt) => stmt.query_map([], |row| row.get(0))
+            .map(|rows| rows.filter_map(|r| r.ok()).collect())
+            .unwrap_or_default(),
+        Err(_) => return,
+    };
+    if ids.is_empty() {
+        return;
+    }
+    let max: i64 = conn.query_row(
+        "SELECT COALESCE(MAX(sort_order), 0) FROM clipboard WHERE is_pinned = 1", [], |row| row.get(0)
+    ).unwrap_or(0);
+    for (i, id) in ids.iter().enumerate() {
+        conn.execute("UPDATE clipboard SET sort_order = ? WHERE id = ?",
+            rusqlite::params![max + (i as i64 + 1) * PINNED_SORT_GAP, id]).ok();
+    }
+}
+
 // 清理文件和图片类型收藏项的自动生成标题
 fn migrate_favorites_auto_titles(conn: &Connection) {
     if let Ok(mut stmt) = conn.prepare(
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 // 剪贴板项
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -7,18 +8,41 @@ pub struct ClipboardItem {
     pub content: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub html_content: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rtf_content: Option<String>,
     pub content_type: String,  
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image_id: Option<String>,
     pub item_order: i64,
     pub is_pinned: bool,
     pub paste_count: i64,
+    // 复制次数（首次为 1，重复复制被去重合并时累加）
+    #[serde(default)]
+    pub copy_count: i64,
+    // 仅本机：导出与同步时排除
+    #[serde(default)]
+    pub local_only: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_app: Option<String>,       
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_icon_hash: Option<String>, 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub char_count: Option<i64>,
+    // 用户备注
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    // 捕获时剪贴板提供的格式（如 CF_UNICODETEXT、HTML Format，仅在按 ID 获取时返回）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_formats: Option<Vec<String>>,
+    // 存储时转换前的原始文本（仅在开启保留原文且内容被转换时存在，仅在按 ID 获取时返回）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_content: Option<String>,
+    // 强制粘贴格式（格式名，如 "plain"），优先于调用方指定的格式
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forced_format: Option<String>,
+    // 搜索关键词在返回内容中的匹配区间 [start, end)，按 UTF-16 码元计（仅在请求高亮时返回）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub match_ranges: Option<Vec<(usize, usize)>>,
     pub created_at: i64,  
     pub updated_at: i64, 
 }
@@ -43,6 +67,35 @@ pub struct FavoriteItem {
     pub updated_at: i64, 
 }
 
+// 将收藏项转换为剪贴板项格式（用于复用剪贴板项的粘贴流程）
+impl From<FavoriteItem> for ClipboardItem {
+    fn from(favorite: FavoriteItem) -> Self {
+        Self {
+            id: 0,
+            content: favorite.content,
+            html_content: favorite.html_content,
+            rtf_content: None,
+            content_type: favorite.content_type,
+            image_id: favorite.image_id,
+            item_order: favorite.item_order,
+            is_pinned: false,
+            paste_count: 0,
+            copy_count: 0,
+            local_only: false,
+            source_app: None,
+            source_icon_hash: None,
+            char_count: favorite.char_count,
+            note: None,
+            source_formats: None,
+            original_content: None,
+            forced_format: None,
+            match_ranges: None,
+            created_at: favorite.created_at,
+            updated_at: favorite.updated_at,
+        }
+    }
+}
+
 // 分组信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GroupInfo {
@@ -56,7 +109,7 @@ pub struct GroupInfo {
 // 分页查询结果
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedResult<T> {
-    // 总记录数
+    // 总记录数（与当前过滤条件一致，用于虚拟列表滚动条）
     pub total_count: i64,
     // 当前页数据
     pub items: Vec<T>,
@@ -82,6 +135,44 @@ impl<T> PaginatedResult<T> {
     }
 }
 
+// 游标分批查询结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClipboardBatch {
+    // 当前批数据（完整内容，不截断）
+    pub items: Vec<ClipboardItem>,
+    // 下一批的游标（为 None 表示已遍历完）
+    pub next_cursor: Option<i64>,
+}
+
+// 最常粘贴的剪贴板项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MostPastedItem {
+    pub id: i64,
+    pub content_type: String,
+    // 文本类为前若干字符，其他类型为 None
+    pub preview: Option<String>,
+    pub paste_count: i64,
+}
+
+// 剪贴板历史统计
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryStats {
+    // 总条数
+    pub total_count: u64,
+    // 各内容类型条数（多类型项同时计入各自类型）
+    pub type_counts: HashMap<String, u64>,
+    // 数据库文件占用字节数
+    pub db_size_bytes: u64,
+    // 最早/最新记录的创建时间（秒级时间戳）
+    pub oldest_created_at: Option<i64>,
+    pub newest_created_at: Option<i64>,
+    // 今天/本周（周一起）新增的条数
+    pub copies_today: u64,
+    pub copies_this_week: u64,
+    // 粘贴次数最多的项（尚无粘贴记录时为 None）
+    pub most_pasted: Option<MostPastedItem>,
+}
+
 // 查询参数
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
@@ -95,6 +186,15 @@ pub struct QueryParams {
     // 内容类型过滤（可选）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_type: Option<String>,
+    // 创建时间早于该时间戳（可选）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_before: Option<i64>,
+    // 搜索时是否返回关键词在内容中的匹配位置（用于高亮）
+    #[serde(default)]
+    pub with_highlights: bool,
+    // 排除仅本机的项（导出、同步等数据离开本机的场景）
+    #[serde(default)]
+    pub exclude_local_only: bool,
 }
 
 impl Default for QueryParams {
@@ -104,6 +204,9 @@ impl Default for QueryParams {
             limit: 50,
             search: None,
             content_type: None,
+            created_before: None,
+            with_highlights: false,
+            exclude_local_only: false,
         }
     }
 }
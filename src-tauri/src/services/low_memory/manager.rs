@@ -8,6 +8,7 @@ const WEBVIEW_LABELS: &[&str] = &[
     "context-menu",
     "settings",
     "text-editor",
+    "compose",
     "screenshot",
     "updater",
 ];
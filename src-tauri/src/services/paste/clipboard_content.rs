@@ -80,6 +80,14 @@ pub fn set_clipboard_text(ctx: &ClipboardContext, text: &str) -> Result<(), Stri
         .map_err(|e| format!("设置文本到剪贴板失败: {}", e))
 }
 
+// 清空系统剪贴板
+pub fn clear_os_clipboard() -> Result<(), String> {
+    let ctx = ClipboardContext::new()
+        .map_err(|e| format!("创建剪贴板上下文失败: {}", e))?;
+    ctx.clear()
+        .map_err(|e| format!("清空剪贴板失败: {}", e))
+}
+
 // 设置剪贴板为富文本（文本 + HTML）
 pub fn set_clipboard_rich_text(ctx: &ClipboardContext, text: &str, html: &str) -> Result<(), String> {
     let cf_html = generate_cf_html(html);
@@ -90,6 +98,44 @@ pub fn set_clipboard_rich_text(ctx: &ClipboardContext, text: &str, html: &str) -
     .map_err(|e| format!("设置剪贴板内容失败: {}", e))
 }
 
+// 设置剪贴板为 RTF（同时保留文本和 HTML）
+pub fn set_clipboard_rtf(ctx: &ClipboardContext, text: &str, html: Option<&str>, rtf: &str) -> Result<(), String> {
+    let mut contents = vec![
+        ClipboardContent::Text(text.to_string()),
+        ClipboardContent::Rtf(rtf.to_string()),
+    ];
+    if let Some(html) = html {
+        contents.push(ClipboardContent::Html(generate_cf_html(html)));
+    }
+    ctx.set(contents)
+        .map_err(|e| format!("设置剪贴板内容失败: {}", e))
+}
+
+// 设置剪贴板为文本（可附带 HTML）+ 图片，由目标应用自行选择格式
+pub fn set_clipboard_text_with_image(ctx: &ClipboardContext, text: &str, html: Option<&str>, image_path: &str) -> Result<(), String> {
+    use clipboard_rs::{common::RustImage, RustImageData};
+    
+    let image = RustImageData::from_path(image_path)
+        .map_err(|e| format!("读取图片失败: {}", e))?;
+    let mut contents = vec![ClipboardContent::Text(text.to_string())];
+    if let Some(html) = html {
+        contents.push(ClipboardContent::Html(generate_cf_html(html)));
+    }
+    contents.push(ClipboardContent::Image(image));
+    ctx.set(contents)
+        .map_err(|e| format!("设置剪贴板内容失败: {}", e))
+}
+
+// 设置剪贴板为图片
+pub fn set_clipboard_image(ctx: &ClipboardContext, image_path: &str) -> Result<(), String> {
+    use clipboard_rs::{common::RustImage, RustImageData};
+    
+    let image = RustImageData::from_path(image_path)
+        .map_err(|e| format!("读取图片失败: {}", e))?;
+    ctx.set_image(image)
+        .map_err(|e| format!("设置图片到剪贴板失败: {}", e))
+}
+
 // 根据内容类型设置剪贴板（不触发粘贴，用于复制操作）
 pub fn set_clipboard_from_item(
     content_type: &str,
@@ -0,0 +1,199 @@
+// 合并粘贴：将多选的剪贴板项按指定顺序与分隔符拼接为一段文本后粘贴
+
+use serde::Deserialize;
+
+use crate::services::database::ClipboardItem;
+
+// 合并粘贴选项
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CombineOptions {
+    // 排列顺序："selection"（按传入的选择顺序）或 "history"（按列表中的顺序）
+    #[serde(default = "default_order")]
+    pub order: String,
+    // 分隔符："newline" | "comma" | "space" | "custom"
+    #[serde(default = "default_separator")]
+    pub separator: String,
+    // separator 为 "custom" 时使用的分隔符（支持 \n、\t 转义）
+    #[serde(default)]
+    pub custom_separator: Option<String>,
+    // 是否包装为编号列表（每项前加 "1. "）
+    #[serde(default)]
+    pub numbered: bool,
+}
+
+fn default_order() -> String {
+    "selection".to_string()
+}
+
+fn default_separator() -> String {
+    "newline".to_string()
+}
+
+impl Default for CombineOptions {
+    fn default() -> Self {
+        Self {
+            order: default_order(),
+            separator: default_separator(),
+            custom_separator: None,
+            numbered: false,
+        }
+    }
+}
+
+// 解析分隔符设置
+fn resolve_separator(options: &CombineOptions) -> Result<String, String> {
+    match options.separator.as_str() {
+        "newline" => Ok("\n".to_string()),
+        "comma" => Ok(", ".to_string()),
+        "space" => Ok(" ".to_string()),
+        "custom" => Ok(options
+            .custom_separator
+            .as_deref()
+            .unwrap_or_default()
+            .replace("\\n", "\n")
+            .replace("\\t", "\t")),
+        other => Err(format!("不支持的分隔符: {}", other)),
+    }
+}
+
+// 按列表顺序排序（与列表查询一致）：置顶项在前并按手动排序（pinned_order 为置顶项的顺序），
+// 其余按 item_order、更新时间倒序
+fn sort_history_order(items: &mut [ClipboardItem], pinned_order: &[i64]) {
+    let pinned_rank = |item: &ClipboardItem| {
+        pinned_order.iter().position(|id| *id == item.id).unwrap_or(usize::MAX)
+    };
+    items.sort_by(|a, b| {
+        b.is_pinned
+            .cmp(&a.is_pinned)
+            .then_with(|| pinned_rank(a).cmp(&pinned_rank(b)))
+            .then(b.item_order.cmp(&a.item_order))
+            .then(b.updated_at.cmp(&a.updated_at))
+    });
+}
+
+// 拼接文本，编号列表时每项单独编号
+fn combine_texts(texts: &[&str], separator: &str, numbered: bool) -> String {
+    if !numbered {
+        return texts.join(separator);
+    }
+    texts
+        .iter()
+        .enumerate()
+        .map(|(i, text)| format!("{}. {}", i + 1, text))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+// 可参与合并的文本类项
+pub(super) fn is_combinable(item: &ClipboardItem) -> bool {
+    let primary_type = item.content_type.split(',').next().unwrap_or(&item.content_type);
+    matches!(primary_type, "text" | "link" | "rich_text")
+}
+
+// 按选项的分隔符与编号方式拼接已排好序的文本
+pub(super) fn join_parts(texts: &[&str], options: &CombineOptions) -> Result<String, String> {
+    let separator = resolve_separator(options)?;
+    Ok(combine_texts(texts, &separator, options.numbered))
+}
+
+// 生成合并后的文本（跳过非文本类项），ids 的顺序即用户的选择顺序
+pub fn build_combined_text(ids: &[i64], options: &CombineOptions) -> Result<String, String> {
+    use crate::services::database::get_clipboard_item_by_id;
+
+    if ids.is_empty() {
+        return Err("至少需要选择一个剪贴板项".to_string());
+    }
+    if !matches!(options.order.as_str(), "selection" | "history") {
+        return Err(format!("不支持的排列顺序: {}", options.order));
+    }
+    resolve_separator(options)?;
+
+    let mut items = Vec::with_capacity(ids.len());
+    for id in ids {
+        let item = get_clipboard_item_by_id(*id)?
+            .ok_or_else(|| format!("剪贴板项不存在: {}", id))?;
+        if is_combinable(&item) {
+            items.push(item);
+        }
+    }
+    if items.is_empty() {
+        return Err("所选项中没有可合并的文本".to_string());
+    }
+
+    if options.order == "history" {
+        let pinned_order = crate::services::database::get_pinned_order()?;
+        sort_history_order(&mut items, &pinned_order);
+    }
+
+    let texts: Vec<&str> = items.iter().map(|item| item.content.as_str()).collect();
+    join_parts(&texts, options)
+}
+
+// 合并粘贴多个剪贴板项（合并结果不记录到历史）
+pub fn paste_items_combined(ids: &[i64], options: &CombineOptions) -> Result<(), String> {
+    let text = build_combined_text(ids, options)?;
+    super::paste_handler::paste_text_direct(&text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: i64, is_pinned: bool, item_order: i64, updated_at: i64) -> ClipboardItem {
+        ClipboardItem {
+            id,
+            content: format!("item{}", id),
+            html_content: None,
+            rtf_content: None,
+            content_type: "text".to_string(),
+            image_id: None,
+            item_order,
+            is_pinned,
+            paste_count: 0,
+            copy_count: 0,
+            local_only: false,
+            source_app: None,
+            source_icon_hash: None,
+            char_count: None,
+            note: None,
+            source_formats: None,
+            original_content: None,
+            forced_format: None,
+            match_ranges: None,
+            created_at: updated_at,
+            updated_at,
+        }
+    }
+
+    #[test]
+    fn test_combine_texts() {
+        assert_eq!(combine_texts(&["a", "b", "c"], "\n", false), "a\nb\nc");
+        assert_eq!(combine_texts(&["a", "b"], ", ", true), "1. a, 2. b");
+    }
+
+    #[test]
+    fn test_resolve_separator() {
+        let mut options = CombineOptions::default();
+        assert_eq!(resolve_separator(&options).unwrap(), "\n");
+        options.separator = "custom".to_string();
+        options.custom_separator = Some(" | \\n".to_string());
+        assert_eq!(resolve_separator(&options).unwrap(), " | \n");
+        options.separator = "tab".to_string();
+        assert!(resolve_separator(&options).is_err());
+    }
+
+    #[test]
+    fn test_history_order_matches_list() {
+        let mut items = vec![item(1, false, 5, 10), item(2, true, 1, 10), item(3, false, 9, 10)];
+        sort_history_order(&mut items, &[2]);
+        let ids: Vec<i64> = items.iter().map(|i| i.id).collect();
+        assert_eq!(ids, vec![2, 3, 1]);
+
+        // 置顶项按手动顺序，而不是 item_order
+        let mut items = vec![item(4, true, 9, 10), item(5, true, 1, 10), item(6, false, 20, 10)];
+        sort_history_order(&mut items, &[5, 4]);
+        let ids: Vec<i64> = items.iter().map(|i| i.id).collect();
+        assert_eq!(ids, vec![5, 4, 6]);
+    }
+}
@@ -0,0 +1,215 @@
+// 组合粘贴：在组合窗口中挑选多个剪贴板项或输入片段，调整顺序与内容后一次性粘贴
+// 组合内容保存在内存中的会话里（不写入数据库），窗口关闭后保留，粘贴成功后清空
+// 每次变更发送 compose-updated 事件，携带最新的条目列表
+
+use super::combined::{is_combinable, join_parts, CombineOptions};
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::Emitter;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposeEntry {
+    // 会话内唯一的条目标识（同一剪贴板项可多次加入）
+    pub key: u64,
+    // 来源剪贴板项，手动输入的片段为 None
+    pub source_id: Option<i64>,
+    pub text: String,
+}
+
+#[derive(Debug)]
+struct ComposeSession {
+    entries: Vec<ComposeEntry>,
+    next_key: u64,
+}
+
+impl ComposeSession {
+    const fn new() -> Self {
+        Self { entries: Vec::new(), next_key: 1 }
+    }
+
+    fn push(&mut self, source_id: Option<i64>, text: String) -> u64 {
+        let key = self.next_key;
+        self.next_key += 1;
+        self.entries.push(ComposeEntry { key, source_id, text });
+        key
+    }
+
+    fn position(&self, key: u64) -> Result<usize, String> {
+        self.entries
+            .iter()
+            .position(|entry| entry.key == key)
+            .ok_or_else(|| format!("组合条目不存在: {}", key))
+    }
+
+    fn update(&mut self, key: u64, text: String) -> Result<(), String> {
+        let index = self.position(key)?;
+        self.entries[index].text = text;
+        Ok(())
+    }
+
+    fn remove(&mut self, key: u64) -> Result<(), String> {
+        let index = self.position(key)?;
+        self.entries.remove(index);
+        Ok(())
+    }
+
+    // 将条目移动到指定位置，超出范围时移到末尾
+    fn move_entry(&mut self, key: u64, to_index: usize) -> Result<(), String> {
+        let index = self.position(key)?;
+        let entry = self.entries.remove(index);
+        let to_index = to_index.min(self.entries.len());
+        self.entries.insert(to_index, entry);
+        Ok(())
+    }
+
+    fn assemble(&self, options: &CombineOptions) -> Result<String, String> {
+        if self.entries.is_empty() {
+            return Err("组合内容为空".into());
+        }
+        let texts: Vec<&str> = self.entries.iter().map(|entry| entry.text.as_str()).collect();
+        join_parts(&texts, options)
+    }
+}
+
+static SESSION: Mutex<ComposeSession> = Mutex::new(ComposeSession::new());
+
+fn notify_updated(entries: &[ComposeEntry]) {
+    if let Some(app) = crate::services::clipboard::get_app_handle() {
+        let _ = app.emit("compose-updated", entries);
+    }
+}
+
+// 修改会话并通知前端，返回最新的条目列表
+fn modify<F>(f: F) -> Result<Vec<ComposeEntry>, String>
+where
+    F: FnOnce(&mut ComposeSession) -> Result<(), String>,
+{
+    let entries = {
+        let mut session = SESSION.lock();
+        f(&mut session)?;
+        session.entries.clone()
+    };
+    notify_updated(&entries);
+    Ok(entries)
+}
+
+pub fn compose_entries() -> Vec<ComposeEntry> {
+    SESSION.lock().entries.clone()
+}
+
+// 按给定顺序加入剪贴板项，只支持文本类项
+pub fn compose_add_items(ids: &[i64]) -> Result<Vec<ComposeEntry>, String> {
+    let mut items = Vec::with_capacity(ids.len());
+    for id in ids {
+        let item = crate::services::database::get_clipboard_item_by_id(*id)?
+            .ok_or_else(|| format!("剪贴板项不存在: {}", id))?;
+        if !is_combinable(&item) {
+            return Err("只能组合文本内容".into());
+        }
+        items.push(item);
+    }
+    modify(|session| {
+        for item in items {
+            session.push(Some(item.id), item.content);
+        }
+        Ok(())
+    })
+}
+
+pub fn compose_add_text(text: String) -> Result<Vec<ComposeEntry>, String> {
+    modify(|session| {
+        session.push(None, text);
+        Ok(())
+    })
+}
+
+pub fn compose_update(key: u64, text: String) -> Result<Vec<ComposeEntry>, String> {
+    modify(|session| session.update(key, text))
+}
+
+pub fn compose_remove(key: u64) -> Result<Vec<ComposeEntry>, String> {
+    modify(|session| session.remove(key))
+}
+
+pub fn compose_move(key: u64, to_index: usize) -> Result<Vec<ComposeEntry>, String> {
+    modify(|session| session.move_entry(key, to_index))
+}
+
+pub fn compose_clear() -> Vec<ComposeEntry> {
+    modify(|session| {
+        session.entries.clear();
+        Ok(())
+    })
+    .unwrap_or_default()
+}
+
+// 按当前选项预览组合结果
+pub fn compose_preview(options: &CombineOptions) -> Result<String, String> {
+    SESSION.lock().assemble(options)
+}
+
+// 粘贴组合结果：final_text 为前端最终编辑后的文本，未提供时按选项组合会话条目
+// 粘贴前隐藏组合窗口并切回之前的外部窗口，粘贴成功后清空会话
+pub fn paste_compose(app: &tauri::AppHandle, final_text: Option<String>, options: &CombineOptions) -> Result<(), String> {
+    let text = match final_text {
+        Some(text) => text,
+        None => compose_preview(options)?,
+    };
+    if text.is_empty() {
+        return Err("组合内容为空".into());
+    }
+
+    crate::windows::compose_window::hide_compose_window(app);
+    let _ = crate::services::system::focus::restore_last_focus();
+    super::paste_handler::paste_text_direct(&text)?;
+    compose_clear();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session_with(texts: &[&str]) -> ComposeSession {
+        let mut session = ComposeSession::new();
+        for text in texts {
+            session.push(None, text.to_string());
+        }
+        session
+    }
+
+    fn texts(session: &ComposeSession) -> Vec<&str> {
+        session.entries.iter().map(|entry| entry.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_keys_stay_unique_after_removal() {
+        let mut session = session_with(&["a", "b"]);
+        session.remove(2).unwrap();
+        assert_eq!(session.push(Some(7), "c".to_string()), 3);
+        assert_eq!(texts(&session), vec!["a", "c"]);
+        assert!(session.remove(2).is_err());
+    }
+
+    #[test]
+    fn test_move_and_update_entries() {
+        let mut session = session_with(&["a", "b", "c"]);
+        session.move_entry(3, 0).unwrap();
+        assert_eq!(texts(&session), vec!["c", "a", "b"]);
+        session.move_entry(3, 99).unwrap();
+        assert_eq!(texts(&session), vec!["a", "b", "c"]);
+        session.update(2, "B".to_string()).unwrap();
+        assert_eq!(texts(&session), vec!["a", "B", "c"]);
+    }
+
+    #[test]
+    fn test_assemble_uses_options() {
+        assert!(ComposeSession::new().assemble(&CombineOptions::default()).is_err());
+
+        let session = session_with(&["a", "b"]);
+        assert_eq!(session.assemble(&CombineOptions::default()).unwrap(), "a\nb");
+        let options = CombineOptions { numbered: true, ..CombineOptions::default() };
+        assert_eq!(session.assemble(&options).unwrap(), "1. a\n2. b");
+    }
+}
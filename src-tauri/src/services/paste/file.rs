@@ -7,7 +7,45 @@ pub fn paste_files(ctx: &ClipboardContext, content: &str) -> Result<(), String>
     set_clipboard_files(ctx, file_paths)
 }
 
+// 含空白的路径加双引号，便于直接粘贴到终端
+fn quote_path(path: &str) -> String {
+    let already_quoted = path.len() >= 2 && path.starts_with('"') && path.ends_with('"');
+    if !already_quoted && path.chars().any(char::is_whitespace) {
+        format!("\"{}\"", path)
+    } else {
+        path.to_string()
+    }
+}
 
+// 文件列表转换为路径文本（每行一个路径）
+pub fn paths_as_text(paths: &[String]) -> String {
+    paths.iter().map(|p| quote_path(p)).collect::<Vec<_>>().join("\n")
+}
 
+// 文件项内容转换为路径文本（不检查文件是否存在，已删除的文件路径同样可用）
+pub fn files_content_as_text(content: &str) -> Result<String, String> {
+    let paths = super::clipboard_content::parse_files_content(content)?;
+    if paths.is_empty() {
+        return Err("无法解析文件内容".to_string());
+    }
+    Ok(paths_as_text(&paths))
+}
 
+#[cfg(test)]
+mod tests {
+    use super::*;
 
+    #[test]
+    fn test_paths_as_text_quotes_paths_with_spaces() {
+        let paths = vec![
+            r"C:\Users\me\report.pdf".to_string(),
+            r"C:\Program Files\app\a b.txt".to_string(),
+            "\"D:\\already quoted.txt\"".to_string(),
+        ];
+        assert_eq!(
+            paths_as_text(&paths),
+            "C:\\Users\\me\\report.pdf\n\"C:\\Program Files\\app\\a b.txt\"\n\"D:\\already quoted.txt\""
+        );
+        assert_eq!(paths_as_text(&[]), "");
+    }
+}
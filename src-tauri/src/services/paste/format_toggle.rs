@@ -0,0 +1,108 @@
+// 格式粘贴开关：常驻模式切换设置；临时模式仅对下一次粘贴翻转，粘贴后恢复
+
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+// 临时翻转未被使用时的自动失效时间
+const MOMENTARY_TIMEOUT: Duration = Duration::from_secs(10);
+const MOMENTARY_TIMER_KIND: &str = "momentary_paste_format";
+
+// 下一次粘贴是否翻转格式设置
+static MOMENTARY_FLIP: AtomicBool = AtomicBool::new(false);
+
+// 快捷键的切换方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToggleMode {
+    // 切换并保存设置
+    Sticky,
+    // 仅翻转下一次粘贴
+    Momentary,
+}
+
+impl ToggleMode {
+    pub fn from_setting(value: &str) -> Self {
+        match value {
+            "momentary" => Self::Momentary,
+            _ => Self::Sticky,
+        }
+    }
+}
+
+// 格式粘贴状态（paste-format-changed 事件内容）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasteFormatState {
+    // 下一次粘贴实际使用的状态
+    pub enabled: bool,
+    // 是否为临时翻转
+    pub momentary: bool,
+}
+
+fn effective(setting: bool, flipped: bool) -> bool {
+    setting != flipped
+}
+
+// 当前粘贴使用的格式状态（设置值叠加临时翻转）
+pub fn paste_with_format() -> bool {
+    effective(crate::services::get_settings().paste_with_format, MOMENTARY_FLIP.load(Ordering::SeqCst))
+}
+
+pub fn current_state() -> PasteFormatState {
+    PasteFormatState {
+        enabled: paste_with_format(),
+        momentary: MOMENTARY_FLIP.load(Ordering::SeqCst),
+    }
+}
+
+pub fn emit_state() {
+    use tauri::Emitter;
+    if let Some(app) = crate::services::clipboard::get_app_handle() {
+        let _ = app.emit("paste-format-changed", current_state());
+    }
+}
+
+// 切换临时翻转：已翻转时取消，否则翻转下一次粘贴（超时未粘贴自动恢复）
+pub fn toggle_momentary() -> PasteFormatState {
+    use crate::services::system::timers;
+
+    let armed = !MOMENTARY_FLIP.fetch_xor(true, Ordering::SeqCst);
+    timers::cancel_timers_of_kind(MOMENTARY_TIMER_KIND);
+    if armed {
+        timers::schedule(MOMENTARY_TIMER_KIND, "临时格式粘贴翻转", MOMENTARY_TIMEOUT, || {
+            if MOMENTARY_FLIP.swap(false, Ordering::SeqCst) {
+                emit_state();
+            }
+        });
+    }
+    emit_state();
+    current_state()
+}
+
+// 一次粘贴完成后调用：临时翻转只作用于这一次
+pub fn consume_momentary() {
+    if MOMENTARY_FLIP.swap(false, Ordering::SeqCst) {
+        crate::services::system::timers::cancel_timers_of_kind(MOMENTARY_TIMER_KIND);
+        emit_state();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_state() {
+        assert!(effective(true, false));
+        assert!(!effective(true, true));
+        assert!(effective(false, true));
+        assert!(!effective(false, false));
+    }
+
+    #[test]
+    fn test_toggle_mode_from_setting() {
+        assert_eq!(ToggleMode::from_setting("momentary"), ToggleMode::Momentary);
+        assert_eq!(ToggleMode::from_setting("sticky"), ToggleMode::Sticky);
+        assert_eq!(ToggleMode::from_setting(""), ToggleMode::Sticky);
+    }
+}
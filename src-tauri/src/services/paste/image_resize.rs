@@ -0,0 +1,175 @@
+// 按设置缩放/重新编码后粘贴图片：原图保持不变，仅在粘贴时生成临时文件写入剪贴板
+
+use image::{DynamicImage, GenericImageView, ImageEncoder};
+use std::path::{Path, PathBuf};
+
+// 输出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageOutputFormat {
+    Png,
+    Jpeg,
+    // 含透明像素时使用 PNG，否则使用 JPEG
+    Auto,
+}
+
+impl ImageOutputFormat {
+    pub fn from_setting(value: &str) -> Self {
+        match value {
+            "png" => Self::Png,
+            "jpeg" => Self::Jpeg,
+            _ => Self::Auto,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImagePasteOptions {
+    // 最长边上限，0 表示不缩放
+    pub max_dimension: u32,
+    pub format: ImageOutputFormat,
+    pub jpeg_quality: u8,
+}
+
+impl ImagePasteOptions {
+    pub fn from_settings() -> Self {
+        let settings = crate::get_settings();
+        Self {
+            max_dimension: settings.image_paste_max_dimension,
+            format: ImageOutputFormat::from_setting(&settings.image_paste_format),
+            jpeg_quality: settings.image_paste_jpeg_quality.clamp(1, 100),
+        }
+    }
+}
+
+// 按最长边上限等比缩放后的尺寸（不放大）
+fn target_size(width: u32, height: u32, max_dimension: u32) -> (u32, u32) {
+    let longest = width.max(height);
+    if max_dimension == 0 || longest <= max_dimension {
+        return (width, height);
+    }
+    let scale = f64::from(max_dimension) / f64::from(longest);
+    let scaled = |v: u32| ((f64::from(v) * scale).round() as u32).max(1);
+    (scaled(width), scaled(height))
+}
+
+fn has_transparency(img: &DynamicImage) -> bool {
+    img.color().has_alpha() && img.to_rgba8().pixels().any(|p| p[3] < u8::MAX)
+}
+
+// JPEG 不支持透明度：半透明像素按透明度与白色背景混合
+fn flatten_onto_white(img: &DynamicImage) -> image::RgbImage {
+    let rgba = img.to_rgba8();
+    image::RgbImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+        let [r, g, b, a] = rgba.get_pixel(x, y).0;
+        let blend = |c: u8| ((u16::from(c) * u16::from(a) + 255 * u16::from(255 - a)) / 255) as u8;
+        image::Rgb([blend(r), blend(g), blend(b)])
+    })
+}
+
+// 缩放并编码图片，返回编码数据与扩展名
+fn encode_for_paste(img: &DynamicImage, options: &ImagePasteOptions) -> Result<(Vec<u8>, &'static str), String> {
+    let (width, height) = img.dimensions();
+    let (target_width, target_height) = target_size(width, height, options.max_dimension);
+    let resized = if (target_width, target_height) == (width, height) {
+        img.clone()
+    } else {
+        img.resize_exact(target_width, target_height, image::imageops::FilterType::Lanczos3)
+    };
+
+    let use_jpeg = match options.format {
+        ImageOutputFormat::Png => false,
+        ImageOutputFormat::Jpeg => true,
+        ImageOutputFormat::Auto => !has_transparency(&resized),
+    };
+
+    let mut data = Vec::new();
+    if use_jpeg {
+        let rgb = flatten_onto_white(&resized);
+        image::codecs::jpeg::JpegEncoder::new_with_quality(&mut data, options.jpeg_quality)
+            .write_image(rgb.as_raw(), rgb.width(), rgb.height(), image::ExtendedColorType::Rgb8)
+            .map_err(|e| format!("JPEG 编码失败: {}", e))?;
+        Ok((data, "jpg"))
+    } else {
+        let rgba = resized.to_rgba8();
+        image::codecs::png::PngEncoder::new(&mut data)
+            .write_image(rgba.as_raw(), rgba.width(), rgba.height(), image::ExtendedColorType::Rgba8)
+            .map_err(|e| format!("PNG 编码失败: {}", e))?;
+        Ok((data, "png"))
+    }
+}
+
+// 生成缩放后的临时文件，返回文件路径与编码数据
+fn prepare_resized_image(source: &Path, options: &ImagePasteOptions) -> Result<(PathBuf, Vec<u8>), String> {
+    let img = image::open(source).map_err(|e| format!("读取图片失败: {}", e))?;
+    let (data, extension) = encode_for_paste(&img, options)?;
+
+    let dir = std::env::temp_dir().join("quickclipboard_paste_images");
+    std::fs::create_dir_all(&dir).map_err(|e| format!("创建临时目录失败: {}", e))?;
+    let stem = source.file_stem().and_then(|s| s.to_str()).unwrap_or("image");
+    let path = dir.join(format!("{}_{}.{}", stem, options.max_dimension, extension));
+    std::fs::write(&path, &data).map_err(|e| format!("写入临时图片失败: {}", e))?;
+    Ok((path, data))
+}
+
+// 将缩放后的图片同时以文件和位图形式写入剪贴板，由目标应用自行选择
+pub fn set_clipboard_resized_image(ctx: &clipboard_rs::ClipboardContext, source: &Path) -> Result<(), String> {
+    use clipboard_rs::{common::RustImage, Clipboard, ClipboardContent, RustImageData};
+
+    let (path, data) = prepare_resized_image(source, &ImagePasteOptions::from_settings())?;
+    let path_str = path.to_str().ok_or("路径转换失败")?.to_string();
+    let image = RustImageData::from_bytes(&data).map_err(|e| format!("读取图片失败: {}", e))?;
+
+    // 预设哈希，避免临时文件被记录为新的剪贴板项
+    crate::services::clipboard::set_last_hash_file(&path_str);
+    ctx.set(vec![ClipboardContent::Files(vec![path_str]), ClipboardContent::Image(image)])
+        .map_err(|e| format!("设置图片到剪贴板失败: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    #[test]
+    fn test_target_size_keeps_aspect_ratio() {
+        assert_eq!(target_size(3840, 2160, 1920), (1920, 1080));
+        assert_eq!(target_size(1000, 4000, 1920), (480, 1920));
+        // 不放大，0 表示不缩放
+        assert_eq!(target_size(800, 600, 1920), (800, 600));
+        assert_eq!(target_size(5000, 100, 0), (5000, 100));
+        assert_eq!(target_size(10000, 1, 100), (100, 1));
+    }
+
+    #[test]
+    fn test_auto_format_preserves_transparency() {
+        let options = ImagePasteOptions { max_dimension: 16, format: ImageOutputFormat::Auto, jpeg_quality: 85 };
+
+        let opaque = DynamicImage::ImageRgba8(RgbaImage::from_pixel(32, 32, Rgba([10, 20, 30, 255])));
+        let (data, ext) = encode_for_paste(&opaque, &options).unwrap();
+        assert_eq!(ext, "jpg");
+        assert_eq!(image::load_from_memory(&data).unwrap().dimensions(), (16, 16));
+
+        let transparent = DynamicImage::ImageRgba8(RgbaImage::from_pixel(32, 32, Rgba([10, 20, 30, 0])));
+        let (data, ext) = encode_for_paste(&transparent, &options).unwrap();
+        assert_eq!(ext, "png");
+        assert!(image::load_from_memory(&data).unwrap().color().has_alpha());
+    }
+
+    #[test]
+    fn test_flatten_onto_white() {
+        let img = DynamicImage::ImageRgba8(RgbaImage::from_fn(2, 1, |x, _| {
+            if x == 0 { Rgba([0, 0, 0, 0]) } else { Rgba([0, 0, 0, 255]) }
+        }));
+        let flat = flatten_onto_white(&img);
+        assert_eq!(flat.get_pixel(0, 0).0, [255, 255, 255]);
+        assert_eq!(flat.get_pixel(1, 0).0, [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_output_format_from_setting() {
+        assert_eq!(ImageOutputFormat::from_setting("png"), ImageOutputFormat::Png);
+        assert_eq!(ImageOutputFormat::from_setting("jpeg"), ImageOutputFormat::Jpeg);
+        assert_eq!(ImageOutputFormat::from_setting("auto"), ImageOutputFormat::Auto);
+        assert_eq!(ImageOutputFormat::from_setting(""), ImageOutputFormat::Auto);
+    }
+}
@@ -4,7 +4,8 @@ use crate::services::system::input_monitor::get_modifier_keys_state;
 #[cfg(target_os = "windows")]
 use windows::Win32::UI::Input::KeyboardAndMouse::{
     GetAsyncKeyState, SendInput, INPUT, INPUT_KEYBOARD, KEYBDINPUT, 
-    KEYBD_EVENT_FLAGS, KEYEVENTF_KEYUP, VK_MENU, VK_CONTROL, VK_V,
+    KEYBD_EVENT_FLAGS, KEYEVENTF_EXTENDEDKEY, KEYEVENTF_KEYUP, KEYEVENTF_UNICODE, VK_MENU, VK_CONTROL, VK_V, VK_C,
+    VK_RETURN, VK_TAB, VK_SHIFT, VK_LEFT,
 };
 
 #[cfg(target_os = "windows")]
@@ -29,6 +30,159 @@ fn send_key(vk: u16, up: bool) {
     unsafe { SendInput(&[input], std::mem::size_of::<INPUT>() as i32); }
 }
 
+#[cfg(target_os = "windows")]
+fn keyboard_input(vk: u16, scan: u16, flags: KEYBD_EVENT_FLAGS) -> INPUT {
+    INPUT {
+        r#type: INPUT_KEYBOARD,
+        Anonymous: windows::Win32::UI::Input::KeyboardAndMouse::INPUT_0 {
+            ki: KEYBDINPUT {
+                wVk: windows::Win32::UI::Input::KeyboardAndMouse::VIRTUAL_KEY(vk),
+                wScan: scan,
+                dwFlags: flags,
+                time: 0,
+                dwExtraInfo: 0,
+            },
+        },
+    }
+}
+
+// 直接输入文本（SendInput Unicode 按键，不经过剪贴板）
+#[cfg(target_os = "windows")]
+pub fn type_text_unicode(text: &str) -> Result<(), String> {
+    const CHUNK_SIZE: usize = 64;
+
+    release_modifier_keys()?;
+
+    let mut inputs: Vec<INPUT> = Vec::with_capacity(CHUNK_SIZE * 2);
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            // \r\n 与单独的 \r 都视为一次回车
+            '\r' => {
+                if chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                inputs.push(keyboard_input(VK_RETURN.0, 0, KEYBD_EVENT_FLAGS(0)));
+                inputs.push(keyboard_input(VK_RETURN.0, 0, KEYEVENTF_KEYUP));
+            }
+            '\n' => {
+                inputs.push(keyboard_input(VK_RETURN.0, 0, KEYBD_EVENT_FLAGS(0)));
+                inputs.push(keyboard_input(VK_RETURN.0, 0, KEYEVENTF_KEYUP));
+            }
+            '\t' => {
+                inputs.push(keyboard_input(VK_TAB.0, 0, KEYBD_EVENT_FLAGS(0)));
+                inputs.push(keyboard_input(VK_TAB.0, 0, KEYEVENTF_KEYUP));
+            }
+            _ => {
+                let mut buf = [0u16; 2];
+                for unit in c.encode_utf16(&mut buf) {
+                    inputs.push(keyboard_input(0, *unit, KEYEVENTF_UNICODE));
+                    inputs.push(keyboard_input(0, *unit, KEYEVENTF_UNICODE | KEYEVENTF_KEYUP));
+                }
+            }
+        }
+
+        if inputs.len() >= CHUNK_SIZE * 2 {
+            send_inputs(&inputs)?;
+            inputs.clear();
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+    }
+
+    if !inputs.is_empty() {
+        send_inputs(&inputs)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn send_inputs(inputs: &[INPUT]) -> Result<(), String> {
+    let sent = unsafe { SendInput(inputs, std::mem::size_of::<INPUT>() as i32) };
+    if sent as usize != inputs.len() {
+        return Err(format!("输入文本失败: 已发送 {}/{} 个按键事件", sent, inputs.len()));
+    }
+    Ok(())
+}
+
+// 从光标处向回选中指定步数的文本（Shift+Left）
+#[cfg(target_os = "windows")]
+pub fn select_backward(steps: usize) -> Result<(), String> {
+    const CHUNK_SIZE: usize = 64;
+
+    if steps == 0 {
+        return Ok(());
+    }
+    // 先释放用户仍按住的快捷键修饰键，避免变成 Ctrl+Shift+Left 等按词选择
+    release_modifier_keys()?;
+
+    send_inputs(&[keyboard_input(VK_SHIFT.0, 0, KEYBD_EVENT_FLAGS(0))])?;
+    let mut remaining = steps;
+    let result = loop {
+        if remaining == 0 {
+            break Ok(());
+        }
+        let count = remaining.min(CHUNK_SIZE);
+        let inputs: Vec<INPUT> = (0..count)
+            .flat_map(|_| [
+                keyboard_input(VK_LEFT.0, 0, KEYEVENTF_EXTENDEDKEY),
+                keyboard_input(VK_LEFT.0, 0, KEYEVENTF_EXTENDEDKEY | KEYEVENTF_KEYUP),
+            ])
+            .collect();
+        if let Err(e) = send_inputs(&inputs) {
+            break Err(e);
+        }
+        remaining -= count;
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    };
+    // 无论成功与否都要松开 Shift
+    send_key(VK_SHIFT.0, true);
+    result
+}
+
+// 从光标处向回选中指定步数的文本（Shift+Left）
+#[cfg(not(target_os = "windows"))]
+pub fn select_backward(steps: usize) -> Result<(), String> {
+    if steps == 0 {
+        return Ok(());
+    }
+    release_modifier_keys()?;
+
+    let mut enigo = Enigo::new(&Settings::default())
+        .map_err(|e| format!("创建键盘模拟器失败: {}", e))?;
+    enigo.key(Key::Shift, Direction::Press)
+        .map_err(|e| format!("按下Shift失败: {}", e))?;
+    let result = (0..steps).try_for_each(|_| {
+        enigo.key(Key::LeftArrow, Direction::Click)
+            .map_err(|e| format!("模拟按键失败: {}", e))
+    });
+    enigo.key(Key::Shift, Direction::Release)
+        .map_err(|e| format!("释放Shift失败: {}", e))?;
+    result
+}
+
+// 直接输入文本
+#[cfg(not(target_os = "windows"))]
+pub fn type_text_unicode(text: &str) -> Result<(), String> {
+    release_modifier_keys()?;
+
+    let mut enigo = Enigo::new(&Settings::default())
+        .map_err(|e| format!("创建键盘模拟器失败: {}", e))?;
+    enigo.text(&text.replace("\r\n", "\n"))
+        .map_err(|e| format!("输入文本失败: {}", e))
+}
+
+// 模拟单个按键点击（如 Tab、Enter）
+pub fn simulate_key_click(key: Key) -> Result<(), String> {
+    release_modifier_keys()?;
+
+    let mut enigo = Enigo::new(&Settings::default())
+        .map_err(|e| format!("创建键盘模拟器失败: {}", e))?;
+    enigo.key(key, Direction::Click)
+        .map_err(|e| format!("模拟按键失败: {}", e))
+}
+
 // 释放所有修饰键（Alt、Ctrl、Shift、Win）
 pub fn release_modifier_keys() -> Result<(), String> {
     let mut enigo = Enigo::new(&Settings::default())
@@ -88,13 +242,46 @@ impl Drop for KeyGuard {
     }
 }
 
+// 粘贴前统一等待：轮询直到焦点离开本应用窗口（最多等待设置的上限），再等待稳定延迟
+pub fn wait_for_paste_target() {
+    let settings = crate::get_settings();
+    let max_wait = std::time::Duration::from_millis(settings.paste_focus_wait_max_ms);
+    if !crate::services::system::focus::wait_for_external_foreground(max_wait) {
+        eprintln!("等待焦点切回目标窗口超时（{}ms），继续粘贴", settings.paste_focus_wait_max_ms);
+    }
+    std::thread::sleep(std::time::Duration::from_millis(settings.paste_delay_ms));
+}
+
+// 模拟粘贴键失败：key_sent 表示失败发生在粘贴键按下之后（目标窗口可能已执行粘贴，不能重试）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasteKeyError {
+    pub message: String,
+    pub key_sent: bool,
+}
+
+impl PasteKeyError {
+    pub fn before_key(message: String) -> Self {
+        Self { message, key_sent: false }
+    }
+
+    pub fn after_key(message: String) -> Self {
+        Self { message, key_sent: true }
+    }
+}
+
 // 模拟粘贴
-#[cfg(target_os = "windows")]
 pub fn simulate_paste() -> Result<(), String> {
+    simulate_paste_keys().map_err(|e| e.message)
+}
+
+// 模拟粘贴，失败时区分是否已发送粘贴键
+#[cfg(target_os = "windows")]
+pub fn simulate_paste_keys() -> Result<(), PasteKeyError> {
     let settings = crate::get_settings();
     
     if settings.paste_shortcut_mode == "ctrl_v" {
-        simulate_paste_ctrl_v()
+        simulate_paste_ctrl_v();
+        Ok(())
     } else {
         simulate_paste_shift_insert()
     }
@@ -102,23 +289,25 @@ pub fn simulate_paste() -> Result<(), String> {
 
 // Shift+Insert 粘贴
 #[cfg(target_os = "windows")]
-fn simulate_paste_shift_insert() -> Result<(), String> {
+fn simulate_paste_shift_insert() -> Result<(), PasteKeyError> {
     let mut enigo = Enigo::new(&Settings::default())
-        .map_err(|e| format!("创建键盘模拟器失败: {}", e))?;
+        .map_err(|e| PasteKeyError::before_key(format!("创建键盘模拟器失败: {}", e)))?;
 
     enigo.key(Key::Shift, Direction::Press)
-        .map_err(|e| format!("按下Shift失败: {}", e))?;
-    enigo.key(Key::Other(0x2D), Direction::Click)
-        .map_err(|e| format!("按下Insert失败: {}", e))?;
+        .map_err(|e| PasteKeyError::before_key(format!("按下Shift失败: {}", e)))?;
+    if let Err(e) = enigo.key(Key::Other(0x2D), Direction::Click) {
+        let _ = enigo.key(Key::Shift, Direction::Release);
+        return Err(PasteKeyError::before_key(format!("按下Insert失败: {}", e)));
+    }
     enigo.key(Key::Shift, Direction::Release)
-        .map_err(|e| format!("释放Shift失败: {}", e))?;
+        .map_err(|e| PasteKeyError::after_key(format!("释放Shift失败: {}", e)))?;
     
     Ok(())
 }
 
-// Ctrl+V 粘贴
+// Ctrl+V 粘贴（直接调用 SendInput，不会失败）
 #[cfg(target_os = "windows")]
-fn simulate_paste_ctrl_v() -> Result<(), String> {
+fn simulate_paste_ctrl_v() {
     let user_alt = is_key_pressed(VK_MENU.0);
     
     if user_alt {
@@ -147,34 +336,64 @@ fn simulate_paste_ctrl_v() -> Result<(), String> {
         send_key(VK_CONTROL.0, false);
         send_key(VK_CONTROL.0, true);
     }
-    
+}
+
+// 模拟复制（Ctrl+C）：先释放用户仍按住的快捷键修饰键，避免组合成其他按键
+#[cfg(target_os = "windows")]
+pub fn simulate_copy() -> Result<(), String> {
+    release_modifier_keys()?;
+
+    let _ctrl_guard = KeyGuard::new(VK_CONTROL.0, true);
+    send_key(VK_C.0, false);
+    std::thread::sleep(std::time::Duration::from_millis(8));
+    send_key(VK_C.0, true);
     Ok(())
 }
 
-// 模拟粘贴
+// 模拟复制（Ctrl+C）
 #[cfg(not(target_os = "windows"))]
-pub fn simulate_paste() -> Result<(), String> {
+pub fn simulate_copy() -> Result<(), String> {
+    release_modifier_keys()?;
+
     let mut enigo = Enigo::new(&Settings::default())
         .map_err(|e| format!("创建键盘模拟器失败: {}", e))?;
+    enigo.key(Key::Control, Direction::Press)
+        .map_err(|e| format!("按下Ctrl失败: {}", e))?;
+    let result = enigo.key(Key::Unicode('c'), Direction::Click)
+        .map_err(|e| format!("按下C失败: {}", e));
+    enigo.key(Key::Control, Direction::Release)
+        .map_err(|e| format!("释放Ctrl失败: {}", e))?;
+    result
+}
+
+// 模拟粘贴，失败时区分是否已发送粘贴键
+#[cfg(not(target_os = "windows"))]
+pub fn simulate_paste_keys() -> Result<(), PasteKeyError> {
+    let mut enigo = Enigo::new(&Settings::default())
+        .map_err(|e| PasteKeyError::before_key(format!("创建键盘模拟器失败: {}", e)))?;
     
     let (ctrl_pressed, _, _, _) = get_modifier_keys_state();
     
     if !ctrl_pressed {
         enigo.key(Key::Control, Direction::Press)
-            .map_err(|e| format!("按下Ctrl失败: {}", e))?;
+            .map_err(|e| PasteKeyError::before_key(format!("按下Ctrl失败: {}", e)))?;
     }
     
-    enigo.key(Key::Unicode('v'), Direction::Press)
-        .map_err(|e| format!("按下V失败: {}", e))?;
+    if let Err(e) = enigo.key(Key::Unicode('v'), Direction::Press) {
+        if !ctrl_pressed {
+            let _ = enigo.key(Key::Control, Direction::Release);
+        }
+        return Err(PasteKeyError::before_key(format!("按下V失败: {}", e)));
+    }
     
     std::thread::sleep(std::time::Duration::from_millis(8));
     
     enigo.key(Key::Unicode('v'), Direction::Release)
-        .map_err(|e| format!("释放V失败: {}", e))?;
+        .map_err(|e| PasteKeyError::after_key(format!("释放V失败: {}", e)))?;
     
     if !ctrl_pressed {
         enigo.key(Key::Control, Direction::Release)
-            .map_err(|e| format!("释放Ctrl失败: {}", e))?;
+            .map_err(|e| PasteKeyError::after_key(format!("释放Ctrl失败: {}", e)))?;
     }
     
     Ok(())
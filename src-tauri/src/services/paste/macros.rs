@@ -0,0 +1,168 @@
+// 剪贴板宏：按顺序保存的一组剪贴板项，每次按下快捷键粘贴下一项
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tauri::Emitter;
+
+use crate::services::store;
+
+const STORE_KEY_MACROS: &str = "clipboard_macros";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClipboardMacro {
+    pub name: String,
+    pub item_ids: Vec<i64>,
+    // 每项粘贴后注入的按键："none" | "tab" | "enter"
+    #[serde(default = "default_after_paste")]
+    pub after_paste: String,
+}
+
+fn default_after_paste() -> String {
+    "none".to_string()
+}
+
+// 当前回放状态
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MacroPlayback {
+    pub name: String,
+    pub position: usize,
+    pub total: usize,
+}
+
+static ACTIVE_MACRO: Lazy<Mutex<Option<MacroPlayback>>> = Lazy::new(|| Mutex::new(None));
+
+fn load_macros() -> Vec<ClipboardMacro> {
+    store::get::<Vec<ClipboardMacro>>(STORE_KEY_MACROS).unwrap_or_default()
+}
+
+pub fn list_macros() -> Vec<ClipboardMacro> {
+    load_macros()
+}
+
+// 保存宏（同名覆盖）
+pub fn save_macro(name: &str, item_ids: Vec<i64>, after_paste: Option<String>) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("宏名称不能为空".into());
+    }
+    if item_ids.is_empty() {
+        return Err("宏至少需要包含一个剪贴板项".into());
+    }
+    let after_paste = after_paste.unwrap_or_else(default_after_paste);
+    if !matches!(after_paste.as_str(), "none" | "tab" | "enter") {
+        return Err(format!("不支持的粘贴后按键: {}", after_paste));
+    }
+
+    let mut macros = load_macros();
+    let entry = ClipboardMacro { name: name.to_string(), item_ids, after_paste };
+    match macros.iter_mut().find(|m| m.name == name) {
+        Some(existing) => *existing = entry,
+        None => macros.push(entry),
+    }
+    store::set(STORE_KEY_MACROS, &macros)?;
+
+    // 正在回放的宏被修改后从头开始
+    let mut active = ACTIVE_MACRO.lock();
+    if active.as_ref().map(|p| p.name == name).unwrap_or(false) {
+        *active = None;
+    }
+    Ok(())
+}
+
+pub fn delete_macro(name: &str) -> Result<(), String> {
+    let mut macros = load_macros();
+    let before = macros.len();
+    macros.retain(|m| m.name != name);
+    if macros.len() == before {
+        return Err(format!("宏不存在: {}", name));
+    }
+    store::set(STORE_KEY_MACROS, &macros)?;
+
+    let mut active = ACTIVE_MACRO.lock();
+    if active.as_ref().map(|p| p.name == name).unwrap_or(false) {
+        *active = None;
+    }
+    Ok(())
+}
+
+// 设为当前回放的宏，从第一项开始
+pub fn start_macro(name: &str) -> Result<MacroPlayback, String> {
+    let m = load_macros()
+        .into_iter()
+        .find(|m| m.name == name)
+        .ok_or_else(|| format!("宏不存在: {}", name))?;
+    let playback = MacroPlayback { name: m.name, position: 0, total: m.item_ids.len() };
+    *ACTIVE_MACRO.lock() = Some(playback.clone());
+    Ok(playback)
+}
+
+pub fn stop_macro() {
+    *ACTIVE_MACRO.lock() = None;
+}
+
+pub fn get_macro_playback() -> Option<MacroPlayback> {
+    ACTIVE_MACRO.lock().clone()
+}
+
+// 粘贴当前宏的下一项；已删除的剪贴板项会被跳过
+// 最后一项粘贴后自动结束回放并发送 macro-finished 事件，再次按下不会重新开始
+pub fn paste_next_macro_step() -> Result<Option<MacroPlayback>, String> {
+    use crate::services::database::get_clipboard_item_by_id;
+    use crate::services::paste::paste_handler::paste_clipboard_item_with_update;
+
+    let Some(playback) = get_macro_playback() else { return Ok(None) };
+    let Some(m) = load_macros().into_iter().find(|m| m.name == playback.name) else {
+        stop_macro();
+        return Err(format!("宏不存在: {}", playback.name));
+    };
+
+    let mut position = playback.position;
+    let mut item = None;
+    while position < m.item_ids.len() {
+        let id = m.item_ids[position];
+        position += 1;
+        if let Some(found) = get_clipboard_item_by_id(id)? {
+            item = Some(found);
+            break;
+        }
+    }
+
+    if let Some(item) = item {
+        paste_clipboard_item_with_update(&item)?;
+        inject_after_paste(&m.after_paste)?;
+    }
+
+    let finished = position >= m.item_ids.len();
+    let next = MacroPlayback { name: m.name.clone(), position, total: m.item_ids.len() };
+    {
+        let mut active = ACTIVE_MACRO.lock();
+        // 回放期间宏可能已被停止或替换
+        if active.as_ref().map(|p| p.name != m.name).unwrap_or(true) {
+            return Ok(None);
+        }
+        *active = if finished { None } else { Some(next.clone()) };
+    }
+
+    if let Some(app) = crate::services::clipboard::get_app_handle() {
+        let event = if finished { "macro-finished" } else { "macro-step" };
+        let _ = app.emit(event, &next);
+    }
+    Ok(Some(next))
+}
+
+fn inject_after_paste(after_paste: &str) -> Result<(), String> {
+    use crate::services::paste::keyboard::simulate_key_click;
+    use enigo::Key;
+
+    let key = match after_paste {
+        "tab" => Key::Tab,
+        "enter" => Key::Return,
+        _ => return Ok(()),
+    };
+    // 等待目标应用处理完粘贴
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    simulate_key_click(key)
+}
@@ -3,11 +3,23 @@ pub mod text;
 mod file;
 pub mod keyboard;
 pub mod clipboard_content;
+pub mod recent;
+pub mod macros;
+pub mod ocr_paste;
+pub mod paste_stack;
+pub mod combined;
+pub mod compose;
+pub mod target;
+pub mod retry;
+mod self_target;
+pub mod format_toggle;
+mod image_resize;
 
 pub use text::PasteFormat;
 pub use clipboard_content::{
     FileInfo, FilesData, 
-    set_clipboard_from_item, set_clipboard_text, set_clipboard_rich_text, set_clipboard_files,
+    set_clipboard_from_item, set_clipboard_text, set_clipboard_rich_text, set_clipboard_rtf,
+    set_clipboard_files,
 };
 
 
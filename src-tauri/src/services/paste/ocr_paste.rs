@@ -0,0 +1,86 @@
+// OCR 粘贴：快速 OCR 截图识别完成后，将识别文本直接粘贴到之前的窗口
+// 识别结果由截图插件写入剪贴板，剪贴板监听捕获到文本时在此完成交接
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+// 等待识别结果的最长时间（包含用户框选区域的时间）
+const OCR_PASTE_TIMEOUT: Duration = Duration::from_secs(60);
+// 截图窗口在此时间内未出现视为启动失败
+const CAPTURE_APPEAR_TIMEOUT: Duration = Duration::from_secs(3);
+// 截图窗口关闭后等待识别结果写入剪贴板的时间，超过后视为已取消（按 Esc 取消或未识别出文本）
+const RESULT_GRACE: Duration = Duration::from_secs(5);
+const CAPTURE_POLL_INTERVAL: Duration = Duration::from_millis(200);
+const SCREENSHOT_WINDOW: &str = "screenshot";
+
+static OCR_PASTE_PENDING: Lazy<Mutex<Option<Instant>>> = Lazy::new(|| Mutex::new(None));
+
+// 标记下一次剪贴板文本为 OCR 结果，并在截图结束后清除未使用的标记，
+// 避免取消截图后下一次普通复制被当作识别结果粘贴
+pub fn arm(app: &AppHandle) {
+    let armed_at = Instant::now();
+    *OCR_PASTE_PENDING.lock() = Some(armed_at);
+    let app = app.clone();
+    std::thread::spawn(move || watch_capture(&app, armed_at));
+}
+
+pub fn cancel() {
+    *OCR_PASTE_PENDING.lock() = None;
+}
+
+fn is_armed_at(armed_at: Instant) -> bool {
+    *OCR_PASTE_PENDING.lock() == Some(armed_at)
+}
+
+fn capture_visible(app: &AppHandle) -> bool {
+    app.get_webview_window(SCREENSHOT_WINDOW)
+        .map(|window| window.is_visible().unwrap_or(false))
+        .unwrap_or(false)
+}
+
+// 等待截图窗口出现并关闭，之后仍未收到识别结果时清除本次标记（已被新的标记替换时不处理）
+fn watch_capture(app: &AppHandle, armed_at: Instant) {
+    let mut seen = false;
+    while is_armed_at(armed_at) && armed_at.elapsed() < OCR_PASTE_TIMEOUT {
+        if capture_visible(app) {
+            seen = true;
+        } else if seen || armed_at.elapsed() > CAPTURE_APPEAR_TIMEOUT {
+            break;
+        }
+        std::thread::sleep(CAPTURE_POLL_INTERVAL);
+    }
+    std::thread::sleep(RESULT_GRACE);
+
+    let mut pending = OCR_PASTE_PENDING.lock();
+    if *pending == Some(armed_at) {
+        *pending = None;
+    }
+}
+
+pub fn is_pending() -> bool {
+    OCR_PASTE_PENDING
+        .lock()
+        .map(|started| started.elapsed() < OCR_PASTE_TIMEOUT)
+        .unwrap_or(false)
+}
+
+// 取出等待标记，超时的标记视为无效
+pub fn take_pending() -> bool {
+    OCR_PASTE_PENDING
+        .lock()
+        .take()
+        .map(|started| started.elapsed() < OCR_PASTE_TIMEOUT)
+        .unwrap_or(false)
+}
+
+// 恢复之前的焦点窗口并粘贴识别文本（不再次记录到历史）
+pub fn paste_recognized_text(text: &str) -> Result<(), String> {
+    if text.trim().is_empty() {
+        return Ok(());
+    }
+    // 焦点切回目标窗口的等待由粘贴流程统一处理
+    let _ = crate::services::system::focus::restore_last_focus();
+    super::paste_handler::paste_text_direct(text)
+}
@@ -2,7 +2,8 @@ use clipboard_rs::ClipboardContext;
 use crate::services::database::ClipboardItem;
 use super::text::{paste_text, paste_rich_text, paste_rich_text_with_format, PasteFormat};
 use super::file::paste_files;
-use super::keyboard::simulate_paste;
+use super::keyboard::{type_text_unicode, wait_for_paste_target};
+use super::retry::simulate_paste_with_retry;
 use chrono;
 
 fn emit_paste_count_updated(id: i64) {
@@ -19,8 +20,52 @@ fn emit_favorite_paste_count_updated(id: &str) {
     }
 }
 
+// 是否使用直接输入方式粘贴纯文本
+fn use_send_input() -> bool {
+    crate::services::get_settings().paste_method == "send_input"
+}
+
+// 模拟粘贴前的准备：等待焦点回到目标窗口，并按设置处理密码输入框
+fn prepare_paste() -> Result<(), String> {
+    use crate::services::system::password_field::{confirm_password_paste, target_is_password};
+
+    wait_for_paste_target();
+    super::self_target::ensure_external_target()?;
+
+    let mode = crate::services::get_settings().password_field_paste_mode;
+    if mode == "allow" || !target_is_password() {
+        return Ok(());
+    }
+
+    if mode == "block" {
+        if let Some(app) = crate::services::clipboard::get_app_handle() {
+            let _ = crate::services::notification::show_notification(&app, "QuickClipboard", "目标为密码输入框，已阻止粘贴");
+        }
+        return Err("目标为密码输入框，已阻止粘贴".to_string());
+    }
+
+    if !confirm_password_paste() {
+        return Err("已取消粘贴到密码输入框".to_string());
+    }
+    wait_for_paste_target();
+    Ok(())
+}
+
+// 直接输入纯文本（不经过剪贴板）
+fn type_plain_text(text: &str) -> Result<(), String> {
+    crate::services::mark_paste_operation();
+    prepare_paste()?;
+    type_text_unicode(text)?;
+    crate::AppSounds::play_paste_on_success();
+    Ok(())
+}
+
 // 直接粘贴文本
 pub fn paste_text_direct(text: &str) -> Result<(), String> {
+    if use_send_input() {
+        return type_plain_text(text);
+    }
+
     crate::services::clipboard::set_last_hash_text(text);
 
     crate::services::mark_paste_operation();
@@ -30,8 +75,8 @@ pub fn paste_text_direct(text: &str) -> Result<(), String> {
     
     paste_text(&ctx, text)?;
     
-    std::thread::sleep(std::time::Duration::from_millis(50));
-    simulate_paste()?;
+    prepare_paste()?;
+    simulate_paste_with_retry(Some(text))?;
     std::thread::sleep(std::time::Duration::from_millis(100));
     crate::AppSounds::play_paste_on_success();
     Ok(())
@@ -55,8 +100,79 @@ pub fn paste_image_file(file_path: &str) -> Result<(), String> {
     
     set_clipboard_files(&ctx, vec![file_path.to_string()])?;
     
-    std::thread::sleep(std::time::Duration::from_millis(50));
-    simulate_paste()?;
+    prepare_paste()?;
+    simulate_paste_with_retry(None)?;
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    crate::AppSounds::play_paste_on_success();
+    
+    Ok(())
+}
+
+// 组合项的粘贴方式
+enum CompositePaste {
+    ImageOnly,
+    Both,
+}
+
+// 组合项附带的图片路径（首个图片ID，仅文本类主类型且带 image 类型时有效）
+fn composite_image_path(item: &ClipboardItem, primary_type: &str) -> Option<String> {
+    if !matches!(primary_type, "text" | "link" | "rich_text") {
+        return None;
+    }
+    if !item.content_type.split(',').any(|t| t.trim() == "image") {
+        return None;
+    }
+    let image_id = item.image_id.as_deref()?.split(',').next()?.trim();
+    if image_id.is_empty() {
+        return None;
+    }
+    let path = crate::services::get_data_directory().ok()?
+        .join("clipboard_images")
+        .join(format!("{}.png", image_id));
+    path.exists().then(|| path.to_string_lossy().to_string())
+}
+
+// 粘贴组合项
+fn paste_composite(item: &ClipboardItem, image_path: &str, mode: CompositePaste) -> Result<(), String> {
+    use super::clipboard_content::{set_clipboard_image, set_clipboard_text_with_image};
+    
+    let stored_path = format!("clipboard_images/{}", std::path::Path::new(image_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default());
+    crate::services::clipboard::set_last_hash_composite(&item.content, &stored_path);
+    crate::services::mark_paste_operation();
+    
+    let ctx = ClipboardContext::new()
+        .map_err(|e| format!("创建剪贴板上下文失败: {}", e))?;
+    
+    match mode {
+        CompositePaste::ImageOnly => set_clipboard_image(&ctx, image_path)?,
+        CompositePaste::Both => {
+            let html = item.html_content.as_deref()
+                .filter(|_| super::format_toggle::paste_with_format());
+            set_clipboard_text_with_image(&ctx, &item.content, html, image_path)?
+        }
+    }
+    
+    prepare_paste()?;
+    simulate_paste_with_retry(None)?;
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    crate::AppSounds::play_paste_on_success();
+    
+    Ok(())
+}
+
+// 粘贴缩放/重新编码后的图片（原图不变）
+fn paste_resized_image(image_path: &str) -> Result<(), String> {
+    crate::services::mark_paste_operation();
+    
+    let ctx = ClipboardContext::new()
+        .map_err(|e| format!("创建剪贴板上下文失败: {}", e))?;
+    super::image_resize::set_clipboard_resized_image(&ctx, std::path::Path::new(image_path))?;
+    
+    prepare_paste()?;
+    simulate_paste_with_retry(None)?;
     std::thread::sleep(std::time::Duration::from_millis(100));
     crate::AppSounds::play_paste_on_success();
     
@@ -65,24 +181,25 @@ pub fn paste_image_file(file_path: &str) -> Result<(), String> {
 
 // 粘贴剪贴板项
 pub fn paste_clipboard_item(item: &ClipboardItem) -> Result<(), String> {
-    paste_item_internal(item, None, None, None)
+    paste_item_internal(item, None, None, None).map(|_| ())
 }
 
 // 粘贴剪贴板项并自动转换旧格式（更新 clipboard 表）
 pub fn paste_clipboard_item_with_update(item: &ClipboardItem) -> Result<(), String> {
-    let result = paste_item_internal(item, Some(item.id), None, None);
-    if result.is_ok() {
-        let _ = crate::services::database::increment_paste_count(item.id);
-        emit_paste_count_updated(item.id);
-    }
-    result
+    let inserted = paste_item_internal(item, Some(item.id), None, None)?;
+    let _ = crate::services::database::increment_paste_count(item.id);
+    super::recent::record_pasted(item.id);
+    super::paste_stack::record_paste(item.id, inserted.as_deref());
+    emit_paste_count_updated(item.id);
+    Ok(())
 }
 
 // 粘贴收藏项并自动转换旧格式（更新 favorites 表）
 pub fn paste_favorite_item_with_update(item: &ClipboardItem, favorite_id: &str) -> Result<(), String> {
-    let result = paste_item_internal(item, None, Some(favorite_id.to_string()), None);
+    let result = paste_item_internal(item, None, Some(favorite_id.to_string()), None).map(|_| ());
     if result.is_ok() {
         let _ = crate::services::database::increment_favorite_paste_count(favorite_id);
+        super::paste_stack::clear();
         emit_favorite_paste_count_updated(favorite_id);
     }
     result
@@ -90,31 +207,50 @@ pub fn paste_favorite_item_with_update(item: &ClipboardItem, favorite_id: &str)
 
 // 粘贴剪贴板项（指定格式）
 pub fn paste_clipboard_item_with_format(item: &ClipboardItem, format: Option<PasteFormat>) -> Result<(), String> {
-    let result = paste_item_internal(item, Some(item.id), None, format);
-    if result.is_ok() {
-        let _ = crate::services::database::increment_paste_count(item.id);
-        emit_paste_count_updated(item.id);
-    }
-    result
+    let inserted = paste_item_internal(item, Some(item.id), None, format)?;
+    let _ = crate::services::database::increment_paste_count(item.id);
+    super::recent::record_pasted(item.id);
+    super::paste_stack::record_paste(item.id, inserted.as_deref());
+    emit_paste_count_updated(item.id);
+    Ok(())
 }
 
 // 粘贴收藏项（指定格式）
 pub fn paste_favorite_item_with_format(item: &ClipboardItem, favorite_id: &str, format: Option<PasteFormat>) -> Result<(), String> {
-    let result = paste_item_internal(item, None, Some(favorite_id.to_string()), format);
+    let result = paste_item_internal(item, None, Some(favorite_id.to_string()), format).map(|_| ());
     if result.is_ok() {
         let _ = crate::services::database::increment_favorite_paste_count(favorite_id);
+        super::paste_stack::clear();
         emit_favorite_paste_count_updated(favorite_id);
     }
     result
 }
 
-// 内部粘贴实现
+// 内部粘贴实现：按优先级确定格式（见 resolve_paste_format），成功后结束格式粘贴的临时翻转
+// 返回文本项实际插入的文本（非文本粘贴返回 None）
 fn paste_item_internal(
+    item: &ClipboardItem,
+    clipboard_id: Option<i64>,
+    favorite_id: Option<String>,
+    format: Option<PasteFormat>
+) -> Result<Option<String>, String> {
+    let format = super::text::resolve_paste_format(
+        item.forced_format.as_deref().and_then(PasteFormat::from_key),
+        format,
+        super::text::app_default_format(),
+        super::text::content_type_default_format(&item.content_type),
+    );
+    let inserted = paste_item_inner(item, clipboard_id, favorite_id, format)?;
+    super::format_toggle::consume_momentary();
+    Ok(inserted)
+}
+
+fn paste_item_inner(
     item: &ClipboardItem, 
     clipboard_id: Option<i64>, 
     favorite_id: Option<String>,
     format: Option<PasteFormat>
-) -> Result<(), String> {
+) -> Result<Option<String>, String> {
     let primary_type = item.content_type.split(',').next().unwrap_or(&item.content_type);
     
     // 检查并转换旧格式图片
@@ -133,6 +269,49 @@ fn paste_item_internal(
         item.content.clone()
     };
     
+    // 组合项（文本附带图片）：按请求格式协商，未指定格式时同时提供文本与图片
+    let composite_image = composite_image_path(item, primary_type);
+    if let Some(image_path) = composite_image.as_deref() {
+        match format {
+            Some(PasteFormat::Image) => return paste_composite(item, image_path, CompositePaste::ImageOnly).map(|_| None),
+            Some(PasteFormat::ResizedImage) => return paste_resized_image(image_path).map(|_| None),
+            None => return paste_composite(item, image_path, CompositePaste::Both).map(|_| None),
+            _ => {}
+        }
+    }
+    
+    // 图片项按设置缩放/重新编码后粘贴
+    if primary_type == "image" && matches!(format, Some(PasteFormat::ResizedImage)) {
+        let paths = super::clipboard_content::parse_files_content_existing(&content)?;
+        let image_path = paths.first().ok_or("图片文件不存在")?;
+        return paste_resized_image(image_path).map(|_| None);
+    }
+    
+    // 文件项以路径文本粘贴（仅在明确选择路径格式时）
+    if primary_type == "file" && matches!(format, Some(PasteFormat::FilePaths)) {
+        return paste_text_direct(&super::file::files_content_as_text(&content)?).map(|_| None);
+    }
+    
+    let is_text = matches!(primary_type, "text" | "link" | "rich_text");
+    let inserted = is_text.then(|| super::text::inserted_text(&item.content, &item.html_content, format));
+    
+    if is_text && use_send_input() {
+        if matches!(format, Some(PasteFormat::Quoted)) {
+            let quoted = super::text::quote_with_settings(&item.content);
+            type_plain_text(&quoted)?;
+            return Ok(Some(quoted));
+        }
+        let is_plain = match format {
+            Some(PasteFormat::PlainText) => true,
+            Some(_) => item.html_content.is_none() && item.rtf_content.is_none(),
+            None => item.html_content.is_none() || !super::format_toggle::paste_with_format(),
+        };
+        if is_plain {
+            type_plain_text(&item.content)?;
+            return Ok(Some(item.content.clone()));
+        }
+    }
+    
     match primary_type {
         "text" | "link" | "rich_text" => {
             crate::services::clipboard::set_last_hash_text(&item.content);
@@ -151,7 +330,7 @@ fn paste_item_internal(
     match primary_type {
         "text" | "link" | "rich_text" => {
             if let Some(paste_format) = format {
-                paste_rich_text_with_format(&ctx, &item.content, &item.html_content, paste_format)?
+                paste_rich_text_with_format(&ctx, &item.content, &item.html_content, &item.rtf_content, paste_format)?
             } else {
                 paste_rich_text(&ctx, &item.content, &item.html_content)?
             }
@@ -160,12 +339,13 @@ fn paste_item_internal(
         _ => return Err(format!("不支持的内容类型: {}", item.content_type)),
     }
     
-    std::thread::sleep(std::time::Duration::from_millis(50));
-    simulate_paste()?;
+    // 文本类可回退为直接输入，输入按格式转换后的文本（与实际粘贴的内容一致）
+    prepare_paste()?;
+    simulate_paste_with_retry(inserted.as_deref())?;
     std::thread::sleep(std::time::Duration::from_millis(100));
     crate::AppSounds::play_paste_on_success();
     
-    Ok(())
+    Ok(inserted)
 }
 
 // 转换旧格式图片为新格式（files:{json}）
@@ -0,0 +1,133 @@
+// 粘贴栈（类似 Emacs kill-ring 的 yank-pop）：
+// 通过快捷键粘贴文本后，按下 paste_pop 快捷键会向回选中刚插入的文本，
+// 并用更早的一项覆盖，连续按下依次向更早的项循环
+
+use parking_lot::Mutex;
+use std::time::{Duration, Instant};
+
+// 上次粘贴超过该时长后不再允许替换（期间用户很可能已移动光标）
+const POP_TIMEOUT: Duration = Duration::from_secs(30);
+// 向更早项查找时每批读取的数量
+const POP_SEARCH_BATCH: usize = 50;
+
+// 上一次粘贴的文本信息
+#[derive(Debug, Clone)]
+struct LastPaste {
+    item_id: i64,
+    // 插入内容占用的光标步数（向回选择时按 Shift+Left 的次数）
+    inserted_len: usize,
+    // 粘贴时的前台窗口
+    target_hwnd: Option<isize>,
+    at: Instant,
+}
+
+static LAST_PASTE: Mutex<Option<LastPaste>> = Mutex::new(None);
+
+// 记录一次粘贴：inserted 为按粘贴格式实际插入的文本（引用、Markdown 等与原内容长度不同），
+// 仅文本粘贴可被替换，非文本粘贴（inserted 为 None）清除记录
+pub fn record_paste(item_id: i64, inserted: Option<&str>) {
+    let mut last = LAST_PASTE.lock();
+    *last = inserted.filter(|_| item_id > 0).map(|text| LastPaste {
+        item_id,
+        inserted_len: caret_length(text),
+        target_hwnd: crate::services::system::focus::get_foreground_hwnd(),
+        at: Instant::now(),
+    });
+}
+
+// 清除记录（如有新的复制或粘贴了非文本内容）
+pub fn clear() {
+    *LAST_PASTE.lock() = None;
+}
+
+// 计算文本插入后占用的光标步数：\r\n 视为一步，代理对字符视为一步
+fn caret_length(text: &str) -> usize {
+    text.chars().count() - text.matches("\r\n").count()
+}
+
+// 用更早的一项替换刚粘贴的文本，返回新粘贴的项 ID（无可替换记录时返回 None）
+pub fn paste_pop() -> Result<Option<i64>, String> {
+    use crate::services::database::get_clipboard_item_by_id;
+    use super::paste_handler::paste_clipboard_item_with_format;
+    use super::PasteFormat;
+
+    let Some(last) = LAST_PASTE.lock().clone() else {
+        return Ok(None);
+    };
+
+    if last.at.elapsed() > POP_TIMEOUT {
+        clear();
+        return Ok(None);
+    }
+
+    // 焦点已切换到其他窗口时无法安全替换
+    if last.target_hwnd != crate::services::system::focus::get_foreground_hwnd() {
+        clear();
+        return Ok(None);
+    }
+
+    let Some(next_id) = find_older_text_item(last.item_id)? else {
+        return Ok(None);
+    };
+    let next = get_clipboard_item_by_id(next_id)?
+        .ok_or_else(|| format!("剪贴板项 {} 不存在", next_id))?;
+
+    super::keyboard::select_backward(last.inserted_len)?;
+    // 以纯文本覆盖选区（项的强制格式仍优先），下次替换时按实际插入的文本长度选择
+    paste_clipboard_item_with_format(&next, Some(PasteFormat::PlainText))?;
+    Ok(Some(next_id))
+}
+
+// 按 ID 倒序查找比当前项更早的文本项，已到最早时从最新一项重新开始
+fn find_older_text_item(current_id: i64) -> Result<Option<i64>, String> {
+    if let Some(id) = scan_text_items(Some(current_id), current_id)? {
+        return Ok(Some(id));
+    }
+    scan_text_items(None, current_id)
+}
+
+fn scan_text_items(start: Option<i64>, exclude_id: i64) -> Result<Option<i64>, String> {
+    use crate::services::database::{query_clipboard_items_after, QueryParams};
+
+    let params = QueryParams {
+        offset: 0,
+        limit: 0,
+        search: None,
+        content_type: None,
+        created_before: None,
+        with_highlights: false,
+        exclude_local_only: false,
+    };
+
+    let mut cursor = start;
+    loop {
+        let batch = query_clipboard_items_after(&params, cursor, POP_SEARCH_BATCH)?;
+        let found = batch.items.iter().find(|item| {
+            let primary_type = item.content_type.split(',').next().unwrap_or(&item.content_type);
+            item.id != exclude_id
+                && matches!(primary_type, "text" | "link" | "rich_text")
+                && !item.content.is_empty()
+        });
+        if let Some(item) = found {
+            return Ok(Some(item.id));
+        }
+        match batch.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => return Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_caret_length() {
+        assert_eq!(caret_length("hello"), 5);
+        assert_eq!(caret_length("中文"), 2);
+        assert_eq!(caret_length("a\r\nb"), 3);
+        assert_eq!(caret_length("a\nb"), 3);
+        assert_eq!(caret_length("😀x"), 2);
+    }
+}
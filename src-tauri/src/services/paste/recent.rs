@@ -0,0 +1,33 @@
+// 最近粘贴记录（与复制时间顺序无关，持久化到 store）
+
+use crate::services::store;
+
+const STORE_KEY_RECENTLY_PASTED: &str = "recently_pasted";
+const MAX_RECENTLY_PASTED: usize = 20;
+
+// 记录一次粘贴（最新的排在最前）
+pub fn record_pasted(id: i64) {
+    let mut ids = store::get::<Vec<i64>>(STORE_KEY_RECENTLY_PASTED).unwrap_or_default();
+    ids.retain(|existing| *existing != id);
+    ids.insert(0, id);
+    ids.truncate(MAX_RECENTLY_PASTED);
+    let _ = store::set(STORE_KEY_RECENTLY_PASTED, &ids);
+}
+
+// 获取最近粘贴的前 n 个剪贴板项（跳过已删除的项）
+pub fn get_recently_pasted(n: usize) -> Result<Vec<crate::services::database::ClipboardItem>, String> {
+    use crate::services::database::get_clipboard_item_by_id_with_limit;
+    use crate::services::database::connection::MAX_CONTENT_LENGTH;
+
+    let ids = store::get::<Vec<i64>>(STORE_KEY_RECENTLY_PASTED).unwrap_or_default();
+    let mut items = Vec::new();
+    for id in ids {
+        if items.len() >= n {
+            break;
+        }
+        if let Some(item) = get_clipboard_item_by_id_with_limit(id, Some(MAX_CONTENT_LENGTH))? {
+            items.push(item);
+        }
+    }
+    Ok(items)
+}
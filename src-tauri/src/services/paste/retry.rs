@@ -0,0 +1,201 @@
+// 模拟粘贴失败时的重试与回退：按设置重试模拟粘贴键，文本项可回退为直接输入文本
+// 只有粘贴键按下之前的失败会重试或回退，之后的失败（如释放按键失败）目标窗口可能已粘贴，直接报错
+
+use super::keyboard::PasteKeyError;
+use serde::Serialize;
+use std::time::Duration;
+
+// 重试策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    // 首次失败后的重试次数
+    pub retries: u32,
+    pub delay: Duration,
+    // 重试仍失败时是否回退为直接输入文本
+    pub fallback_to_send_input: bool,
+}
+
+impl RetryPolicy {
+    pub fn from_settings() -> Self {
+        let settings = crate::get_settings();
+        Self {
+            retries: settings.paste_retry_count,
+            delay: Duration::from_millis(settings.paste_retry_delay_ms),
+            fallback_to_send_input: settings.paste_fallback_send_input,
+        }
+    }
+}
+
+// 一次模拟粘贴失败后的下一步
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NextStep {
+    Retry(Duration),
+    Fallback,
+    Fail,
+}
+
+// failed_attempts 为已失败的次数（含首次），有可直接输入的文本时才允许回退
+fn next_step(policy: &RetryPolicy, failed_attempts: u32, can_fallback: bool) -> NextStep {
+    if failed_attempts <= policy.retries {
+        NextStep::Retry(policy.delay)
+    } else if policy.fallback_to_send_input && can_fallback {
+        NextStep::Fallback
+    } else {
+        NextStep::Fail
+    }
+}
+
+// 粘贴结果（通过 paste-result 事件发送给前端）
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasteResult {
+    pub success: bool,
+    // 模拟粘贴的尝试次数
+    pub attempts: u32,
+    // 是否回退为直接输入文本
+    pub fallback: bool,
+    pub error: Option<String>,
+}
+
+// 按策略执行模拟粘贴，fallback_text 为可直接输入的文本（非文本项为 None）
+pub fn run_with_retry<P, T>(
+    policy: &RetryPolicy,
+    fallback_text: Option<&str>,
+    mut paste: P,
+    type_text: T,
+) -> PasteResult
+where
+    P: FnMut() -> Result<(), PasteKeyError>,
+    T: FnOnce(&str) -> Result<(), String>,
+{
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        let error = match paste() {
+            Ok(()) => return PasteResult { success: true, attempts, fallback: false, error: None },
+            Err(e) if e.key_sent => {
+                return PasteResult { success: false, attempts, fallback: false, error: Some(e.message) };
+            }
+            Err(e) => e.message,
+        };
+        match next_step(policy, attempts, fallback_text.is_some()) {
+            NextStep::Retry(delay) => {
+                eprintln!("模拟粘贴失败（第 {} 次），稍后重试: {}", attempts, error);
+                std::thread::sleep(delay);
+            }
+            NextStep::Fallback => {
+                let result = type_text(fallback_text.unwrap_or_default());
+                return PasteResult {
+                    success: result.is_ok(),
+                    attempts,
+                    fallback: true,
+                    error: result.err().map(|e| format!("{}；回退直接输入也失败: {}", error, e)),
+                };
+            }
+            NextStep::Fail => {
+                return PasteResult { success: false, attempts, fallback: false, error: Some(error) };
+            }
+        }
+    }
+}
+
+// 执行模拟粘贴（含重试与回退）并发送 paste-result 事件
+pub fn simulate_paste_with_retry(fallback_text: Option<&str>) -> Result<(), String> {
+    use tauri::Emitter;
+
+    let policy = RetryPolicy::from_settings();
+    let result = run_with_retry(
+        &policy,
+        fallback_text,
+        super::keyboard::simulate_paste_keys,
+        super::keyboard::type_text_unicode,
+    );
+    if let Some(app) = crate::services::clipboard::get_app_handle() {
+        let _ = app.emit("paste-result", &result);
+    }
+    match result.error {
+        Some(error) if !result.success => Err(error),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn policy(retries: u32, fallback: bool) -> RetryPolicy {
+        RetryPolicy { retries, delay: Duration::ZERO, fallback_to_send_input: fallback }
+    }
+
+    #[test]
+    fn test_next_step() {
+        let p = policy(2, true);
+        assert_eq!(next_step(&p, 1, true), NextStep::Retry(Duration::ZERO));
+        assert_eq!(next_step(&p, 2, true), NextStep::Retry(Duration::ZERO));
+        assert_eq!(next_step(&p, 3, true), NextStep::Fallback);
+        // 非文本项不能回退
+        assert_eq!(next_step(&p, 3, false), NextStep::Fail);
+        assert_eq!(next_step(&policy(0, false), 1, true), NextStep::Fail);
+    }
+
+    #[test]
+    fn test_retry_succeeds_after_failure() {
+        let calls = Cell::new(0);
+        let result = run_with_retry(
+            &policy(2, false),
+            None,
+            || {
+                calls.set(calls.get() + 1);
+                if calls.get() < 2 { Err(PasteKeyError::before_key("SendInput 被阻止".to_string())) } else { Ok(()) }
+            },
+            |_| panic!("不应回退"),
+        );
+        assert_eq!(result, PasteResult { success: true, attempts: 2, fallback: false, error: None });
+    }
+
+    #[test]
+    fn test_fallback_types_text_after_retries() {
+        let typed = Cell::new(false);
+        let result = run_with_retry(
+            &policy(1, true),
+            Some("hello"),
+            || Err(PasteKeyError::before_key("无焦点".to_string())),
+            |text| {
+                assert_eq!(text, "hello");
+                typed.set(true);
+                Ok(())
+            },
+        );
+        assert!(typed.get());
+        assert!(result.success && result.fallback);
+        assert_eq!(result.attempts, 2);
+    }
+
+    #[test]
+    fn test_failure_reports_last_error() {
+        let result = run_with_retry(
+            &policy(1, true),
+            None,
+            || Err(PasteKeyError::before_key("失败".to_string())),
+            |_| Ok(()),
+        );
+        assert_eq!(result, PasteResult { success: false, attempts: 2, fallback: false, error: Some("失败".to_string()) });
+    }
+
+    #[test]
+    fn test_failure_after_key_sent_is_not_retried() {
+        let calls = Cell::new(0);
+        let result = run_with_retry(
+            &policy(2, true),
+            Some("hello"),
+            || {
+                calls.set(calls.get() + 1);
+                Err(PasteKeyError::after_key("释放V失败".to_string()))
+            },
+            |_| panic!("已发送粘贴键时不应回退"),
+        );
+        assert_eq!(calls.get(), 1);
+        assert_eq!(result, PasteResult { success: false, attempts: 1, fallback: false, error: Some("释放V失败".to_string()) });
+    }
+}
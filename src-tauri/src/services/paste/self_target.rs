@@ -0,0 +1,100 @@
+// 粘贴目标为本应用自身窗口（如设置窗口）时的处理：粘贴到自身几乎不是期望行为，还可能引起循环
+// 按设置跳过粘贴（默认，发送 paste-target-self 警告事件）、切回记录的上一个外部窗口后粘贴，或照常粘贴
+
+use serde::Serialize;
+use std::time::Duration;
+use tauri::Emitter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SelfPasteMode {
+    Skip,
+    Retarget,
+    Allow,
+}
+
+impl SelfPasteMode {
+    fn from_setting(value: &str) -> Self {
+        match value {
+            "retarget" => Self::Retarget,
+            "allow" => Self::Allow,
+            _ => Self::Skip,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SelfTargetAction {
+    Proceed,
+    Skip,
+    // 激活记录的外部窗口后再粘贴
+    Activate(isize),
+}
+
+// own_foreground 为前台窗口是否属于本应用，remembered 为最近获得焦点的外部窗口
+fn decide(mode: SelfPasteMode, own_foreground: bool, remembered: Option<isize>) -> SelfTargetAction {
+    if !own_foreground {
+        return SelfTargetAction::Proceed;
+    }
+    match mode {
+        SelfPasteMode::Allow => SelfTargetAction::Proceed,
+        SelfPasteMode::Skip => SelfTargetAction::Skip,
+        SelfPasteMode::Retarget => remembered.map_or(SelfTargetAction::Skip, SelfTargetAction::Activate),
+    }
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SelfTargetEvent {
+    // 尝试切回外部窗口但未成功（或没有记录的外部窗口）
+    retarget_failed: bool,
+}
+
+fn skip_paste(retarget_failed: bool) -> Result<(), String> {
+    if let Some(app) = crate::services::clipboard::get_app_handle() {
+        let _ = app.emit("paste-target-self", SelfTargetEvent { retarget_failed });
+    }
+    Err("粘贴目标是本应用的窗口，已跳过粘贴".to_string())
+}
+
+// 粘贴前检查前台窗口，目标为本应用时按设置跳过或切换目标
+pub fn ensure_external_target() -> Result<(), String> {
+    use crate::services::system::focus::{foreground_is_own_app, get_last_focus_hwnd, set_foreground_hwnd, wait_for_external_foreground};
+
+    let settings = crate::get_settings();
+    let mode = SelfPasteMode::from_setting(&settings.self_paste_mode);
+    match decide(mode, foreground_is_own_app(), get_last_focus_hwnd()) {
+        SelfTargetAction::Proceed => Ok(()),
+        SelfTargetAction::Skip => skip_paste(mode == SelfPasteMode::Retarget),
+        SelfTargetAction::Activate(hwnd) => {
+            set_foreground_hwnd(hwnd);
+            if !wait_for_external_foreground(Duration::from_millis(settings.paste_focus_wait_max_ms)) {
+                return skip_paste(true);
+            }
+            std::thread::sleep(Duration::from_millis(settings.paste_delay_ms));
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_external_target_always_proceeds() {
+        for mode in [SelfPasteMode::Skip, SelfPasteMode::Retarget, SelfPasteMode::Allow] {
+            assert_eq!(decide(mode, false, None), SelfTargetAction::Proceed);
+        }
+    }
+
+    #[test]
+    fn test_self_target_by_mode() {
+        assert_eq!(decide(SelfPasteMode::from_setting("skip"), true, Some(42)), SelfTargetAction::Skip);
+        assert_eq!(decide(SelfPasteMode::from_setting("allow"), true, Some(42)), SelfTargetAction::Proceed);
+        assert_eq!(decide(SelfPasteMode::from_setting("retarget"), true, Some(42)), SelfTargetAction::Activate(42));
+        // 没有记录的外部窗口时无法切换，跳过粘贴
+        assert_eq!(decide(SelfPasteMode::Retarget, true, None), SelfTargetAction::Skip);
+        // 未知的设置值按默认方式跳过
+        assert_eq!(SelfPasteMode::from_setting(""), SelfPasteMode::Skip);
+    }
+}
@@ -0,0 +1,165 @@
+// 粘贴到指定窗口：按标题或句柄查找目标窗口，激活并确认获得焦点后再粘贴（用于自动化）
+
+use serde::Deserialize;
+use std::time::Duration;
+
+// 默认的激活等待时间
+const DEFAULT_ACTIVATE_TIMEOUT_MS: u64 = 1500;
+
+// 目标窗口
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum WindowTarget {
+    // 标题包含指定文本（不区分大小写）的第一个可见顶层窗口
+    Title { title: String },
+    // 窗口句柄（HWND）
+    Handle { hwnd: isize },
+}
+
+// 标题是否匹配（不区分大小写的子串匹配）
+#[cfg(windows)]
+fn title_matches(title: &str, query: &str) -> bool {
+    title.to_lowercase().contains(&query.to_lowercase())
+}
+
+// 校验目标参数
+fn validate_target(target: &WindowTarget) -> Result<(), String> {
+    match target {
+        WindowTarget::Title { title } if title.trim().is_empty() => Err("窗口标题不能为空".to_string()),
+        WindowTarget::Handle { hwnd } if *hwnd == 0 => Err("窗口句柄无效".to_string()),
+        _ => Ok(()),
+    }
+}
+
+// 激活目标窗口后粘贴剪贴板项，timeout_ms 为等待目标获得焦点的最长时间
+pub fn paste_to_window(target: &WindowTarget, id: i64, timeout_ms: Option<u64>) -> Result<(), String> {
+    use crate::services::database::get_clipboard_item_by_id;
+
+    validate_target(target)?;
+    let item = get_clipboard_item_by_id(id)?
+        .ok_or_else(|| format!("剪贴板项不存在: {}", id))?;
+    let hwnd = find_target_window(target)?;
+    let timeout = Duration::from_millis(timeout_ms.unwrap_or(DEFAULT_ACTIVATE_TIMEOUT_MS));
+    activate_and_verify(hwnd, timeout)?;
+    super::paste_handler::paste_clipboard_item_with_update(&item)
+}
+
+#[cfg(windows)]
+struct TitleSearch {
+    query: String,
+    found: Option<isize>,
+}
+
+// 查找目标窗口句柄
+#[cfg(windows)]
+fn find_target_window(target: &WindowTarget) -> Result<isize, String> {
+    use std::ffi::c_void;
+    use windows::Win32::Foundation::{HWND, LPARAM};
+    use windows::Win32::UI::WindowsAndMessaging::{EnumWindows, IsWindow};
+
+    match target {
+        WindowTarget::Handle { hwnd } => {
+            if unsafe { IsWindow(Some(HWND(*hwnd as *mut c_void))).as_bool() } {
+                Ok(*hwnd)
+            } else {
+                Err(format!("窗口不存在: {:#x}", hwnd))
+            }
+        }
+        WindowTarget::Title { title } => {
+            let mut search = TitleSearch { query: title.trim().to_string(), found: None };
+            unsafe {
+                let _ = EnumWindows(Some(enum_title_callback), LPARAM(&mut search as *mut TitleSearch as isize));
+            }
+            search.found.ok_or_else(|| format!("未找到标题包含“{}”的窗口", title.trim()))
+        }
+    }
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn enum_title_callback(
+    hwnd: windows::Win32::Foundation::HWND,
+    lparam: windows::Win32::Foundation::LPARAM,
+) -> windows::core::BOOL {
+    use windows::core::BOOL;
+    use windows::Win32::System::Threading::GetCurrentProcessId;
+    use windows::Win32::UI::WindowsAndMessaging::{GetWindowTextW, GetWindowThreadProcessId, IsWindowVisible};
+
+    let search = &mut *(lparam.0 as *mut TitleSearch);
+
+    if !IsWindowVisible(hwnd).as_bool() {
+        return BOOL(1);
+    }
+    // 跳过本应用窗口，避免匹配到自身
+    let mut pid: u32 = 0;
+    GetWindowThreadProcessId(hwnd, Some(&mut pid));
+    if pid == 0 || pid == GetCurrentProcessId() {
+        return BOOL(1);
+    }
+
+    let mut title_buf = [0u16; 512];
+    let title_len = GetWindowTextW(hwnd, &mut title_buf);
+    if title_len <= 0 {
+        return BOOL(1);
+    }
+    let title = String::from_utf16_lossy(&title_buf[..title_len as usize]);
+    if title_matches(&title, &search.query) {
+        search.found = Some(hwnd.0 as isize);
+        return BOOL(0);
+    }
+    BOOL(1)
+}
+
+// 激活窗口并等待其成为前台窗口，超时报错（不在焦点不确定时粘贴）
+#[cfg(windows)]
+fn activate_and_verify(hwnd: isize, timeout: Duration) -> Result<(), String> {
+    use std::time::Instant;
+    use crate::services::system::focus::get_foreground_hwnd;
+
+    crate::services::system::source_app::activate_window(hwnd);
+    let started = Instant::now();
+    loop {
+        if get_foreground_hwnd() == Some(hwnd) {
+            // 给目标窗口处理焦点切换留出时间
+            std::thread::sleep(Duration::from_millis(50));
+            return Ok(());
+        }
+        if started.elapsed() >= timeout {
+            return Err(format!("激活目标窗口超时（{} 毫秒）", timeout.as_millis()));
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
+
+#[cfg(not(windows))]
+fn find_target_window(_target: &WindowTarget) -> Result<isize, String> {
+    Err("当前平台不支持粘贴到指定窗口".to_string())
+}
+
+#[cfg(not(windows))]
+fn activate_and_verify(_hwnd: isize, _timeout: Duration) -> Result<(), String> {
+    Err("当前平台不支持粘贴到指定窗口".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(windows)]
+    #[test]
+    fn test_title_matches_case_insensitive_substring() {
+        assert!(title_matches("Untitled - Notepad", "notepad"));
+        assert!(title_matches("README.md - Visual Studio Code", "Visual Studio"));
+        assert!(!title_matches("Calculator", "notepad"));
+    }
+
+    #[test]
+    fn test_target_deserialize_and_validate() {
+        let target: WindowTarget = serde_json::from_str(r#"{"kind":"title","title":"记事本"}"#).unwrap();
+        assert!(validate_target(&target).is_ok());
+        let target: WindowTarget = serde_json::from_str(r#"{"kind":"handle","hwnd":131844}"#).unwrap();
+        assert!(matches!(target, WindowTarget::Handle { hwnd: 131844 }));
+
+        assert!(validate_target(&WindowTarget::Title { title: "  ".to_string() }).is_err());
+        assert!(validate_target(&WindowTarget::Handle { hwnd: 0 }).is_err());
+    }
+}
@@ -1,10 +1,139 @@
 use clipboard_rs::ClipboardContext;
-use super::clipboard_content::{set_clipboard_text, set_clipboard_rich_text};
+use super::clipboard_content::{set_clipboard_text, set_clipboard_rich_text, set_clipboard_rtf};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum PasteFormat {
     PlainText,
     WithFormat,
+    Rtf,
+    Markdown,
+    // 仅粘贴组合项附带的图片
+    Image,
+    // 每行添加引用前缀后以纯文本粘贴
+    Quoted,
+    // 文件项以路径文本粘贴（每行一个路径）
+    FilePaths,
+    // 图片按设置缩放/重新编码后粘贴（不修改原图）
+    ResizedImage,
+}
+
+impl PasteFormat {
+    // 由前端/数据库中使用的格式名解析
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "plain" => Some(Self::PlainText),
+            "formatted" => Some(Self::WithFormat),
+            "rtf" => Some(Self::Rtf),
+            "markdown" => Some(Self::Markdown),
+            "image" => Some(Self::Image),
+            "quoted" => Some(Self::Quoted),
+            "paths" => Some(Self::FilePaths),
+            "resized" => Some(Self::ResizedImage),
+            _ => None,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::PlainText => "plain",
+            Self::WithFormat => "formatted",
+            Self::Rtf => "rtf",
+            Self::Markdown => "markdown",
+            Self::Image => "image",
+            Self::Quoted => "quoted",
+            Self::FilePaths => "paths",
+            Self::ResizedImage => "resized",
+        }
+    }
+}
+
+// 确定一次粘贴使用的格式，优先级从高到低：
+// 1. 项目强制格式（set_item_forced_format 设置）
+// 2. 调用方显式指定的格式（如纯文本粘贴快捷键、右键菜单）
+// 3. 目标应用的默认格式（设置 app_paste_formats，按前台应用匹配）
+// 4. 内容类型的默认格式（设置 content_type_paste_formats）
+// 5. 以上均无时返回 None，按全局格式粘贴开关处理
+pub fn resolve_paste_format(
+    forced: Option<PasteFormat>,
+    explicit: Option<PasteFormat>,
+    app_default: Option<PasteFormat>,
+    content_type_default: Option<PasteFormat>,
+) -> Option<PasteFormat> {
+    forced.or(explicit).or(app_default).or(content_type_default)
+}
+
+// 按应用规则查找格式：规则为 应用匹配规则（进程名/标题/路径，支持通配符）-> 格式名，无效格式名的规则忽略；
+// 多条规则匹配时优先不含通配符的规则，其次取最长（最具体）的规则，再按规则文本排序保证结果稳定
+fn match_app_format(
+    rules: &std::collections::HashMap<String, String>,
+    process_name: &str,
+    window_title: &str,
+    process_path: &str,
+) -> Option<PasteFormat> {
+    use crate::services::system::app_filter::matches_filter_rule_text;
+
+    rules
+        .iter()
+        .filter(|(pattern, _)| matches_filter_rule_text(process_name, window_title, process_path, pattern))
+        .filter_map(|(pattern, key)| Some((pattern.trim(), PasteFormat::from_key(key)?)))
+        .max_by_key(|&(pattern, _)| {
+            let is_wildcard = pattern.contains('*') || pattern.contains('?');
+            (!is_wildcard, pattern.len(), std::cmp::Reverse(pattern))
+        })
+        .map(|(_, format)| format)
+}
+
+// 粘贴目标应用的默认粘贴格式（前台为本应用窗口时按记录的上一个外部窗口判断）
+pub fn app_default_format() -> Option<PasteFormat> {
+    let settings = crate::services::get_settings();
+    if settings.app_paste_formats.is_empty() {
+        return None;
+    }
+    let info = crate::services::system::focus::get_paste_target_app_info()?;
+    match_app_format(&settings.app_paste_formats, &info.process_name, &info.window_title, &info.process_path)
+}
+
+// 内容类型的默认粘贴格式（按主类型查找设置）
+pub fn content_type_default_format(content_type: &str) -> Option<PasteFormat> {
+    let primary_type = content_type.split(',').next().unwrap_or(content_type);
+    crate::services::get_settings()
+        .content_type_paste_formats
+        .get(primary_type)
+        .and_then(|key| PasteFormat::from_key(key))
+}
+
+// 为每行添加引用前缀，保留原有换行符（\r\n 或 \n）与末尾换行；
+// 空行只添加去除尾部空白的前缀，避免产生行尾空格
+pub fn quote_text(text: &str, prefix: &str) -> String {
+    let empty_prefix = prefix.trim_end();
+    let mut result = String::with_capacity(text.len() + prefix.len() * 4);
+    for segment in text.split_inclusive('\n') {
+        let (line, ending) = if let Some(line) = segment.strip_suffix("\r\n") {
+            (line, "\r\n")
+        } else if let Some(line) = segment.strip_suffix('\n') {
+            (line, "\n")
+        } else {
+            (segment, "")
+        };
+        result.push_str(if line.is_empty() { empty_prefix } else { prefix });
+        result.push_str(line);
+        result.push_str(ending);
+    }
+    result
+}
+
+// 按设置中的前缀生成引用文本
+pub fn quote_with_settings(text: &str) -> String {
+    quote_text(text, &crate::services::get_settings().quote_paste_prefix)
+}
+
+// 文本项按粘贴格式实际插入的文本（引用、Markdown 格式会改变文本内容）
+pub fn inserted_text(text: &str, html_content: &Option<String>, format: Option<PasteFormat>) -> String {
+    match (format, html_content) {
+        (Some(PasteFormat::Quoted), _) => quote_with_settings(text),
+        (Some(PasteFormat::Markdown), Some(html)) => crate::utils::html_to_markdown(html),
+        _ => text.to_string(),
+    }
 }
 
 
@@ -49,10 +178,8 @@ pub fn paste_rich_text(
     text: &str,
     html_content: &Option<String>,
 ) -> Result<(), String> {
-    let settings = crate::services::get_settings();
-    
     if let Some(html) = html_content {
-        if !settings.paste_with_format {
+        if !super::format_toggle::paste_with_format() {
             return set_clipboard_text(ctx, text);
         }
         set_clipboard_rich_text(ctx, text, html)
@@ -66,6 +193,7 @@ pub fn paste_rich_text_with_format(
     ctx: &ClipboardContext,
     text: &str,
     html_content: &Option<String>,
+    rtf_content: &Option<String>,
     format: PasteFormat,
 ) -> Result<(), String> {
     match format {
@@ -77,5 +205,91 @@ pub fn paste_rich_text_with_format(
                 set_clipboard_text(ctx, text)
             }
         }
+        PasteFormat::Rtf => {
+            // 未捕获 RTF 时由 HTML 转换
+            let rtf = match (rtf_content, html_content) {
+                (Some(rtf), _) => rtf.clone(),
+                (None, Some(html)) => crate::utils::html_to_rtf(html),
+                (None, None) => return set_clipboard_text(ctx, text),
+            };
+            set_clipboard_rtf(ctx, text, html_content.as_deref(), &rtf)
+        }
+        PasteFormat::Markdown => {
+            // 无 HTML 时保持纯文本不变
+            let Some(html) = html_content else {
+                return set_clipboard_text(ctx, text);
+            };
+            let markdown = crate::utils::html_to_markdown(html);
+            // 转换后的文本与原内容不同，需预设哈希避免被重新记录
+            crate::services::clipboard::set_last_hash_text(&markdown);
+            set_clipboard_text(ctx, &markdown)
+        }
+        // 无附带图片的文本项、非文件项退回纯文本
+        PasteFormat::Image | PasteFormat::FilePaths | PasteFormat::ResizedImage => set_clipboard_text(ctx, text),
+        PasteFormat::Quoted => {
+            let quoted = quote_with_settings(text);
+            // 引用后的文本与原内容不同，需预设哈希避免被重新记录
+            crate::services::clipboard::set_last_hash_text(&quoted);
+            set_clipboard_text(ctx, &quoted)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_text_multiline() {
+        assert_eq!(quote_text("a\nb", "> "), "> a\n> b");
+        assert_eq!(quote_text("a\r\nb\r\n", "> "), "> a\r\n> b\r\n");
+        assert_eq!(quote_text("single", "| "), "| single");
+    }
+
+    #[test]
+    fn test_resolve_paste_format_precedence() {
+        use PasteFormat::*;
+        // 强制格式 > 显式格式 > 应用默认 > 内容类型默认 > 全局（None）
+        assert_eq!(resolve_paste_format(Some(PlainText), Some(Rtf), Some(WithFormat), Some(Markdown)), Some(PlainText));
+        assert_eq!(resolve_paste_format(None, Some(Rtf), Some(WithFormat), Some(Markdown)), Some(Rtf));
+        assert_eq!(resolve_paste_format(None, None, Some(WithFormat), Some(Markdown)), Some(WithFormat));
+        assert_eq!(resolve_paste_format(None, None, None, Some(Markdown)), Some(Markdown));
+        assert_eq!(resolve_paste_format(None, None, None, None), None);
+    }
+
+    #[test]
+    fn test_match_app_format() {
+        let rules = std::collections::HashMap::from([
+            ("slack".to_string(), "plain".to_string()),
+            ("WINWORD.EXE".to_string(), "formatted".to_string()),
+            ("*.exe".to_string(), "markdown".to_string()),
+            ("broken".to_string(), "bogus".to_string()),
+        ]);
+        assert_eq!(match_app_format(&rules, "slack.exe", "general - Slack", r"C:\slack\slack.exe"), Some(PasteFormat::PlainText));
+        assert_eq!(match_app_format(&rules, "WINWORD.EXE", "Doc1 - Word", ""), Some(PasteFormat::WithFormat));
+        // 只匹配通配规则
+        assert_eq!(match_app_format(&rules, "notepad.exe", "Untitled", ""), Some(PasteFormat::Markdown));
+        assert_eq!(match_app_format(&rules, "code", "README", "/usr/bin/code"), None);
+        // 无效格式名的规则忽略
+        assert_eq!(match_app_format(&rules, "broken", "", ""), None);
+        assert_eq!(match_app_format(&rules, "broken.exe", "", ""), Some(PasteFormat::Markdown));
+    }
+
+    #[test]
+    fn test_format_key_round_trip() {
+        for format in [
+            PasteFormat::PlainText, PasteFormat::WithFormat, PasteFormat::Rtf, PasteFormat::Markdown,
+            PasteFormat::Image, PasteFormat::Quoted, PasteFormat::FilePaths, PasteFormat::ResizedImage,
+        ] {
+            assert_eq!(PasteFormat::from_key(format.key()), Some(format));
+        }
+        assert_eq!(PasteFormat::from_key("bogus"), None);
+    }
+
+    #[test]
+    fn test_quote_text_empty_lines() {
+        assert_eq!(quote_text("a\n\nb\n", "> "), "> a\n>\n> b\n");
+        assert_eq!(quote_text("\r\n", "> "), ">\r\n");
+        assert_eq!(quote_text("", "> "), "");
     }
 }
@@ -0,0 +1,267 @@
+// 设置包：将全部设置（含快捷键）与可选的配置方案导出为单个 JSON 文件，用于迁移到新设备
+//
+// 文件结构：
+//   { "format": "quickclipboard-settings", "schemaVersion": 1, "minReaderVersion": 1,
+//     "appVersion": "...", "exportedAt": "...", "settings": {...}, "profiles": { "名称": {...} } }
+// - schemaVersion 为写入时的格式版本，minReaderVersion 为读取该文件所需的最低格式版本；
+//   新版本只新增字段时保持 minReaderVersion 不变，旧版本仍可导入并忽略不认识的设置项
+// - 不带 format 的普通设置 JSON（settings.json 或配置方案文件）按版本 0 读取
+// - 存储位置、窗口位置等与设备相关的设置不导出，导入时保留当前值
+
+use super::{get_settings, profiles, AppSettings};
+use crate::services::system::hotkey::ShortcutStatus;
+use crate::services::system::hotkey_import::validate_settings_shortcuts;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+const BUNDLE_FORMAT: &str = "quickclipboard-settings";
+// 当前格式版本
+pub const BUNDLE_SCHEMA_VERSION: u32 = 1;
+
+// 与设备相关、不随设置包迁移的设置项
+const DEVICE_KEYS: [&str; 4] = ["customStoragePath", "useCustomStorage", "savedWindowPosition", "edgeSnapPosition"];
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SettingsBundle {
+    format: String,
+    schema_version: u32,
+    min_reader_version: u32,
+    #[serde(default)]
+    app_version: String,
+    #[serde(default)]
+    exported_at: String,
+    settings: Value,
+    #[serde(default)]
+    profiles: BTreeMap<String, Value>,
+}
+
+// 解析后的设置包
+#[derive(Debug)]
+pub struct ParsedBundle {
+    pub schema_version: u32,
+    pub settings: AppSettings,
+    pub profiles: Vec<(String, AppSettings)>,
+    // 当前版本不认识的设置项（来自更新版本的导出，导入时忽略）
+    pub ignored_keys: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+// 导入报告：存在快捷键问题时不应用
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleImportReport {
+    pub schema_version: u32,
+    pub applied: bool,
+    pub conflicts: Vec<ShortcutStatus>,
+    pub ignored_keys: Vec<String>,
+    pub profiles: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+fn strip_device_keys(value: &mut Value) {
+    if let Some(map) = value.as_object_mut() {
+        for key in DEVICE_KEYS {
+            map.remove(key);
+        }
+    }
+}
+
+fn settings_value(settings: &AppSettings) -> Result<Value, String> {
+    let mut value = serde_json::to_value(settings).map_err(|e| format!("序列化设置失败: {}", e))?;
+    strip_device_keys(&mut value);
+    Ok(value)
+}
+
+fn build_bundle(settings: &AppSettings, profiles: &[(String, AppSettings)]) -> Result<SettingsBundle, String> {
+    Ok(SettingsBundle {
+        format: BUNDLE_FORMAT.to_string(),
+        schema_version: BUNDLE_SCHEMA_VERSION,
+        min_reader_version: 1,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: chrono::Local::now().to_rfc3339(),
+        settings: settings_value(settings)?,
+        profiles: profiles
+            .iter()
+            .map(|(name, profile)| Ok((name.clone(), settings_value(profile)?)))
+            .collect::<Result<_, String>>()?,
+    })
+}
+
+// 导出当前设置，include_profiles 为 true 时一并导出全部配置方案
+pub fn export_settings_bundle(path: &Path, include_profiles: bool) -> Result<(), String> {
+    let mut saved_profiles = Vec::new();
+    if include_profiles {
+        for name in profiles::list_profiles()? {
+            saved_profiles.push((name.clone(), profiles::read_profile(&name)?));
+        }
+    }
+    let bundle = build_bundle(&get_settings(), &saved_profiles)?;
+    let content = serde_json::to_string_pretty(&bundle).map_err(|e| format!("序列化设置包失败: {}", e))?;
+    fs::write(path, content).map_err(|e| format!("写入设置包失败: {}", e))
+}
+
+// 早期设置文件使用的 snake_case 键，由字段别名读取
+const LEGACY_KEYS: [&str; 3] = ["history_limit", "custom_storage_path", "use_custom_storage"];
+
+// 当前版本认识的设置项（默认设置序列化后的键与旧键）
+fn known_keys() -> Vec<String> {
+    let mut keys: Vec<String> = match serde_json::to_value(AppSettings::default()) {
+        Ok(Value::Object(map)) => map.into_iter().map(|(key, _)| key).collect(),
+        _ => Vec::new(),
+    };
+    keys.extend(LEGACY_KEYS.iter().map(|key| key.to_string()));
+    keys
+}
+
+// 将设置 JSON 转换为设置，返回设置与不认识的键；缺失的设置项使用默认值
+fn decode_settings(mut value: Value, known: &[String]) -> Result<(AppSettings, Vec<String>), String> {
+    strip_device_keys(&mut value);
+    let Value::Object(map) = value else {
+        return Err("设置内容格式无效".into());
+    };
+    let mut ignored: Vec<String> = map
+        .keys()
+        .filter(|key| !known.contains(key))
+        .cloned()
+        .collect();
+    ignored.sort();
+    let settings = serde_json::from_value(Value::Object(map)).map_err(|e| format!("解析设置失败: {}", e))?;
+    Ok((settings, ignored))
+}
+
+// 解析设置包内容，校验格式与版本
+pub fn parse_bundle(content: &str) -> Result<ParsedBundle, String> {
+    let value: Value = serde_json::from_str(content).map_err(|e| format!("设置包不是有效的 JSON: {}", e))?;
+    let known = known_keys();
+
+    // 版本 0：没有外层结构的普通设置文件
+    if value.get("format").is_none() {
+        let (settings, ignored_keys) = decode_settings(value, &known)?;
+        return Ok(ParsedBundle { schema_version: 0, settings, profiles: Vec::new(), ignored_keys, warnings: Vec::new() });
+    }
+
+    let bundle: SettingsBundle = serde_json::from_value(value).map_err(|e| format!("设置包结构无效: {}", e))?;
+    if bundle.format != BUNDLE_FORMAT {
+        return Err(format!("不是 QuickClipboard 设置包: {}", bundle.format));
+    }
+    if bundle.min_reader_version > BUNDLE_SCHEMA_VERSION {
+        return Err(format!(
+            "设置包需要更新版本的应用才能导入（格式版本 {}，当前支持 {}）",
+            bundle.schema_version, BUNDLE_SCHEMA_VERSION
+        ));
+    }
+
+    let (settings, ignored_keys) = decode_settings(bundle.settings, &known)?;
+    let mut warnings = Vec::new();
+    let mut parsed_profiles = Vec::new();
+    for (name, value) in bundle.profiles {
+        match decode_settings(value, &known) {
+            Ok((profile, _)) => parsed_profiles.push((name, profile)),
+            Err(e) => warnings.push(format!("跳过配置方案 {}: {}", name, e)),
+        }
+    }
+    Ok(ParsedBundle {
+        schema_version: bundle.schema_version,
+        settings,
+        profiles: parsed_profiles,
+        ignored_keys,
+        warnings,
+    })
+}
+
+// 读取设置包并校验快捷键，返回要应用的设置（设备相关项保留当前值）与报告
+pub fn read_settings_bundle(path: &Path) -> Result<(ParsedBundle, BundleImportReport), String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("读取设置包失败: {}", e))?;
+    let mut parsed = parse_bundle(&content)?;
+
+    let current = get_settings();
+    keep_device_settings(&mut parsed.settings, &current);
+    for (_, profile) in &mut parsed.profiles {
+        keep_device_settings(profile, &current);
+    }
+
+    let report = BundleImportReport {
+        schema_version: parsed.schema_version,
+        applied: false,
+        conflicts: validate_settings_shortcuts(&parsed.settings),
+        ignored_keys: parsed.ignored_keys.clone(),
+        profiles: parsed.profiles.iter().map(|(name, _)| name.clone()).collect(),
+        warnings: parsed.warnings.clone(),
+    };
+    Ok((parsed, report))
+}
+
+fn keep_device_settings(settings: &mut AppSettings, current: &AppSettings) {
+    settings.custom_storage_path = current.custom_storage_path.clone();
+    settings.use_custom_storage = current.use_custom_storage;
+    settings.saved_window_position = current.saved_window_position;
+    settings.edge_snap_position = current.edge_snap_position;
+}
+
+// 写入设置包中的配置方案，返回失败的方案说明
+pub fn write_bundle_profiles(parsed: &ParsedBundle) -> Vec<String> {
+    parsed
+        .profiles
+        .iter()
+        .filter_map(|(name, profile)| {
+            profiles::write_profile(name, profile).err().map(|e| format!("导入配置方案 {} 失败: {}", name, e))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundle_round_trip() {
+        let mut settings = AppSettings::default();
+        settings.history_limit = 500;
+        settings.toggle_shortcut = "Ctrl+Alt+Q".to_string();
+        settings.custom_storage_path = Some("D:\\data".to_string());
+        let profile = AppSettings { history_limit: 50, ..AppSettings::default() };
+
+        let bundle = build_bundle(&settings, &[("work".to_string(), profile)]).unwrap();
+        assert!(bundle.settings.get("customStoragePath").is_none());
+        let content = serde_json::to_string(&bundle).unwrap();
+
+        let parsed = parse_bundle(&content).unwrap();
+        assert_eq!(parsed.schema_version, BUNDLE_SCHEMA_VERSION);
+        assert_eq!(parsed.settings.history_limit, 500);
+        assert_eq!(parsed.settings.toggle_shortcut, "Ctrl+Alt+Q");
+        assert_eq!(parsed.settings.custom_storage_path, None);
+        assert_eq!(parsed.profiles.len(), 1);
+        assert_eq!(parsed.profiles[0].1.history_limit, 50);
+        assert!(parsed.ignored_keys.is_empty());
+    }
+
+    #[test]
+    fn test_newer_bundle_is_read_when_compatible() {
+        let content = r#"{
+            "format": "quickclipboard-settings", "schemaVersion": 3, "minReaderVersion": 1,
+            "settings": { "historyLimit": 200, "futureOption": true }
+        }"#;
+        let parsed = parse_bundle(content).unwrap();
+        assert_eq!(parsed.schema_version, 3);
+        assert_eq!(parsed.settings.history_limit, 200);
+        assert_eq!(parsed.ignored_keys, vec!["futureOption".to_string()]);
+
+        let incompatible = r#"{ "format": "quickclipboard-settings", "schemaVersion": 3, "minReaderVersion": 2, "settings": {} }"#;
+        assert!(parse_bundle(incompatible).is_err());
+        let foreign = r#"{ "format": "other", "schemaVersion": 1, "minReaderVersion": 1, "settings": {} }"#;
+        assert!(parse_bundle(foreign).is_err());
+    }
+
+    #[test]
+    fn test_plain_settings_file_is_version_zero() {
+        let parsed = parse_bundle(r#"{ "history_limit": 300, "theme": "dark" }"#).unwrap();
+        assert_eq!(parsed.schema_version, 0);
+        assert_eq!(parsed.settings.history_limit, 300);
+        assert_eq!(parsed.settings.theme, "dark");
+        assert!(parsed.ignored_keys.is_empty());
+    }
+}
@@ -1,4 +1,8 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::services::clipboard::TextTransform;
+use crate::services::system::hotkey_feedback::{default_feedback_rules, FeedbackKind};
+use crate::services::system::hotkey_execution::ExecutionMode;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
@@ -10,6 +14,8 @@ pub struct AppSettings {
     pub show_startup_notification: bool,
     #[serde(alias = "history_limit")]
     pub history_limit: u64,
+    // 超出历史数量限制自动清理旧记录时，在主窗口提示清理的数量
+    pub notify_expired_items: bool,
     pub language: String,
     pub theme: String,
     pub dark_theme_style: String,
@@ -18,11 +24,32 @@ pub struct AppSettings {
     pub toggle_shortcut: String,
     pub number_shortcuts: bool,
     pub number_shortcuts_modifier: String,
+    // 便捷粘贴窗口显示时数字快捷键对应的项："visible"（窗口中显示的顺序）或 "history"（原始历史顺序）
+    pub number_shortcuts_source: String,
+    // 粘贴快捷键的首次粘贴尚未完成时再次按下："overlap"（立即粘贴）、"drop"（丢弃）或 "queue"（完成后依次粘贴）
+    pub repeat_paste_policy: String,
+    // 数字键 → 剪贴板项 ID 的固定绑定（类似片段快捷栏），不随历史变化
+    pub number_item_bindings: HashMap<u8, i64>,
+    // 未绑定的数字键："index"（按序号粘贴）或 "none"（不注册，按键交给其他程序）
+    pub number_unbound_behavior: String,
     pub clipboard_monitor: bool,
+    // 轮询兜底间隔（毫秒，0 表示关闭）：部分系统会丢失剪贴板变化通知，定期检查以补录遗漏的内容
+    pub clipboard_poll_interval_ms: u64,
+    // 无痕模式：暂停记录历史，直到手动关闭（重启后保持）
+    pub incognito_mode: bool,
     pub ignore_duplicates: bool,
     pub save_images: bool,
+    // 图片连拍合并：时间窗口（毫秒，0 表示不合并）内相似度达到阈值（0~1）的图片只保留最后一张
+    pub image_coalesce_window_ms: u64,
+    pub image_coalesce_similarity: f64,
+    // 缩放粘贴图片：最长边上限（0 表示不缩放）、输出格式（"auto" / "png" / "jpeg"）与 JPEG 质量（1~100）
+    pub image_paste_max_dimension: u32,
+    pub image_paste_format: String,
+    pub image_paste_jpeg_quality: u8,
     pub image_preview: bool,
     pub text_preview: bool,
+    // 列表中文本项保存的预览字符数，完整内容按需加载
+    pub list_preview_length: usize,
 
     // 音效设置
     pub sound_enabled: bool,
@@ -43,6 +70,9 @@ pub struct AppSettings {
     pub screenshot_quick_save_shortcut: String,
     pub screenshot_quick_pin_shortcut: String,
     pub screenshot_quick_ocr_shortcut: String,
+    pub ocr_paste_shortcut: String,
+    // OCR 粘贴时是否同时将识别文本保存到历史
+    pub ocr_paste_keep_history: bool,
     pub screenshot_quality: u8,
     pub screenshot_auto_save: bool,
     pub screenshot_show_hints: bool,
@@ -57,10 +87,22 @@ pub struct AppSettings {
     pub quickpaste_enabled: bool,
     pub quickpaste_shortcut: String,
     pub quickpaste_paste_on_modifier_release: bool,
+    // 松开快捷键后延迟隐藏的毫秒数（给粘贴留出时间）
+    pub quickpaste_hide_delay_ms: u64,
+    // 隐藏方式："release"（松开快捷键）或 "outside_click"（点击窗口外部）
+    pub quickpaste_hide_mode: String,
+    // 无交互超过该秒数后自动隐藏便捷粘贴窗口（0 表示不自动隐藏）
+    pub quickpaste_idle_timeout_secs: u64,
     pub quickpaste_scroll_sound: bool,
     pub quickpaste_scroll_sound_path: String,
     pub quickpaste_window_width: u32,
     pub quickpaste_window_height: u32,
+    // 固定显示的项数（0 表示按窗口高度自动计算），窗口按项数与列数自动调整尺寸
+    pub quickpaste_item_count: u32,
+    // 网格列数（1 为单列列表）
+    pub quickpaste_grid_columns: u32,
+    // 便捷粘贴窗口显示时按下主窗口切换快捷键："close_quickpaste"（仅关闭便捷粘贴，不粘贴）或 "ignore"（忽略）
+    pub toggle_while_quickpaste: String,
 
     // AI翻译设置
     pub ai_translation_enabled: bool,
@@ -94,6 +136,18 @@ pub struct AppSettings {
     pub app_filter_mode: String,
     pub app_filter_list: Vec<String>,
     pub app_filter_effect: String,
+    // 全局禁用应用持续位于前台该毫秒数后才停用热键，短暂切换（如 Alt+Tab 经过）不会注销热键（0 表示立即停用）
+    pub app_filter_disable_grace_ms: u64,
+    // 忽略的剪贴板格式名称
+    pub ignored_clipboard_formats: Vec<String>,
+    // 排除的文本正则（匹配任一规则的文本不记录到历史）
+    pub excluded_text_patterns: Vec<String>,
+    // 同时包含图片与文本时的处理方式："separate"（分别保存）、"prefer_image"、"prefer_text" 或 "composite"（合并为一项）
+    pub mixed_content_mode: String,
+    // 存储前按顺序应用于文本的转换（为空时不转换）
+    pub store_transforms: Vec<TextTransform>,
+    // 应用转换时同时保留原始文本
+    pub store_transforms_preserve_original: bool,
 
     // 窗口设置
     pub window_position_mode: String,
@@ -108,6 +162,10 @@ pub struct AppSettings {
 
     // 窗口行为设置
     pub auto_focus_search: bool,
+    // 切换快捷键隐藏主窗口的方式："hide"（隐藏并移出任务栏）或 "minimize"（最小化到任务栏）
+    pub toggle_hide_mode: String,
+    // 隐藏主窗口后是否将焦点还给之前的前台窗口
+    pub restore_focus_on_hide: bool,
 
     // 标题栏设置
     pub title_bar_position: String,
@@ -115,8 +173,27 @@ pub struct AppSettings {
     // 格式设置
     pub paste_with_format: bool,
     pub paste_shortcut_mode: String,
+    // 纯文本粘贴方式："clipboard"（剪贴板 + 模拟粘贴键）或 "send_input"（直接输入文本）
+    pub paste_method: String,
+    // 焦点回到目标窗口后、模拟粘贴前的稳定延迟（毫秒）
+    pub paste_delay_ms: u64,
+    // 引用粘贴时添加在每行前的标记
+    pub quote_paste_prefix: String,
+    // 粘贴前等待前台窗口离开本应用窗口的最长毫秒数
+    pub paste_focus_wait_max_ms: u64,
+    // 模拟粘贴失败后的重试次数与间隔（毫秒）
+    pub paste_retry_count: u32,
+    pub paste_retry_delay_ms: u64,
+    // 重试仍失败时，文本项回退为直接输入文本
+    pub paste_fallback_send_input: bool,
+    // 粘贴到密码输入框时的行为："allow"（允许）、"prompt"（询问）或 "block"（阻止）
+    pub password_field_paste_mode: String,
+    // 粘贴时前台窗口属于本应用的行为："skip"（跳过并发出警告事件）、"retarget"（切回上一个外部窗口后粘贴）或 "allow"（照常粘贴）
+    pub self_paste_mode: String,
     
     pub paste_to_top: bool,
+    // "复制为"的转换结果是否作为新项保存到历史（默认只写入剪贴板）
+    pub copy_as_store_result: bool,
     pub show_badges: bool,
     pub show_source_icon: bool,
 
@@ -134,7 +211,62 @@ pub struct AppSettings {
     pub toggle_pin_shortcut: String,
     pub toggle_clipboard_monitor_shortcut: String,
     pub toggle_paste_with_format_shortcut: String,
+    // 格式粘贴快捷键的切换方式："sticky"（切换并保存设置）或 "momentary"（仅翻转下一次粘贴）
+    pub paste_format_toggle_mode: String,
+    // 按内容类型的默认粘贴格式（主类型 -> 格式名，如 {"link": "plain"}），优先级低于项目强制格式与显式格式
+    pub content_type_paste_formats: HashMap<String, String>,
+    // 按目标应用的默认粘贴格式（应用匹配规则 -> 格式名，如 {"slack.exe": "plain"}），优先级高于内容类型默认格式
+    pub app_paste_formats: HashMap<String, String>,
     pub paste_plain_text_shortcut: String,
+    pub macro_next_shortcut: String,
+    pub swap_last_two_shortcut: String,
+    pub capture_clipboard_shortcut: String,
+    // 抓取选中内容：模拟 Ctrl+C 后等待剪贴板变化（毫秒）并保存
+    pub grab_selection_shortcut: String,
+    pub grab_selection_timeout_ms: u64,
+    // 抓取后显示便捷粘贴窗口
+    pub grab_selection_show_quickpaste: bool,
+    // 用更早的一项替换刚粘贴的文本（粘贴栈）
+    pub paste_pop_shortcut: String,
+    // 打开最新一条剪贴板项的来源应用
+    pub open_source_app_shortcut: String,
+    // 临时冻结最新一条剪贴板项（本次会话置顶）
+    pub freeze_top_item_shortcut: String,
+    // 将最新一条文本以引用格式（每行加前缀）粘贴
+    pub paste_quoted_shortcut: String,
+    // 快速笔记：弹出输入框，将输入的文本直接保存到历史
+    pub quick_note_shortcut: String,
+    // 组合粘贴：打开组合窗口，挑选并编排多个剪贴板项后一次性粘贴
+    pub compose_shortcut: String,
+    pub toggle_incognito_shortcut: String,
+    // 紧急隐藏快捷键（前台应用禁用热键时仍然有效）
+    pub panic_shortcut: String,
+    // 紧急隐藏时执行的动作
+    pub panic_hide_windows: bool,
+    pub panic_clear_clipboard: bool,
+    pub panic_enter_incognito: bool,
+    // 单个快捷键启用状态（按快捷键 ID，未记录的视为启用）
+    pub shortcut_enabled: HashMap<String, bool>,
+    // 备用组合（按快捷键 ID），与主组合触发同一动作，状态 ID 依次为 "ID#1"、"ID#2"…
+    pub alternate_shortcuts: HashMap<String, Vec<String>>,
+    // 粘贴指定序号项的快捷键（序号从 1 开始，可绑定数字行以外的任意序号）
+    pub paste_index_shortcuts: Vec<PasteIndexShortcut>,
+    // 粘滞修饰键（无障碍）：依次单独按下修饰键后再按主键，等同于同时按下
+    pub sticky_modifiers_enabled: bool,
+    // 已锁定的修饰键在该毫秒数内未按主键则自动释放
+    pub sticky_modifiers_timeout_ms: u64,
+    // 全局快捷键注册数量软上限，超过后给出警告状态（0 表示不检查）
+    pub hotkey_soft_limit: usize,
+    // 快捷键触发反馈总开关
+    pub hotkey_feedback_enabled: bool,
+    // 反馈规则：键为动作 ID 或 "category:" 加类别（paste / toggle / screenshot / other），动作 ID 优先
+    pub hotkey_feedback: HashMap<String, FeedbackKind>,
+    // 快捷键动作执行方式覆盖：键为动作 ID 或分组（number / paste_index），未设置的使用默认方式
+    pub hotkey_execution: HashMap<String, ExecutionMode>,
+    // 需要连按两次确认才执行的快捷键动作 ID（如 "panic"），默认都不需要确认
+    pub hotkey_confirm_actions: Vec<String>,
+    // 两次按下的最长间隔（毫秒）
+    pub hotkey_confirm_window_ms: u64,
 
     // 数据存储设置
     #[serde(alias = "custom_storage_path")]
@@ -151,6 +283,7 @@ impl Default for AppSettings {
             start_hidden: true,
             show_startup_notification: true,
             history_limit: 100,
+            notify_expired_items: false,
             language: "zh-CN".to_string(),
             theme: "light".to_string(),
             dark_theme_style: "classic".to_string(),
@@ -159,11 +292,23 @@ impl Default for AppSettings {
             toggle_shortcut: "Shift+Space".to_string(),
             number_shortcuts: true,
             number_shortcuts_modifier: "Ctrl".to_string(),
+            number_shortcuts_source: "visible".to_string(),
+            repeat_paste_policy: "overlap".to_string(),
+            number_item_bindings: HashMap::new(),
+            number_unbound_behavior: "index".to_string(),
             clipboard_monitor: true,
+            clipboard_poll_interval_ms: 0,
+            incognito_mode: false,
             ignore_duplicates: true,
             save_images: true,
+            image_coalesce_window_ms: 0,
+            image_coalesce_similarity: 0.95,
+            image_paste_max_dimension: 1920,
+            image_paste_format: "auto".to_string(),
+            image_paste_jpeg_quality: 85,
             image_preview: false,
             text_preview: false,
+            list_preview_length: 1600,
 
             sound_enabled: true,
             sound_volume: 50.0,
@@ -181,6 +326,8 @@ impl Default for AppSettings {
             screenshot_quick_save_shortcut: String::new(),
             screenshot_quick_pin_shortcut: String::new(),
             screenshot_quick_ocr_shortcut: String::new(),
+            ocr_paste_shortcut: String::new(),
+            ocr_paste_keep_history: true,
             screenshot_quality: 85,
             screenshot_auto_save: true,
             screenshot_show_hints: true,
@@ -194,10 +341,16 @@ impl Default for AppSettings {
             quickpaste_enabled: true,
             quickpaste_shortcut: "Ctrl+`".to_string(),
             quickpaste_paste_on_modifier_release: false,
+            quickpaste_hide_delay_ms: 50,
+            quickpaste_hide_mode: "release".to_string(),
+            quickpaste_idle_timeout_secs: 0,
             quickpaste_scroll_sound: true,
             quickpaste_scroll_sound_path: "sounds/roll.mp3".to_string(),
             quickpaste_window_width: 300,
             quickpaste_window_height: 400,
+            quickpaste_item_count: 0,
+            quickpaste_grid_columns: 1,
+            toggle_while_quickpaste: "close_quickpaste".to_string(),
 
             ai_translation_enabled: false,
             ai_api_key: String::new(),
@@ -226,6 +379,12 @@ impl Default for AppSettings {
             app_filter_mode: "blacklist".to_string(),
             app_filter_list: vec![],
             app_filter_effect: "clipboard_only".to_string(),
+            app_filter_disable_grace_ms: 0,
+            ignored_clipboard_formats: vec![],
+            excluded_text_patterns: vec![],
+            mixed_content_mode: "separate".to_string(),
+            store_transforms: Vec::new(),
+            store_transforms_preserve_original: false,
 
             window_position_mode: "smart".to_string(),
             remember_window_size: false,
@@ -237,12 +396,24 @@ impl Default for AppSettings {
             edge_hide_offset: 3,
 
             auto_focus_search: false,
+            toggle_hide_mode: "hide".to_string(),
+            restore_focus_on_hide: false,
 
             title_bar_position: "top".to_string(),
 
             paste_with_format: true,
             paste_shortcut_mode: "ctrl_v".to_string(),
+            paste_method: "clipboard".to_string(),
+            paste_delay_ms: 50,
+            quote_paste_prefix: "> ".to_string(),
+            paste_focus_wait_max_ms: 300,
+            paste_retry_count: 1,
+            paste_retry_delay_ms: 80,
+            paste_fallback_send_input: false,
+            password_field_paste_mode: "allow".to_string(),
+            self_paste_mode: "skip".to_string(),
             paste_to_top: false,
+            copy_as_store_result: false,
             show_badges: true,
             show_source_icon: true,
 
@@ -259,7 +430,38 @@ impl Default for AppSettings {
             toggle_pin_shortcut: "Ctrl+P".to_string(),
             toggle_clipboard_monitor_shortcut: "Ctrl+Shift+Z".to_string(),
             toggle_paste_with_format_shortcut: "Ctrl+Shift+X".to_string(),
+            paste_format_toggle_mode: "sticky".to_string(),
+            content_type_paste_formats: HashMap::new(),
+            app_paste_formats: HashMap::new(),
             paste_plain_text_shortcut: String::new(),
+            macro_next_shortcut: String::new(),
+            swap_last_two_shortcut: String::new(),
+            capture_clipboard_shortcut: String::new(),
+            grab_selection_shortcut: String::new(),
+            grab_selection_timeout_ms: 600,
+            grab_selection_show_quickpaste: false,
+            paste_pop_shortcut: String::new(),
+            open_source_app_shortcut: String::new(),
+            freeze_top_item_shortcut: String::new(),
+            paste_quoted_shortcut: String::new(),
+            quick_note_shortcut: String::new(),
+            compose_shortcut: String::new(),
+            toggle_incognito_shortcut: String::new(),
+            panic_shortcut: String::new(),
+            panic_hide_windows: true,
+            panic_clear_clipboard: true,
+            panic_enter_incognito: false,
+            shortcut_enabled: HashMap::new(),
+            alternate_shortcuts: HashMap::new(),
+            paste_index_shortcuts: Vec::new(),
+            sticky_modifiers_enabled: false,
+            sticky_modifiers_timeout_ms: 3000,
+            hotkey_soft_limit: 40,
+            hotkey_feedback_enabled: false,
+            hotkey_feedback: default_feedback_rules(),
+            hotkey_execution: HashMap::new(),
+            hotkey_confirm_actions: Vec::new(),
+            hotkey_confirm_window_ms: 600,
 
             custom_storage_path: None,
             use_custom_storage: false,
@@ -267,3 +469,75 @@ impl Default for AppSettings {
     }
 }
 
+
+// 粘贴指定序号项的快捷键绑定
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasteIndexShortcut {
+    // 列表中的序号（从 1 开始）
+    pub index: usize,
+    pub shortcut: String,
+}
+
+impl AppSettings {
+    // 检查单个快捷键是否启用
+    pub fn is_shortcut_enabled(&self, id: &str) -> bool {
+        self.shortcut_enabled.get(id).copied().unwrap_or(true)
+    }
+
+    // 将部分设置合并到当前设置上：前端只提交它认识的设置项，未提交的（仅后端使用的）设置项保持不变
+    pub fn merged_with(&self, patch: serde_json::Value) -> Result<AppSettings, String> {
+        let serde_json::Value::Object(patch) = patch else {
+            return Err("设置内容格式无效".into());
+        };
+        let mut value = serde_json::to_value(self).map_err(|e| format!("序列化设置失败: {}", e))?;
+        if let serde_json::Value::Object(map) = &mut value {
+            for (key, field) in patch {
+                // 旧版 snake_case 键与当前键同时存在会导致重复字段错误，统一为 camelCase
+                map.insert(camel_case_key(&key), field);
+            }
+        }
+        serde_json::from_value(value).map_err(|e| format!("解析设置失败: {}", e))
+    }
+}
+
+fn camel_case_key(key: &str) -> String {
+    let mut result = String::with_capacity(key.len());
+    let mut upper = false;
+    for c in key.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            result.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_save_keeps_other_fields() {
+        let current = AppSettings {
+            paste_delay_ms: 250,
+            self_paste_mode: "allow".to_string(),
+            macro_next_shortcut: "Ctrl+Alt+M".to_string(),
+            ..AppSettings::default()
+        };
+        let merged = current
+            .merged_with(serde_json::json!({ "theme": "dark", "history_limit": 42 }))
+            .unwrap();
+        assert_eq!(merged.theme, "dark");
+        assert_eq!(merged.history_limit, 42);
+        assert_eq!(merged.paste_delay_ms, 250);
+        assert_eq!(merged.self_paste_mode, "allow");
+        assert_eq!(merged.macro_next_shortcut, "Ctrl+Alt+M");
+
+        assert!(current.merged_with(serde_json::json!([1, 2])).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+use super::{AppSettings, get_settings, get_data_directory};
+use std::{fs, path::PathBuf};
+
+// 配置方案目录
+fn get_profiles_dir() -> Result<PathBuf, String> {
+    let dir = get_data_directory()?.join("profiles");
+    fs::create_dir_all(&dir).map_err(|e| format!("创建配置方案目录失败: {}", e))?;
+    Ok(dir)
+}
+
+fn get_profile_path(name: &str) -> Result<PathBuf, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("配置方案名称不能为空".to_string());
+    }
+    if name.contains(['/', '\\', ':', '*', '?', '"', '<', '>', '|']) || name.starts_with('.') {
+        return Err(format!("配置方案名称无效: {}", name));
+    }
+    Ok(get_profiles_dir()?.join(format!("{}.json", name)))
+}
+
+// 将当前设置保存为配置方案
+pub fn save_profile(name: &str) -> Result<(), String> {
+    write_profile(name, &get_settings())
+}
+
+// 写入配置方案（同名方案被覆盖）
+pub fn write_profile(name: &str, settings: &AppSettings) -> Result<(), String> {
+    let path = get_profile_path(name)?;
+    let content = serde_json::to_string_pretty(settings)
+        .map_err(|e| format!("序列化配置方案失败: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("保存配置方案失败: {}", e))
+}
+
+// 读取配置方案（保留当前设备相关的存储位置设置）
+pub fn read_profile(name: &str) -> Result<AppSettings, String> {
+    let path = get_profile_path(name)?;
+    if !path.exists() {
+        return Err(format!("配置方案不存在: {}", name));
+    }
+    let content = fs::read_to_string(&path).map_err(|e| format!("读取配置方案失败: {}", e))?;
+    let mut profile: AppSettings = serde_json::from_str(&content)
+        .map_err(|e| format!("解析配置方案失败: {}", e))?;
+
+    let current = get_settings();
+    profile.custom_storage_path = current.custom_storage_path;
+    profile.use_custom_storage = current.use_custom_storage;
+    Ok(profile)
+}
+
+// 列出所有配置方案名称
+pub fn list_profiles() -> Result<Vec<String>, String> {
+    let dir = get_profiles_dir()?;
+    let mut names: Vec<String> = fs::read_dir(&dir)
+        .map_err(|e| format!("读取配置方案目录失败: {}", e))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().map(|ext| ext == "json").unwrap_or(false))
+        .filter_map(|path| path.file_stem().and_then(|s| s.to_str()).map(String::from))
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+// 删除配置方案
+pub fn delete_profile(name: &str) -> Result<(), String> {
+    let path = get_profile_path(name)?;
+    if path.exists() {
+        fs::remove_file(&path).map_err(|e| format!("删除配置方案失败: {}", e))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,174 @@
+// 快捷键动作队列：由单个工作线程按触发顺序执行，
+// 避免每次触发都创建线程，并可丢弃尚未执行的重复动作
+
+use parking_lot::Mutex;
+use std::collections::HashSet;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{sync_channel, SyncSender, TrySendError};
+use std::sync::Arc;
+
+// 队列容量，超出时新动作被丢弃
+const ACTION_QUEUE_CAPACITY: usize = 32;
+
+// 重复动作的合并策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergePolicy {
+    // 每次触发都执行
+    Always,
+    // 同一动作尚在排队（未开始执行）时丢弃新的触发，如短时间内连按两次切换
+    DropIfPending,
+}
+
+struct QueuedAction {
+    key: &'static str,
+    policy: MergePolicy,
+    run: Box<dyn FnOnce() + Send>,
+}
+
+pub struct ActionQueue {
+    name: &'static str,
+    sender: Mutex<Option<SyncSender<QueuedAction>>>,
+    pending: Arc<Mutex<HashSet<&'static str>>>,
+}
+
+impl ActionQueue {
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            sender: Mutex::new(None),
+            pending: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    // 提交动作，返回是否已入队（被合并或队列已满时返回 false）
+    pub fn submit<F>(&self, key: &'static str, policy: MergePolicy, run: F) -> bool
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        if policy == MergePolicy::DropIfPending && !self.pending.lock().insert(key) {
+            return false;
+        }
+
+        let action = QueuedAction { key, policy, run: Box::new(run) };
+        let mut sender = self.sender.lock();
+        let tx = sender.get_or_insert_with(|| self.spawn_worker());
+
+        match tx.try_send(action) {
+            Ok(()) => true,
+            Err(TrySendError::Full(action)) => {
+                eprintln!("动作队列 [{}] 已满，丢弃动作: {}", self.name, action.key);
+                self.finish(&action);
+                false
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                // 工作线程已退出，排队中的动作随之丢失，清空待执行集合，下次提交时重新创建
+                *sender = None;
+                self.pending.lock().clear();
+                false
+            }
+        }
+    }
+
+    fn finish(&self, action: &QueuedAction) {
+        if action.policy == MergePolicy::DropIfPending {
+            self.pending.lock().remove(action.key);
+        }
+    }
+
+    fn spawn_worker(&self) -> SyncSender<QueuedAction> {
+        let (tx, rx) = sync_channel::<QueuedAction>(ACTION_QUEUE_CAPACITY);
+        let pending = Arc::clone(&self.pending);
+        let name = self.name;
+
+        std::thread::Builder::new()
+            .name(format!("action-queue-{}", name))
+            .spawn(move || {
+                for action in rx {
+                    // 开始执行即移出待执行集合，执行期间的新触发会排在其后
+                    if action.policy == MergePolicy::DropIfPending {
+                        pending.lock().remove(action.key);
+                    }
+                    // 单个动作 panic 不结束工作线程，否则后续动作与待执行标记都会丢失
+                    if catch_unwind(AssertUnwindSafe(action.run)).is_err() {
+                        eprintln!("动作队列 [{}] 执行动作 {} 时发生 panic", name, action.key);
+                    }
+                }
+            })
+            .expect("创建动作队列线程失败");
+        tx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    #[test]
+    fn test_actions_run_in_order() {
+        let queue = ActionQueue::new("test-order");
+        let (tx, rx) = channel();
+        for i in 0..5 {
+            let tx = tx.clone();
+            assert!(queue.submit("step", MergePolicy::Always, move || tx.send(i).unwrap()));
+        }
+        let received: Vec<i32> = (0..5)
+            .map(|_| rx.recv_timeout(Duration::from_secs(5)).unwrap())
+            .collect();
+        assert_eq!(received, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_pending_duplicates_are_dropped() {
+        let queue = ActionQueue::new("test-merge");
+        let count = Arc::new(AtomicUsize::new(0));
+        let (release_tx, release_rx) = channel::<()>();
+        let (done_tx, done_rx) = channel::<()>();
+
+        // 阻塞工作线程，使后续动作保持排队状态
+        assert!(queue.submit("block", MergePolicy::Always, move || {
+            release_rx.recv().unwrap();
+        }));
+
+        let c = Arc::clone(&count);
+        assert!(queue.submit("toggle", MergePolicy::DropIfPending, move || {
+            c.fetch_add(1, Ordering::SeqCst);
+        }));
+        let c = Arc::clone(&count);
+        assert!(!queue.submit("toggle", MergePolicy::DropIfPending, move || {
+            c.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        release_tx.send(()).unwrap();
+        assert!(queue.submit("done", MergePolicy::Always, move || done_tx.send(()).unwrap()));
+        done_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+
+        // 执行完成后同一动作可再次入队
+        let c = Arc::clone(&count);
+        let (done_tx, done_rx) = channel::<()>();
+        assert!(queue.submit("toggle", MergePolicy::DropIfPending, move || {
+            c.fetch_add(1, Ordering::SeqCst);
+            done_tx.send(()).unwrap();
+        }));
+        done_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_panicking_action_keeps_worker_alive() {
+        let queue = ActionQueue::new("test-panic");
+        assert!(queue.submit("boom", MergePolicy::DropIfPending, || panic!("action failed")));
+
+        let (done_tx, done_rx) = channel::<()>();
+        assert!(queue.submit("toggle", MergePolicy::DropIfPending, move || done_tx.send(()).unwrap()));
+        done_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+
+        // panic 的动作不会一直占用待执行标记
+        let (done_tx, done_rx) = channel::<()>();
+        assert!(queue.submit("boom", MergePolicy::DropIfPending, move || done_tx.send(()).unwrap()));
+        done_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    }
+}
@@ -26,7 +26,7 @@ pub enum ClipboardSourceType {
     Unknown,
 }
 
-fn matches_filter_rule_text(process_name: &str, window_title: &str, process_path: &str, filter: &str) -> bool {
+pub(crate) fn matches_filter_rule_text(process_name: &str, window_title: &str, process_path: &str, filter: &str) -> bool {
     let filter = filter.trim();
     if filter.is_empty() {
         return false;
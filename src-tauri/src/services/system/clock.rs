@@ -0,0 +1,52 @@
+// 时间抽象：热键计时逻辑通过 Clock 获取当前时间和等待，
+// 测试中可替换为手动推进的时钟，无需真实等待即可模拟按键时序
+
+use std::time::{Duration, Instant};
+
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+    fn sleep(&self, duration: Duration);
+}
+
+// 系统时钟
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+pub static SYSTEM_CLOCK: SystemClock = SystemClock;
+
+// 手动推进的时钟，sleep 立即返回并推进虚拟时间
+#[cfg(test)]
+pub struct MockClock {
+    now: parking_lot::Mutex<Instant>,
+}
+
+#[cfg(test)]
+impl MockClock {
+    pub fn new() -> Self {
+        Self { now: parking_lot::Mutex::new(Instant::now()) }
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock() += duration;
+    }
+}
+
+#[cfg(test)]
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        *self.now.lock()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.advance(duration);
+    }
+}
@@ -93,25 +93,129 @@ pub fn restore_last_focus() -> Result<(), String> {
     }
 }
 
+// 将指定窗口设为前台窗口
+pub fn set_foreground_hwnd(hwnd: isize) {
+    #[cfg(windows)]
+    {
+        use windows::Win32::Foundation::HWND;
+        use windows::Win32::UI::WindowsAndMessaging::SetForegroundWindow;
+        use std::ffi::c_void;
+
+        unsafe {
+            let _ = SetForegroundWindow(HWND(hwnd as *mut c_void));
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = hwnd;
+    }
+}
+
 // 获取当前记录的焦点窗口句柄
 pub fn get_last_focus_hwnd() -> Option<isize> {
     *LAST_FOCUS_HWND.lock()
 }
 
+// 获取当前前台窗口句柄
+pub fn get_foreground_hwnd() -> Option<isize> {
+    #[cfg(windows)]
+    {
+        use windows::Win32::UI::WindowsAndMessaging::GetForegroundWindow;
+
+        let hwnd = unsafe { GetForegroundWindow() };
+        (!hwnd.0.is_null()).then_some(hwnd.0 as isize)
+    }
+
+    #[cfg(not(windows))]
+    {
+        None
+    }
+}
+
+// 窗口是否属于本应用（本进程创建的窗口或已登记的排除窗口）
+#[cfg(windows)]
+fn is_own_window(hwnd: isize) -> bool {
+    use std::ffi::c_void;
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::System::Threading::GetCurrentProcessId;
+    use windows::Win32::UI::WindowsAndMessaging::GetWindowThreadProcessId;
+
+    let mut pid: u32 = 0;
+    unsafe {
+        GetWindowThreadProcessId(HWND(hwnd as *mut c_void), Some(&mut pid));
+        pid == GetCurrentProcessId() || EXCLUDED_HWNDS.lock().contains(&hwnd)
+    }
+}
+
+// 当前前台窗口是否属于本应用（无前台窗口时为 false）
+pub fn foreground_is_own_app() -> bool {
+    #[cfg(windows)]
+    {
+        get_foreground_hwnd().is_some_and(is_own_window)
+    }
+
+    #[cfg(not(windows))]
+    {
+        false
+    }
+}
+
+// 等待前台窗口切换为非本应用窗口，超时返回 false
+pub fn wait_for_external_foreground(timeout: std::time::Duration) -> bool {
+    #[cfg(windows)]
+    {
+        use std::time::{Duration, Instant};
+
+        let started = Instant::now();
+        loop {
+            // 切换过程中前台窗口可能短暂为空，继续等待
+            if get_foreground_hwnd().is_some_and(|hwnd| !is_own_window(hwnd)) {
+                return true;
+            }
+            if started.elapsed() >= timeout {
+                return false;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = timeout;
+        true
+    }
+}
+
 pub fn get_foreground_app_info() -> Option<ForegroundAppInfo> {
+    get_window_app_info(get_foreground_hwnd()?)
+}
+
+// 粘贴目标应用的信息：前台为本应用窗口（如从主窗口点击粘贴）时使用记录的上一个外部窗口
+pub fn get_paste_target_app_info() -> Option<ForegroundAppInfo> {
+    if foreground_is_own_app() {
+        get_window_app_info(get_last_focus_hwnd()?)
+    } else {
+        get_foreground_app_info()
+    }
+}
+
+// 获取指定窗口所属应用的信息
+pub fn get_window_app_info(hwnd_val: isize) -> Option<ForegroundAppInfo> {
     #[cfg(windows)]
     {
+        use std::ffi::c_void;
+        use windows::Win32::Foundation::HWND;
         use windows::Win32::System::ProcessStatus::GetModuleFileNameExW;
         use windows::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_VM_READ};
-        use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowTextW, GetWindowThreadProcessId};
+        use windows::Win32::UI::WindowsAndMessaging::{GetWindowTextW, GetWindowThreadProcessId};
 
         unsafe {
-            let hwnd = GetForegroundWindow();
+            let hwnd = HWND(hwnd_val as *mut c_void);
             if hwnd.0.is_null() {
                 return None;
             }
 
-            let hwnd_val = hwnd.0 as isize;
             if let Some((cached_hwnd, cached_info)) = LAST_FOREGROUND_CACHE.lock().clone() {
                 if cached_hwnd == hwnd_val {
                     return Some(cached_info);
@@ -180,6 +284,7 @@ pub fn get_foreground_app_info() -> Option<ForegroundAppInfo> {
 
     #[cfg(not(windows))]
     {
+        let _ = hwnd_val;
         None
     }
 }
@@ -265,7 +370,10 @@ unsafe extern "system" fn focus_callback(
         return;
     }
     
-    *LAST_FOCUS_HWND.lock() = Some(hwnd_val);
+    // 只记录外部窗口：本应用的其他窗口（如设置窗口）获得焦点时保留上一个外部窗口，作为粘贴目标
+    if !is_own_window(hwnd_val) {
+        *LAST_FOCUS_HWND.lock() = Some(hwnd_val);
+    }
 
     crate::services::system::hotkey::sync_hotkeys_for_foreground();
 }
@@ -2,14 +2,29 @@ use once_cell::sync::Lazy;
 use parking_lot::Mutex;
 use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Emitter, Manager, WebviewWindow};
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use super::clock::{Clock, SYSTEM_CLOCK};
+use super::action_queue::{ActionQueue, MergePolicy};
+use super::hotkey_execution::{resolve_execution_mode, ExecutionMode};
+use super::hotkey_error::{localize, HotkeyError};
 
 static APP_HANDLE: Lazy<Mutex<Option<AppHandle>>> = Lazy::new(|| Mutex::new(None));
 static REGISTERED_SHORTCUTS: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
 static HOTKEYS_ENABLED: AtomicBool = AtomicBool::new(true);
 static FOREGROUND_GLOBALLY_DISABLED: AtomicBool = AtomicBool::new(false);
+// 全局快捷键插件是否可用（受限环境中插件可能初始化失败）
+static HOTKEYS_AVAILABLE: AtomicBool = AtomicBool::new(false);
+// 已关闭热键管理器（退出流程中），此后不再注册任何热键
+static SHUT_DOWN: AtomicBool = AtomicBool::new(false);
+// 当前数字快捷键使用的修饰键（用于增量同步时判断是否需要重新注册）
+static NUMBER_SHORTCUTS_MODIFIER: Mutex<Option<String>> = Mutex::new(None);
+// 运行时快捷键覆盖（仅本次会话有效，不写入设置）
+static SHORTCUT_OVERRIDES: Lazy<Mutex<ShortcutOverrides>> = Lazy::new(|| Mutex::new(ShortcutOverrides::default()));
+// 快捷键动作在单个工作线程上按顺序执行
+static HOTKEY_ACTIONS: Lazy<ActionQueue> = Lazy::new(|| ActionQueue::new("hotkey"));
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum HotkeyActivation {
@@ -21,6 +36,10 @@ enum HotkeyActivation {
 struct HotkeySyncState {
     current: HotkeyActivation,
     desired: HotkeyActivation,
+    // 期望状态最近一次变化的时间
+    desired_since: Instant,
+    // 停用的宽限期：期望停用持续该时长后才应用
+    inactive_grace: Duration,
     syncing: bool,
 }
 
@@ -28,31 +47,158 @@ static HOTKEY_SYNC_STATE: Lazy<Mutex<HotkeySyncState>> = Lazy::new(|| {
     Mutex::new(HotkeySyncState {
         current: HotkeyActivation::Active,
         desired: HotkeyActivation::Active,
+        desired_since: Instant::now(),
+        inactive_grace: Duration::ZERO,
         syncing: false,
     })
 });
 
-static ACTIVE_PASTE_KEYS: Lazy<Mutex<HashSet<String>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+// 最近两项交替粘贴状态：(最新项, 次新项, 上次粘贴的项)
+static SWAP_LAST_TWO_STATE: Lazy<Mutex<Option<(i64, i64, i64)>>> = Lazy::new(|| Mutex::new(None));
+
+// 热键计时使用的时钟
+static HOTKEY_CLOCK: &dyn Clock = &SYSTEM_CLOCK;
+
+// 按键按下状态跟踪（记录按下时刻，用于区分首次按下与重复按下）
+#[derive(Debug, Default)]
+struct KeyPressTracker {
+    pressed: HashMap<String, Instant>,
+}
+
+impl KeyPressTracker {
+    // 首次按下返回 true，按住期间的重复按下返回 false
+    fn press(&mut self, key_id: &str, now: Instant) -> bool {
+        if self.pressed.contains_key(key_id) {
+            false
+        } else {
+            self.pressed.insert(key_id.to_string(), now);
+            true
+        }
+    }
+
+    fn is_active(&self, key_id: &str) -> bool {
+        self.pressed.contains_key(key_id)
+    }
+
+    // 释放按键，返回按住时长
+    fn release(&mut self, key_id: &str, now: Instant) -> Option<Duration> {
+        self.pressed
+            .remove(key_id)
+            .map(|pressed_at| now.saturating_duration_since(pressed_at))
+    }
+}
+
+static ACTIVE_PASTE_KEYS: Lazy<Mutex<KeyPressTracker>> = Lazy::new(|| Mutex::new(KeyPressTracker::default()));
 
 // 检查快捷键是否首次按下
 fn try_activate_key(key_id: &str) -> bool {
-    let mut active = ACTIVE_PASTE_KEYS.lock();
-    if active.contains(key_id) {
-        false
-    } else {
-        active.insert(key_id.to_string());
-        true
-    }
+    ACTIVE_PASTE_KEYS.lock().press(key_id, HOTKEY_CLOCK.now())
 }
 
 // 检查快捷键是否处于活跃状态（重复按下）
 fn is_key_active(key_id: &str) -> bool {
-    ACTIVE_PASTE_KEYS.lock().contains(key_id)
+    ACTIVE_PASTE_KEYS.lock().is_active(key_id)
 }
 
 // 释放快捷键
 fn deactivate_key(key_id: &str) {
-    ACTIVE_PASTE_KEYS.lock().remove(key_id);
+    ACTIVE_PASTE_KEYS.lock().release(key_id, HOTKEY_CLOCK.now());
+}
+
+// 首次粘贴超过该时长仍未结束时视为状态遗留，不再阻止重复按下
+const PASTE_IN_FLIGHT_TIMEOUT: Duration = Duration::from_secs(10);
+// 排队等待的重复粘贴上限（按住按键时系统自动重复触发，避免无限累积）
+const MAX_QUEUED_REPEATS: u32 = 20;
+
+// 首次粘贴尚未完成时重复按下的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RepeatPastePolicy {
+    // 立即粘贴（原有行为，慢速目标上可能与首次粘贴交错）
+    Overlap,
+    // 丢弃
+    Drop,
+    // 等首次粘贴完成后依次执行
+    Queue,
+}
+
+impl RepeatPastePolicy {
+    fn from_setting(value: &str) -> Self {
+        match value {
+            "drop" => Self::Drop,
+            "queue" => Self::Queue,
+            _ => Self::Overlap,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct InFlightPaste {
+    started: Instant,
+    queued: u32,
+}
+
+// 各粘贴快捷键的首次粘贴进行状态
+#[derive(Debug, Default)]
+struct PasteInFlightTracker {
+    pastes: HashMap<String, InFlightPaste>,
+}
+
+impl PasteInFlightTracker {
+    fn begin(&mut self, key_id: &str, now: Instant) {
+        self.pastes.insert(key_id.to_string(), InFlightPaste { started: now, queued: 0 });
+    }
+
+    // 重复按下时调用，返回是否应立即粘贴；排队时只记录次数
+    fn on_repeat(&mut self, key_id: &str, policy: RepeatPastePolicy, now: Instant) -> bool {
+        if policy == RepeatPastePolicy::Overlap {
+            return true;
+        }
+        match self.pastes.get_mut(key_id) {
+            Some(paste) if now.saturating_duration_since(paste.started) < PASTE_IN_FLIGHT_TIMEOUT => {
+                if policy == RepeatPastePolicy::Queue && paste.queued < MAX_QUEUED_REPEATS {
+                    paste.queued += 1;
+                }
+                false
+            }
+            _ => {
+                self.pastes.remove(key_id);
+                true
+            }
+        }
+    }
+
+    // 首次粘贴结束（成功或失败），返回排队的重复粘贴次数
+    fn finish(&mut self, key_id: &str) -> u32 {
+        self.pastes.remove(key_id).map_or(0, |paste| paste.queued)
+    }
+}
+
+static PASTE_IN_FLIGHT: Lazy<Mutex<PasteInFlightTracker>> = Lazy::new(|| Mutex::new(PasteInFlightTracker::default()));
+
+// 首次按下：标记粘贴进行中
+fn begin_paste(key_id: &str) {
+    PASTE_IN_FLIGHT.lock().begin(key_id, HOTKEY_CLOCK.now());
+}
+
+// 重复按下是否应立即粘贴（按设置丢弃或排队到首次粘贴完成后）
+fn repeat_paste_allowed(key_id: &str) -> bool {
+    let policy = RepeatPastePolicy::from_setting(&crate::get_settings().repeat_paste_policy);
+    PASTE_IN_FLIGHT.lock().on_repeat(key_id, policy, HOTKEY_CLOCK.now())
+}
+
+// 首次粘贴结束：清除进行中状态，失败时释放按键；成功时执行排队的重复粘贴
+fn complete_paste(key_id: &str, succeeded: bool) {
+    let queued = PASTE_IN_FLIGHT.lock().finish(key_id);
+    if !succeeded {
+        deactivate_key(key_id);
+        return;
+    }
+    for _ in 0..queued {
+        if let Err(e) = simulate_paste_only() {
+            eprintln!("执行排队的重复粘贴失败: {}", e);
+            break;
+        }
+    }
 }
 
 // 快捷键注册状态
@@ -61,14 +207,61 @@ pub struct ShortcutStatus {
     pub id: String,
     pub shortcut: String,
     pub success: bool,
+    // 机器可读的错误码（如 CONFLICT、SELF_CONFLICT）
     pub error: Option<String>,
+    // 按界面语言本地化的错误提示
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+impl ShortcutStatus {
+    pub fn ok(id: &str, shortcut: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            shortcut: shortcut.to_string(),
+            success: true,
+            error: None,
+            message: None,
+        }
+    }
+
+    pub fn failed(id: &str, shortcut: &str, error: &HotkeyError) -> Self {
+        Self {
+            id: id.to_string(),
+            shortcut: shortcut.to_string(),
+            success: false,
+            error: Some(error.code().to_string()),
+            message: Some(error.localized()),
+        }
+    }
 }
 
 static SHORTCUT_STATUS: Lazy<Mutex<HashMap<String, ShortcutStatus>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
+// 运行时启用/暂停状态的持久化键
+const STORE_KEY_HOTKEYS_ENABLED: &str = "hotkeys_runtime_enabled";
+
 pub fn init_hotkey_manager(app: AppHandle, _window: WebviewWindow) {
+    // 插件初始化失败时不会注册其状态，此后的注册全部短路并标记为 PLUGIN_UNAVAILABLE
+    let available = app
+        .try_state::<tauri_plugin_global_shortcut::GlobalShortcut<tauri::Wry>>()
+        .is_some();
+    HOTKEYS_AVAILABLE.store(available, Ordering::SeqCst);
+    if !available {
+        eprintln!("全局快捷键插件不可用，快捷键功能已停用，可通过托盘菜单使用");
+        let _ = crate::services::notification::show_notification(
+            &app,
+            "QuickClipboard",
+            &HotkeyError::PluginUnavailable.localized(),
+        );
+    }
     *APP_HANDLE.lock() = Some(app);
+    SHUT_DOWN.store(false, Ordering::SeqCst);
+
+    // 恢复上次的运行时开关，避免崩溃或重启后被暂停的热键意外恢复
+    let enabled = crate::services::store::get::<bool>(STORE_KEY_HOTKEYS_ENABLED).unwrap_or(true);
+    HOTKEYS_ENABLED.store(enabled, Ordering::Relaxed);
 }
 
 fn is_foreground_globally_disabled() -> bool {
@@ -78,10 +271,10 @@ fn is_foreground_globally_disabled() -> bool {
 fn apply_activation(desired: HotkeyActivation) {
     match desired {
         HotkeyActivation::Active => {
-            let _ = reload_from_settings();
+            let _ = sync_from_settings();
         }
         HotkeyActivation::Inactive => {
-            unregister_all();
+            deactivate(&crate::get_settings());
         }
     }
 }
@@ -100,9 +293,28 @@ pub fn sync_hotkeys_for_foreground() {
         HotkeyActivation::Active
     };
 
+    let grace = Duration::from_millis(settings.app_filter_disable_grace_ms);
+    request_activation(&HOTKEY_SYNC_STATE, desired, grace, apply_activation, HOTKEY_CLOCK);
+}
+
+// 前台切换合并延迟：连续切换应用时只应用稳定后的最终状态
+const HOTKEY_SYNC_SETTLE_MS: u64 = 150;
+
+// 记录期望状态，必要时启动唯一的同步线程；inactive_grace 为停用前的宽限期，启用总是立即应用
+fn request_activation(
+    sync_state: &'static Mutex<HotkeySyncState>,
+    desired: HotkeyActivation,
+    inactive_grace: Duration,
+    apply: fn(HotkeyActivation),
+    clock: &'static dyn Clock,
+) {
     {
-        let mut state = HOTKEY_SYNC_STATE.lock();
+        let mut state = sync_state.lock();
+        if state.desired != desired {
+            state.desired_since = clock.now();
+        }
         state.desired = desired;
+        state.inactive_grace = inactive_grace;
 
         if state.syncing {
             return;
@@ -115,33 +327,100 @@ pub fn sync_hotkeys_for_foreground() {
         state.syncing = true;
     }
 
-    std::thread::spawn(|| loop {
+    std::thread::spawn(move || run_sync_worker(sync_state, apply, clock));
+}
+
+fn run_sync_worker(
+    sync_state: &'static Mutex<HotkeySyncState>,
+    apply: fn(HotkeyActivation),
+    clock: &dyn Clock,
+) {
+    let mut wait = Duration::from_millis(HOTKEY_SYNC_SETTLE_MS);
+    loop {
+        clock.sleep(wait);
+        wait = Duration::from_millis(HOTKEY_SYNC_SETTLE_MS);
+
         let desired_now = {
-            let state = HOTKEY_SYNC_STATE.lock();
+            let mut state = sync_state.lock();
+            // 切换后又切回原状态，无需应用（宽限期内切回时即取消停用）
+            if state.current == state.desired {
+                state.syncing = false;
+                return;
+            }
+            // 停用需等期望状态保持满宽限期
+            if state.desired == HotkeyActivation::Inactive {
+                let elapsed = clock.now().saturating_duration_since(state.desired_since);
+                let remaining = state.inactive_grace.saturating_sub(elapsed);
+                if !remaining.is_zero() {
+                    wait = remaining;
+                    continue;
+                }
+            }
             state.desired
         };
 
-        apply_activation(desired_now);
+        apply(desired_now);
 
-        let mut state = HOTKEY_SYNC_STATE.lock();
+        let mut state = sync_state.lock();
         state.current = desired_now;
 
         if state.current == state.desired {
             state.syncing = false;
-            break;
+            return;
         }
-    });
+    }
+}
+
+// 快捷键动作被接受后的公共出口：按设置给出声音或界面反馈
+fn record_trigger(id: &str) {
+    if let Ok(app) = get_app() {
+        super::hotkey_feedback::dispatch(&app, binding_action(id));
+    }
 }
 
-fn get_app() -> Result<AppHandle, String> {
+fn get_app() -> Result<AppHandle, HotkeyError> {
     APP_HANDLE
         .lock()
         .clone()
-        .ok_or_else(|| "热键管理器未初始化".to_string())
+        .ok_or(HotkeyError::NotInitialized)
+}
+
+// 全局快捷键插件是否可用
+pub fn hotkeys_available() -> bool {
+    HOTKEYS_AVAILABLE.load(Ordering::SeqCst)
+}
+
+// 获取用于注册/注销快捷键的 AppHandle，插件不可用时返回 PluginUnavailable
+fn get_shortcut_app() -> Result<AppHandle, HotkeyError> {
+    let app = get_app()?;
+    if !hotkeys_available() {
+        return Err(HotkeyError::PluginUnavailable);
+    }
+    Ok(app)
 }
 
-fn parse_shortcut(shortcut_str: &str) -> Result<Shortcut, String> {
-    let normalized = shortcut_str
+// 将用户输入的快捷键字符串规范化为 Tauri 可解析的形式（不进行实际解析和注册）
+pub fn normalize_shortcut(input: &str) -> String {
+    let trimmed = input.trim();
+    let input = if trimmed.ends_with("++") {
+        // 加号键：即 Shift+=，未按 Shift 时补上
+        let modifiers = &trimmed[..trimmed.len() - 2];
+        let has_shift = modifiers.split('+').any(|m| m.trim().eq_ignore_ascii_case("shift"));
+        if has_shift {
+            format!("{}+Equal", modifiers)
+        } else {
+            format!("{}+Shift+Equal", modifiers)
+        }
+    } else if trimmed.ends_with('+') && input.trim_start().len() > trimmed.len() {
+        // 以 "+ " 结尾：按键本身是空格（前端 e.key 为 " "）
+        format!("{}Space", trimmed)
+    } else {
+        trimmed.to_string()
+    };
+    // 去除各段两侧多余空白
+    let input = input.split('+').map(|part| part.trim()).collect::<Vec<_>>().join("+");
+
+    let normalized = input
         .replace("Win+", "Super+")
         .replace("Ctrl+", "Control+");
     // 符号键 → Tauri Code 枚举名称
@@ -180,52 +459,310 @@ fn parse_shortcut(shortcut_str: &str) -> Result<Shortcut, String> {
             break;
         }
     }
-    normalized.parse::<Shortcut>()
-        .map_err(|e| format!("解析快捷键失败: {}", e))
+    normalized
+}
+
+pub(crate) fn parse_shortcut(shortcut_str: &str) -> Result<Shortcut, HotkeyError> {
+    normalize_shortcut(shortcut_str)
+        .parse::<Shortcut>()
+        .map_err(|_| HotkeyError::InvalidShortcut { shortcut: shortcut_str.to_string() })
+}
+
+// 修饰键（按规范顺序声明）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Modifier {
+    Control,
+    Alt,
+    Shift,
+    Super,
+}
+
+impl Modifier {
+    const ALL: [Modifier; 4] = [Modifier::Control, Modifier::Alt, Modifier::Shift, Modifier::Super];
+
+    fn flag(self) -> tauri_plugin_global_shortcut::Modifiers {
+        use tauri_plugin_global_shortcut::Modifiers;
+        match self {
+            Self::Control => Modifiers::CONTROL,
+            Self::Alt => Modifiers::ALT,
+            Self::Shift => Modifiers::SHIFT,
+            Self::Super => Modifiers::SUPER,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Control => "Control",
+            Self::Alt => "Alt",
+            Self::Shift => "Shift",
+            Self::Super => "Super",
+        }
+    }
+}
+
+// 解析后的快捷键组成（供界面分别显示各个按键）：修饰键按规范顺序，按键为 Code 名称
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ShortcutParts {
+    pub modifiers: Vec<Modifier>,
+    pub key: String,
+}
+
+// 按规范化结果拆分快捷键，符号、小键盘与媒体键的映射与注册时一致
+pub fn parse_shortcut_parts(input: &str) -> Result<ShortcutParts, String> {
+    let shortcut = parse_shortcut(input).map_err(|_| format!("无法识别的快捷键: {}", input))?;
+    Ok(ShortcutParts {
+        modifiers: Modifier::ALL
+            .into_iter()
+            .filter(|modifier| shortcut.mods.contains(modifier.flag()))
+            .collect(),
+        key: shortcut.key.to_string(),
+    })
+}
+
+// 快捷键的规范形式：修饰键按固定顺序排列，按键使用 Code 名称（如 "Control+Shift+KeyV"），
+// 同一组合的不同写法（Ctrl/Control、符号/Code 名称、修饰键顺序）得到相同结果
+pub fn canonical_shortcut(input: &str) -> Result<String, String> {
+    let parts = parse_shortcut_parts(input)?;
+    let mut names: Vec<&str> = parts.modifiers.iter().map(|modifier| modifier.name()).collect();
+    names.push(&parts.key);
+    Ok(names.join("+"))
+}
+
+// 两个快捷键字符串是否表示同一组合（无法解析时按原文比较）
+pub(crate) fn same_shortcut(a: &str, b: &str) -> bool {
+    match (canonical_shortcut(a), canonical_shortcut(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a.trim() == b.trim(),
+    }
 }
 
 pub fn register_shortcut<F>(id: &str, shortcut_str: &str, handler: F) -> Result<(), String>
 where
-    F: Fn(&AppHandle) + Send + Sync + 'static,
+    F: Fn(&AppHandle) -> Result<(), HotkeyError> + Send + Sync + 'static,
 {
-    let app = get_app()?;
+    let app = get_shortcut_app()?;
     
-    unregister_shortcut(id);
+    unregister_binding(id);
     
     let shortcut = match parse_shortcut(shortcut_str) {
         Ok(s) => s,
-        Err(_e) => {
-            update_shortcut_status(id, shortcut_str, false, Some("REGISTRATION_FAILED".to_string()));
-            return Err("REGISTRATION_FAILED".to_string());
+        Err(e) => {
+            update_shortcut_status(id, shortcut_str, Some(&e));
+            return Err(e.into());
         }
     };
     
+    let trigger_id = id.to_string();
     match app.global_shortcut()
         .on_shortcut(shortcut, move |app, _shortcut, event| {
             if event.state == ShortcutState::Pressed {
-                handler(app);
+                // 反馈只在动作通过确认与守卫后给出
+                super::hotkey_confirm::confirm_then(app, binding_action(&trigger_id), || {
+                    if handler(app).is_ok() {
+                        record_trigger(&trigger_id);
+                    }
+                });
             }
         }) {
         Ok(_) => {
             REGISTERED_SHORTCUTS.lock().push((id.to_string(), shortcut_str.to_string()));
-            update_shortcut_status(id, shortcut_str, true, None);
+            update_shortcut_status(id, shortcut_str, None);
             println!("已注册快捷键 [{}]: {}", id, shortcut_str);
             Ok(())
         }
         Err(e) => {
-            let error_msg = if e.to_string().contains("already registered") {
-                "CONFLICT".to_string()
-            } else {
-                "REGISTRATION_FAILED".to_string()
-            };
-            update_shortcut_status(id, shortcut_str, false, Some(error_msg.clone()));
-            Err(format!("注册快捷键失败: {}", e))
+            let error = registration_error(shortcut_str, &e.to_string());
+            eprintln!("{}", error);
+            update_shortcut_status(id, shortcut_str, Some(&error));
+            Err(error.into())
+        }
+    }
+}
+
+// 将插件返回的注册失败归类为错误码
+fn registration_error(shortcut_str: &str, detail: &str) -> HotkeyError {
+    if let Some(action) = is_reserved_system_shortcut(shortcut_str) {
+        HotkeyError::SystemReserved { shortcut: shortcut_str.to_string(), action }
+    } else if detail.contains("already registered") {
+        HotkeyError::Conflict { shortcut: shortcut_str.to_string() }
+    } else {
+        HotkeyError::RegistrationFailed { shortcut: shortcut_str.to_string(), detail: detail.to_string() }
+    }
+}
+
+// 查找本应用中已注册该组合的快捷键 ID
+fn find_registered_owner(shortcut: &Shortcut) -> Option<String> {
+    REGISTERED_SHORTCUTS
+        .lock()
+        .iter()
+        .find(|(_, s)| parse_shortcut(s).map(|r| &r == shortcut).unwrap_or(false))
+        .map(|(id, _)| id.clone())
+}
+
+// 系统保留快捷键（组合, 中文名称, 英文名称）：系统不会交给应用，或占用后会打断常用操作
+const RESERVED_SYSTEM_SHORTCUTS: &[(&str, &str, &str)] = &[
+    ("Win+L", "锁定电脑", "Lock PC"),
+    ("Ctrl+Alt+Delete", "安全选项", "Security options"),
+    ("Ctrl+Shift+Escape", "任务管理器", "Task Manager"),
+    ("Ctrl+Escape", "开始菜单", "Start menu"),
+    ("Alt+Tab", "切换窗口", "Switch windows"),
+    ("Alt+F4", "关闭窗口", "Close window"),
+    ("Win+Tab", "任务视图", "Task View"),
+    ("Win+D", "显示桌面", "Show desktop"),
+    ("Win+M", "最小化所有窗口", "Minimize all windows"),
+    ("Win+E", "文件资源管理器", "File Explorer"),
+    ("Win+R", "运行", "Run"),
+    ("Win+I", "系统设置", "Settings"),
+    ("Win+S", "搜索", "Search"),
+    ("Win+X", "快速链接菜单", "Quick Link menu"),
+    ("Win+A", "快速设置", "Quick Settings"),
+    ("Win+N", "通知中心", "Notification center"),
+    ("Win+P", "投影", "Project"),
+    ("Win+G", "游戏栏", "Game Bar"),
+    ("Win+Shift+S", "截图工具", "Snipping Tool"),
+    ("Win+.", "表情符号面板", "Emoji panel"),
+    ("Win+Space", "切换输入法", "Switch input method"),
+];
+
+// 检查组合是否为系统保留快捷键，返回对应的系统动作名称
+pub fn is_reserved_system_shortcut(shortcut_str: &str) -> Option<String> {
+    let shortcut = parse_shortcut(shortcut_str).ok()?;
+    RESERVED_SYSTEM_SHORTCUTS
+        .iter()
+        .find(|(combo, _, _)| parse_shortcut(combo).map(|r| r == shortcut).unwrap_or(false))
+        .map(|(_, zh, en)| localize(zh, en))
+}
+
+// 探测快捷键是否可用：不是系统保留组合、未被本应用占用，且能被系统成功注册
+pub fn check_shortcut_available(shortcut_str: &str) -> bool {
+    let Ok(app) = get_shortcut_app() else { return false };
+    let Ok(shortcut) = parse_shortcut(shortcut_str) else { return false };
+
+    if is_reserved_system_shortcut(shortcut_str).is_some() {
+        return false;
+    }
+
+    if find_registered_owner(&shortcut).is_some() || app.global_shortcut().is_registered(shortcut) {
+        return false;
+    }
+
+    match app.global_shortcut().register(shortcut) {
+        Ok(_) => {
+            let _ = app.global_shortcut().unregister(shortcut);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+// 生成候选组合：追加 Shift/Alt/Ctrl、Alt 与 Ctrl 互换，按与原组合的接近程度排序
+fn alternative_candidates(shortcut_str: &str) -> Vec<String> {
+    const ORDER: [&str; 4] = ["Ctrl", "Alt", "Shift", "Win"];
+
+    let parts: Vec<&str> = shortcut_str.split('+').map(|p| p.trim()).collect();
+    let Some((key, modifiers)) = parts.split_last() else { return Vec::new() };
+    if key.is_empty() {
+        return Vec::new();
+    }
+
+    let canonical = |m: &str| -> Option<&'static str> {
+        match m.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" | "cmdorctrl" | "commandorcontrol" => Some("Ctrl"),
+            "alt" | "option" => Some("Alt"),
+            "shift" => Some("Shift"),
+            "win" | "super" | "meta" | "cmd" | "command" => Some("Win"),
+            _ => None,
+        }
+    };
+    let mut original: Vec<&'static str> = Vec::new();
+    for m in modifiers {
+        match canonical(m) {
+            Some(c) if !original.contains(&c) => original.push(c),
+            Some(_) => {}
+            None => return Vec::new(),
+        }
+    }
+
+    let with = |mods: &[&'static str], add: &'static str| -> Vec<&'static str> {
+        let mut v = mods.to_vec();
+        if !v.contains(&add) { v.push(add); }
+        v
+    };
+    let swap = |mods: &[&'static str], from: &'static str, to: &'static str| -> Vec<&'static str> {
+        mods.iter().map(|m| if *m == from { to } else { *m }).collect()
+    };
+
+    let mut variants: Vec<Vec<&'static str>> = vec![
+        with(&original, "Shift"),
+        with(&original, "Alt"),
+        swap(&original, "Alt", "Ctrl"),
+        swap(&original, "Ctrl", "Alt"),
+        with(&original, "Ctrl"),
+        with(&with(&original, "Shift"), "Alt"),
+        with(&with(&original, "Ctrl"), "Shift"),
+    ];
+
+    let mut original_sorted = original.clone();
+    original_sorted.sort_by_key(|m| ORDER.iter().position(|o| o == m));
+
+    let mut result: Vec<String> = Vec::new();
+    for v in variants.iter_mut() {
+        v.sort_by_key(|m| ORDER.iter().position(|o| o == m));
+        v.dedup();
+        if v.is_empty() || *v == original_sorted {
+            continue;
         }
+        let candidate = format!("{}+{}", v.join("+"), key);
+        if !result.contains(&candidate) {
+            result.push(candidate);
+        }
+    }
+    result
+}
+
+// 快捷键冲突时推荐附近可用的组合
+pub fn suggest_alternative(shortcut_str: &str) -> Option<String> {
+    alternative_candidates(shortcut_str)
+        .into_iter()
+        .find(|candidate| check_shortcut_available(candidate))
+}
+
+// 组合的注册 ID：主组合为动作 ID 本身，第 n 个备用组合为 "ID#n"
+fn binding_id(action: &str, n: usize) -> String {
+    if n == 0 {
+        action.to_string()
+    } else {
+        format!("{}#{}", action, n)
     }
 }
 
+// 注册 ID 对应的动作 ID
+fn binding_action(id: &str) -> &str {
+    id.split_once('#').map_or(id, |(action, _)| action)
+}
+
+// 注册 ID 是否属于 id：id 为动作 ID 时包含其全部备用组合，为 "ID#n" 时只匹配自身
+fn binding_matches(binding: &str, id: &str) -> bool {
+    binding == id || (!id.contains('#') && binding_action(binding) == id)
+}
+
+// 注销快捷键：传入动作 ID 时同时注销其全部备用组合，传入 "ID#n" 时只注销该组合
 pub fn unregister_shortcut(id: &str) {
-    let app = match get_app() {
+    let bindings: Vec<String> = REGISTERED_SHORTCUTS
+        .lock()
+        .iter()
+        .map(|(registered_id, _)| registered_id.clone())
+        .filter(|registered_id| binding_matches(registered_id, id))
+        .collect();
+    for binding in bindings {
+        unregister_binding(&binding);
+    }
+    SHORTCUT_STATUS.lock().retain(|status_id, _| !binding_matches(status_id, id));
+}
+
+// 注销单个组合（按注册 ID 精确匹配）
+fn unregister_binding(id: &str) {
+    let app = match get_shortcut_app() {
         Ok(app) => app,
         Err(_) => return,
     };
@@ -242,208 +779,622 @@ pub fn unregister_shortcut(id: &str) {
     clear_shortcut_status(id);
 }
 
-pub fn register_toggle_hotkey(shortcut_str: &str) -> Result<(), String> {
-    register_shortcut("toggle", shortcut_str, |app| {
-        if is_foreground_globally_disabled() {
-            return;
+// 快捷键动作：真实按键与 trigger_shortcut_action 共用同一实现。
+// 返回 Err 表示被守卫拦截（低占用模式、前台应用已禁用），动作本身的失败在内部记录日志
+
+// 守卫：前台应用已全局禁用时不执行
+fn guard_foreground() -> Result<(), HotkeyError> {
+    if is_foreground_globally_disabled() {
+        return Err(HotkeyError::ForegroundDisabled);
+    }
+    Ok(())
+}
+
+// 守卫：低占用模式下不执行（窗口已销毁），并检查前台应用
+fn guard_windows_available() -> Result<(), HotkeyError> {
+    if crate::services::low_memory::is_low_memory_mode() {
+        return Err(HotkeyError::LowMemoryMode);
+    }
+    guard_foreground()
+}
+
+// 快捷键动作当前是否会被守卫接受（与各 action_* 中的守卫一致）
+// 用于粘滞修饰键：被拒绝时不拦截按键，也不消费已锁定的修饰键
+pub fn shortcut_action_allowed(id: &str) -> bool {
+    match binding_action(id) {
+        "panic" | "toggle_clipboard_monitor" | "toggle_incognito" | "toggle_paste_with_format" | "paste_plain_text" => true,
+        "quickpaste" | "screenshot" | "screenshot_quick_save" | "screenshot_quick_pin" | "screenshot_quick_ocr" | "ocr_paste" => {
+            guard_windows_available().is_ok()
         }
-        let _ = crate::toggle_main_window_visibility(app);
-    })
+        _ => guard_foreground().is_ok(),
+    }
 }
 
-pub fn register_quickpaste_hotkey(shortcut_str: &str) -> Result<(), String> {
-    let app = get_app()?;
-    
-    unregister_shortcut("quickpaste");
-    
-    let shortcut = parse_shortcut(shortcut_str)?;
-    
-    app.global_shortcut()
-        .on_shortcut(shortcut, move |app, _shortcut, event| {
-            if event.state == ShortcutState::Pressed {
-                if crate::services::low_memory::is_low_memory_mode() {
-                    return;
-                }
+// 主窗口切换快捷键的处理结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ToggleDecision {
+    ToggleMain,
+    CloseQuickpaste,
+    Ignore,
+}
 
-                if is_foreground_globally_disabled() {
-                    return;
-                }
-                
-                let settings = crate::get_settings();
-                let is_keyboard_mode = settings.quickpaste_paste_on_modifier_release;
-                let is_visible = crate::windows::quickpaste::is_visible();
-                
-                if is_keyboard_mode && is_visible {
-                    return;
-                }
-                
-                if let Err(e) = crate::windows::quickpaste::show_quickpaste_window(&app) {
-                    eprintln!("显示便捷粘贴窗口失败: {}", e);
-                }
-            } else if event.state == ShortcutState::Released {
-                if crate::services::low_memory::is_low_memory_mode() {
-                    return;
-                }
+// 便捷粘贴窗口显示时优先处理便捷粘贴，避免两个窗口同时显示
+fn toggle_decision(quickpaste_visible: bool, mode: &str) -> ToggleDecision {
+    if !quickpaste_visible {
+        return ToggleDecision::ToggleMain;
+    }
+    match mode {
+        "ignore" => ToggleDecision::Ignore,
+        _ => ToggleDecision::CloseQuickpaste,
+    }
+}
 
-                if is_foreground_globally_disabled() {
-                    return;
-                }
-                
-                let settings = crate::get_settings();
-                if settings.quickpaste_paste_on_modifier_release {
-                    return;
-                }
-                
-                if let Some(window) = app.get_webview_window("quickpaste") {
-                    let _ = window.emit("quickpaste-hide", ());
+// 按动作的执行方式运行：直接在快捷键回调中执行，或提交到动作队列（默认方式见 hotkey_execution）
+fn run_action<F>(key: &'static str, policy: MergePolicy, run: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    run_action_as(key, key, policy, run);
+}
+
+// 执行方式按动作 ID（如 "number_3"）解析，key 为动作队列中用于合并的键；
+// 返回动作是否已执行或入队（被合并或队列已满时为 false）
+fn run_action_as<F>(id: &str, key: &'static str, policy: MergePolicy, run: F) -> bool
+where
+    F: FnOnce() + Send + 'static,
+{
+    match resolve_execution_mode(&crate::get_settings().hotkey_execution, id) {
+        ExecutionMode::Inline => {
+            run();
+            true
+        }
+        ExecutionMode::Worker => HOTKEY_ACTIONS.submit(key, policy, run),
+    }
+}
+
+fn action_toggle(app: &AppHandle) -> Result<(), HotkeyError> {
+    guard_foreground()?;
+    let app = app.clone();
+    run_action("toggle", MergePolicy::DropIfPending, move || {
+        let mode = crate::get_settings().toggle_while_quickpaste;
+        match toggle_decision(crate::windows::quickpaste::is_visible(), &mode) {
+            ToggleDecision::ToggleMain => {
+                let _ = crate::toggle_main_window_visibility(&app);
+            }
+            ToggleDecision::CloseQuickpaste => {
+                if let Err(e) = crate::windows::quickpaste::hide_quickpaste_window(&app) {
+                    eprintln!("关闭便捷粘贴窗口失败: {}", e);
                 }
-                
-                let app_clone = app.clone();
-                std::thread::spawn(move || {
-                    std::thread::sleep(std::time::Duration::from_millis(50));
-                    if let Err(e) = crate::windows::quickpaste::hide_quickpaste_window(&app_clone) {
-                        eprintln!("隐藏便捷粘贴窗口失败: {}", e);
-                    }
-                });
             }
-        })
-        .map_err(|e| format!("注册便捷粘贴快捷键失败: {}", e))?;
-    
-    REGISTERED_SHORTCUTS.lock().push(("quickpaste".to_string(), shortcut_str.to_string()));
-    
-    println!("已注册便捷粘贴快捷键: {}", shortcut_str);
+            ToggleDecision::Ignore => {}
+        }
+    });
     Ok(())
 }
 
-#[cfg(feature = "screenshot-suite")]
-pub fn register_screenshot_hotkey(shortcut_str: &str) -> Result<(), String> {
-    register_shortcut("screenshot", shortcut_str, |app| {
-        if crate::services::low_memory::is_low_memory_mode() {
-            return;
-        }
+// 按下与松开使用同一执行方式，保证两者按顺序处理
+fn action_quickpaste_show(app: &AppHandle) -> Result<(), HotkeyError> {
+    guard_windows_available()?;
+    let app = app.clone();
+    run_action("quickpaste", MergePolicy::Always, move || {
+        let settings = crate::get_settings();
+        let is_keyboard_mode = settings.quickpaste_paste_on_modifier_release;
+        let is_visible = crate::windows::quickpaste::is_visible();
 
-        if is_foreground_globally_disabled() {
+        if is_keyboard_mode && is_visible {
             return;
         }
-        screenshot_suite::windows::screenshot_window::auto_selection::clear_auto_selection_cache();
-        if let Err(e) = screenshot_suite::start_screenshot(app) {
-            eprintln!("启动截图窗口失败: {}", e);
-        }
-    })
-}
 
-#[cfg(not(feature = "screenshot-suite"))]
-pub fn register_screenshot_hotkey(_shortcut_str: &str) -> Result<(), String> {
+        if let Err(e) = crate::windows::quickpaste::show_quickpaste_window(&app) {
+            eprintln!("显示便捷粘贴窗口失败: {}", e);
+        }
+    });
     Ok(())
 }
 
-#[cfg(feature = "screenshot-suite")]
-pub fn register_screenshot_quick_save_hotkey(shortcut_str: &str) -> Result<(), String> {
-    register_shortcut("screenshot_quick_save", shortcut_str, |app| {
-        if crate::services::low_memory::is_low_memory_mode() {
+fn action_quickpaste_release(app: &AppHandle) -> Result<(), HotkeyError> {
+    guard_windows_available()?;
+    let app = app.clone();
+    run_action("quickpaste", MergePolicy::Always, move || {
+        let settings = crate::get_settings();
+        if settings.quickpaste_paste_on_modifier_release {
             return;
         }
-        if is_foreground_globally_disabled() {
+
+        // 点击外部隐藏模式下松开快捷键保持窗口显示，由鼠标选择粘贴
+        if settings.quickpaste_hide_mode == "outside_click" {
             return;
         }
-        if let Err(e) = screenshot_suite::start_screenshot_quick_save(app) {
-            eprintln!("启动快速保存截图失败: {}", e);
+
+        if let Some(window) = app.get_webview_window("quickpaste") {
+            let _ = window.emit("quickpaste-hide", ());
         }
-    })
-}
 
-#[cfg(not(feature = "screenshot-suite"))]
-pub fn register_screenshot_quick_save_hotkey(_shortcut_str: &str) -> Result<(), String> {
+        crate::windows::quickpaste::schedule_hide_quickpaste_window(&app);
+    });
     Ok(())
 }
 
 #[cfg(feature = "screenshot-suite")]
-pub fn register_screenshot_quick_pin_hotkey(shortcut_str: &str) -> Result<(), String> {
-    register_shortcut("screenshot_quick_pin", shortcut_str, |app| {
-        if crate::services::low_memory::is_low_memory_mode() {
-            return;
-        }
-        if is_foreground_globally_disabled() {
-            return;
+fn action_screenshot(app: &AppHandle) -> Result<(), HotkeyError> {
+    guard_windows_available()?;
+    let app = app.clone();
+    run_action("screenshot", MergePolicy::DropIfPending, move || {
+        screenshot_suite::windows::screenshot_window::auto_selection::clear_auto_selection_cache();
+        if let Err(e) = screenshot_suite::start_screenshot(&app) {
+            eprintln!("启动截图窗口失败: {}", e);
         }
-        if let Err(e) = screenshot_suite::start_screenshot_quick_pin(app) {
-            eprintln!("启动快速贴图截图失败: {}", e);
+    });
+    Ok(())
+}
+
+#[cfg(feature = "screenshot-suite")]
+fn action_screenshot_quick_save(app: &AppHandle) -> Result<(), HotkeyError> {
+    guard_windows_available()?;
+    let app = app.clone();
+    run_action("screenshot_quick_save", MergePolicy::DropIfPending, move || {
+        if let Err(e) = screenshot_suite::start_screenshot_quick_save(&app) {
+            eprintln!("启动快速保存截图失败: {}", e);
         }
-    })
+    });
+    Ok(())
 }
 
-#[cfg(not(feature = "screenshot-suite"))]
-pub fn register_screenshot_quick_pin_hotkey(_shortcut_str: &str) -> Result<(), String> {
+#[cfg(feature = "screenshot-suite")]
+fn action_screenshot_quick_pin(app: &AppHandle) -> Result<(), HotkeyError> {
+    guard_windows_available()?;
+    let app = app.clone();
+    run_action("screenshot_quick_pin", MergePolicy::DropIfPending, move || {
+        if let Err(e) = screenshot_suite::start_screenshot_quick_pin(&app) {
+            eprintln!("启动快速贴图截图失败: {}", e);
+        }
+    });
     Ok(())
 }
 
 #[cfg(feature = "screenshot-suite")]
-pub fn register_screenshot_quick_ocr_hotkey(shortcut_str: &str) -> Result<(), String> {
-    register_shortcut("screenshot_quick_ocr", shortcut_str, |app| {
-        if crate::services::low_memory::is_low_memory_mode() {
-            return;
+fn action_screenshot_quick_ocr(app: &AppHandle) -> Result<(), HotkeyError> {
+    guard_windows_available()?;
+    let app = app.clone();
+    run_action("screenshot_quick_ocr", MergePolicy::DropIfPending, move || {
+        if let Err(e) = screenshot_suite::start_screenshot_quick_ocr(&app) {
+            eprintln!("启动快速OCR截图失败: {}", e);
+        }
+    });
+    Ok(())
+}
+
+#[cfg(feature = "screenshot-suite")]
+fn action_ocr_paste(app: &AppHandle) -> Result<(), HotkeyError> {
+    guard_windows_available()?;
+    let app = app.clone();
+    run_action("ocr_paste", MergePolicy::DropIfPending, move || {
+        crate::services::paste::ocr_paste::arm(&app);
+        if let Err(e) = screenshot_suite::start_screenshot_quick_ocr(&app) {
+            crate::services::paste::ocr_paste::cancel();
+            eprintln!("启动OCR粘贴失败: {}", e);
+        }
+    });
+    Ok(())
+}
+
+fn action_toggle_clipboard_monitor(app: &AppHandle) -> Result<(), HotkeyError> {
+    let app = app.clone();
+    run_action("toggle_clipboard_monitor", MergePolicy::DropIfPending, move || {
+        if let Err(e) = crate::commands::settings::toggle_clipboard_monitor(&app) {
+            eprintln!("切换剪贴板监听状态失败: {}", e);
+        }
+    });
+    Ok(())
+}
+
+fn action_toggle_incognito(app: &AppHandle) -> Result<(), HotkeyError> {
+    let app = app.clone();
+    run_action("toggle_incognito", MergePolicy::DropIfPending, move || {
+        if let Err(e) = crate::commands::settings::toggle_incognito(&app) {
+            eprintln!("切换无痕模式失败: {}", e);
+        }
+    });
+    Ok(())
+}
+
+fn action_toggle_paste_with_format(app: &AppHandle) -> Result<(), HotkeyError> {
+    let app = app.clone();
+    run_action("toggle_paste_with_format", MergePolicy::DropIfPending, move || {
+        if let Err(e) = crate::commands::settings::toggle_paste_with_format_by_hotkey(&app) {
+            eprintln!("切换格式粘贴状态失败: {}", e);
+        }
+    });
+    Ok(())
+}
+
+// 紧急隐藏：不经过守卫（前台应用已禁用、低占用模式下也要执行），也不经过 run_action 排队等待其他动作
+fn action_panic(app: &AppHandle) -> Result<(), HotkeyError> {
+    crate::services::system::panic::trigger_panic(app);
+    Ok(())
+}
+
+// 纯文本粘贴首次按下；失败时回调 on_error（用于重置按键状态）
+// on_done 在粘贴结束后调用，参数为是否成功
+fn action_paste_plain_text<F>(app: &AppHandle, on_done: F) -> Result<(), HotkeyError>
+where
+    F: FnOnce(bool) + Send + 'static,
+{
+    let app = app.clone();
+    run_action("paste_plain_text", MergePolicy::Always, move || {
+        let result = handle_paste_plain_text_press(&app);
+        if let Err(e) = &result {
+            eprintln!("纯文本粘贴失败: {}", e);
+        }
+        on_done(result.is_ok());
+    });
+    Ok(())
+}
+
+fn action_macro_next() -> Result<(), HotkeyError> {
+    guard_foreground()?;
+    run_action("macro_next", MergePolicy::Always, || {
+        if let Err(e) = crate::services::paste::macros::paste_next_macro_step() {
+            eprintln!("粘贴宏下一项失败: {}", e);
+        }
+    });
+    Ok(())
+}
+
+fn action_swap_last_two() -> Result<(), HotkeyError> {
+    guard_foreground()?;
+    run_action("swap_last_two", MergePolicy::Always, || {
+        if let Err(e) = handle_swap_last_two_press() {
+            eprintln!("交替粘贴最近两项失败: {}", e);
         }
-        if is_foreground_globally_disabled() {
+    });
+    Ok(())
+}
+
+fn action_capture_clipboard() -> Result<(), HotkeyError> {
+    guard_foreground()?;
+    run_action("capture_clipboard", MergePolicy::DropIfPending, || {
+        if let Err(e) = crate::services::clipboard::capture_current_clipboard() {
+            eprintln!("捕获当前剪贴板失败: {}", e);
+        }
+    });
+    Ok(())
+}
+
+// 抓取选中内容：模拟复制并等待剪贴板变化后保存，按设置随后显示便捷粘贴窗口
+fn action_grab_selection(app: &AppHandle) -> Result<(), HotkeyError> {
+    guard_foreground()?;
+    let app = app.clone();
+    run_action("grab_selection", MergePolicy::DropIfPending, move || {
+        if let Err(e) = crate::services::clipboard::grab_selection() {
+            eprintln!("抓取选中内容失败: {}", e);
             return;
         }
-        if let Err(e) = screenshot_suite::start_screenshot_quick_ocr(app) {
-            eprintln!("启动快速OCR截图失败: {}", e);
+        if crate::get_settings().grab_selection_show_quickpaste
+            && !crate::services::low_memory::is_low_memory_mode()
+        {
+            if let Err(e) = crate::windows::quickpaste::show_quickpaste_window(&app) {
+                eprintln!("显示便捷粘贴窗口失败: {}", e);
+            }
         }
-    })
+    });
+    Ok(())
 }
 
-#[cfg(not(feature = "screenshot-suite"))]
-pub fn register_screenshot_quick_ocr_hotkey(_shortcut_str: &str) -> Result<(), String> {
+fn action_paste_pop() -> Result<(), HotkeyError> {
+    guard_foreground()?;
+    run_action("paste_pop", MergePolicy::Always, || {
+        if let Err(e) = crate::services::paste::paste_stack::paste_pop() {
+            eprintln!("替换上次粘贴失败: {}", e);
+        }
+    });
+    Ok(())
+}
+
+// 粘贴指定索引（从 0 开始）的项；失败时回调 on_error（用于重置按键状态）
+// on_done 在粘贴结束后调用，参数为是否成功
+fn action_paste_index<F>(item_index: usize, on_done: F) -> Result<(), HotkeyError>
+where
+    F: FnOnce(bool) + Send + 'static,
+{
+    guard_foreground()?;
+    run_action_as(&paste_index_id(item_index + 1), "paste_index", MergePolicy::Always, move || {
+        let result = handle_number_shortcut_press(item_index);
+        if let Err(e) = &result {
+            eprintln!("粘贴第 {} 项失败: {}", item_index + 1, e);
+        }
+        on_done(result.is_ok());
+    });
     Ok(())
 }
 
-pub fn register_toggle_clipboard_monitor_hotkey(shortcut_str: &str) -> Result<(), String> {
-    register_shortcut("toggle_clipboard_monitor", shortcut_str, |app| {
-        let app_clone = app.clone();
-        std::thread::spawn(move || {
-            if let Err(e) = crate::commands::settings::toggle_clipboard_monitor(&app_clone) {
-                eprintln!("切换剪贴板监听状态失败: {}", e);
+fn action_open_source_app() -> Result<(), HotkeyError> {
+    guard_foreground()?;
+    run_action("open_source_app", MergePolicy::DropIfPending, || {
+        if let Err(e) = crate::services::system::source_app::open_latest_source_app() {
+            eprintln!("打开来源应用失败: {}", e);
+        }
+    });
+    Ok(())
+}
+
+fn action_paste_quoted() -> Result<(), HotkeyError> {
+    guard_foreground()?;
+    run_action("paste_quoted", MergePolicy::Always, || {
+        if let Err(e) = handle_paste_quoted_press() {
+            eprintln!("引用粘贴失败: {}", e);
+        }
+    });
+    Ok(())
+}
+
+fn action_freeze_top_item(app: &AppHandle) -> Result<(), HotkeyError> {
+    guard_foreground()?;
+    let app = app.clone();
+    run_action("freeze_top_item", MergePolicy::DropIfPending, move || {
+        match crate::services::database::freeze_top_item() {
+            Ok(Some(_)) => {
+                let _ = app.emit("clipboard-updated", ());
             }
-        });
-    })
+            Ok(None) => {}
+            Err(e) => eprintln!("冻结最新剪贴板项失败: {}", e),
+        }
+    });
+    Ok(())
+}
+
+fn action_quick_note(app: &AppHandle) -> Result<(), HotkeyError> {
+    guard_foreground()?;
+    let app = app.clone();
+    run_action("quick_note", MergePolicy::DropIfPending, move || open_quick_note(app));
+    Ok(())
 }
 
-pub fn register_toggle_paste_with_format_hotkey(shortcut_str: &str) -> Result<(), String> {
-    register_shortcut("toggle_paste_with_format", shortcut_str, |app| {
-        let app_clone = app.clone();
-        std::thread::spawn(move || {
-            if let Err(e) = crate::commands::settings::toggle_paste_with_format(&app_clone) {
-                eprintln!("切换格式粘贴状态失败: {}", e);
+fn open_quick_note(app: AppHandle) {
+    // 输入框已打开时只将其置前
+    if let Some(window) = app.get_webview_window("input-dialog") {
+        let _ = window.set_focus();
+        return;
+    }
+    tauri::async_runtime::spawn(async move {
+        use crate::windows::plugins::input_dialog::window::{show_dialog, InputDialogOptions, InputType};
+        let options = InputDialogOptions {
+            title: localize("快速笔记", "Quick Note"),
+            message: localize("输入内容，按 Ctrl+Enter 保存到历史：", "Type a note, press Ctrl+Enter to save it to history:"),
+            placeholder: None,
+            default_value: None,
+            input_type: InputType::Multiline,
+            min_value: None,
+            max_value: None,
+        };
+        match show_dialog(app, options).await {
+            Ok(Some(text)) => {
+                if let Err(e) = crate::services::clipboard::save_quick_note(&text) {
+                    eprintln!("保存快速笔记失败: {}", e);
+                }
+            }
+            Ok(None) => {}
+            Err(e) => eprintln!("打开快速笔记输入框失败: {}", e),
+        }
+    });
+}
+
+fn action_compose(app: &AppHandle) -> Result<(), HotkeyError> {
+    guard_foreground()?;
+    let app = app.clone();
+    run_action("compose", MergePolicy::DropIfPending, move || {
+        // 在异步运行时中创建窗口，避免阻塞快捷键线程
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = crate::windows::compose_window::open_compose_window(&app) {
+                eprintln!("打开组合粘贴窗口失败: {}", e);
             }
         });
-    })
+    });
+    Ok(())
+}
+
+// 不按键直接执行快捷键对应的动作（用于设置界面的测试按钮），守卫同样生效
+pub fn trigger_shortcut_action(id: &str) -> Result<(), HotkeyError> {
+    let app = get_app()?;
+    // 备用组合执行与主组合相同的动作
+    let id = binding_action(id);
+    dispatch_shortcut_action(&app, id)?;
+    record_trigger(id);
+    Ok(())
 }
 
-pub fn register_paste_plain_text_hotkey(shortcut_str: &str) -> Result<(), String> {
+// 以按键方式触发快捷键动作（粘滞修饰键等非全局快捷键路径），与真实按键一样需通过连按确认
+pub fn press_shortcut_action(id: &str) -> Result<(), HotkeyError> {
     let app = get_app()?;
+    let mut result = Ok(());
+    super::hotkey_confirm::confirm_then(&app, binding_action(id), || {
+        result = trigger_shortcut_action(id);
+    });
+    result
+}
+
+// 按动作 ID 分发，返回 Err 表示动作被拒绝（守卫拦截或未知动作）
+fn dispatch_shortcut_action(app: &AppHandle, id: &str) -> Result<(), HotkeyError> {
+    if let Some(index) = id
+        .strip_prefix("number_")
+        .and_then(|n| n.parse::<usize>().ok())
+        .filter(|n| (1..=9).contains(n))
+    {
+        guard_foreground()?;
+        run_action_as(id, "number_shortcut", MergePolicy::Always, move || {
+            if let Err(e) = handle_number_shortcut_press(index - 1) {
+                eprintln!("执行数字快捷键 {} 失败: {}", index, e);
+            }
+        });
+        return Ok(());
+    }
 
-    unregister_shortcut("paste_plain_text");
+    if let Some(index) = id.strip_prefix("paste_index_").and_then(|n| n.parse::<usize>().ok()) {
+        let item_index = validate_paste_index(index)
+            .map_err(|_| HotkeyError::UnknownAction { id: id.to_string() })?;
+        return action_paste_index(item_index, |_| {});
+    }
 
+    match id {
+        "toggle" => action_toggle(app),
+        "quickpaste" => action_quickpaste_show(app),
+        #[cfg(feature = "screenshot-suite")]
+        "screenshot" => action_screenshot(app),
+        #[cfg(feature = "screenshot-suite")]
+        "screenshot_quick_save" => action_screenshot_quick_save(app),
+        #[cfg(feature = "screenshot-suite")]
+        "screenshot_quick_pin" => action_screenshot_quick_pin(app),
+        #[cfg(feature = "screenshot-suite")]
+        "screenshot_quick_ocr" => action_screenshot_quick_ocr(app),
+        #[cfg(feature = "screenshot-suite")]
+        "ocr_paste" => action_ocr_paste(app),
+        #[cfg(not(feature = "screenshot-suite"))]
+        "screenshot" | "screenshot_quick_save" | "screenshot_quick_pin" | "screenshot_quick_ocr" | "ocr_paste" => {
+            Err(HotkeyError::FeatureUnavailable { feature: localize("截图", "screenshot") })
+        }
+        "toggle_clipboard_monitor" => action_toggle_clipboard_monitor(app),
+        "toggle_incognito" => action_toggle_incognito(app),
+        "toggle_paste_with_format" => action_toggle_paste_with_format(app),
+        "panic" => action_panic(app),
+        "paste_plain_text" => action_paste_plain_text(app, |_| {}),
+        "macro_next" => action_macro_next(),
+        "swap_last_two" => action_swap_last_two(),
+        "capture_clipboard" => action_capture_clipboard(),
+        "grab_selection" => action_grab_selection(app),
+        "paste_pop" => action_paste_pop(),
+        "open_source_app" => action_open_source_app(),
+        "freeze_top_item" => action_freeze_top_item(app),
+        "paste_quoted" => action_paste_quoted(),
+        "quick_note" => action_quick_note(app),
+        "compose" => action_compose(app),
+        _ => Err(HotkeyError::UnknownAction { id: id.to_string() }),
+    }
+}
+
+pub fn register_toggle_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, action_toggle)
+}
+
+pub fn register_quickpaste_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    let app = get_shortcut_app()?;
+    
+    unregister_binding(id);
+    
     let shortcut = parse_shortcut(shortcut_str)?;
-    let key_id = "paste_plain_text".to_string();
+    
+    app.global_shortcut()
+        .on_shortcut(shortcut, move |app, _shortcut, event| {
+            if event.state == ShortcutState::Pressed {
+                if action_quickpaste_show(app).is_ok() {
+                    record_trigger("quickpaste");
+                }
+            } else if event.state == ShortcutState::Released {
+                let _ = action_quickpaste_release(app);
+            }
+        })
+        .map_err(|e| registration_error(shortcut_str, &e.to_string()))?;
+    
+    REGISTERED_SHORTCUTS.lock().push((id.to_string(), shortcut_str.to_string()));
+    
+    println!("已注册便捷粘贴快捷键 [{}]: {}", id, shortcut_str);
+    Ok(())
+}
+
+#[cfg(feature = "screenshot-suite")]
+pub fn register_screenshot_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, action_screenshot)
+}
+
+#[cfg(not(feature = "screenshot-suite"))]
+pub fn register_screenshot_hotkey(_id: &str, _shortcut_str: &str) -> Result<(), String> {
+    Ok(())
+}
+
+#[cfg(feature = "screenshot-suite")]
+pub fn register_screenshot_quick_save_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, action_screenshot_quick_save)
+}
+
+#[cfg(not(feature = "screenshot-suite"))]
+pub fn register_screenshot_quick_save_hotkey(_id: &str, _shortcut_str: &str) -> Result<(), String> {
+    Ok(())
+}
+
+#[cfg(feature = "screenshot-suite")]
+pub fn register_screenshot_quick_pin_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, action_screenshot_quick_pin)
+}
+
+#[cfg(not(feature = "screenshot-suite"))]
+pub fn register_screenshot_quick_pin_hotkey(_id: &str, _shortcut_str: &str) -> Result<(), String> {
+    Ok(())
+}
+
+#[cfg(feature = "screenshot-suite")]
+pub fn register_screenshot_quick_ocr_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, action_screenshot_quick_ocr)
+}
+
+#[cfg(not(feature = "screenshot-suite"))]
+pub fn register_screenshot_quick_ocr_hotkey(_id: &str, _shortcut_str: &str) -> Result<(), String> {
+    Ok(())
+}
+
+#[cfg(feature = "screenshot-suite")]
+pub fn register_ocr_paste_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, action_ocr_paste)
+}
+
+#[cfg(not(feature = "screenshot-suite"))]
+pub fn register_ocr_paste_hotkey(_id: &str, _shortcut_str: &str) -> Result<(), String> {
+    Ok(())
+}
+
+pub fn register_toggle_clipboard_monitor_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, action_toggle_clipboard_monitor)
+}
+
+pub fn register_toggle_incognito_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, action_toggle_incognito)
+}
+
+pub fn register_toggle_paste_with_format_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, action_toggle_paste_with_format)
+}
+
+pub fn register_panic_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, action_panic)
+}
+
+pub fn register_paste_plain_text_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    let app = get_shortcut_app()?;
+
+    unregister_binding(id);
+
+    let shortcut = parse_shortcut(shortcut_str)?;
+    // 按下状态按组合分别跟踪，主组合与备用组合互不影响
+    let key_id = id.to_string();
 
     app.global_shortcut()
         .on_shortcut(shortcut, move |app, _shortcut, event| {
             match event.state {
                 ShortcutState::Pressed => {
                     if try_activate_key(&key_id) {
+                        // 快速连按时上一次粘贴可能仍未完成，按设置丢弃或排队
+                        if !repeat_paste_allowed(&key_id) {
+                            return;
+                        }
                         // 首次按下
-                        let app = app.clone();
-                        let key_id = key_id.clone();
-                        std::thread::spawn(move || {
-                            if let Err(e) = handle_paste_plain_text_press(&app) {
-                                eprintln!("纯文本粘贴失败: {}", e);
-                                deactivate_key(&key_id);
-                            }
-                        });
-                    } else if is_key_active(&key_id) {
-                        // 重复按下
-                        std::thread::spawn(|| {
+                        begin_paste(&key_id);
+                        let done_key = key_id.clone();
+                        if action_paste_plain_text(app, move |ok| complete_paste(&done_key, ok)).is_err() {
+                            complete_paste(&key_id, false);
+                        } else {
+                            record_trigger(&key_id);
+                        }
+                    } else if is_key_active(&key_id) && repeat_paste_allowed(&key_id) {
+                        // 重复按下（排在首次粘贴之后执行）
+                        run_action_as(binding_action(&key_id), "paste_plain_text_repeat", MergePolicy::Always, || {
                             let _ = simulate_paste_only();
                         });
                     }
@@ -453,13 +1404,208 @@ pub fn register_paste_plain_text_hotkey(shortcut_str: &str) -> Result<(), String
                 }
             }
         })
-        .map_err(|e| format!("注册纯文本粘贴快捷键失败: {}", e))?;
+        .map_err(|e| registration_error(shortcut_str, &e.to_string()))?;
 
     REGISTERED_SHORTCUTS
         .lock()
-        .push(("paste_plain_text".to_string(), shortcut_str.to_string()));
-    update_shortcut_status("paste_plain_text", shortcut_str, true, None);
-    println!("已注册纯文本粘贴快捷键: {}", shortcut_str);
+        .push((id.to_string(), shortcut_str.to_string()));
+    update_shortcut_status(id, shortcut_str, None);
+    println!("已注册纯文本粘贴快捷键 [{}]: {}", id, shortcut_str);
+    Ok(())
+}
+
+pub fn register_macro_next_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, |_app| action_macro_next())
+}
+
+// 交替粘贴最近两项。粘贴不会改变历史顺序（已预设去重哈希），因此前两项保持不变；
+// 有新的复制时前两项变为 (新内容, 原最新项)，交替状态重置，首次按下粘贴原最新项
+pub fn register_swap_last_two_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, |_app| action_swap_last_two())
+}
+
+// 手动将当前剪贴板内容记录到历史
+pub fn register_capture_clipboard_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, |_app| action_capture_clipboard())
+}
+
+pub fn register_grab_selection_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, action_grab_selection)
+}
+
+// 粘贴栈：用更早的一项替换刚通过快捷键粘贴的文本
+pub fn register_paste_pop_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, |_app| action_paste_pop())
+}
+
+// 激活或启动最新一条剪贴板项的来源应用
+pub fn register_open_source_app_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, |_app| action_open_source_app())
+}
+
+// 冻结最新一条剪贴板项，使其在本次会话中不被后续复制挤出
+pub fn register_freeze_top_item_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, action_freeze_top_item)
+}
+
+// 快速笔记：弹出输入框，提交后保存为文本项
+pub fn register_quick_note_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, action_quick_note)
+}
+
+// 组合粘贴：打开组合窗口
+pub fn register_compose_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, action_compose)
+}
+
+// 将最新一条文本项以引用格式粘贴（剪贴板中的原始内容不变）
+pub fn register_paste_quoted_hotkey(id: &str, shortcut_str: &str) -> Result<(), String> {
+    register_shortcut(id, shortcut_str, |_app| action_paste_quoted())
+}
+
+// 序号快捷键可绑定的最大序号
+pub const MAX_PASTE_INDEX: usize = 99;
+
+// 校验序号（从 1 开始），返回从 0 开始的列表索引
+fn validate_paste_index(index: usize) -> Result<usize, String> {
+    if (1..=MAX_PASTE_INDEX).contains(&index) {
+        Ok(index - 1)
+    } else {
+        Err(format!("序号 {} 超出范围（1-{}）", index, MAX_PASTE_INDEX))
+    }
+}
+
+fn paste_index_id(index: usize) -> String {
+    format!("paste_index_{}", index)
+}
+
+// 粘贴指定序号（从 1 开始）的剪贴板项，与数字快捷键共用粘贴逻辑；id 为该组合的注册 ID
+pub fn register_paste_index_hotkey(id: &str, index: usize, shortcut_str: &str) -> Result<(), String> {
+    let item_index = validate_paste_index(index)?;
+    let app = get_shortcut_app()?;
+    let id = id.to_string();
+
+    unregister_binding(&id);
+
+    let shortcut = match parse_shortcut(shortcut_str) {
+        Ok(s) => s,
+        Err(e) => {
+            update_shortcut_status(&id, shortcut_str, Some(&e));
+            return Err(e.into());
+        }
+    };
+
+    // 与本应用已注册的其他快捷键冲突时单独标记
+    if let Some(owner) = find_registered_owner(&shortcut) {
+        let error = HotkeyError::SelfConflict { shortcut: shortcut_str.to_string(), owner };
+        update_shortcut_status(&id, shortcut_str, Some(&error));
+        return Err(error.into());
+    }
+
+    let key_id = id.clone();
+    app.global_shortcut()
+        .on_shortcut(shortcut, move |_app, _shortcut, event| {
+            match event.state {
+                ShortcutState::Pressed => {
+                    if try_activate_key(&key_id) {
+                        // 快速连按时上一次粘贴可能仍未完成，按设置丢弃或排队
+                        if !repeat_paste_allowed(&key_id) {
+                            return;
+                        }
+                        begin_paste(&key_id);
+                        let done_key = key_id.clone();
+                        if action_paste_index(item_index, move |ok| complete_paste(&done_key, ok)).is_err() {
+                            complete_paste(&key_id, false);
+                        } else {
+                            record_trigger(&key_id);
+                        }
+                    } else if is_key_active(&key_id) && repeat_paste_allowed(&key_id) {
+                        // 重复按下
+                        run_action_as(binding_action(&key_id), "paste_index_repeat", MergePolicy::Always, || {
+                            let _ = simulate_paste_only();
+                        });
+                    }
+                }
+                ShortcutState::Released => {
+                    deactivate_key(&key_id);
+                }
+            }
+        })
+        .map_err(|e| {
+            let error = registration_error(shortcut_str, &e.to_string());
+            update_shortcut_status(&id, shortcut_str, Some(&error));
+            error
+        })?;
+
+    REGISTERED_SHORTCUTS.lock().push((id.clone(), shortcut_str.to_string()));
+    update_shortcut_status(&id, shortcut_str, None);
+    println!("已注册序号快捷键 [{}]: {}", id, shortcut_str);
+    Ok(())
+}
+
+// 计算本次应粘贴的项：前两项未变化时粘贴上次未粘贴的那一项，否则从次新项开始
+fn next_swap_target(state: Option<(i64, i64, i64)>, first: i64, second: i64) -> i64 {
+    match state {
+        Some((f, s, last)) if f == first && s == second => {
+            if last == first { second } else { first }
+        }
+        _ => second,
+    }
+}
+
+fn handle_swap_last_two_press() -> Result<(), String> {
+    use crate::services::database::{query_clipboard_items, get_clipboard_item_by_id, QueryParams};
+    use crate::services::paste::paste_handler::paste_clipboard_item_with_update;
+
+    let items = query_clipboard_items(QueryParams {
+        offset: 0,
+        limit: 2,
+        search: None,
+        content_type: None,
+        created_before: None,
+        with_highlights: false,
+        exclude_local_only: false,
+    })?
+    .items;
+
+    let target_id = match items.as_slice() {
+        [first, second] => {
+            let mut state = SWAP_LAST_TWO_STATE.lock();
+            let target = next_swap_target(*state, first.id, second.id);
+            *state = Some((first.id, second.id, target));
+            target
+        }
+        [only] => only.id,
+        _ => return Ok(()),
+    };
+
+    let full_item = get_clipboard_item_by_id(target_id)?
+        .ok_or_else(|| format!("剪贴板项 {} 不存在", target_id))?;
+    paste_clipboard_item_with_update(&full_item)
+}
+
+// 以引用格式粘贴最新一条剪贴板项
+fn handle_paste_quoted_press() -> Result<(), String> {
+    use crate::services::database::{query_clipboard_items, get_clipboard_item_by_id, QueryParams};
+    use crate::services::paste::paste_handler::paste_clipboard_item_with_format;
+    use crate::services::paste::PasteFormat;
+
+    let items = query_clipboard_items(QueryParams {
+        offset: 0,
+        limit: 1,
+        search: None,
+        content_type: None,
+        created_before: None,
+        with_highlights: false,
+        exclude_local_only: false,
+    })?
+    .items;
+
+    if let Some(item) = items.first() {
+        let full_item = get_clipboard_item_by_id(item.id)?
+            .ok_or_else(|| format!("剪贴板项 {} 不存在", item.id))?;
+        paste_clipboard_item_with_format(&full_item, Some(PasteFormat::Quoted))?;
+    }
     Ok(())
 }
 
@@ -482,6 +1628,9 @@ fn handle_paste_plain_text_press(app: &AppHandle) -> Result<(), String> {
             limit: 1,
             search: None,
             content_type: None,
+            created_before: None,
+            with_highlights: false,
+            exclude_local_only: false,
         })?
         .items;
 
@@ -496,9 +1645,10 @@ fn handle_paste_plain_text_press(app: &AppHandle) -> Result<(), String> {
 }
 
 pub fn register_number_shortcuts(modifier: &str) -> Result<(), String> {
-    let app = get_app()?;
+    let app = get_shortcut_app()?;
     
     unregister_number_shortcuts();
+    *NUMBER_SHORTCUTS_MODIFIER.lock() = Some(modifier.to_string());
     
     {
         let mut status_map = SHORTCUT_STATUS.lock();
@@ -513,8 +1663,10 @@ pub fn register_number_shortcuts(modifier: &str) -> Result<(), String> {
     };
     
     let mut failed_shortcuts: Vec<String> = Vec::new();
+    let mut self_conflicts: Vec<String> = Vec::new();
     
-    for num in 1..=9 {
+    let settings = crate::get_settings();
+    for num in 1..=9u8 {
         let id = format!("number_{}", num);
         let shortcut_str = if is_f_key {
             if prefix.is_empty() {
@@ -527,8 +1679,22 @@ pub fn register_number_shortcuts(modifier: &str) -> Result<(), String> {
         };
         
         if let Ok(shortcut) = parse_shortcut(&shortcut_str) {
+            // 与本应用已注册的其他快捷键冲突时单独标记，避免按键无响应却无提示
+            if let Some(owner) = find_registered_owner(&shortcut) {
+                let error = HotkeyError::SelfConflict { shortcut: shortcut_str.clone(), owner };
+                eprintln!("{}，已跳过", error);
+                update_shortcut_status(&id, &shortcut_str, Some(&error));
+                self_conflicts.push(shortcut_str);
+                continue;
+            }
+
+            // 未绑定且设置为不处理的数字键不注册，按键保留给其他程序
+            let target = number_target(num, &settings.number_item_bindings, &settings.number_unbound_behavior);
+            if target == NumberTarget::Nothing {
+                continue;
+            }
+
             let key_id = format!("number_{}", num);
-            let index = (num - 1) as usize;
 
             match app
                 .global_shortcut()
@@ -536,15 +1702,29 @@ pub fn register_number_shortcuts(modifier: &str) -> Result<(), String> {
                     match event.state {
                         ShortcutState::Pressed => {
                             if try_activate_key(&key_id) {
+                                // 快速连按时上一次粘贴可能仍未完成，按设置丢弃或排队
+                                if !repeat_paste_allowed(&key_id) {
+                                    return;
+                                }
                                 // 首次按下
-                                let key_id = key_id.clone();
-                                if let Err(e) = handle_number_shortcut_press(index) {
-                                    eprintln!("执行数字快捷键 {} 失败: {}", index + 1, e);
-                                    deactivate_key(&key_id);
+                                begin_paste(&key_id);
+                                let done_key = key_id.clone();
+                                let scheduled = run_action_as(&key_id, "number_shortcut", MergePolicy::Always, move || {
+                                    let result = handle_number_key_press(num);
+                                    match &result {
+                                        Ok(()) => record_trigger(&done_key),
+                                        Err(e) => eprintln!("执行数字快捷键 {} 失败: {}", num, e),
+                                    }
+                                    complete_paste(&done_key, result.is_ok());
+                                });
+                                if !scheduled {
+                                    complete_paste(&key_id, false);
                                 }
-                            } else if is_key_active(&key_id) {
+                            } else if is_key_active(&key_id) && repeat_paste_allowed(&key_id) {
                                 // 重复按下
-                                let _ = simulate_paste_only();
+                                run_action_as(&key_id, "number_shortcut_repeat", MergePolicy::Always, || {
+                                    let _ = simulate_paste_only();
+                                });
                             }
                         }
                         ShortcutState::Released => {
@@ -558,10 +1738,8 @@ pub fn register_number_shortcuts(modifier: &str) -> Result<(), String> {
                     println!("已注册数字快捷键: {}", shortcut_str);
                 }
                 Err(e) => {
-                    eprintln!(
-                        "注册数字快捷键 {} 失败: {}，继续注册其他快捷键",
-                        shortcut_str, e
-                    );
+                    let error = registration_error(&shortcut_str, &e.to_string());
+                    eprintln!("{}，继续注册其他快捷键", error);
                     failed_shortcuts.push(shortcut_str);
                 }
             }
@@ -569,19 +1747,26 @@ pub fn register_number_shortcuts(modifier: &str) -> Result<(), String> {
     }
     
     if !failed_shortcuts.is_empty() {
-        let mut status_map = SHORTCUT_STATUS.lock();
-        status_map.insert("number_shortcuts".to_string(), ShortcutStatus {
-            id: "number_shortcuts".to_string(),
-            shortcut: failed_shortcuts.join(", "),
-            success: false,
-            error: Some("REGISTRATION_FAILED".to_string()),
-        });
+        let shortcut = failed_shortcuts.join(", ");
+        let error = HotkeyError::RegistrationFailed {
+            shortcut: shortcut.clone(),
+            detail: localize("部分数字快捷键注册失败", "some number shortcuts could not be registered"),
+        };
+        update_shortcut_status("number_shortcuts", &shortcut, Some(&error));
+    } else if !self_conflicts.is_empty() {
+        let shortcut = self_conflicts.join(", ");
+        let error = HotkeyError::SelfConflict {
+            shortcut: shortcut.clone(),
+            owner: localize("其他快捷键", "other shortcuts"),
+        };
+        update_shortcut_status("number_shortcuts", &shortcut, Some(&error));
     }
     
     Ok(())
 }
 
 pub fn unregister_number_shortcuts() {
+    *NUMBER_SHORTCUTS_MODIFIER.lock() = None;
     let mut shortcuts = REGISTERED_SHORTCUTS.lock();
     let number_shortcuts: Vec<_> = shortcuts
         .iter()
@@ -591,7 +1776,7 @@ pub fn unregister_number_shortcuts() {
     
     for (id, shortcut_str) in number_shortcuts {
         if let Ok(shortcut) = parse_shortcut(&shortcut_str) {
-            if let Ok(app) = get_app() {
+            if let Ok(app) = get_shortcut_app() {
                 let _ = app.global_shortcut().unregister(shortcut);
                 println!("已注销数字快捷键: {}", shortcut_str);
             }
@@ -600,16 +1785,91 @@ pub fn unregister_number_shortcuts() {
     }
 }
 
+// 数字键的粘贴目标
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumberTarget {
+    // 绑定的剪贴板项
+    Item(i64),
+    // 按序号粘贴（从 0 开始）
+    Index(usize),
+    Nothing,
+}
+
+fn number_target(num: u8, bindings: &HashMap<u8, i64>, unbound_behavior: &str) -> NumberTarget {
+    match bindings.get(&num) {
+        Some(&id) => NumberTarget::Item(id),
+        None if unbound_behavior == "none" => NumberTarget::Nothing,
+        None => NumberTarget::Index(usize::from(num) - 1),
+    }
+}
+
+// 按下数字键：按绑定粘贴指定项，未绑定时按设置粘贴对应序号的项
+fn handle_number_key_press(num: u8) -> Result<(), String> {
+    use crate::services::database::get_clipboard_item_by_id;
+    use crate::services::paste::paste_handler::paste_clipboard_item_with_update;
+
+    let settings = crate::get_settings();
+    match number_target(num, &settings.number_item_bindings, &settings.number_unbound_behavior) {
+        NumberTarget::Item(id) => {
+            let item = get_clipboard_item_by_id(id)?
+                .ok_or_else(|| format!("数字键 {} 绑定的剪贴板项不存在: {}", num, id))?;
+            paste_clipboard_item_with_update(&item)
+        }
+        NumberTarget::Index(index) => handle_number_shortcut_press(index),
+        NumberTarget::Nothing => Ok(()),
+    }
+}
+
+// 将数字键绑定到剪贴板项（item_id 为 None 时解除绑定）并重新注册快捷键。
+// 绑定的项会被置顶，避免被历史数量限制清理；返回是否因此新置顶了该项
+pub fn bind_number_to_item(num: u8, item_id: Option<i64>) -> Result<bool, String> {
+    use crate::services::database::{get_clipboard_item_by_id, toggle_pin_clipboard_item};
+
+    if !(1..=9).contains(&num) {
+        return Err(format!("数字键超出范围: {}", num));
+    }
+    let mut pinned = false;
+    if let Some(id) = item_id {
+        let item = get_clipboard_item_by_id(id)?
+            .ok_or_else(|| format!("剪贴板项不存在: {}", id))?;
+        if !item.is_pinned {
+            pinned = toggle_pin_clipboard_item(id)?;
+        }
+    }
+    crate::services::settings::update_with(|settings| match item_id {
+        Some(id) => {
+            // 同一项只绑定到一个数字键
+            settings.number_item_bindings.retain(|_, bound| *bound != id);
+            settings.number_item_bindings.insert(num, id);
+        }
+        None => {
+            settings.number_item_bindings.remove(&num);
+        }
+    })?;
+    reload_from_settings()?;
+    Ok(pinned)
+}
+
 // 首次按下
 fn handle_number_shortcut_press(index: usize) -> Result<(), String> {
     use crate::services::database::{query_clipboard_items, get_clipboard_item_by_id, QueryParams};
     use crate::services::paste::paste_handler::paste_clipboard_item_with_update;
 
+    // 便捷粘贴窗口显示时按窗口中的顺序（可能经过筛选），与所见一致
+    if crate::get_settings().number_shortcuts_source == "visible" {
+        if let Some(entry) = crate::windows::quickpaste::visible_entry(index) {
+            return paste_visible_entry(entry?);
+        }
+    }
+
     let items = query_clipboard_items(QueryParams {
         offset: 0,
-        limit: 9,
+        limit: (index + 1) as i64,
         search: None,
         content_type: None,
+        created_before: None,
+        with_highlights: false,
+        exclude_local_only: false,
     })?
     .items;
 
@@ -627,21 +1887,87 @@ fn handle_number_shortcut_press(index: usize) -> Result<(), String> {
     paste_clipboard_item_with_update(&full_item)
 }
 
+fn paste_visible_entry(entry: crate::windows::quickpaste::VisibleEntry) -> Result<(), String> {
+    use crate::services::database::{get_clipboard_item_by_id, get_favorite_by_id, ClipboardItem};
+    use crate::services::paste::paste_handler::{paste_clipboard_item_with_update, paste_favorite_item_with_update};
+    use crate::windows::quickpaste::VisibleEntry;
+
+    match entry {
+        VisibleEntry::Clipboard { id } => {
+            let item = get_clipboard_item_by_id(id)?
+                .ok_or_else(|| format!("剪贴板项 {} 不存在", id))?;
+            paste_clipboard_item_with_update(&item)
+        }
+        VisibleEntry::Favorite { id } => {
+            let favorite = get_favorite_by_id(&id)?
+                .ok_or_else(|| format!("收藏项不存在: {}", id))?;
+            paste_favorite_item_with_update(&ClipboardItem::from(favorite), &id)
+        }
+    }
+}
+
 // 重复按下
 fn simulate_paste_only() -> Result<(), String> {
     use crate::services::paste::keyboard::simulate_paste;
 
-    std::thread::sleep(std::time::Duration::from_millis(50));
-    simulate_paste()?;
-    std::thread::sleep(std::time::Duration::from_millis(50));
+    HOTKEY_CLOCK.sleep(Duration::from_millis(50));
+    simulate_paste()?;
+    HOTKEY_CLOCK.sleep(Duration::from_millis(50));
+
+    Ok(())
+}
+
+pub fn unregister_all() {
+    let shortcuts = REGISTERED_SHORTCUTS.lock().clone();
+    for (id, _) in shortcuts {
+        unregister_binding(&id);
+    }
+}
+
+// 关闭热键管理器：注销全部快捷键并清空状态，在应用退出或重启前调用，
+// 避免残留的系统注册导致下次启动时出现冲突
+pub fn shutdown_hotkey_manager() {
+    SHUT_DOWN.store(true, Ordering::SeqCst);
+
+    unregister_all();
+    // 兜底：注销插件中可能未被记录的注册（如记录与系统状态不一致时）
+    if let Ok(app) = get_shortcut_app() {
+        if let Err(e) = app.global_shortcut().unregister_all() {
+            eprintln!("注销全部快捷键失败: {}", e);
+        }
+    }
+
+    clear_registration_state(&REGISTERED_SHORTCUTS, &SHORTCUT_STATUS);
+    println!("热键管理器已关闭，剩余注册数: {}", REGISTERED_SHORTCUTS.lock().len());
+}
+
+// 取消关闭并按设置重新注册（如以管理员身份重启被用户取消）
+pub fn resume_hotkey_manager() {
+    if SHUT_DOWN.swap(false, Ordering::SeqCst) {
+        if let Err(e) = reload_from_settings() {
+            eprintln!("恢复快捷键失败: {}", e);
+        }
+    }
+}
 
-    Ok(())
+fn clear_registration_state(
+    registered: &Mutex<Vec<(String, String)>>,
+    statuses: &Mutex<HashMap<String, ShortcutStatus>>,
+) {
+    registered.lock().clear();
+    statuses.lock().clear();
 }
 
-pub fn unregister_all() {
-    let shortcuts = REGISTERED_SHORTCUTS.lock().clone();
-    for (id, _) in shortcuts {
-        unregister_shortcut(&id);
+// 热键开关优先级：
+// 1. 设置项 hotkeys_enabled 为总开关，关闭时无论运行时状态如何都不注册任何热键
+// 2. 运行时开关（enable/disable_hotkeys）仅在总开关开启时生效，会持久化并在重启后恢复
+// 3. 在设置中重新开启总开关视为用户明确意图，同时清除运行时暂停状态
+fn set_runtime_enabled(enabled: bool, persist: bool) {
+    HOTKEYS_ENABLED.store(enabled, Ordering::Relaxed);
+    if persist {
+        if let Err(e) = crate::services::store::set(STORE_KEY_HOTKEYS_ENABLED, &enabled) {
+            eprintln!("保存热键启用状态失败: {}", e);
+        }
     }
 }
 
@@ -650,44 +1976,78 @@ pub fn enable_hotkeys() -> Result<(), String> {
         return Ok(());
     }
     
+    set_runtime_enabled(true, true);
     reload_from_settings()?;
-    HOTKEYS_ENABLED.store(true, Ordering::Relaxed);
     println!("已启用全局热键");
     Ok(())
 }
 
 pub fn disable_hotkeys() {
+    disable_hotkeys_inner(true);
+}
+
+// 临时暂停热键（不持久化，重启后按上次保存的状态恢复）
+pub fn suspend_hotkeys() {
+    disable_hotkeys_inner(false);
+}
+
+fn disable_hotkeys_inner(persist: bool) {
     if !HOTKEYS_ENABLED.load(Ordering::Relaxed) {
         return;
     }
     
     unregister_all();
-    HOTKEYS_ENABLED.store(false, Ordering::Relaxed);
+    set_runtime_enabled(false, persist);
     println!("已禁用全局热键");
 }
 
+// 设置中重新开启总开关时清除运行时暂停状态（由调用方随后重新加载）
+pub fn clear_runtime_suspension() {
+    if !HOTKEYS_ENABLED.load(Ordering::Relaxed) {
+        set_runtime_enabled(true, true);
+    }
+}
+
 pub fn is_hotkeys_enabled() -> bool {
     HOTKEYS_ENABLED.load(Ordering::Relaxed)
 }
 
-// 更新快捷键状态
-fn update_shortcut_status(id: &str, shortcut: &str, success: bool, error: Option<String>) {
-    let mut status_map = SHORTCUT_STATUS.lock();
-    status_map.insert(
-        id.to_string(),
-        ShortcutStatus {
-            id: id.to_string(),
-            shortcut: shortcut.to_string(),
-            success,
-            error,
-        },
-    );
+// 更新快捷键状态（error 为 None 表示注册成功）
+fn update_shortcut_status(id: &str, shortcut: &str, error: Option<&HotkeyError>) {
+    let status = match error {
+        Some(error) => ShortcutStatus::failed(id, shortcut, error),
+        None => ShortcutStatus::ok(id, shortcut),
+    };
+    SHORTCUT_STATUS.lock().insert(id.to_string(), status);
 }
 
-// 获取所有快捷键状态
+// 获取所有快捷键状态，按设置界面的顺序排列（状态表为 HashMap，读取时排序保证顺序稳定）
 pub fn get_shortcut_statuses() -> Vec<ShortcutStatus> {
-    let status_map = SHORTCUT_STATUS.lock();
-    status_map.values().cloned().collect()
+    let settings = crate::get_settings();
+    let order: Vec<&str> = shortcut_candidates(&settings).iter().map(|(id, ..)| *id).collect();
+    let mut statuses: Vec<ShortcutStatus> = SHORTCUT_STATUS.lock().values().cloned().collect();
+    sort_statuses(&mut statuses, &order);
+    statuses
+}
+
+// 状态排序：先按 order 中的动作顺序，其次为序号快捷键（按序号）、其他状态（按 ID），
+// 注册数量警告排在最后；同一动作的备用组合紧随主组合
+fn sort_statuses(statuses: &mut [ShortcutStatus], order: &[&str]) {
+    let sort_key = |id: &str| {
+        let action = binding_action(id);
+        let alternate: usize = id.split_once('#').and_then(|(_, n)| n.parse().ok()).unwrap_or(0);
+        let (rank, index) = if let Some(pos) = order.iter().position(|candidate| *candidate == action) {
+            (0, pos)
+        } else if let Some(index) = action.strip_prefix("paste_index_").and_then(|n| n.parse().ok()) {
+            (1, index)
+        } else if action == REGISTRATION_LIMIT_STATUS_ID {
+            (3, 0)
+        } else {
+            (2, 0)
+        };
+        (rank, index, action.to_string(), alternate)
+    };
+    statuses.sort_by_cached_key(|status| sort_key(&status.id));
 }
 
 // 获取单个快捷键状态
@@ -696,13 +2056,304 @@ pub fn get_shortcut_status(id: &str) -> Option<ShortcutStatus> {
     status_map.get(id).cloned()
 }
 
+// 获取已注册的快捷键（ID, 组合键）
+pub fn get_registered_shortcuts() -> Vec<(String, String)> {
+    REGISTERED_SHORTCUTS.lock().clone()
+}
+
+// 当前已注册的全局快捷键数量（数字快捷键按实际注册的个数计）
+pub fn registered_count() -> usize {
+    REGISTERED_SHORTCUTS.lock().len()
+}
+
+// 软上限检查结果在状态表中的 ID
+const REGISTRATION_LIMIT_STATUS_ID: &str = "registration_limit";
+
+// 注册数量是否超过软上限（limit 为 0 表示不检查）
+fn exceeds_soft_limit(count: usize, limit: usize) -> bool {
+    limit > 0 && count > limit
+}
+
+// 注册完成后检查数量，超过软上限时写入警告状态，否则清除之前的警告
+fn check_registration_limit(settings: &crate::AppSettings) {
+    let count = registered_count();
+    let limit = settings.hotkey_soft_limit;
+    if exceeds_soft_limit(count, limit) {
+        let error = HotkeyError::SoftLimitExceeded { count, limit };
+        eprintln!("{}", error);
+        SHORTCUT_STATUS.lock().insert(
+            REGISTRATION_LIMIT_STATUS_ID.to_string(),
+            ShortcutStatus::failed(REGISTRATION_LIMIT_STATUS_ID, &count.to_string(), &error),
+        );
+    } else {
+        clear_shortcut_status(REGISTRATION_LIMIT_STATUS_ID);
+    }
+}
+
+// 热键子系统诊断快照
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HotkeyDiagnostics {
+    pub initialized: bool,
+    // 全局快捷键插件是否可用
+    pub available: bool,
+    pub enabled: bool,
+    pub foreground_disabled: bool,
+    pub registered: Vec<(String, String)>,
+    pub registered_count: usize,
+    pub soft_limit: usize,
+    pub statuses: Vec<ShortcutStatus>,
+}
+
+// 获取热键子系统诊断信息
+pub fn hotkey_diagnostics() -> HotkeyDiagnostics {
+    HotkeyDiagnostics {
+        initialized: APP_HANDLE.lock().is_some(),
+        available: hotkeys_available(),
+        enabled: is_hotkeys_enabled(),
+        foreground_disabled: is_foreground_globally_disabled(),
+        registered: get_registered_shortcuts(),
+        registered_count: registered_count(),
+        soft_limit: crate::get_settings().hotkey_soft_limit,
+        statuses: get_shortcut_statuses(),
+    }
+}
+
 // 清除快捷键状态
 fn clear_shortcut_status(id: &str) {
     let mut status_map = SHORTCUT_STATUS.lock();
     status_map.remove(id);
 }
 
+// 检查快捷键是否需要注册，已禁用的快捷键保留组合并标记为 DISABLED
+fn is_shortcut_active(settings: &crate::AppSettings, id: &str, shortcut: &str) -> bool {
+    if shortcut.is_empty() {
+        return false;
+    }
+    // 启用状态按动作设置，对主组合和备用组合同时生效
+    if !settings.is_shortcut_enabled(binding_action(id)) {
+        update_shortcut_status(id, shortcut, Some(&HotkeyError::Disabled));
+        return false;
+    }
+    true
+}
+
+// 快捷键的注册方式
+#[derive(Clone, Copy)]
+enum Registrar {
+    // 参数为 (注册 ID, 组合)
+    Plain(fn(&str, &str) -> Result<(), String>),
+    // 粘贴指定序号项（序号作为参数）
+    PasteIndex(usize),
+}
+
+// 期望注册的快捷键
+struct DesiredShortcut {
+    id: String,
+    shortcut: String,
+    register: Registrar,
+    // 注册失败日志中的名称
+    label: &'static str,
+}
+
+type ShortcutEntry<'a> = (&'static str, &'a String, bool, fn(&str, &str) -> Result<(), String>, &'static str);
+
+// 按设置计算需要注册的快捷键（数字快捷键排在最后，以便检测与其他快捷键的冲突）
+fn shortcut_candidates(settings: &crate::AppSettings) -> [ShortcutEntry<'_>; 23] {
+    [
+        ("toggle", &settings.toggle_shortcut, true, register_toggle_hotkey, "主窗口切换快捷键"),
+        ("quickpaste", &settings.quickpaste_shortcut, settings.quickpaste_enabled, register_quickpaste_hotkey, "预览窗口快捷键"),
+        ("screenshot", &settings.screenshot_shortcut, settings.screenshot_enabled, register_screenshot_hotkey, "截图快捷键"),
+        ("screenshot_quick_save", &settings.screenshot_quick_save_shortcut, settings.screenshot_enabled, register_screenshot_quick_save_hotkey, "快速保存截图快捷键"),
+        ("screenshot_quick_pin", &settings.screenshot_quick_pin_shortcut, settings.screenshot_enabled, register_screenshot_quick_pin_hotkey, "快速贴图截图快捷键"),
+        ("screenshot_quick_ocr", &settings.screenshot_quick_ocr_shortcut, settings.screenshot_enabled, register_screenshot_quick_ocr_hotkey, "快速OCR截图快捷键"),
+        ("ocr_paste", &settings.ocr_paste_shortcut, settings.screenshot_enabled, register_ocr_paste_hotkey, "OCR粘贴快捷键"),
+        ("toggle_clipboard_monitor", &settings.toggle_clipboard_monitor_shortcut, true, register_toggle_clipboard_monitor_hotkey, "切换剪贴板监听快捷键"),
+        ("toggle_incognito", &settings.toggle_incognito_shortcut, true, register_toggle_incognito_hotkey, "切换无痕模式快捷键"),
+        ("toggle_paste_with_format", &settings.toggle_paste_with_format_shortcut, true, register_toggle_paste_with_format_hotkey, "切换格式粘贴快捷键"),
+        ("paste_plain_text", &settings.paste_plain_text_shortcut, true, register_paste_plain_text_hotkey, "纯文本粘贴快捷键"),
+        ("macro_next", &settings.macro_next_shortcut, true, register_macro_next_hotkey, "宏粘贴快捷键"),
+        ("swap_last_two", &settings.swap_last_two_shortcut, true, register_swap_last_two_hotkey, "交替粘贴快捷键"),
+        ("capture_clipboard", &settings.capture_clipboard_shortcut, true, register_capture_clipboard_hotkey, "捕获剪贴板快捷键"),
+        ("grab_selection", &settings.grab_selection_shortcut, true, register_grab_selection_hotkey, "抓取选中内容快捷键"),
+        ("paste_pop", &settings.paste_pop_shortcut, true, register_paste_pop_hotkey, "粘贴栈快捷键"),
+        ("open_source_app", &settings.open_source_app_shortcut, true, register_open_source_app_hotkey, "打开来源应用快捷键"),
+        ("freeze_top_item", &settings.freeze_top_item_shortcut, true, register_freeze_top_item_hotkey, "冻结最新项快捷键"),
+        ("paste_quoted", &settings.paste_quoted_shortcut, true, register_paste_quoted_hotkey, "引用粘贴快捷键"),
+        ("quick_note", &settings.quick_note_shortcut, true, register_quick_note_hotkey, "快速笔记快捷键"),
+        ("compose", &settings.compose_shortcut, true, register_compose_hotkey, "组合粘贴快捷键"),
+        ("panic", &settings.panic_shortcut, true, register_panic_hotkey, "紧急隐藏快捷键"),
+        ("number_shortcuts", &settings.number_shortcuts_modifier, settings.number_shortcuts, |_id, modifier| register_number_shortcuts(modifier), "数字快捷键"),
+    ]
+}
+
+// 动作的全部组合 (注册 ID, 组合)：主组合在前，随后为备用组合，存在运行时覆盖时使用栈顶的组合。
+// 空组合保留位置，使备用组合的 ID 与设置中的顺序一致
+fn action_bindings(
+    settings: &crate::AppSettings,
+    overrides: &ShortcutOverrides,
+    action: &str,
+    primary: &str,
+) -> Vec<(String, String)> {
+    let alternates = settings.alternate_shortcuts.get(action).map(Vec::as_slice).unwrap_or_default();
+    std::iter::once(primary)
+        .chain(alternates.iter().map(String::as_str))
+        .enumerate()
+        .map(|(n, shortcut)| {
+            let id = binding_id(action, n);
+            let shortcut = overrides.current(&id).unwrap_or(shortcut).to_string();
+            (id, shortcut)
+        })
+        .collect()
+}
+
+fn desired_shortcuts(settings: &crate::AppSettings) -> Vec<DesiredShortcut> {
+    let candidates = shortcut_candidates(settings);
+    let overrides = SHORTCUT_OVERRIDES.lock();
+
+    let (numbers, fixed) = candidates.split_last().expect("快捷键列表不为空");
+    let mut desired: Vec<DesiredShortcut> = Vec::new();
+    for (id, shortcut, enabled, register, label) in fixed {
+        if !*enabled {
+            continue;
+        }
+        for (binding, shortcut) in action_bindings(settings, &overrides, id, shortcut) {
+            if is_shortcut_active(settings, &binding, &shortcut) {
+                desired.push(DesiredShortcut { id: binding, shortcut, register: Registrar::Plain(*register), label: *label });
+            }
+        }
+    }
+    // 序号快捷键排在数字快捷键之前，数字快捷键注册时可检测到与其冲突；同一序号的多条绑定作为备用组合
+    let mut index_counts: HashMap<usize, usize> = HashMap::new();
+    for binding in &settings.paste_index_shortcuts {
+        let n = index_counts.entry(binding.index).or_default();
+        let id = binding_id(&paste_index_id(binding.index), *n);
+        *n += 1;
+        let shortcut = overrides.current(&id).unwrap_or(binding.shortcut.as_str()).to_string();
+        if !is_shortcut_active(settings, &id, &shortcut) {
+            continue;
+        }
+        desired.push(DesiredShortcut {
+            id,
+            shortcut,
+            register: Registrar::PasteIndex(binding.index),
+            label: "序号快捷键",
+        });
+    }
+    // 数字快捷键的设置值是修饰键，不支持备用组合
+    let (id, modifier, enabled, register, label) = numbers;
+    let modifier = overrides.current(id).unwrap_or(modifier.as_str());
+    if *enabled && is_shortcut_active(settings, id, modifier) {
+        desired.push(DesiredShortcut {
+            id: id.to_string(),
+            shortcut: modifier.to_string(),
+            register: Registrar::Plain(*register),
+            label: *label,
+        });
+    }
+    desired
+}
+
+fn register_desired(desired: &DesiredShortcut) {
+    // 插件不可用时不尝试注册，标记状态供界面说明原因
+    if !hotkeys_available() {
+        update_shortcut_status(&desired.id, &desired.shortcut, Some(&HotkeyError::PluginUnavailable));
+        return;
+    }
+    // 备用组合与本应用已注册的组合（包括同一动作的其他组合）冲突时单独标记
+    if desired.id.contains('#') {
+        if let Some(owner) = parse_shortcut(&desired.shortcut).ok().and_then(|s| find_registered_owner(&s)) {
+            let error = HotkeyError::SelfConflict { shortcut: desired.shortcut.clone(), owner };
+            eprintln!("注册{}失败: {}", desired.label, error);
+            update_shortcut_status(&desired.id, &desired.shortcut, Some(&error));
+            return;
+        }
+    }
+    let result = match desired.register {
+        Registrar::Plain(register) => register(&desired.id, &desired.shortcut),
+        Registrar::PasteIndex(index) => register_paste_index_hotkey(&desired.id, index, &desired.shortcut),
+    };
+    if let Err(e) = result {
+        eprintln!("注册{}失败: {}", desired.label, e);
+    }
+}
+
+// 当前是否应注册热键（总开关、运行时开关、前台应用禁用）
+fn should_register(settings: &crate::AppSettings) -> bool {
+    settings.hotkeys_enabled
+        && HOTKEYS_ENABLED.load(Ordering::Relaxed)
+        && !is_foreground_globally_disabled()
+}
+
+// 前台应用禁用热键时仍保留紧急隐藏快捷键（包括备用组合）；总开关关闭或运行时暂停时不保留
+fn kept_panic_bindings(settings: &crate::AppSettings) -> Vec<(String, String)> {
+    if !settings.hotkeys_enabled || !HOTKEYS_ENABLED.load(Ordering::Relaxed) {
+        return Vec::new();
+    }
+    let bindings = action_bindings(settings, &SHORTCUT_OVERRIDES.lock(), "panic", &settings.panic_shortcut);
+    bindings
+        .into_iter()
+        .filter(|(id, shortcut)| is_shortcut_active(settings, id, shortcut))
+        .collect()
+}
+
+// 停用热键：注销除紧急隐藏外的全部快捷键
+fn deactivate(settings: &crate::AppSettings) {
+    let panic_bindings = kept_panic_bindings(settings);
+    if panic_bindings.is_empty() {
+        unregister_all();
+        return;
+    }
+    let is_kept = |id: &str, shortcut: &str| {
+        panic_bindings.iter().any(|(kept_id, kept)| kept_id == id && same_shortcut(kept, shortcut))
+    };
+    for (id, shortcut) in get_registered_shortcuts() {
+        if !is_kept(&id, &shortcut) {
+            unregister_binding(&id);
+        }
+    }
+    let registered = get_registered_shortcuts();
+    for (id, shortcut) in &panic_bindings {
+        if registered.iter().any(|(registered_id, s)| registered_id == id && same_shortcut(s, shortcut)) {
+            continue;
+        }
+        if let Err(e) = register_panic_hotkey(id, shortcut) {
+            eprintln!("注册紧急隐藏快捷键失败: {}", e);
+        }
+    }
+}
+
+// 快捷键重新加载的原因（随 hotkeys-reloaded 事件发送，界面据此决定是否提示冲突）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReloadReason {
+    Startup,
+    SettingsChange,
+    ForegroundSync,
+    // 运行时覆盖入栈或出栈
+    Override,
+}
+
+#[derive(Serialize)]
+struct HotkeysReloadedEvent {
+    reason: ReloadReason,
+    statuses: Vec<ShortcutStatus>,
+}
+
+fn emit_hotkeys_reloaded(reason: ReloadReason) {
+    if let Ok(app) = get_app() {
+        let _ = app.emit("hotkeys-reloaded", HotkeysReloadedEvent { reason, statuses: get_shortcut_statuses() });
+    }
+}
+
+// 完全重建：注销全部快捷键后按设置重新注册（设置变更时使用，处理函数可能依赖新设置）
 pub fn reload_from_settings() -> Result<(), String> {
+    reload_with_reason(ReloadReason::SettingsChange)
+}
+
+pub fn reload_with_reason(reason: ReloadReason) -> Result<(), String> {
+    if SHUT_DOWN.load(Ordering::SeqCst) {
+        return Ok(());
+    }
     let settings = crate::get_settings();
     
     unregister_all();
@@ -711,72 +2362,583 @@ pub fn reload_from_settings() -> Result<(), String> {
         status_map.clear();
     }
     
-    if settings.hotkeys_enabled {
-        if is_foreground_globally_disabled() {
-            return Ok(());
+    if should_register(&settings) {
+        for desired in desired_shortcuts(&settings) {
+            register_desired(&desired);
         }
+    } else {
+        deactivate(&settings);
+    }
+    check_registration_limit(&settings);
+    emit_hotkeys_reloaded(reason);
+    
+    Ok(())
+}
 
-        if !settings.toggle_shortcut.is_empty() {
-            if let Err(e) = register_toggle_hotkey(&settings.toggle_shortcut) {
-                eprintln!("注册主窗口切换快捷键失败: {}", e);
-            }
+// 增量同步：仅注销多余或组合已变化的快捷键、注册缺失的快捷键，已注册且未变化的保持不动。
+// 用于前台应用切换回允许的应用时恢复热键，避免全部重建造成的短暂失效
+fn sync_from_settings() -> Result<(), String> {
+    if SHUT_DOWN.load(Ordering::SeqCst) {
+        return Ok(());
+    }
+    let settings = crate::get_settings();
+
+    if !should_register(&settings) {
+        deactivate(&settings);
+        emit_hotkeys_reloaded(ReloadReason::ForegroundSync);
+        return Ok(());
+    }
+
+    let desired = desired_shortcuts(&settings);
+    let number_modifier = NUMBER_SHORTCUTS_MODIFIER.lock().clone();
+
+    // 判断已注册的项是否仍与期望一致（数字快捷键按修饰键整体比较）
+    let is_current = |id: &str, shortcut: &str| -> bool {
+        if id.starts_with("number_") {
+            desired.iter().any(|d| d.id == "number_shortcuts" && number_modifier.as_deref() == Some(d.shortcut.as_str()))
+        } else {
+            desired.iter().any(|d| d.id == id && same_shortcut(&d.shortcut, shortcut))
         }
-        
-        if settings.quickpaste_enabled && !settings.quickpaste_shortcut.is_empty() {
-            if let Err(e) = register_quickpaste_hotkey(&settings.quickpaste_shortcut) {
-                eprintln!("注册预览窗口快捷键失败: {}", e);
-            }
+    };
+
+    let registered = get_registered_shortcuts();
+    let mut removed_numbers = false;
+    for (id, shortcut) in &registered {
+        if is_current(id, shortcut) {
+            continue;
         }
-        
-        if settings.screenshot_enabled && !settings.screenshot_shortcut.is_empty() {
-            if let Err(e) = register_screenshot_hotkey(&settings.screenshot_shortcut) {
-                eprintln!("注册截图快捷键失败: {}", e);
-            }
+        if id.starts_with("number_") {
+            removed_numbers = true;
+        } else {
+            unregister_binding(id);
         }
-        
-        if settings.screenshot_enabled && !settings.screenshot_quick_save_shortcut.is_empty() {
-            if let Err(e) = register_screenshot_quick_save_hotkey(&settings.screenshot_quick_save_shortcut) {
-                eprintln!("注册快速保存截图快捷键失败: {}", e);
-            }
+    }
+    if removed_numbers {
+        unregister_number_shortcuts();
+    }
+
+    let registered = get_registered_shortcuts();
+    for d in &desired {
+        let already = if d.id == "number_shortcuts" {
+            number_modifier.as_deref() == Some(d.shortcut.as_str())
+                && registered.iter().any(|(id, _)| id.starts_with("number_"))
+        } else {
+            registered.iter().any(|(id, s)| *id == d.id && same_shortcut(s, &d.shortcut))
+        };
+        if !already {
+            register_desired(d);
         }
-        
-        if settings.screenshot_enabled && !settings.screenshot_quick_pin_shortcut.is_empty() {
-            if let Err(e) = register_screenshot_quick_pin_hotkey(&settings.screenshot_quick_pin_shortcut) {
-                eprintln!("注册快速贴图截图快捷键失败: {}", e);
-            }
+    }
+    check_registration_limit(&settings);
+    emit_hotkeys_reloaded(ReloadReason::ForegroundSync);
+
+    Ok(())
+}
+
+// 运行时快捷键覆盖：每个快捷键 ID 一个栈，栈顶生效；全部出栈后恢复设置中的组合。
+// 栈中不保存设置值，出栈时总是读取最新设置，因此覆盖期间修改设置也能正确恢复
+#[derive(Debug, Default)]
+struct ShortcutOverrides {
+    stacks: HashMap<String, Vec<String>>,
+}
+
+impl ShortcutOverrides {
+    // 入栈，返回该快捷键当前的覆盖层数
+    fn push(&mut self, id: &str, shortcut: String) -> usize {
+        let stack = self.stacks.entry(id.to_string()).or_default();
+        stack.push(shortcut);
+        stack.len()
+    }
+
+    // 出栈，返回被移除的组合；栈空时移除该 ID
+    fn pop(&mut self, id: &str) -> Option<String> {
+        let stack = self.stacks.get_mut(id)?;
+        let popped = stack.pop();
+        if stack.is_empty() {
+            self.stacks.remove(id);
         }
-        
-        if settings.screenshot_enabled && !settings.screenshot_quick_ocr_shortcut.is_empty() {
-            if let Err(e) = register_screenshot_quick_ocr_hotkey(&settings.screenshot_quick_ocr_shortcut) {
-                eprintln!("注册快速OCR截图快捷键失败: {}", e);
-            }
+        popped
+    }
+
+    fn current(&self, id: &str) -> Option<&str> {
+        self.stacks.get(id)?.last().map(String::as_str)
+    }
+}
+
+// 校验可覆盖的快捷键 ID（数字快捷键的设置值是修饰键而非组合，不支持覆盖）
+// 备用组合按 "ID#n" 覆盖
+fn validate_override_id(settings: &crate::AppSettings, id: &str) -> Result<(), String> {
+    let action = binding_action(id);
+    let known = shortcut_candidates(settings).iter().any(|(candidate, ..)| *candidate == action)
+        || settings.paste_index_shortcuts.iter().any(|b| paste_index_id(b.index) == action);
+    if !known || action == "number_shortcuts" {
+        return Err(format!("不支持覆盖的快捷键: {}", id));
+    }
+    Ok(())
+}
+
+// 临时覆盖快捷键组合（不持久化），可多次入栈；返回重新注册后该快捷键的状态
+pub fn push_shortcut_override(id: &str, shortcut: &str) -> Result<Option<ShortcutStatus>, String> {
+    validate_override_id(&crate::get_settings(), id)?;
+    let normalized = normalize_shortcut(shortcut);
+    parse_shortcut(&normalized).map_err(|e| e.localized())?;
+
+    let depth = SHORTCUT_OVERRIDES.lock().push(id, normalized.clone());
+    println!("快捷键 {} 临时覆盖为 {}（第 {} 层）", id, normalized, depth);
+    reload_with_reason(ReloadReason::Override)?;
+    Ok(get_shortcut_status(id))
+}
+
+// 移除最近一次覆盖，恢复上一层覆盖或设置中的组合
+pub fn pop_shortcut_override(id: &str) -> Result<Option<ShortcutStatus>, String> {
+    let popped = SHORTCUT_OVERRIDES.lock().pop(id);
+    if popped.is_none() {
+        return Err(format!("快捷键没有临时覆盖: {}", id));
+    }
+    reload_with_reason(ReloadReason::Override)?;
+    Ok(get_shortcut_status(id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::system::clock::MockClock;
+    use std::sync::atomic::AtomicUsize;
+
+    static TEST_SYNC_STATE: Lazy<Mutex<HotkeySyncState>> = Lazy::new(test_sync_state);
+    static APPLY_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    fn test_sync_state() -> Mutex<HotkeySyncState> {
+        Mutex::new(HotkeySyncState {
+            current: HotkeyActivation::Active,
+            desired: HotkeyActivation::Active,
+            desired_since: Instant::now(),
+            inactive_grace: Duration::ZERO,
+            syncing: false,
+        })
+    }
+
+    fn count_apply(_desired: HotkeyActivation) {
+        APPLY_COUNT.fetch_add(1, Ordering::SeqCst);
+    }
+
+    // 每次等待期间模拟一批前台切换请求
+    struct ScriptedClock {
+        inner: MockClock,
+        state: &'static Mutex<HotkeySyncState>,
+        apply: fn(HotkeyActivation),
+        batches: Mutex<Vec<Vec<HotkeyActivation>>>,
+    }
+
+    impl Clock for ScriptedClock {
+        fn now(&self) -> Instant {
+            self.inner.now()
         }
-        
-        if !settings.toggle_clipboard_monitor_shortcut.is_empty() {
-            if let Err(e) = register_toggle_clipboard_monitor_hotkey(&settings.toggle_clipboard_monitor_shortcut) {
-                eprintln!("注册切换剪贴板监听快捷键失败: {}", e);
+
+        fn sleep(&self, duration: Duration) {
+            self.inner.sleep(duration);
+            let batch = self.batches.lock().pop().unwrap_or_default();
+            for desired in batch {
+                let grace = self.state.lock().inactive_grace;
+                request_activation(self.state, desired, grace, self.apply, &SYSTEM_CLOCK);
             }
         }
-        
-        if !settings.toggle_paste_with_format_shortcut.is_empty() {
-            if let Err(e) = register_toggle_paste_with_format_hotkey(&settings.toggle_paste_with_format_shortcut) {
-                eprintln!("注册切换格式粘贴快捷键失败: {}", e);
-            }
+    }
+
+    #[test]
+    fn test_rapid_switches_are_coalesced() {
+        use HotkeyActivation::{Active, Inactive};
+
+        let clock = ScriptedClock {
+            inner: MockClock::new(),
+            state: &TEST_SYNC_STATE,
+            apply: count_apply,
+            // 等待窗口内来回切换，最终停在 Inactive
+            batches: Mutex::new(vec![vec![Active, Inactive, Active, Inactive]]),
+        };
+
+        {
+            let mut state = TEST_SYNC_STATE.lock();
+            state.desired = Inactive;
+            state.syncing = true;
         }
-        
-        if !settings.paste_plain_text_shortcut.is_empty() {
-            if let Err(e) = register_paste_plain_text_hotkey(&settings.paste_plain_text_shortcut) {
-                eprintln!("注册纯文本粘贴快捷键失败: {}", e);
-            }
+        run_sync_worker(&TEST_SYNC_STATE, count_apply, &clock);
+
+        let state = TEST_SYNC_STATE.lock();
+        assert_eq!(state.current, Inactive);
+        assert!(!state.syncing);
+        assert_eq!(APPLY_COUNT.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_inactive_waits_for_grace_period() {
+        use HotkeyActivation::Inactive;
+        static STATE: Lazy<Mutex<HotkeySyncState>> = Lazy::new(test_sync_state);
+        static APPLIED: AtomicUsize = AtomicUsize::new(0);
+        fn apply(_desired: HotkeyActivation) {
+            APPLIED.fetch_add(1, Ordering::SeqCst);
         }
-        
-        if settings.number_shortcuts && !settings.number_shortcuts_modifier.is_empty() {
-            if let Err(e) = register_number_shortcuts(&settings.number_shortcuts_modifier) {
-                eprintln!("注册数字快捷键失败: {}", e);
-            }
+
+        let clock = ScriptedClock { inner: MockClock::new(), state: &STATE, apply, batches: Mutex::new(Vec::new()) };
+        let start = clock.now();
+        {
+            let mut state = STATE.lock();
+            state.desired = Inactive;
+            state.desired_since = start;
+            state.inactive_grace = Duration::from_millis(1000);
+            state.syncing = true;
         }
+        run_sync_worker(&STATE, apply, &clock);
+
+        assert_eq!(APPLIED.load(Ordering::SeqCst), 1);
+        assert_eq!(STATE.lock().current, Inactive);
+        // 满宽限期后才应用
+        assert_eq!(clock.now() - start, Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn test_switching_back_within_grace_cancels_deactivation() {
+        use HotkeyActivation::{Active, Inactive};
+        static STATE: Lazy<Mutex<HotkeySyncState>> = Lazy::new(test_sync_state);
+        static APPLIED: AtomicUsize = AtomicUsize::new(0);
+        fn apply(_desired: HotkeyActivation) {
+            APPLIED.fetch_add(1, Ordering::SeqCst);
+        }
+
+        // 第一次等待结束后仍在宽限期内，第二次等待期间切回允许的应用
+        let clock = ScriptedClock {
+            inner: MockClock::new(),
+            state: &STATE,
+            apply,
+            batches: Mutex::new(vec![vec![Active], vec![]]),
+        };
+        {
+            let mut state = STATE.lock();
+            state.desired = Inactive;
+            state.desired_since = clock.now();
+            state.inactive_grace = Duration::from_millis(1000);
+            state.syncing = true;
+        }
+        run_sync_worker(&STATE, apply, &clock);
+
+        let state = STATE.lock();
+        assert_eq!(APPLIED.load(Ordering::SeqCst), 0);
+        assert_eq!(state.current, Active);
+        assert!(!state.syncing);
+    }
+
+    #[test]
+    fn test_key_press_tracker_repeat_and_hold_duration() {
+        let clock = MockClock::new();
+        let mut tracker = KeyPressTracker::default();
+
+        assert!(tracker.press("number_1", clock.now()));
+        clock.advance(Duration::from_millis(30));
+        assert!(!tracker.press("number_1", clock.now()));
+        assert!(tracker.is_active("number_1"));
+
+        clock.advance(Duration::from_millis(270));
+        assert_eq!(tracker.release("number_1", clock.now()), Some(Duration::from_millis(300)));
+        assert!(!tracker.is_active("number_1"));
+        assert_eq!(tracker.release("number_1", clock.now()), None);
+
+        assert!(tracker.press("number_1", clock.now()));
+    }
+
+    #[test]
+    fn test_shutdown_clears_registrations() {
+        // 使用局部状态，不修改全局关闭标记，避免影响并行运行的其他测试
+        let registered = Mutex::new(vec![("toggle".to_string(), "Alt+V".to_string())]);
+        let statuses = Mutex::new(HashMap::from([(
+            "toggle".to_string(),
+            ShortcutStatus::failed("toggle", "Alt+V", &HotkeyError::NotInitialized),
+        )]));
+
+        clear_registration_state(&registered, &statuses);
+
+        assert!(registered.lock().is_empty());
+        assert!(statuses.lock().is_empty());
+    }
+
+    #[test]
+    fn test_reserved_system_shortcuts() {
+        for (combo, _, _) in RESERVED_SYSTEM_SHORTCUTS {
+            assert!(parse_shortcut(combo).is_ok(), "保留组合无法解析: {}", combo);
+        }
+        assert_eq!(is_reserved_system_shortcut("Win+L").as_deref(), Some("锁定电脑"));
+        assert_eq!(is_reserved_system_shortcut("Alt+Ctrl+Delete").as_deref(), Some("安全选项"));
+        assert_eq!(is_reserved_system_shortcut("Shift+Win+S").as_deref(), Some("截图工具"));
+        assert!(is_reserved_system_shortcut("Ctrl+Shift+V").is_none());
+    }
+
+    #[test]
+    fn test_validate_paste_index() {
+        assert_eq!(validate_paste_index(1), Ok(0));
+        assert_eq!(validate_paste_index(12), Ok(11));
+        assert!(validate_paste_index(0).is_err());
+        assert!(validate_paste_index(MAX_PASTE_INDEX + 1).is_err());
+    }
+
+    #[test]
+    fn test_alternative_candidates() {
+        let candidates = alternative_candidates("Ctrl+Shift+V");
+        assert_eq!(candidates.first().map(String::as_str), Some("Ctrl+Alt+Shift+V"));
+        assert!(candidates.contains(&"Alt+Shift+V".to_string()));
+        assert!(!candidates.contains(&"Ctrl+Shift+V".to_string()));
+
+        let candidates = alternative_candidates("Shift+Space");
+        assert!(!candidates.contains(&"Shift+Space".to_string()));
+        assert!(candidates.contains(&"Ctrl+Shift+Space".to_string()));
+
+        assert!(alternative_candidates("Foo+V").is_empty());
+    }
+
+    #[test]
+    fn test_next_swap_target_alternates() {
+        assert_eq!(next_swap_target(None, 2, 1), 1);
+        assert_eq!(next_swap_target(Some((2, 1, 1)), 2, 1), 2);
+        assert_eq!(next_swap_target(Some((2, 1, 2)), 2, 1), 1);
+        // 新的复制到达后重置
+        assert_eq!(next_swap_target(Some((2, 1, 1)), 3, 2), 2);
+    }
+
+    #[test]
+    fn test_normalize_shortcut_modifiers() {
+        assert_eq!(normalize_shortcut("Ctrl+Shift+A"), "Control+Shift+A");
+        assert_eq!(normalize_shortcut("Win+V"), "Super+V");
+        assert_eq!(normalize_shortcut("Shift+Space"), "Shift+Space");
+    }
+
+    #[test]
+    fn test_normalize_shortcut_symbols() {
+        assert_eq!(normalize_shortcut("Ctrl+`"), "Control+Backquote");
+        assert_eq!(normalize_shortcut("Ctrl+Shift+~"), "Control+Shift+Backquote");
+        assert_eq!(normalize_shortcut("Alt+-"), "Alt+Minus");
+        assert_eq!(normalize_shortcut("Ctrl+Shift+?"), "Control+Shift+Slash");
+        assert_eq!(normalize_shortcut("Ctrl+\\"), "Control+Backslash");
+        assert_eq!(normalize_shortcut("Ctrl+Shift+\""), "Control+Shift+Quote");
+    }
+
+    #[test]
+    fn test_normalize_shortcut_plus_and_whitespace() {
+        assert_eq!(normalize_shortcut("Ctrl++"), "Control+Shift+Equal");
+        assert_eq!(normalize_shortcut("Ctrl+Shift++"), "Control+Shift+Equal");
+        assert_eq!(normalize_shortcut("Ctrl+Shift+="), "Control+Shift+Equal");
+        assert_eq!(normalize_shortcut("Ctrl+Shift+V "), "Control+Shift+V");
+        assert_eq!(normalize_shortcut(" Ctrl + Alt + K"), "Control+Alt+K");
+        assert_eq!(normalize_shortcut("Ctrl+ "), "Control+Space");
     }
-    
-    Ok(())
-}
 
+    #[test]
+    fn test_parse_shortcut_plus_and_whitespace() {
+        assert!(parse_shortcut("Ctrl++").is_ok());
+        assert!(parse_shortcut("Ctrl+Shift+=").is_ok());
+        assert!(parse_shortcut("Ctrl+Shift+V ").is_ok());
+    }
+
+    #[test]
+    fn test_canonical_shortcut_equates_spellings() {
+        assert_eq!(canonical_shortcut("Ctrl+Shift+V").unwrap(), "Control+Shift+KeyV");
+        assert_eq!(canonical_shortcut("Shift+Control+KeyV").unwrap(), "Control+Shift+KeyV");
+        assert_eq!(canonical_shortcut("Ctrl+`").unwrap(), canonical_shortcut("Control+Backquote").unwrap());
+        assert_eq!(canonical_shortcut("Ctrl++").unwrap(), canonical_shortcut("Shift+Ctrl+=").unwrap());
+        assert_eq!(canonical_shortcut("Win+Alt+1").unwrap(), "Alt+Super+Digit1");
+        // 规范形式可再次解析且保持不变
+        let canonical = canonical_shortcut("Alt+Ctrl+F5").unwrap();
+        assert_eq!(canonical_shortcut(&canonical).unwrap(), canonical);
+        assert!(canonical_shortcut("Ctrl+Nope").is_err());
+    }
+
+    #[test]
+    fn test_parse_shortcut_parts() {
+        use Modifier::*;
+        let parts = |input: &str| {
+            let parts = parse_shortcut_parts(input).unwrap();
+            (parts.modifiers, parts.key)
+        };
+        assert_eq!(parts("Shift+Ctrl+V"), (vec![Control, Shift], "KeyV".to_string()));
+        assert_eq!(parts("Win+Alt+1"), (vec![Alt, Super], "Digit1".to_string()));
+        assert_eq!(parts("F9"), (vec![], "F9".to_string()));
+        // 符号键映射为 Code 名称，Shift 后的变体字符与基础字符相同
+        assert_eq!(parts("Ctrl+`"), (vec![Control], "Backquote".to_string()));
+        assert_eq!(parts("Ctrl+Shift+~"), (vec![Control, Shift], "Backquote".to_string()));
+        assert_eq!(parts("Alt+Shift+?"), (vec![Alt, Shift], "Slash".to_string()));
+        // 加号键补上 Shift
+        assert_eq!(parts("Ctrl++"), (vec![Control, Shift], "Equal".to_string()));
+        assert!(parse_shortcut_parts("Ctrl+Nope").is_err());
+    }
+
+    #[test]
+    fn test_same_shortcut() {
+        assert!(same_shortcut("Ctrl+Shift+V", "Shift+Control+V"));
+        assert!(!same_shortcut("Ctrl+Shift+V", "Ctrl+V"));
+        // 无法解析时按原文比较
+        assert!(same_shortcut("Ctrl+Nope", " Ctrl+Nope"));
+    }
+
+    #[test]
+    fn test_reload_reason_serializes_kebab_case() {
+        assert_eq!(serde_json::to_value(ReloadReason::Startup).unwrap(), "startup");
+        assert_eq!(serde_json::to_value(ReloadReason::SettingsChange).unwrap(), "settings-change");
+        assert_eq!(serde_json::to_value(ReloadReason::ForegroundSync).unwrap(), "foreground-sync");
+        assert_eq!(serde_json::to_value(ReloadReason::Override).unwrap(), "override");
+    }
+
+    #[test]
+    fn test_repeat_paste_policy_while_in_flight() {
+        let start = Instant::now();
+        let soon = start + Duration::from_millis(100);
+
+        let mut tracker = PasteInFlightTracker::default();
+        tracker.begin("number_1", start);
+        // 原有行为：始终立即粘贴
+        assert!(tracker.on_repeat("number_1", RepeatPastePolicy::Overlap, soon));
+        assert!(!tracker.on_repeat("number_1", RepeatPastePolicy::Drop, soon));
+        assert_eq!(tracker.finish("number_1"), 0);
+        // 完成后不再阻止
+        assert!(tracker.on_repeat("number_1", RepeatPastePolicy::Drop, soon));
+
+        tracker.begin("number_1", start);
+        assert!(!tracker.on_repeat("number_1", RepeatPastePolicy::Queue, soon));
+        assert!(!tracker.on_repeat("number_1", RepeatPastePolicy::Queue, soon));
+        // 其他按键不受影响
+        assert!(tracker.on_repeat("number_2", RepeatPastePolicy::Queue, soon));
+        assert_eq!(tracker.finish("number_1"), 2);
+        assert_eq!(tracker.finish("number_1"), 0);
+    }
+
+    #[test]
+    fn test_repeat_paste_queue_limit_and_stale_state() {
+        let start = Instant::now();
+        let mut tracker = PasteInFlightTracker::default();
+        tracker.begin("paste_plain_text", start);
+        for _ in 0..(MAX_QUEUED_REPEATS + 5) {
+            tracker.on_repeat("paste_plain_text", RepeatPastePolicy::Queue, start);
+        }
+        assert_eq!(tracker.finish("paste_plain_text"), MAX_QUEUED_REPEATS);
+
+        // 超时未完成的状态视为遗留，允许重新粘贴
+        tracker.begin("paste_plain_text", start);
+        assert!(tracker.on_repeat("paste_plain_text", RepeatPastePolicy::Drop, start + PASTE_IN_FLIGHT_TIMEOUT));
+        assert_eq!(tracker.finish("paste_plain_text"), 0);
+
+        assert_eq!(RepeatPastePolicy::from_setting("queue"), RepeatPastePolicy::Queue);
+        assert_eq!(RepeatPastePolicy::from_setting("drop"), RepeatPastePolicy::Drop);
+        assert_eq!(RepeatPastePolicy::from_setting("overlap"), RepeatPastePolicy::Overlap);
+        assert_eq!(RepeatPastePolicy::from_setting(""), RepeatPastePolicy::Overlap);
+    }
+
+    #[test]
+    fn test_number_target_prefers_binding() {
+        let bindings = HashMap::from([(2u8, 42i64)]);
+        assert_eq!(number_target(2, &bindings, "index"), NumberTarget::Item(42));
+        assert_eq!(number_target(2, &bindings, "none"), NumberTarget::Item(42));
+        assert_eq!(number_target(1, &bindings, "index"), NumberTarget::Index(0));
+        assert_eq!(number_target(9, &bindings, "index"), NumberTarget::Index(8));
+        assert_eq!(number_target(1, &bindings, "none"), NumberTarget::Nothing);
+    }
+
+    #[test]
+    fn test_shortcut_overrides_stack() {
+        let mut overrides = ShortcutOverrides::default();
+        assert_eq!(overrides.current("quickpaste"), None);
+
+        assert_eq!(overrides.push("quickpaste", "Alt+1".to_string()), 1);
+        assert_eq!(overrides.push("quickpaste", "Alt+2".to_string()), 2);
+        assert_eq!(overrides.current("quickpaste"), Some("Alt+2"));
+        assert_eq!(overrides.current("toggle"), None);
+
+        assert_eq!(overrides.pop("quickpaste").as_deref(), Some("Alt+2"));
+        assert_eq!(overrides.current("quickpaste"), Some("Alt+1"));
+        assert_eq!(overrides.pop("quickpaste").as_deref(), Some("Alt+1"));
+        // 全部出栈后回到设置值
+        assert_eq!(overrides.current("quickpaste"), None);
+        assert_eq!(overrides.pop("quickpaste"), None);
+    }
+
+    #[test]
+    fn test_sort_statuses_follows_display_order() {
+        let ids = [
+            "registration_limit",
+            "paste_index_10",
+            "quickpaste#2",
+            "custom",
+            "paste_index_2",
+            "quickpaste",
+            "toggle",
+            "quickpaste#1",
+            "paste_index_2#1",
+            "number_shortcuts",
+        ];
+        let mut statuses: Vec<ShortcutStatus> = ids.iter().map(|id| ShortcutStatus::ok(id, "")).collect();
+        sort_statuses(&mut statuses, &["toggle", "quickpaste", "number_shortcuts"]);
+
+        let sorted: Vec<&str> = statuses.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(sorted, vec![
+            "toggle",
+            "quickpaste",
+            "quickpaste#1",
+            "quickpaste#2",
+            "number_shortcuts",
+            "paste_index_2",
+            "paste_index_2#1",
+            "paste_index_10",
+            "custom",
+            "registration_limit",
+        ]);
+    }
+
+    #[test]
+    fn test_binding_ids() {
+        assert_eq!(binding_id("quickpaste", 0), "quickpaste");
+        assert_eq!(binding_id("quickpaste", 2), "quickpaste#2");
+        assert_eq!(binding_action("quickpaste#2"), "quickpaste");
+        assert_eq!(binding_action("paste_index_3"), "paste_index_3");
+
+        // 动作 ID 匹配全部组合，"ID#n" 只匹配自身
+        assert!(binding_matches("quickpaste#1", "quickpaste"));
+        assert!(binding_matches("quickpaste", "quickpaste"));
+        assert!(!binding_matches("quickpaste", "quickpaste#1"));
+        assert!(!binding_matches("quickpaste#2", "quickpaste#1"));
+        assert!(!binding_matches("quickpaste_alt", "quickpaste"));
+    }
+
+    #[test]
+    fn test_action_bindings_with_alternates_and_overrides() {
+        let mut settings = crate::AppSettings::default();
+        settings.alternate_shortcuts.insert(
+            "quickpaste".to_string(),
+            vec!["Ctrl+Shift+V".to_string(), String::new(), "Alt+Q".to_string()],
+        );
+        let mut overrides = ShortcutOverrides::default();
+        overrides.push("quickpaste#3", "Alt+W".to_string());
+
+        let bindings = action_bindings(&settings, &overrides, "quickpaste", "Ctrl+`");
+        let expected = [
+            ("quickpaste", "Ctrl+`"),
+            ("quickpaste#1", "Ctrl+Shift+V"),
+            // 空组合保留位置，后续 ID 与设置顺序一致
+            ("quickpaste#2", ""),
+            ("quickpaste#3", "Alt+W"),
+        ];
+        assert_eq!(bindings, expected.map(|(id, s)| (id.to_string(), s.to_string())));
+
+        assert_eq!(action_bindings(&settings, &overrides, "toggle", "Shift+Space").len(), 1);
+    }
+
+    #[test]
+    fn test_exceeds_soft_limit() {
+        assert!(!exceeds_soft_limit(40, 40));
+        assert!(exceeds_soft_limit(41, 40));
+        assert!(!exceeds_soft_limit(100, 0));
+    }
+
+    #[test]
+    fn test_toggle_decision_while_quickpaste_visible() {
+        assert_eq!(toggle_decision(false, "ignore"), ToggleDecision::ToggleMain);
+        assert_eq!(toggle_decision(true, "close_quickpaste"), ToggleDecision::CloseQuickpaste);
+        assert_eq!(toggle_decision(true, "ignore"), ToggleDecision::Ignore);
+        // 未知取值按默认行为处理
+        assert_eq!(toggle_decision(true, ""), ToggleDecision::CloseQuickpaste);
+    }
+}
@@ -0,0 +1,104 @@
+// 破坏性快捷键的确认：设置中选择的动作需在短时间内连按两次才执行，避免误触
+// 第一次按下只给出提示音并发送 hotkey-confirm-pending 事件
+
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+// 连按确认状态：记录最近一次等待确认的动作及按下时间
+#[derive(Debug, Default)]
+pub struct ConfirmGate {
+    armed: Option<(String, Instant)>,
+}
+
+impl ConfirmGate {
+    pub const fn new() -> Self {
+        Self { armed: None }
+    }
+
+    // 处理一次按下，返回是否已确认（同一动作在窗口期内第二次按下）
+    // 其他动作的按下或超时都会重新开始计时
+    pub fn press(&mut self, id: &str, now: Instant, window: Duration) -> bool {
+        if let Some((armed_id, at)) = self.armed.take() {
+            if armed_id == id && now.duration_since(at) <= window {
+                return true;
+            }
+        }
+        self.armed = Some((id.to_string(), now));
+        false
+    }
+
+    // 是否放行本次按下：未开启确认的动作直接放行，开启确认的动作需连按确认
+    pub fn admit(&mut self, confirm_actions: &[String], id: &str, now: Instant, window: Duration) -> bool {
+        if !confirm_actions.iter().any(|a| a == id) {
+            return true;
+        }
+        self.press(id, now, window)
+    }
+}
+
+static GATE: Mutex<ConfirmGate> = Mutex::new(ConfirmGate::new());
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConfirmPendingEvent<'a> {
+    id: &'a str,
+    window_ms: u64,
+}
+
+// 需要确认的动作第一次按下时只提示，窗口期内再次按下才执行；未开启确认的动作直接执行
+pub fn confirm_then<F: FnOnce()>(app: &AppHandle, id: &str, action: F) {
+    let settings = crate::get_settings();
+    let window_ms = settings.hotkey_confirm_window_ms;
+    let admitted = GATE
+        .lock()
+        .admit(&settings.hotkey_confirm_actions, id, Instant::now(), Duration::from_millis(window_ms));
+    if admitted {
+        action();
+        return;
+    }
+    let volume = (settings.sound_volume / 100.0) as f32;
+    crate::SoundPlayer::play_beep(440.0, 60, volume);
+    let _ = app.emit("hotkey-confirm-pending", ConfirmPendingEvent { id, window_ms });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_millis(600);
+
+    #[test]
+    fn test_second_press_within_window_confirms() {
+        let mut gate = ConfirmGate::new();
+        let now = Instant::now();
+        assert!(!gate.press("panic", now, WINDOW));
+        assert!(gate.press("panic", now + Duration::from_millis(300), WINDOW));
+        // 确认后重新开始
+        assert!(!gate.press("panic", now + Duration::from_millis(400), WINDOW));
+    }
+
+    #[test]
+    fn test_timeout_and_other_actions_rearm() {
+        let mut gate = ConfirmGate::new();
+        let now = Instant::now();
+        assert!(!gate.press("panic", now, WINDOW));
+        assert!(!gate.press("panic", now + WINDOW * 2, WINDOW));
+
+        assert!(!gate.press("paste_pop", now + WINDOW * 2, WINDOW));
+        assert!(!gate.press("panic", now + WINDOW * 2, WINDOW));
+        assert!(gate.press("panic", now + WINDOW * 2, WINDOW));
+    }
+
+    #[test]
+    fn test_listed_action_needs_second_trigger() {
+        let mut gate = ConfirmGate::new();
+        let actions = vec!["panic".to_string()];
+        let now = Instant::now();
+        // 未开启确认的动作单次触发即执行
+        assert!(gate.admit(&actions, "paste_pop", now, WINDOW));
+        assert!(!gate.admit(&actions, "panic", now, WINDOW));
+        assert!(gate.admit(&actions, "panic", now + Duration::from_millis(100), WINDOW));
+    }
+}
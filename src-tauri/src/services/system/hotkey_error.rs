@@ -0,0 +1,194 @@
+// 热键错误：机器可读的错误码与面向用户的提示分离，提示按界面语言本地化
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HotkeyError {
+    // 热键管理器尚未初始化
+    NotInitialized,
+    // 全局快捷键插件不可用（初始化失败），只能通过托盘操作
+    PluginUnavailable,
+    // 快捷键字符串无法解析
+    InvalidShortcut { shortcut: String },
+    // 已被其他程序占用
+    Conflict { shortcut: String },
+    // 系统保留组合，action 为已本地化的系统动作名称
+    SystemReserved { shortcut: String, action: String },
+    // 其他注册失败，detail 为底层错误
+    RegistrationFailed { shortcut: String, detail: String },
+    // 与本应用的其他快捷键重复，owner 为占用者的快捷键 ID
+    SelfConflict { shortcut: String, owner: String },
+    // 已被用户单独禁用
+    Disabled,
+    // 当前前台应用已禁用快捷键
+    ForegroundDisabled,
+    // 低占用模式下窗口已销毁
+    LowMemoryMode,
+    // 当前版本未包含对应功能
+    FeatureUnavailable { feature: String },
+    // 未知的快捷键动作 ID
+    UnknownAction { id: String },
+    // 导入时无法映射的动作或按键
+    Unmapped { name: String },
+    // 导入时重复映射到同一动作
+    Duplicate { name: String },
+    // 已注册的全局快捷键数量超过软上限
+    SoftLimitExceeded { count: usize, limit: usize },
+}
+
+impl HotkeyError {
+    // 机器可读的错误码（前端与快捷键状态中使用）
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NotInitialized => "NOT_INITIALIZED",
+            Self::PluginUnavailable => "PLUGIN_UNAVAILABLE",
+            Self::InvalidShortcut { .. } => "INVALID_SHORTCUT",
+            Self::Conflict { .. } => "CONFLICT",
+            Self::SystemReserved { .. } => "SYSTEM_RESERVED",
+            Self::RegistrationFailed { .. } => "REGISTRATION_FAILED",
+            Self::SelfConflict { .. } => "SELF_CONFLICT",
+            Self::Disabled => "DISABLED",
+            Self::ForegroundDisabled => "FOREGROUND_DISABLED",
+            Self::LowMemoryMode => "LOW_MEMORY_MODE",
+            Self::FeatureUnavailable { .. } => "FEATURE_UNAVAILABLE",
+            Self::UnknownAction { .. } => "UNKNOWN_ACTION",
+            Self::Unmapped { .. } => "UNMAPPED",
+            Self::Duplicate { .. } => "DUPLICATE",
+            Self::SoftLimitExceeded { .. } => "SOFT_LIMIT_EXCEEDED",
+        }
+    }
+
+    // 指定语言的提示信息
+    pub fn message_for(&self, zh: bool) -> String {
+        match self {
+            Self::NotInitialized => pick(zh, "热键管理器未初始化", "Hotkey manager is not initialized"),
+            Self::PluginUnavailable => pick(
+                zh,
+                "全局快捷键在当前环境中不可用，请通过托盘图标打开窗口",
+                "Global shortcuts are unavailable in this environment; use the tray icon to open windows",
+            ),
+            Self::InvalidShortcut { shortcut } => {
+                pick_fmt(zh, format!("无法解析快捷键: {}", shortcut), format!("Invalid shortcut: {}", shortcut))
+            }
+            Self::Conflict { shortcut } => pick_fmt(
+                zh,
+                format!("快捷键 {} 已被其他程序占用", shortcut),
+                format!("Shortcut {} is already in use by another application", shortcut),
+            ),
+            Self::SystemReserved { shortcut, action } => pick_fmt(
+                zh,
+                format!("快捷键 {} 为系统保留组合（{}）", shortcut, action),
+                format!("Shortcut {} is reserved by the system ({})", shortcut, action),
+            ),
+            Self::RegistrationFailed { shortcut, detail } => pick_fmt(
+                zh,
+                format!("注册快捷键 {} 失败: {}", shortcut, detail),
+                format!("Failed to register shortcut {}: {}", shortcut, detail),
+            ),
+            Self::SelfConflict { shortcut, owner } => pick_fmt(
+                zh,
+                format!("快捷键 {} 与快捷键 [{}] 冲突", shortcut, owner),
+                format!("Shortcut {} conflicts with shortcut [{}]", shortcut, owner),
+            ),
+            Self::Disabled => pick(zh, "快捷键已禁用", "Shortcut is disabled"),
+            Self::ForegroundDisabled => {
+                pick(zh, "当前前台应用已禁用快捷键", "Shortcuts are disabled for the foreground application")
+            }
+            Self::LowMemoryMode => pick(zh, "低占用模式下不可用", "Unavailable in low memory mode"),
+            Self::FeatureUnavailable { feature } => pick_fmt(
+                zh,
+                format!("当前版本未包含{}功能", feature),
+                format!("This build does not include the {} feature", feature),
+            ),
+            Self::UnknownAction { id } => {
+                pick_fmt(zh, format!("未知的快捷键动作: {}", id), format!("Unknown shortcut action: {}", id))
+            }
+            Self::Unmapped { name } => {
+                pick_fmt(zh, format!("无法映射: {}", name), format!("Cannot be mapped: {}", name))
+            }
+            Self::Duplicate { name } => {
+                pick_fmt(zh, format!("重复映射: {}", name), format!("Mapped more than once: {}", name))
+            }
+            Self::SoftLimitExceeded { count, limit } => pick_fmt(
+                zh,
+                format!("已注册 {} 个全局快捷键，超过建议上限 {}，部分快捷键可能间歇失效，建议减少", count, limit),
+                format!(
+                    "{} global shortcuts are registered, above the recommended limit of {}; some may fail intermittently, consider reducing them",
+                    count, limit
+                ),
+            ),
+        }
+    }
+
+    // 按当前界面语言本地化的提示信息
+    pub fn localized(&self) -> String {
+        self.message_for(is_zh())
+    }
+}
+
+// 当前界面语言是否为中文
+pub fn is_zh() -> bool {
+    crate::get_settings().language.starts_with("zh")
+}
+
+// 按当前界面语言选择文案
+pub fn localize(zh: &str, en: &str) -> String {
+    pick(is_zh(), zh, en)
+}
+
+fn pick(zh: bool, zh_text: &str, en_text: &str) -> String {
+    if zh { zh_text } else { en_text }.to_string()
+}
+
+fn pick_fmt(zh: bool, zh_text: String, en_text: String) -> String {
+    if zh { zh_text } else { en_text }
+}
+
+impl fmt::Display for HotkeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.code(), self.localized())
+    }
+}
+
+impl std::error::Error for HotkeyError {}
+
+// 兼容以 String 作为错误类型的调用方
+impl From<HotkeyError> for String {
+    fn from(error: HotkeyError) -> Self {
+        error.localized()
+    }
+}
+
+// 作为命令返回值时序列化为 { code, message }
+impl Serialize for HotkeyError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("HotkeyError", 2)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", &self.localized())?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_is_language_independent() {
+        let error = HotkeyError::Conflict { shortcut: "Ctrl+Q".to_string() };
+        assert_eq!(error.code(), "CONFLICT");
+        assert!(error.message_for(true).contains("Ctrl+Q"));
+        assert_eq!(
+            error.message_for(false),
+            "Shortcut Ctrl+Q is already in use by another application"
+        );
+    }
+
+    #[test]
+    fn test_message_for_languages() {
+        assert_eq!(HotkeyError::NotInitialized.message_for(true), "热键管理器未初始化");
+        assert_eq!(HotkeyError::NotInitialized.message_for(false), "Hotkey manager is not initialized");
+    }
+}
@@ -0,0 +1,77 @@
+// 快捷键动作的执行方式：在快捷键回调中直接执行，或交给动作队列的工作线程
+// 快捷键回调运行在插件的事件线程上，耗时动作在回调中执行会推迟后续按键事件的处理
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionMode {
+    // 在快捷键回调中直接执行
+    Inline,
+    // 交给动作队列按触发顺序执行
+    Worker,
+}
+
+// 各动作的默认执行方式：
+// - toggle / quickpaste：只显示或隐藏窗口，需要在按键时立即响应（便捷粘贴的按下与松开须保持顺序）
+// - quick_note / compose：只检查并打开窗口，窗口本身在异步任务中显示
+// - 截图与 OCR 粘贴：启动时需要截取屏幕，放到工作线程
+// - 粘贴类、数字键、序号键：读取数据库并模拟按键，放到工作线程
+// - 切换监听/无痕/格式粘贴、捕获、抓取、冻结等：会写入设置或数据库，放到工作线程
+// panic（紧急隐藏）不经过动作队列，固定在快捷键回调中直接执行，不在此解析
+pub fn default_execution_mode(id: &str) -> ExecutionMode {
+    match id {
+        "toggle" | "quickpaste" | "quick_note" | "compose" => ExecutionMode::Inline,
+        _ => ExecutionMode::Worker,
+    }
+}
+
+// 规则分组：数字键与序号键按组配置
+fn execution_group(id: &str) -> Option<&'static str> {
+    if id.starts_with("number_") {
+        Some("number")
+    } else if id.starts_with("paste_index_") {
+        Some("paste_index")
+    } else {
+        None
+    }
+}
+
+// 解析动作的执行方式：动作 ID 的规则优先于分组规则，均未设置时使用默认方式
+pub fn resolve_execution_mode(rules: &HashMap<String, ExecutionMode>, id: &str) -> ExecutionMode {
+    rules
+        .get(id)
+        .or_else(|| execution_group(id).and_then(|group| rules.get(group)))
+        .copied()
+        .unwrap_or_else(|| default_execution_mode(id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_execution_modes() {
+        let rules = HashMap::new();
+        assert_eq!(resolve_execution_mode(&rules, "toggle"), ExecutionMode::Inline);
+        assert_eq!(resolve_execution_mode(&rules, "quickpaste"), ExecutionMode::Inline);
+        assert_eq!(resolve_execution_mode(&rules, "compose"), ExecutionMode::Inline);
+        assert_eq!(resolve_execution_mode(&rules, "number_3"), ExecutionMode::Worker);
+        assert_eq!(resolve_execution_mode(&rules, "paste_index_12"), ExecutionMode::Worker);
+        assert_eq!(resolve_execution_mode(&rules, "screenshot"), ExecutionMode::Worker);
+        assert_eq!(resolve_execution_mode(&rules, "freeze_top_item"), ExecutionMode::Worker);
+    }
+
+    #[test]
+    fn test_rules_override_defaults() {
+        let rules = HashMap::from([
+            ("toggle".to_string(), ExecutionMode::Worker),
+            ("number".to_string(), ExecutionMode::Inline),
+            ("number_9".to_string(), ExecutionMode::Worker),
+        ]);
+        assert_eq!(resolve_execution_mode(&rules, "toggle"), ExecutionMode::Worker);
+        assert_eq!(resolve_execution_mode(&rules, "number_1"), ExecutionMode::Inline);
+        assert_eq!(resolve_execution_mode(&rules, "number_9"), ExecutionMode::Worker);
+    }
+}
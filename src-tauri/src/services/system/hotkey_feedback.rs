@@ -0,0 +1,135 @@
+// 快捷键触发反馈：按动作或动作类别给出提示音或界面闪烁（无障碍与操作确认）
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tauri::{AppHandle, Emitter};
+
+// 反馈方式
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackKind {
+    #[default]
+    None,
+    // 提示音
+    Sound,
+    // 发送 hotkey-feedback 事件，由界面显示闪烁/提示
+    Flash,
+    Both,
+}
+
+impl FeedbackKind {
+    fn has_sound(self) -> bool {
+        matches!(self, Self::Sound | Self::Both)
+    }
+
+    fn has_flash(self) -> bool {
+        matches!(self, Self::Flash | Self::Both)
+    }
+}
+
+// 类别规则的键带 category: 前缀，避免与同名动作 ID（如 "toggle"）冲突
+const CATEGORY_PREFIX: &str = "category:";
+
+pub fn category_rule_key(category: &str) -> String {
+    format!("{}{}", CATEGORY_PREFIX, category)
+}
+
+// 默认规则：粘贴类播放提示音，切换类闪烁提示（总开关默认关闭）
+pub fn default_feedback_rules() -> HashMap<String, FeedbackKind> {
+    HashMap::from([
+        (category_rule_key("paste"), FeedbackKind::Sound),
+        (category_rule_key("toggle"), FeedbackKind::Flash),
+    ])
+}
+
+// 快捷键动作所属类别："paste" | "toggle" | "screenshot" | "other"
+pub fn action_category(id: &str) -> &'static str {
+    if id.starts_with("number_")
+        || id.starts_with("paste_index_")
+        || matches!(id, "paste_plain_text" | "paste_quoted" | "paste_pop" | "macro_next" | "swap_last_two" | "ocr_paste")
+    {
+        "paste"
+    } else if id.starts_with("toggle") || id == "quickpaste" {
+        "toggle"
+    } else if id.starts_with("screenshot") {
+        "screenshot"
+    } else {
+        "other"
+    }
+}
+
+// 解析动作的反馈方式：动作 ID 的规则优先于类别规则
+pub fn resolve_feedback(rules: &HashMap<String, FeedbackKind>, id: &str) -> FeedbackKind {
+    rules
+        .get(id)
+        .or_else(|| rules.get(&category_rule_key(action_category(id))))
+        .copied()
+        .unwrap_or_default()
+}
+
+// 不同类别使用不同音高，便于仅凭声音区分
+fn beep_frequency(category: &str) -> f32 {
+    match category {
+        "paste" => 880.0,
+        "toggle" => 660.0,
+        "screenshot" => 990.0,
+        _ => 520.0,
+    }
+}
+
+#[derive(Clone, Serialize)]
+struct FeedbackEvent<'a> {
+    id: &'a str,
+    category: &'static str,
+}
+
+// 按设置分发反馈
+pub fn dispatch(app: &AppHandle, id: &str) {
+    let settings = crate::get_settings();
+    if !settings.hotkey_feedback_enabled {
+        return;
+    }
+    let kind = resolve_feedback(&settings.hotkey_feedback, id);
+    let category = action_category(id);
+    if kind.has_sound() {
+        let volume = (settings.sound_volume / 100.0) as f32;
+        crate::SoundPlayer::play_beep(beep_frequency(category), 40, volume);
+    }
+    if kind.has_flash() {
+        let _ = app.emit("hotkey-feedback", FeedbackEvent { id, category });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_category() {
+        assert_eq!(action_category("number_3"), "paste");
+        assert_eq!(action_category("paste_index_12"), "paste");
+        assert_eq!(action_category("toggle_incognito"), "toggle");
+        assert_eq!(action_category("quickpaste"), "toggle");
+        assert_eq!(action_category("screenshot_quick_pin"), "screenshot");
+        assert_eq!(action_category("freeze_top_item"), "other");
+    }
+
+    #[test]
+    fn test_action_rule_overrides_category() {
+        let mut rules = default_feedback_rules();
+        assert_eq!(resolve_feedback(&rules, "paste_pop"), FeedbackKind::Sound);
+        assert_eq!(resolve_feedback(&rules, "toggle"), FeedbackKind::Flash);
+        assert_eq!(resolve_feedback(&rules, "capture_clipboard"), FeedbackKind::None);
+
+        // "toggle" 动作规则只影响该动作，同类别的其他动作仍使用类别规则
+        rules.insert("paste_pop".to_string(), FeedbackKind::Both);
+        rules.insert("toggle".to_string(), FeedbackKind::None);
+        assert_eq!(resolve_feedback(&rules, "paste_pop"), FeedbackKind::Both);
+        assert_eq!(resolve_feedback(&rules, "toggle"), FeedbackKind::None);
+        assert_eq!(resolve_feedback(&rules, "toggle_clipboard_monitor"), FeedbackKind::Flash);
+
+        rules.insert(category_rule_key("toggle"), FeedbackKind::Sound);
+        assert_eq!(resolve_feedback(&rules, "toggle_incognito"), FeedbackKind::Sound);
+        assert_eq!(resolve_feedback(&rules, "toggle"), FeedbackKind::None);
+    }
+}
@@ -0,0 +1,455 @@
+// 从其他剪贴板管理器导入快捷键设置
+//
+// 接受的输入格式（INI 风格，逐行解析）：
+//   - `名称 = 值`，名称为对方软件中的动作名（不区分大小写，忽略空格/下划线/连字符）
+//   - `[节名]` 与以 `;` 或 `#` 开头的注释行会被忽略，值两侧的引号会被去除
+//   - 值可以是文本组合，如 `Ctrl + Shift + V`、`Control+Alt+V`、`Win+V`
+//   - 也可以是 Ditto 导出的注册表数值（十进制或 `dword:0000xxxx`/`0x...` 十六进制），
+//     低 8 位为虚拟键码，次 8 位为修饰键：Shift=1、Ctrl=2、Alt=4、Win=8
+
+use super::hotkey::{canonical_shortcut, is_reserved_system_shortcut, parse_shortcut, ShortcutStatus};
+use super::hotkey_error::{localize, HotkeyError};
+use crate::AppSettings;
+use serde::{Deserialize, Serialize};
+
+// 导入报告：已映射、无法映射、存在冲突的条目
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HotkeyImportReport {
+    // id 为本应用快捷键 ID，shortcut 为转换后的组合
+    pub mapped: Vec<ShortcutStatus>,
+    // id 为原始动作名，shortcut 为原始值，error 为 UNMAPPED / REGISTRATION_FAILED / DUPLICATE
+    pub unmapped: Vec<ShortcutStatus>,
+    // id 为本应用快捷键 ID，error 为 SYSTEM_RESERVED / SELF_CONFLICT
+    pub conflicting: Vec<ShortcutStatus>,
+}
+
+// 对方动作名（已规范化）→ 本应用快捷键 ID
+const ACTION_ALIASES: &[(&str, &str)] = &[
+    // 显示/隐藏主窗口
+    ("dittohotkey", "toggle"),
+    ("activatehotkey", "toggle"),
+    ("showhistory", "toggle"),
+    ("showclipboardhistory", "toggle"),
+    ("showclipboardmanager", "toggle"),
+    ("toggle", "toggle"),
+    // 纯文本粘贴
+    ("textonlyhotkey", "paste_plain_text"),
+    ("pasteplaintext", "paste_plain_text"),
+    ("pasteasplaintext", "paste_plain_text"),
+    ("pastetextonly", "paste_plain_text"),
+    // 便捷粘贴
+    ("quickpaste", "quickpaste"),
+    ("quickpastehotkey", "quickpaste"),
+    // 暂停/恢复监听
+    ("disconnecthotkey", "toggle_clipboard_monitor"),
+    ("toggleconnect", "toggle_clipboard_monitor"),
+    ("togglemonitoring", "toggle_clipboard_monitor"),
+    ("pauseclipboardmonitoring", "toggle_clipboard_monitor"),
+    // 粘贴上一项
+    ("pasteprevious", "swap_last_two"),
+    ("pastepreviousclip", "swap_last_two"),
+];
+
+// 解析导入内容并生成报告，同时返回可应用的 (快捷键 ID, 组合)
+pub fn build_import_report(
+    content: &str,
+    settings: &AppSettings,
+) -> (HotkeyImportReport, Vec<(&'static str, String)>) {
+    let mut report = HotkeyImportReport::default();
+    let mut accepted: Vec<(&'static str, String)> = Vec::new();
+
+    for (name, value) in parse_entries(content) {
+        let Some(id) = map_action(&name) else {
+            report.unmapped.push(ShortcutStatus::failed(&name, &value, &HotkeyError::Unmapped { name: name.clone() }));
+            continue;
+        };
+
+        let Some(shortcut) = convert_value(&value).filter(|s| parse_shortcut(s).is_ok()) else {
+            let error = HotkeyError::RegistrationFailed {
+                shortcut: value.clone(),
+                detail: localize("无法识别的按键值", "unrecognized key value"),
+            };
+            report.unmapped.push(ShortcutStatus::failed(&name, &value, &error));
+            continue;
+        };
+
+        if accepted.iter().any(|(existing, _)| *existing == id) {
+            report.unmapped.push(ShortcutStatus::failed(&name, &value, &HotkeyError::Duplicate { name: name.clone() }));
+            continue;
+        }
+
+        if let Some(action) = is_reserved_system_shortcut(&shortcut) {
+            let error = HotkeyError::SystemReserved { shortcut: shortcut.clone(), action };
+            report.conflicting.push(ShortcutStatus::failed(id, &shortcut, &error));
+            continue;
+        }
+
+        // 与本次导入的其他条目或未被覆盖的现有设置重复
+        let collides_with_import = accepted.iter().any(|(_, s)| same_combo(s, &shortcut));
+        let collides_with_settings = configured_shortcuts(settings).iter().any(|(other, s)| {
+            *other != id
+                && !accepted.iter().any(|(imported, _)| imported == other)
+                && same_combo(s, &shortcut)
+        });
+        if collides_with_import || collides_with_settings {
+            let owner = accepted
+                .iter()
+                .map(|(other, s)| (*other, s))
+                .chain(configured_shortcuts(settings).iter().map(|(other, s)| (*other, s)))
+                .find(|(other, s)| *other != id && same_combo(s, &shortcut))
+                .map(|(other, _)| other.to_string())
+                .unwrap_or_default();
+            let error = HotkeyError::SelfConflict { shortcut: shortcut.clone(), owner };
+            report.conflicting.push(ShortcutStatus::failed(id, &shortcut, &error));
+            continue;
+        }
+
+        report.mapped.push(ShortcutStatus::ok(id, &shortcut));
+        accepted.push((id, shortcut));
+    }
+
+    (report, accepted)
+}
+
+// 将已映射的组合写入设置
+pub fn apply_to_settings(settings: &mut AppSettings, accepted: &[(&'static str, String)]) {
+    for (id, shortcut) in accepted {
+        let field = match *id {
+            "toggle" => &mut settings.toggle_shortcut,
+            "quickpaste" => &mut settings.quickpaste_shortcut,
+            "paste_plain_text" => &mut settings.paste_plain_text_shortcut,
+            "toggle_clipboard_monitor" => &mut settings.toggle_clipboard_monitor_shortcut,
+            "swap_last_two" => &mut settings.swap_last_two_shortcut,
+            _ => continue,
+        };
+        *field = shortcut.clone();
+    }
+}
+
+// 校验设置中的全部快捷键（含备用组合），返回无法解析、系统保留或互相重复的组合，
+// 状态 ID 与快捷键状态一致（备用组合为 "ID#n"）；用于导入设置前检查
+pub fn validate_settings_shortcuts(settings: &AppSettings) -> Vec<ShortcutStatus> {
+    // 主组合在前，重复时标记排在后面的备用组合
+    let mut bindings: Vec<(String, String)> = configured_shortcuts(settings)
+        .into_iter()
+        .map(|(id, shortcut)| (id.to_string(), shortcut))
+        .collect();
+    let mut alternates: Vec<(String, String)> = Vec::new();
+    for (id, shortcuts) in &settings.alternate_shortcuts {
+        for (n, shortcut) in shortcuts.iter().enumerate().filter(|(_, s)| !s.is_empty()) {
+            alternates.push((format!("{}#{}", id, n + 1), shortcut.clone()));
+        }
+    }
+    alternates.sort();
+    bindings.extend(alternates);
+
+    let mut problems = Vec::new();
+    for (i, (id, shortcut)) in bindings.iter().enumerate() {
+        if let Err(e) = parse_shortcut(shortcut) {
+            problems.push(ShortcutStatus::failed(id, shortcut, &e));
+        } else if let Some(action) = is_reserved_system_shortcut(shortcut) {
+            problems.push(ShortcutStatus::failed(id, shortcut, &HotkeyError::SystemReserved { shortcut: shortcut.clone(), action }));
+        } else if let Some((owner, _)) = bindings[..i].iter().find(|(_, other)| same_combo(other, shortcut)) {
+            let error = HotkeyError::SelfConflict { shortcut: shortcut.clone(), owner: owner.clone() };
+            problems.push(ShortcutStatus::failed(id, shortcut, &error));
+        }
+    }
+    problems
+}
+
+fn same_combo(a: &str, b: &str) -> bool {
+    match (canonical_shortcut(a), canonical_shortcut(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+// 当前设置中的全局快捷键
+fn configured_shortcuts(settings: &AppSettings) -> Vec<(&'static str, String)> {
+    [
+        ("toggle", &settings.toggle_shortcut),
+        ("quickpaste", &settings.quickpaste_shortcut),
+        ("screenshot", &settings.screenshot_shortcut),
+        ("screenshot_quick_save", &settings.screenshot_quick_save_shortcut),
+        ("screenshot_quick_pin", &settings.screenshot_quick_pin_shortcut),
+        ("screenshot_quick_ocr", &settings.screenshot_quick_ocr_shortcut),
+        ("ocr_paste", &settings.ocr_paste_shortcut),
+        ("toggle_clipboard_monitor", &settings.toggle_clipboard_monitor_shortcut),
+        ("toggle_paste_with_format", &settings.toggle_paste_with_format_shortcut),
+        ("paste_plain_text", &settings.paste_plain_text_shortcut),
+        ("macro_next", &settings.macro_next_shortcut),
+        ("swap_last_two", &settings.swap_last_two_shortcut),
+        ("capture_clipboard", &settings.capture_clipboard_shortcut),
+        ("grab_selection", &settings.grab_selection_shortcut),
+        ("paste_pop", &settings.paste_pop_shortcut),
+        ("open_source_app", &settings.open_source_app_shortcut),
+        ("freeze_top_item", &settings.freeze_top_item_shortcut),
+        ("paste_quoted", &settings.paste_quoted_shortcut),
+        ("quick_note", &settings.quick_note_shortcut),
+        ("compose", &settings.compose_shortcut),
+        ("toggle_incognito", &settings.toggle_incognito_shortcut),
+        ("panic", &settings.panic_shortcut),
+    ]
+    .into_iter()
+    .filter(|(_, s)| !s.is_empty())
+    .map(|(id, s)| (id, s.clone()))
+    .collect()
+}
+
+// 逐行解析 `名称 = 值`
+fn parse_entries(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with(';') && !line.starts_with('#') && !line.starts_with('['))
+        .filter_map(|line| {
+            let (name, value) = line.split_once('=')?;
+            let name = name.trim().trim_matches('"').trim();
+            let value = value.trim().trim_matches('"').trim();
+            if name.is_empty() || value.is_empty() {
+                return None;
+            }
+            Some((name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+fn map_action(name: &str) -> Option<&'static str> {
+    let key: String = name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    ACTION_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == key)
+        .map(|(_, id)| *id)
+}
+
+// 将导入值转换为本应用的组合字符串（修饰键顺序 Ctrl、Alt、Shift、Win）
+fn convert_value(value: &str) -> Option<String> {
+    if let Some(code) = parse_numeric(value) {
+        return convert_ditto_code(code);
+    }
+
+    let mut modifiers = [false; 4];
+    let mut key = None;
+    for part in value.split('+').map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" | "ctl" => modifiers[0] = true,
+            "alt" | "menu" => modifiers[1] = true,
+            "shift" => modifiers[2] = true,
+            "win" | "windows" | "super" | "meta" | "lwin" => modifiers[3] = true,
+            _ if key.is_none() => key = Some(normalize_key_name(part)),
+            _ => return None,
+        }
+    }
+    join_combo(modifiers, &key?)
+}
+
+fn parse_numeric(value: &str) -> Option<u32> {
+    let lower = value.to_ascii_lowercase();
+    if let Some(hex) = lower.strip_prefix("dword:").or_else(|| lower.strip_prefix("0x")) {
+        return u32::from_str_radix(hex.trim(), 16).ok();
+    }
+    if lower.chars().all(|c| c.is_ascii_digit()) {
+        return lower.parse().ok();
+    }
+    None
+}
+
+// Ditto 注册表数值：低 8 位虚拟键码，次 8 位修饰键
+fn convert_ditto_code(code: u32) -> Option<String> {
+    let vk = code & 0xFF;
+    let mods = (code >> 8) & 0xFF;
+    let modifiers = [mods & 0x02 != 0, mods & 0x04 != 0, mods & 0x01 != 0, mods & 0x08 != 0];
+    join_combo(modifiers, &vk_to_key_name(vk)?)
+}
+
+fn join_combo(modifiers: [bool; 4], key: &str) -> Option<String> {
+    // 全局快捷键至少需要一个修饰键（功能键除外）
+    let is_function_key = key.len() > 1 && key.starts_with('F') && key[1..].chars().all(|c| c.is_ascii_digit());
+    if !modifiers.iter().any(|m| *m) && !is_function_key {
+        return None;
+    }
+    let mut parts: Vec<&str> = ["Ctrl", "Alt", "Shift", "Win"]
+        .iter()
+        .zip(modifiers)
+        .filter(|(_, on)| *on)
+        .map(|(name, _)| *name)
+        .collect();
+    parts.push(key);
+    Some(parts.join("+"))
+}
+
+fn normalize_key_name(key: &str) -> String {
+    match key.to_ascii_lowercase().as_str() {
+        "space" | "spacebar" => "Space".to_string(),
+        "tilde" | "grave" | "backquote" | "oem3" => "`".to_string(),
+        "ins" | "insert" => "Insert".to_string(),
+        "del" | "delete" => "Delete".to_string(),
+        "home" => "Home".to_string(),
+        "end" => "End".to_string(),
+        "pgup" | "pageup" => "PageUp".to_string(),
+        "pgdn" | "pagedown" => "PageDown".to_string(),
+        "enter" | "return" => "Enter".to_string(),
+        "tab" => "Tab".to_string(),
+        "esc" | "escape" => "Escape".to_string(),
+        _ if key.chars().count() == 1 => key.to_ascii_uppercase(),
+        lower => {
+            // F1-F24 等按原样大写首字母
+            let mut chars = lower.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        }
+    }
+}
+
+fn vk_to_key_name(vk: u32) -> Option<String> {
+    let name = match vk {
+        0x30..=0x39 | 0x41..=0x5A => char::from_u32(vk)?.to_string(),
+        0x70..=0x87 => format!("F{}", vk - 0x6F),
+        0x20 => "Space".to_string(),
+        0x09 => "Tab".to_string(),
+        0x0D => "Enter".to_string(),
+        0x2D => "Insert".to_string(),
+        0x2E => "Delete".to_string(),
+        0x24 => "Home".to_string(),
+        0x23 => "End".to_string(),
+        0x21 => "PageUp".to_string(),
+        0x22 => "PageDown".to_string(),
+        0xC0 => "`".to_string(),
+        0xBD => "-".to_string(),
+        0xBB => "=".to_string(),
+        0xBC => ",".to_string(),
+        0xBE => ".".to_string(),
+        0xBF => "/".to_string(),
+        0xBA => ";".to_string(),
+        0xDB => "[".to_string(),
+        0xDD => "]".to_string(),
+        0xDC => "\\".to_string(),
+        0xDE => "'".to_string(),
+        _ => return None,
+    };
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_settings() -> AppSettings {
+        AppSettings {
+            toggle_shortcut: String::new(),
+            quickpaste_shortcut: String::new(),
+            screenshot_shortcut: String::new(),
+            screenshot_quick_save_shortcut: String::new(),
+            screenshot_quick_pin_shortcut: String::new(),
+            screenshot_quick_ocr_shortcut: String::new(),
+            ocr_paste_shortcut: String::new(),
+            toggle_clipboard_monitor_shortcut: String::new(),
+            toggle_paste_with_format_shortcut: String::new(),
+            paste_plain_text_shortcut: String::new(),
+            macro_next_shortcut: String::new(),
+            swap_last_two_shortcut: String::new(),
+            capture_clipboard_shortcut: String::new(),
+            grab_selection_shortcut: String::new(),
+            paste_pop_shortcut: String::new(),
+            open_source_app_shortcut: String::new(),
+            freeze_top_item_shortcut: String::new(),
+            paste_quoted_shortcut: String::new(),
+            quick_note_shortcut: String::new(),
+            compose_shortcut: String::new(),
+            toggle_incognito_shortcut: String::new(),
+            panic_shortcut: String::new(),
+            ..AppSettings::default()
+        }
+    }
+
+    #[test]
+    fn test_convert_text_and_ditto_values() {
+        assert_eq!(convert_value("Control + Shift + v").as_deref(), Some("Ctrl+Shift+V"));
+        assert_eq!(convert_value("Win+Alt+Tilde").as_deref(), Some("Alt+Win+`"));
+        assert_eq!(convert_value("F9").as_deref(), Some("F9"));
+        assert_eq!(convert_value("V"), None);
+        // Ctrl(2) + `（0xC0）
+        assert_eq!(convert_value("dword:000002c0").as_deref(), Some("Ctrl+`"));
+        // Ctrl+Shift(3) + V（0x56）
+        assert_eq!(convert_value(&(0x0356).to_string()).as_deref(), Some("Ctrl+Shift+V"));
+    }
+
+    #[test]
+    fn test_report_groups_entries() {
+        let content = "\
+[Hotkeys]
+; Ditto 导出
+DittoHotKey=dword:000002c0
+TextOnlyHotKey = \"Ctrl+Shift+V\"
+Show History = Ctrl+Alt+H
+QuickPaste = Win+L
+PastePrevious = Ctrl+Shift+V
+SaveClip = Ctrl+S
+ToggleConnect = Ctrl+Foo+Bar
+";
+        let settings = empty_settings();
+        let (report, accepted) = build_import_report(content, &settings);
+
+        let mapped: Vec<_> = report.mapped.iter().map(|s| (s.id.as_str(), s.shortcut.as_str())).collect();
+        assert_eq!(mapped, vec![("toggle", "Ctrl+`"), ("paste_plain_text", "Ctrl+Shift+V")]);
+        assert_eq!(accepted.len(), 2);
+
+        let unmapped: Vec<_> = report.unmapped.iter().map(|s| (s.id.as_str(), s.error.as_deref())).collect();
+        assert_eq!(unmapped, vec![
+            ("Show History", Some("DUPLICATE")),
+            ("SaveClip", Some("UNMAPPED")),
+            ("ToggleConnect", Some("REGISTRATION_FAILED")),
+        ]);
+
+        let conflicting: Vec<_> = report.conflicting.iter().map(|s| (s.id.as_str(), s.error.as_deref())).collect();
+        assert_eq!(conflicting, vec![
+            ("quickpaste", Some("SYSTEM_RESERVED")),
+            ("swap_last_two", Some("SELF_CONFLICT")),
+        ]);
+
+        let mut applied = settings.clone();
+        apply_to_settings(&mut applied, &accepted);
+        assert_eq!(applied.toggle_shortcut, "Ctrl+`");
+        assert_eq!(applied.paste_plain_text_shortcut, "Ctrl+Shift+V");
+    }
+
+    #[test]
+    fn test_conflict_with_existing_settings() {
+        let mut settings = empty_settings();
+        settings.capture_clipboard_shortcut = "Ctrl+Alt+C".to_string();
+        settings.toggle_shortcut = "Ctrl+Alt+T".to_string();
+
+        // 与现有的其他快捷键重复视为冲突；覆盖自身原有组合不算冲突
+        let (report, _) = build_import_report("PastePlainText=Ctrl+Alt+C\nDittoHotKey=Ctrl+Alt+T", &settings);
+        assert_eq!(report.conflicting.len(), 1);
+        assert_eq!(report.conflicting[0].id, "paste_plain_text");
+        assert_eq!(report.mapped.len(), 1);
+        assert_eq!(report.mapped[0].id, "toggle");
+    }
+
+    #[test]
+    fn test_validate_settings_shortcuts() {
+        let mut settings = empty_settings();
+        settings.toggle_shortcut = "Ctrl+Alt+T".to_string();
+        settings.capture_clipboard_shortcut = "Ctrl+Foo".to_string();
+        settings.quickpaste_shortcut = "Win+L".to_string();
+        settings.alternate_shortcuts.insert("paste_pop".to_string(), vec![String::new(), "Alt+Ctrl+T".to_string()]);
+        assert!(validate_settings_shortcuts(&empty_settings()).is_empty());
+
+        let problems: Vec<_> = validate_settings_shortcuts(&settings)
+            .into_iter()
+            .map(|s| (s.id, s.error))
+            .collect();
+        assert_eq!(problems, vec![
+            ("quickpaste".to_string(), Some("SYSTEM_RESERVED".to_string())),
+            ("capture_clipboard".to_string(), Some("INVALID_SHORTCUT".to_string())),
+            ("paste_pop#2".to_string(), Some("SELF_CONFLICT".to_string())),
+        ]);
+    }
+}
@@ -35,6 +35,11 @@ static KEYBOARD_STATE: Mutex<KeyboardState> = Mutex::new(KeyboardState {
     meta: false,
 });
 
+// 粘滞修饰键状态（无障碍顺序输入组合键）
+#[cfg(all(windows, feature = "sticky-modifiers"))]
+static STICKY_STATE: Mutex<super::sticky_modifiers::StickyModifiers> =
+    Mutex::new(super::sticky_modifiers::StickyModifiers::new());
+
 static THROTTLE_STATE: Lazy<Mutex<HashMap<String, Instant>>> = Lazy::new(|| Mutex::new(HashMap::new()));
 
 fn get_throttle_delay(action: &str) -> Option<Duration> {
@@ -152,6 +157,10 @@ fn handle_input_event(event: Event) -> Option<Event> {
             Some(event)
         }
         EventType::Wheel { delta_y, .. } => {
+            #[cfg(all(windows, feature = "quickpaste-wheel-nav"))]
+            if handle_quickpaste_wheel(delta_y) {
+                return None;
+            }
             handle_wheel_event(delta_y);
             Some(event)
         }
@@ -160,6 +169,12 @@ fn handle_input_event(event: Event) -> Option<Event> {
 
 fn handle_key_press(key: Key, _event: &Event) -> bool {
     update_modifier_key(key, true);
+
+    #[cfg(all(windows, feature = "sticky-modifiers"))]
+    if handle_sticky_key_press(key) {
+        return true;
+    }
+
     if QUICKPASTE_KEYBOARD_MODE_ENABLED.load(Ordering::SeqCst) {
         if handle_quickpaste_key_press(key) {
             return true;
@@ -193,12 +208,78 @@ fn handle_key_press(key: Key, _event: &Event) -> bool {
 
 fn handle_key_release(key: Key) {
     update_modifier_key(key, false);
+
+    #[cfg(all(windows, feature = "sticky-modifiers"))]
+    handle_sticky_key_release(key);
     
     if QUICKPASTE_KEYBOARD_MODE_ENABLED.load(Ordering::SeqCst) {
         handle_quickpaste_key_release(key);
     }
 }
 
+// 主键到来时与已锁定的修饰键组合匹配全局快捷键，命中则执行对应动作并拦截该键
+#[cfg(all(windows, feature = "sticky-modifiers"))]
+fn handle_sticky_key_press(key: Key) -> bool {
+    use super::sticky_modifiers::Modifiers;
+
+    let mut sticky = STICKY_STATE.lock();
+    let timeout = if sticky.latched().is_empty() {
+        Duration::MAX
+    } else {
+        Duration::from_millis(crate::get_settings().sticky_modifiers_timeout_ms)
+    };
+    let Some(latched) = sticky.on_key_press(key, Instant::now(), timeout) else {
+        return false;
+    };
+
+    let (ctrl, alt, shift, meta) = get_modifier_keys_state();
+    let combo = latched.union(Modifiers { ctrl, alt, shift, meta }).as_tuple();
+
+    let matched = crate::hotkey::get_registered_shortcuts()
+        .into_iter()
+        .find(|(_, shortcut)| check_shortcut_match_fast(key, shortcut, combo));
+
+    // 未命中快捷键时按普通按键处理并解除锁定；命中但被守卫拒绝时保留锁定，按键照常传递
+    let Some((id, _)) = matched else {
+        sticky.consume();
+        return false;
+    };
+    if !crate::hotkey::shortcut_action_allowed(&id) {
+        return false;
+    }
+    sticky.consume();
+    drop(sticky);
+
+    // 动作可能涉及窗口操作，不在钩子线程中执行
+    thread::spawn(move || {
+        if let Err(e) = crate::hotkey::press_shortcut_action(&id) {
+            eprintln!("粘滞修饰键触发快捷键 {} 失败: {}", id, e);
+        }
+    });
+    true
+}
+
+#[cfg(all(windows, feature = "sticky-modifiers"))]
+fn handle_sticky_key_release(key: Key) {
+    let is_modifier = matches!(
+        key,
+        Key::ControlLeft | Key::ControlRight |
+        Key::Alt | Key::AltGr |
+        Key::ShiftLeft | Key::ShiftRight |
+        Key::MetaLeft | Key::MetaRight
+    );
+    if !is_modifier {
+        return;
+    }
+
+    let mut sticky = STICKY_STATE.lock();
+    if crate::get_settings().sticky_modifiers_enabled {
+        sticky.on_key_release(key, Instant::now());
+    } else {
+        sticky.clear();
+    }
+}
+
 fn handle_quickpaste_key_press(key: Key) -> bool {
     let is_modifier = matches!(
         key,
@@ -209,6 +290,14 @@ fn handle_quickpaste_key_press(key: Key) -> bool {
     );
     
     if !is_modifier {
+        if let Some(index) = digit_key_index(key) {
+            // 超出显示项数的数字键不响应，保持窗口打开
+            if crate::windows::quickpaste::is_digit_index_visible(index, crate::get_settings().quickpaste_item_count) {
+                paste_quickpaste_index(index);
+            }
+            return true;
+        }
+
         if let Some(window) = MAIN_WINDOW.lock().as_ref() {
             if let Some(qp_window) = window.app_handle().get_webview_window("quickpaste") {
                 let _ = qp_window.emit("quickpaste-next", ());
@@ -220,6 +309,36 @@ fn handle_quickpaste_key_press(key: Key) -> bool {
     false
 }
 
+// 数字键对应的可见项索引（1-9 对应前九项，0 对应第十项）
+fn digit_key_index(key: Key) -> Option<usize> {
+    match key {
+        Key::Num1 | Key::Kp1 => Some(0),
+        Key::Num2 | Key::Kp2 => Some(1),
+        Key::Num3 | Key::Kp3 => Some(2),
+        Key::Num4 | Key::Kp4 => Some(3),
+        Key::Num5 | Key::Kp5 => Some(4),
+        Key::Num6 | Key::Kp6 => Some(5),
+        Key::Num7 | Key::Kp7 => Some(6),
+        Key::Num8 | Key::Kp8 => Some(7),
+        Key::Num9 | Key::Kp9 => Some(8),
+        Key::Num0 | Key::Kp0 => Some(9),
+        _ => None,
+    }
+}
+
+// 粘贴便捷粘贴窗口中指定可见索引的项并隐藏窗口
+fn paste_quickpaste_index(index: usize) {
+    // 先退出键盘模式，避免随后松开修饰键再次粘贴当前选中项
+    disable_quickpaste_keyboard_mode();
+
+    if let Some(window) = MAIN_WINDOW.lock().as_ref() {
+        if let Some(qp_window) = window.app_handle().get_webview_window("quickpaste") {
+            let _ = qp_window.emit("quickpaste-paste-index", index);
+        }
+        crate::windows::quickpaste::schedule_hide_quickpaste_window(window.app_handle());
+    }
+}
+
 fn handle_quickpaste_key_release(key: Key) {
     let is_modifier = matches!(
         key,
@@ -239,11 +358,7 @@ fn handle_quickpaste_key_release(key: Key) {
                 if let Some(qp_window) = window.app_handle().get_webview_window("quickpaste") {
                     let _ = qp_window.emit("quickpaste-hide", ());
                 }
-                let app = window.app_handle().clone();
-                std::thread::spawn(move || {
-                    std::thread::sleep(Duration::from_millis(50));
-                    let _ = crate::windows::quickpaste::hide_quickpaste_window(&app);
-                });
+                crate::windows::quickpaste::schedule_hide_quickpaste_window(window.app_handle());
             }
         }
     }
@@ -375,6 +490,7 @@ fn match_key(key: Key, key_str: &str) -> bool {
         "End" => End,
         "PageUp" => PageUp,
         "PageDown" => PageDown,
+        "`" | "Backquote" => BackQuote,
         // 字母键 A-Z
         "A" => KeyA, "B" => KeyB, "C" => KeyC, "D" => KeyD, "E" => KeyE,
         "F" => KeyF, "G" => KeyG, "H" => KeyH, "I" => KeyI, "J" => KeyJ,
@@ -418,6 +534,12 @@ fn handle_mouse_button_press(button: rdev::Button) {
         }
     }
     
+    if matches!(button, rdev::Button::Left | rdev::Button::Right)
+        && settings.quickpaste_hide_mode == "outside_click"
+    {
+        hide_quickpaste_on_click_outside();
+    }
+    
     let mouse_monitoring_enabled = MOUSE_MONITORING_ENABLED.load(Ordering::Relaxed);
     let context_menu_visible = crate::is_context_menu_visible();
     
@@ -497,6 +619,28 @@ fn handle_wheel_event(delta_y: i64) {
     scroll_page(delta);
 }
 
+// 便捷粘贴窗口显示且按住修饰键时，滚轮移动选中项并拦截滚动（松开修饰键沿用原有粘贴路径）
+#[cfg(all(windows, feature = "quickpaste-wheel-nav"))]
+fn handle_quickpaste_wheel(delta_y: i64) -> bool {
+    if delta_y == 0 || !crate::windows::quickpaste::is_visible() {
+        return false;
+    }
+
+    let (ctrl, alt, shift, meta) = get_modifier_keys_state();
+    if !(ctrl || alt || shift || meta) {
+        return false;
+    }
+
+    if let Some(window) = MAIN_WINDOW.lock().as_ref() {
+        if let Some(qp_window) = window.app_handle().get_webview_window("quickpaste") {
+            let event = if delta_y < 0 { "quickpaste-next" } else { "quickpaste-prev" };
+            let _ = qp_window.emit(event, ());
+            return true;
+        }
+    }
+    false
+}
+
 fn handle_middle_button_action() {
     if crate::services::low_memory::is_low_memory_mode() {
         return;
@@ -545,6 +689,20 @@ fn is_mouse_outside_window(window: &WebviewWindow) -> bool {
         || cursor_y < win_y || cursor_y > win_y + win_height as i32
 }
 
+// 点击外部隐藏模式：点击便捷粘贴窗口外部时直接隐藏（不粘贴）
+fn hide_quickpaste_on_click_outside() {
+    if !crate::windows::quickpaste::is_visible() {
+        return;
+    }
+    if let Some(window) = MAIN_WINDOW.lock().as_ref() {
+        if let Some(qp_window) = window.app_handle().get_webview_window("quickpaste") {
+            if is_mouse_outside_window(&qp_window) {
+                let _ = crate::windows::quickpaste::hide_quickpaste_window(window.app_handle());
+            }
+        }
+    }
+}
+
 // 处理点击窗口外部事件
 fn handle_click_outside() {
     // 右键菜单
@@ -1,9 +1,23 @@
 pub mod hotkey;
+pub mod hotkey_error;
+pub mod hotkey_import;
+pub mod hotkey_feedback;
+pub mod hotkey_execution;
+pub mod hotkey_confirm;
+pub mod clock;
+pub mod timers;
+pub mod panic;
+pub mod action_queue;
 pub mod input_monitor;
+#[cfg(feature = "sticky-modifiers")]
+pub mod sticky_modifiers;
 pub mod focus;
+pub mod password_field;
 pub mod app_filter;
+pub mod source_app;
 pub mod win_v_hotkey;
 pub mod elevate;
+pub mod system_theme;
 
 pub use focus::{focus_clipboard_window, restore_last_focus, save_current_focus};
 pub use app_filter::{
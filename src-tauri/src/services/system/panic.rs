@@ -0,0 +1,130 @@
+// 紧急隐藏（panic key）：一键隐藏主窗口和便捷粘贴窗口，可选清空系统剪贴板、开启无痕模式
+
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::AppHandle;
+
+// 同一时刻只执行一次紧急隐藏，连按时后一次等待前一次完成
+static PANIC_LOCK: Mutex<()> = Mutex::new(());
+
+// 紧急隐藏要执行的动作（各项可在设置中单独开关）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PanicActions {
+    pub hide_windows: bool,
+    pub clear_clipboard: bool,
+    pub enter_incognito: bool,
+}
+
+impl PanicActions {
+    pub fn from_settings(settings: &crate::AppSettings) -> Self {
+        Self {
+            hide_windows: settings.panic_hide_windows,
+            clear_clipboard: settings.panic_clear_clipboard,
+            enter_incognito: settings.panic_enter_incognito,
+        }
+    }
+}
+
+// 单个动作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PanicStep {
+    EnterIncognito,
+    HideWindows,
+    ClearClipboard,
+}
+
+// 执行顺序：隐藏窗口最先执行以尽快遮挡；先开启无痕再清空剪贴板，避免其间的剪贴板变化被记录
+fn plan_steps(actions: &PanicActions) -> Vec<PanicStep> {
+    let mut steps = Vec::new();
+    if actions.hide_windows {
+        steps.push(PanicStep::HideWindows);
+    }
+    if actions.enter_incognito {
+        steps.push(PanicStep::EnterIncognito);
+    }
+    if actions.clear_clipboard {
+        steps.push(PanicStep::ClearClipboard);
+    }
+    steps
+}
+
+// 执行结果（panic-triggered 事件内容）
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PanicReport {
+    pub hid_windows: bool,
+    pub cleared_clipboard: bool,
+    pub entered_incognito: bool,
+    pub errors: Vec<String>,
+}
+
+// 执行全部已启用的动作：单个动作失败不影响其余动作，失败原因汇总到结果中
+pub fn trigger_panic(app: &AppHandle) -> PanicReport {
+    use tauri::Emitter;
+
+    let _lock = PANIC_LOCK.lock();
+    let actions = PanicActions::from_settings(&crate::get_settings());
+    let mut report = PanicReport::default();
+
+    for step in plan_steps(&actions) {
+        let result = match step {
+            PanicStep::HideWindows => hide_windows(app),
+            PanicStep::EnterIncognito => enter_incognito(app),
+            PanicStep::ClearClipboard => crate::services::paste::clipboard_content::clear_os_clipboard(),
+        };
+        match result {
+            Ok(()) => match step {
+                PanicStep::HideWindows => report.hid_windows = true,
+                PanicStep::EnterIncognito => report.entered_incognito = true,
+                PanicStep::ClearClipboard => report.cleared_clipboard = true,
+            },
+            Err(e) => {
+                eprintln!("紧急隐藏动作失败 {:?}: {}", step, e);
+                report.errors.push(e);
+            }
+        }
+    }
+
+    let _ = app.emit("panic-triggered", &report);
+    report
+}
+
+// 隐藏主窗口与便捷粘贴窗口（右键菜单打开时也强制隐藏主窗口）
+fn hide_windows(app: &AppHandle) -> Result<(), String> {
+    crate::windows::quickpaste::hide_quickpaste_window(app)?;
+    if let Some(window) = crate::get_main_window(app) {
+        crate::hide_main_window(&window);
+        if window.is_visible().unwrap_or(false) {
+            window.hide().map_err(|e| format!("隐藏主窗口失败: {}", e))?;
+        }
+    }
+    Ok(())
+}
+
+// 已处于无痕模式时不重复切换（避免重复通知）
+fn enter_incognito(app: &AppHandle) -> Result<(), String> {
+    if crate::services::clipboard::is_incognito() {
+        return Ok(());
+    }
+    crate::commands::settings::apply_incognito(app, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_steps_follow_settings() {
+        let all = PanicActions { hide_windows: true, clear_clipboard: true, enter_incognito: true };
+        assert_eq!(
+            plan_steps(&all),
+            vec![PanicStep::HideWindows, PanicStep::EnterIncognito, PanicStep::ClearClipboard]
+        );
+
+        let clear_only = PanicActions { hide_windows: false, clear_clipboard: true, enter_incognito: false };
+        assert_eq!(plan_steps(&clear_only), vec![PanicStep::ClearClipboard]);
+
+        let none = PanicActions { hide_windows: false, clear_clipboard: false, enter_incognito: false };
+        assert!(plan_steps(&none).is_empty());
+    }
+}
@@ -0,0 +1,111 @@
+// 检测当前焦点控件是否为密码输入框
+
+// 当前前台窗口中获得焦点的控件是否为密码输入框
+#[cfg(windows)]
+pub fn target_is_password() -> bool {
+    // 先检查传统 Edit 控件的 ES_PASSWORD 样式，再通过 UI Automation 覆盖浏览器、WPF 等自绘控件
+    is_focused_edit_password() || is_focused_element_password()
+}
+
+#[cfg(not(windows))]
+pub fn target_is_password() -> bool {
+    false
+}
+
+#[cfg(windows)]
+fn is_focused_edit_password() -> bool {
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetClassNameW, GetForegroundWindow, GetGUIThreadInfo, GetWindowLongW,
+        GetWindowThreadProcessId, GUITHREADINFO, GWL_STYLE,
+    };
+
+    const ES_PASSWORD: u32 = 0x0020;
+
+    unsafe {
+        let foreground = GetForegroundWindow();
+        if foreground.0.is_null() {
+            return false;
+        }
+
+        let thread_id = GetWindowThreadProcessId(foreground, None);
+        let mut info = GUITHREADINFO {
+            cbSize: std::mem::size_of::<GUITHREADINFO>() as u32,
+            ..Default::default()
+        };
+        if GetGUIThreadInfo(thread_id, &mut info).is_err() || info.hwndFocus.0.is_null() {
+            return false;
+        }
+
+        // ES_PASSWORD 只对编辑框类控件有意义（Edit、RichEdit、WinForms 的 EDIT 等）
+        let mut class_buf = [0u16; 256];
+        let class_len = GetClassNameW(info.hwndFocus, &mut class_buf);
+        let class_name = String::from_utf16_lossy(&class_buf[..class_len as usize]);
+        if !class_name.to_ascii_lowercase().contains("edit") {
+            return false;
+        }
+
+        let style = GetWindowLongW(info.hwndFocus, GWL_STYLE) as u32;
+        style & ES_PASSWORD != 0
+    }
+}
+
+#[cfg(windows)]
+fn is_focused_element_password() -> bool {
+    use windows::Win32::System::Com::{
+        CoCreateInstance, CoInitializeEx, CoUninitialize, CLSCTX_INPROC_SERVER, COINIT_MULTITHREADED,
+    };
+    use windows::Win32::UI::Accessibility::{CUIAutomation, IUIAutomation};
+
+    unsafe {
+        // 调用线程可能已按 STA 初始化（如主线程），此时沿用现有套间且不反初始化
+        let initialized = CoInitializeEx(None, COINIT_MULTITHREADED).is_ok();
+
+        let result = (|| -> windows::core::Result<bool> {
+            let automation: IUIAutomation = CoCreateInstance(&CUIAutomation, None, CLSCTX_INPROC_SERVER)?;
+            let element = automation.GetFocusedElement()?;
+            Ok(element.CurrentIsPassword()?.as_bool())
+        })();
+
+        if initialized {
+            CoUninitialize();
+        }
+
+        result.unwrap_or_else(|e| {
+            eprintln!("检测密码输入框失败: {}", e);
+            false
+        })
+    }
+}
+
+// 询问是否继续粘贴到密码输入框（系统模态对话框，可在任意线程调用），确认后切回原目标窗口
+#[cfg(windows)]
+pub fn confirm_password_paste() -> bool {
+    use windows::core::w;
+    use windows::Win32::UI::WindowsAndMessaging::{
+        MessageBoxW, IDOK, MB_ICONWARNING, MB_OKCANCEL, MB_SETFOREGROUND, MB_TOPMOST,
+    };
+
+    let target = super::focus::get_foreground_hwnd();
+    let result = unsafe {
+        MessageBoxW(
+            None,
+            w!("当前焦点位于密码输入框，确定要粘贴吗？"),
+            w!("QuickClipboard"),
+            MB_OKCANCEL | MB_ICONWARNING | MB_TOPMOST | MB_SETFOREGROUND,
+        )
+    };
+    if result != IDOK {
+        return false;
+    }
+
+    // 对话框会抢走焦点，确认后切回目标窗口
+    if let Some(hwnd) = target {
+        super::focus::set_foreground_hwnd(hwnd);
+    }
+    true
+}
+
+#[cfg(not(windows))]
+pub fn confirm_password_paste() -> bool {
+    true
+}
@@ -0,0 +1,187 @@
+// 打开剪贴板项的来源应用：已运行时激活其窗口，否则尝试启动
+
+use serde::{Deserialize, Serialize};
+
+// 打开来源应用的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceAppAction {
+    // 激活了已运行的窗口
+    Activated,
+    // 启动了新进程
+    Launched,
+}
+
+// 打开指定剪贴板项的来源应用
+pub fn open_item_source_app(id: i64) -> Result<SourceAppAction, String> {
+    use crate::services::database::connection::MAX_CONTENT_LENGTH;
+    use crate::services::database::get_clipboard_item_by_id_with_limit;
+
+    let item = get_clipboard_item_by_id_with_limit(id, Some(MAX_CONTENT_LENGTH))?
+        .ok_or_else(|| format!("剪贴板项不存在: {}", id))?;
+    let source = item.source_app
+        .filter(|s| !s.trim().is_empty())
+        .ok_or_else(|| "该剪贴板项没有记录来源应用".to_string())?;
+    open_source_app(&source)
+}
+
+// 打开最新一条剪贴板项的来源应用
+pub fn open_latest_source_app() -> Result<SourceAppAction, String> {
+    use crate::services::database::{query_clipboard_items, QueryParams};
+
+    let items = query_clipboard_items(QueryParams {
+        offset: 0,
+        limit: 1,
+        search: None,
+        content_type: None,
+        created_before: None,
+        with_highlights: false,
+        exclude_local_only: false,
+    })?
+    .items;
+    let item = items.first().ok_or_else(|| "剪贴板历史为空".to_string())?;
+    open_item_source_app(item.id)
+}
+
+// 按进程名激活窗口或启动应用
+#[cfg(windows)]
+pub fn open_source_app(process_name: &str) -> Result<SourceAppAction, String> {
+    if let Some(hwnd) = find_main_window(process_name) {
+        activate_window(hwnd);
+        return Ok(SourceAppAction::Activated);
+    }
+
+    // UWP 应用记录的是显示名称，无法按可执行文件启动
+    if !process_name.to_ascii_lowercase().ends_with(".exe") {
+        return Err(format!("来源应用未运行且无法启动: {}", process_name));
+    }
+    launch(process_name)?;
+    Ok(SourceAppAction::Launched)
+}
+
+#[cfg(not(windows))]
+pub fn open_source_app(_process_name: &str) -> Result<SourceAppAction, String> {
+    Err("当前平台不支持打开来源应用".to_string())
+}
+
+#[cfg(windows)]
+struct WindowSearch {
+    target: String,
+    found: Option<isize>,
+}
+
+// 查找进程的主窗口（可见、无所有者、有标题的顶层窗口）
+#[cfg(windows)]
+fn find_main_window(process_name: &str) -> Option<isize> {
+    use windows::Win32::Foundation::LPARAM;
+    use windows::Win32::UI::WindowsAndMessaging::EnumWindows;
+
+    let mut search = WindowSearch {
+        target: process_name.to_lowercase(),
+        found: None,
+    };
+    unsafe {
+        let _ = EnumWindows(Some(enum_window_callback), LPARAM(&mut search as *mut WindowSearch as isize));
+    }
+    search.found
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn enum_window_callback(
+    hwnd: windows::Win32::Foundation::HWND,
+    lparam: windows::Win32::Foundation::LPARAM,
+) -> windows::core::BOOL {
+    use windows::core::BOOL;
+    use windows::Win32::UI::WindowsAndMessaging::{
+        GetWindow, GetWindowTextLengthW, GetWindowThreadProcessId, IsWindowVisible, GW_OWNER,
+    };
+
+    let search = &mut *(lparam.0 as *mut WindowSearch);
+
+    if !IsWindowVisible(hwnd).as_bool() || GetWindowTextLengthW(hwnd) == 0 {
+        return BOOL(1);
+    }
+    if GetWindow(hwnd, GW_OWNER).map(|owner| !owner.0.is_null()).unwrap_or(false) {
+        return BOOL(1);
+    }
+
+    let mut pid: u32 = 0;
+    GetWindowThreadProcessId(hwnd, Some(&mut pid));
+    if pid == 0 {
+        return BOOL(1);
+    }
+
+    match process_name_by_pid(pid) {
+        Some(name) if name.to_lowercase() == search.target => {
+            search.found = Some(hwnd.0 as isize);
+            BOOL(0)
+        }
+        _ => BOOL(1),
+    }
+}
+
+#[cfg(windows)]
+fn process_name_by_pid(pid: u32) -> Option<String> {
+    use windows::core::PWSTR;
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+        let mut buffer = [0u16; 260];
+        let mut len = buffer.len() as u32;
+        let result = QueryFullProcessImageNameW(handle, PROCESS_NAME_WIN32, PWSTR(buffer.as_mut_ptr()), &mut len);
+        let _ = CloseHandle(handle);
+        result.ok()?;
+        let path = String::from_utf16_lossy(&buffer[..len as usize]);
+        path.rsplit('\\').next().map(|name| name.to_string())
+    }
+}
+
+// 激活窗口（最小化时先还原）
+#[cfg(windows)]
+pub(crate) fn activate_window(hwnd: isize) {
+    use std::ffi::c_void;
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::UI::WindowsAndMessaging::{IsIconic, ShowWindow, SW_RESTORE};
+
+    let hwnd_value = HWND(hwnd as *mut c_void);
+    unsafe {
+        if IsIconic(hwnd_value).as_bool() {
+            let _ = ShowWindow(hwnd_value, SW_RESTORE);
+        }
+    }
+    super::focus::set_foreground_hwnd(hwnd);
+}
+
+// 通过外壳启动（可解析 App Paths 中登记的可执行文件名）
+#[cfg(windows)]
+fn launch(process_name: &str) -> Result<(), String> {
+    use std::ffi::OsStr;
+    use std::os::windows::ffi::OsStrExt;
+    use windows::core::PCWSTR;
+    use windows::Win32::UI::Shell::ShellExecuteW;
+    use windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
+
+    let operation: Vec<u16> = OsStr::new("open").encode_wide().chain(std::iter::once(0)).collect();
+    let file: Vec<u16> = OsStr::new(process_name).encode_wide().chain(std::iter::once(0)).collect();
+
+    let result = unsafe {
+        ShellExecuteW(
+            None,
+            PCWSTR(operation.as_ptr()),
+            PCWSTR(file.as_ptr()),
+            PCWSTR(std::ptr::null()),
+            PCWSTR(std::ptr::null()),
+            SW_SHOWNORMAL,
+        )
+    };
+
+    if result.0 as usize > 32 {
+        Ok(())
+    } else {
+        Err(format!("来源应用已不存在或无法启动: {}", process_name))
+    }
+}
@@ -0,0 +1,199 @@
+// 粘滞修饰键（无障碍输入）：单独按下并松开修饰键即锁定该修饰键，
+// 随后按下的主键与已锁定的修饰键组合，按全局快捷键进行匹配
+
+use rdev::Key;
+use std::time::{Duration, Instant};
+
+// 修饰键组合
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Modifiers {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub meta: bool,
+}
+
+impl Modifiers {
+    pub fn is_empty(&self) -> bool {
+        !self.ctrl && !self.alt && !self.shift && !self.meta
+    }
+
+    pub fn union(self, other: Modifiers) -> Modifiers {
+        Modifiers {
+            ctrl: self.ctrl || other.ctrl,
+            alt: self.alt || other.alt,
+            shift: self.shift || other.shift,
+            meta: self.meta || other.meta,
+        }
+    }
+
+    pub fn as_tuple(&self) -> (bool, bool, bool, bool) {
+        (self.ctrl, self.alt, self.shift, self.meta)
+    }
+
+    fn toggle(&mut self, kind: ModifierKind) {
+        match kind {
+            ModifierKind::Ctrl => self.ctrl = !self.ctrl,
+            ModifierKind::Alt => self.alt = !self.alt,
+            ModifierKind::Shift => self.shift = !self.shift,
+            ModifierKind::Meta => self.meta = !self.meta,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ModifierKind {
+    Ctrl,
+    Alt,
+    Shift,
+    Meta,
+}
+
+fn modifier_kind(key: Key) -> Option<ModifierKind> {
+    match key {
+        Key::ControlLeft | Key::ControlRight => Some(ModifierKind::Ctrl),
+        Key::Alt | Key::AltGr => Some(ModifierKind::Alt),
+        Key::ShiftLeft | Key::ShiftRight => Some(ModifierKind::Shift),
+        Key::MetaLeft | Key::MetaRight => Some(ModifierKind::Meta),
+        _ => None,
+    }
+}
+
+// 粘滞修饰键状态机
+#[derive(Debug, Default)]
+pub struct StickyModifiers {
+    latched: Modifiers,
+    // 按下后尚未有其他键介入的修饰键（松开时锁定）
+    tap_candidate: Option<ModifierKind>,
+    latched_at: Option<Instant>,
+}
+
+impl StickyModifiers {
+    pub const fn new() -> Self {
+        Self {
+            latched: Modifiers { ctrl: false, alt: false, shift: false, meta: false },
+            tap_candidate: None,
+            latched_at: None,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.latched = Modifiers::default();
+        self.tap_candidate = None;
+        self.latched_at = None;
+    }
+
+    pub fn latched(&self) -> Modifiers {
+        self.latched
+    }
+
+    fn expire(&mut self, now: Instant, timeout: Duration) {
+        if let Some(at) = self.latched_at {
+            if now.duration_since(at) > timeout {
+                self.latched = Modifiers::default();
+                self.latched_at = None;
+            }
+        }
+    }
+
+    // 处理按键按下，主键到来且存在已锁定修饰键时返回锁定的组合
+    // 锁定不会在此清除，由调用方在动作被接受后调用 consume
+    pub fn on_key_press(&mut self, key: Key, now: Instant, timeout: Duration) -> Option<Modifiers> {
+        self.expire(now, timeout);
+
+        if let Some(kind) = modifier_kind(key) {
+            // 同时按住其他修饰键时视为普通组合，不参与锁定
+            self.tap_candidate = match self.tap_candidate {
+                Some(current) if current != kind => None,
+                _ => Some(kind),
+            };
+            return None;
+        }
+
+        self.tap_candidate = None;
+        if self.latched.is_empty() {
+            return None;
+        }
+        // Esc 仅用于取消锁定
+        if matches!(key, Key::Escape) {
+            self.clear();
+            return None;
+        }
+        Some(self.latched)
+    }
+
+    // 锁定的组合已被使用，清除锁定
+    pub fn consume(&mut self) {
+        self.clear();
+    }
+
+    // 处理按键松开，单独按下并松开的修饰键被锁定（再次单独按下则解除）
+    pub fn on_key_release(&mut self, key: Key, now: Instant) {
+        let Some(kind) = modifier_kind(key) else {
+            return;
+        };
+        if self.tap_candidate == Some(kind) {
+            self.latched.toggle(kind);
+            self.latched_at = if self.latched.is_empty() { None } else { Some(now) };
+        }
+        self.tap_candidate = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(3);
+
+    fn tap(state: &mut StickyModifiers, key: Key, now: Instant) {
+        assert_eq!(state.on_key_press(key, now, TIMEOUT), None);
+        state.on_key_release(key, now);
+    }
+
+    #[test]
+    fn test_sequential_modifiers_combine() {
+        let mut state = StickyModifiers::new();
+        let now = Instant::now();
+        tap(&mut state, Key::ControlLeft, now);
+        tap(&mut state, Key::ShiftLeft, now);
+        let combo = state.on_key_press(Key::KeyV, now, TIMEOUT).unwrap();
+        assert_eq!(combo, Modifiers { ctrl: true, alt: false, shift: true, meta: false });
+        // 未消费前锁定保持，动作被拒绝时可继续使用
+        assert_eq!(state.latched(), combo);
+        state.consume();
+        assert!(state.latched().is_empty());
+    }
+
+    #[test]
+    fn test_held_combo_does_not_latch() {
+        let mut state = StickyModifiers::new();
+        let now = Instant::now();
+        assert_eq!(state.on_key_press(Key::ControlLeft, now, TIMEOUT), None);
+        assert_eq!(state.on_key_press(Key::KeyC, now, TIMEOUT), None);
+        state.on_key_release(Key::KeyC, now);
+        state.on_key_release(Key::ControlLeft, now);
+        assert!(state.latched().is_empty());
+    }
+
+    #[test]
+    fn test_double_tap_unlatches_and_escape_cancels() {
+        let mut state = StickyModifiers::new();
+        let now = Instant::now();
+        tap(&mut state, Key::Alt, now);
+        tap(&mut state, Key::Alt, now);
+        assert!(state.latched().is_empty());
+
+        tap(&mut state, Key::Alt, now);
+        assert_eq!(state.on_key_press(Key::Escape, now, TIMEOUT), None);
+        assert!(state.latched().is_empty());
+    }
+
+    #[test]
+    fn test_latch_expires() {
+        let mut state = StickyModifiers::new();
+        let now = Instant::now();
+        tap(&mut state, Key::MetaLeft, now);
+        assert_eq!(state.on_key_press(Key::KeyV, now + TIMEOUT * 2, TIMEOUT), None);
+    }
+}
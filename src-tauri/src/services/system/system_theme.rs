@@ -0,0 +1,179 @@
+// 系统主题监听：系统切换浅色/深色模式时通知所有窗口，并同步原生标题栏颜色
+
+use once_cell::sync::OnceCell;
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::AppHandle;
+#[cfg(windows)]
+use tauri::{Emitter, Manager};
+
+static APP_HANDLE: OnceCell<AppHandle> = OnceCell::new();
+// 上一次广播的主题，WM_SETTINGCHANGE 会因其他设置变化重复到达
+static LAST_THEME: Mutex<Option<SystemTheme>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SystemTheme {
+    Light,
+    Dark,
+}
+
+impl SystemTheme {
+    // 注册表 AppsUseLightTheme：0 为深色，缺失时按浅色处理
+    #[cfg(windows)]
+    fn from_apps_use_light_theme(value: Option<u32>) -> Self {
+        match value {
+            Some(0) => Self::Dark,
+            _ => Self::Light,
+        }
+    }
+
+    #[cfg(windows)]
+    fn to_tauri(self) -> tauri::Theme {
+        match self {
+            Self::Light => tauri::Theme::Light,
+            Self::Dark => tauri::Theme::Dark,
+        }
+    }
+}
+
+#[cfg(windows)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SystemThemeChanged {
+    theme: SystemTheme,
+}
+
+// WM_SETTINGCHANGE 附带的区域名为 ImmersiveColorSet 时表示颜色/主题设置变化
+#[cfg(windows)]
+fn is_theme_setting_area(area: Option<&str>) -> bool {
+    area.is_some_and(|area| area.eq_ignore_ascii_case("ImmersiveColorSet"))
+}
+
+// 读取当前系统主题
+#[cfg(windows)]
+pub fn current_system_theme() -> SystemTheme {
+    use winreg::enums::HKEY_CURRENT_USER;
+    use winreg::RegKey;
+
+    let value = RegKey::predef(HKEY_CURRENT_USER)
+        .open_subkey(r"Software\Microsoft\Windows\CurrentVersion\Themes\Personalize")
+        .and_then(|key| key.get_value::<u32, _>("AppsUseLightTheme"))
+        .ok();
+    SystemTheme::from_apps_use_light_theme(value)
+}
+
+#[cfg(not(windows))]
+pub fn current_system_theme() -> SystemTheme {
+    SystemTheme::Light
+}
+
+// 跟随系统主题时同步所有窗口的原生标题栏
+#[cfg(windows)]
+fn apply_window_chrome(app: &AppHandle, theme: SystemTheme) {
+    let settings = crate::get_settings();
+    if !settings.theme.is_empty() && settings.theme != "auto" {
+        return;
+    }
+    for window in app.webview_windows().values() {
+        let _ = window.set_theme(Some(theme.to_tauri()));
+    }
+}
+
+// 系统设置变化后检查主题，确实变化时才广播
+#[cfg(windows)]
+fn handle_theme_change() {
+    let Some(app) = APP_HANDLE.get() else { return };
+    let theme = current_system_theme();
+    if LAST_THEME.lock().replace(theme) == Some(theme) {
+        return;
+    }
+    apply_window_chrome(app, theme);
+    let _ = app.emit("system-theme-changed", SystemThemeChanged { theme });
+}
+
+// 启动系统主题监听
+pub fn start_system_theme_listener(app: AppHandle) {
+    if APP_HANDLE.set(app).is_err() {
+        return;
+    }
+    *LAST_THEME.lock() = Some(current_system_theme());
+
+    #[cfg(windows)]
+    std::thread::spawn(run_message_loop);
+}
+
+// 隐藏的顶层窗口用于接收 WM_SETTINGCHANGE 广播（仅消息窗口收不到广播）
+#[cfg(windows)]
+fn run_message_loop() {
+    use windows::core::{w, PCWSTR};
+    use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, WPARAM};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        CreateWindowExW, DefWindowProcW, DispatchMessageW, GetMessageW, RegisterClassW,
+        TranslateMessage, MSG, WINDOW_EX_STYLE, WM_SETTINGCHANGE, WNDCLASSW, WS_OVERLAPPED,
+    };
+
+    unsafe extern "system" fn wnd_proc(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+        if msg == WM_SETTINGCHANGE {
+            let area = if lparam.0 == 0 {
+                None
+            } else {
+                PCWSTR(lparam.0 as *const u16).to_string().ok()
+            };
+            if is_theme_setting_area(area.as_deref()) {
+                handle_theme_change();
+            }
+        }
+        DefWindowProcW(hwnd, msg, wparam, lparam)
+    }
+
+    unsafe {
+        let class_name = w!("QuickClipboardThemeListener");
+        let wc = WNDCLASSW {
+            lpfnWndProc: Some(wnd_proc),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        if RegisterClassW(&wc) == 0 {
+            eprintln!("注册系统主题监听窗口类失败");
+            return;
+        }
+
+        let Ok(hwnd) = CreateWindowExW(
+            WINDOW_EX_STYLE(0),
+            class_name,
+            w!("Theme Listener"),
+            WS_OVERLAPPED,
+            0, 0, 0, 0,
+            None, None, None, None,
+        ) else {
+            eprintln!("创建系统主题监听窗口失败");
+            return;
+        };
+
+        let mut msg = MSG::default();
+        while GetMessageW(&mut msg, Some(hwnd), 0, 0).as_bool() {
+            let _ = TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+        }
+    }
+}
+
+#[cfg(all(test, windows))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_from_registry_value() {
+        assert_eq!(SystemTheme::from_apps_use_light_theme(Some(0)), SystemTheme::Dark);
+        assert_eq!(SystemTheme::from_apps_use_light_theme(Some(1)), SystemTheme::Light);
+        assert_eq!(SystemTheme::from_apps_use_light_theme(None), SystemTheme::Light);
+    }
+
+    #[test]
+    fn test_only_color_set_changes_count() {
+        assert!(is_theme_setting_area(Some("ImmersiveColorSet")));
+        assert!(!is_theme_setting_area(Some("Environment")));
+        assert!(!is_theme_setting_area(None));
+    }
+}
@@ -0,0 +1,262 @@
+// 定时操作登记表：延时执行的操作在此登记（ID、类型、到期时间），可统一查看与取消，
+// 替代分散的 thread::spawn + sleep
+
+use once_cell::sync::Lazy;
+use parking_lot::{Condvar, Mutex};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// 待执行的定时操作
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingTimer {
+    pub id: u64,
+    // 操作类型（如 quickpaste_hide），可按类型批量取消
+    pub kind: String,
+    pub label: String,
+    // 到期时间（Unix 毫秒）
+    pub expires_at: u64,
+}
+
+// 取消信号：等待中的线程被取消时立即唤醒
+struct CancelSignal {
+    cancelled: Mutex<bool>,
+    condvar: Condvar,
+}
+
+impl CancelSignal {
+    fn new() -> Self {
+        Self { cancelled: Mutex::new(false), condvar: Condvar::new() }
+    }
+
+    fn cancel(&self) {
+        *self.cancelled.lock() = true;
+        self.condvar.notify_all();
+    }
+
+    fn is_cancelled(&self) -> bool {
+        *self.cancelled.lock()
+    }
+
+    // 等待到期，期间被取消时返回 true
+    fn wait_until(&self, deadline: Instant) -> bool {
+        let mut cancelled = self.cancelled.lock();
+        while !*cancelled {
+            if self.condvar.wait_until(&mut cancelled, deadline).timed_out() {
+                break;
+            }
+        }
+        *cancelled
+    }
+}
+
+struct Entry {
+    info: PendingTimer,
+    signal: Arc<CancelSignal>,
+}
+
+pub struct TimerRegistry {
+    next_id: AtomicU64,
+    entries: Mutex<HashMap<u64, Entry>>,
+}
+
+fn unix_ms_after(delay: Duration) -> u64 {
+    (SystemTime::now() + delay)
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+impl TimerRegistry {
+    pub fn new() -> Self {
+        Self { next_id: AtomicU64::new(1), entries: Mutex::new(HashMap::new()) }
+    }
+
+    fn insert(&self, kind: &str, label: &str, delay: Duration) -> (u64, Arc<CancelSignal>) {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let signal = Arc::new(CancelSignal::new());
+        let info = PendingTimer {
+            id,
+            kind: kind.to_string(),
+            label: label.to_string(),
+            expires_at: unix_ms_after(delay),
+        };
+        self.entries.lock().insert(id, Entry { info, signal: signal.clone() });
+        (id, signal)
+    }
+
+    // 注销登记项，返回是否仍在登记表中
+    fn remove(&self, id: u64) -> bool {
+        self.entries.lock().remove(&id).is_some()
+    }
+
+    // 延时执行 action，返回定时器 ID；到期前取消则不执行
+    pub fn schedule<F>(&'static self, kind: &str, label: &str, delay: Duration, action: F) -> u64
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let (id, signal) = self.insert(kind, label, delay);
+        let deadline = Instant::now() + delay;
+        std::thread::spawn(move || {
+            signal.wait_until(deadline);
+            // 取消时已被移出登记表，仅在本线程成功注销时执行
+            if self.remove(id) {
+                action();
+            }
+        });
+        id
+    }
+
+    // 登记由调用方自行计时的操作（如轮询循环），句柄释放时自动注销
+    pub fn register(&'static self, kind: &str, label: &str, delay: Duration) -> TimerHandle {
+        let (id, signal) = self.insert(kind, label, delay);
+        TimerHandle { registry: self, id, signal }
+    }
+
+    pub fn cancel(&self, id: u64) -> bool {
+        let entry = self.entries.lock().remove(&id);
+        match entry {
+            Some(entry) => {
+                entry.signal.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    // 取消指定类型的全部定时器，返回取消的数量
+    pub fn cancel_kind(&self, kind: &str) -> usize {
+        let removed: Vec<Entry> = {
+            let mut entries = self.entries.lock();
+            let ids: Vec<u64> = entries
+                .values()
+                .filter(|e| e.info.kind == kind)
+                .map(|e| e.info.id)
+                .collect();
+            ids.iter().filter_map(|id| entries.remove(id)).collect()
+        };
+        for entry in &removed {
+            entry.signal.cancel();
+        }
+        removed.len()
+    }
+
+    // 按到期时间排序的待执行列表
+    pub fn list(&self) -> Vec<PendingTimer> {
+        let mut timers: Vec<PendingTimer> = self.entries.lock().values().map(|e| e.info.clone()).collect();
+        timers.sort_by_key(|t| (t.expires_at, t.id));
+        timers
+    }
+
+    fn set_expiry(&self, id: u64, delay: Duration) {
+        if let Some(entry) = self.entries.lock().get_mut(&id) {
+            entry.info.expires_at = unix_ms_after(delay);
+        }
+    }
+}
+
+// 自行计时的登记项句柄
+pub struct TimerHandle {
+    registry: &'static TimerRegistry,
+    id: u64,
+    signal: Arc<CancelSignal>,
+}
+
+impl TimerHandle {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.signal.is_cancelled()
+    }
+
+    // 更新剩余时间（如空闲计时被重置）
+    pub fn set_remaining(&self, remaining: Duration) {
+        self.registry.set_expiry(self.id, remaining);
+    }
+}
+
+impl Drop for TimerHandle {
+    fn drop(&mut self) {
+        self.registry.remove(self.id);
+    }
+}
+
+static TIMERS: Lazy<TimerRegistry> = Lazy::new(TimerRegistry::new);
+
+pub fn schedule<F>(kind: &str, label: &str, delay: Duration, action: F) -> u64
+where
+    F: FnOnce() + Send + 'static,
+{
+    TIMERS.schedule(kind, label, delay, action)
+}
+
+pub fn register(kind: &str, label: &str, delay: Duration) -> TimerHandle {
+    TIMERS.register(kind, label, delay)
+}
+
+pub fn list_pending_timers() -> Vec<PendingTimer> {
+    TIMERS.list()
+}
+
+pub fn cancel_timer(id: u64) -> bool {
+    TIMERS.cancel(id)
+}
+
+pub fn cancel_timers_of_kind(kind: &str) -> usize {
+    TIMERS.cancel_kind(kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    fn registry() -> &'static TimerRegistry {
+        Box::leak(Box::new(TimerRegistry::new()))
+    }
+
+    #[test]
+    fn test_timer_runs_and_deregisters() {
+        let timers = registry();
+        let (tx, rx) = mpsc::channel();
+        timers.schedule("test", "立即执行", Duration::ZERO, move || tx.send(()).unwrap());
+        rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert!(timers.list().is_empty());
+    }
+
+    #[test]
+    fn test_cancelled_timer_does_not_run() {
+        let timers = registry();
+        let (tx, rx) = mpsc::channel::<()>();
+        let id = timers.schedule("test", "稍后执行", Duration::from_secs(60), move || tx.send(()).unwrap());
+        assert_eq!(timers.list().len(), 1);
+        assert!(timers.cancel(id));
+        assert!(!timers.cancel(id));
+        assert!(timers.list().is_empty());
+        // 被取消的线程立即退出且不执行（发送端随闭包一起释放）
+        assert!(rx.recv_timeout(Duration::from_secs(5)).is_err());
+    }
+
+    #[test]
+    fn test_cancel_kind_and_handles() {
+        let timers = registry();
+        let handle = timers.register("idle", "空闲检测", Duration::from_secs(30));
+        timers.schedule("hide", "隐藏", Duration::from_secs(60), || {});
+        timers.schedule("hide", "隐藏", Duration::from_secs(90), || {});
+
+        let listed = timers.list();
+        assert_eq!(listed.len(), 3);
+        assert_eq!(listed[0].id, handle.id());
+
+        assert_eq!(timers.cancel_kind("hide"), 2);
+        assert!(timers.cancel(handle.id()));
+        assert!(handle.is_cancelled());
+        drop(handle);
+        assert!(timers.list().is_empty());
+    }
+}
@@ -1,5 +1,8 @@
 // HTML 处理工具函数
 
+use std::iter::Peekable;
+use std::str::Chars;
+
 pub fn truncate_html(html: String, max_visible_len: usize) -> String {
     if html.is_empty() {
         return html;
@@ -101,3 +104,353 @@ pub fn truncate_html(html: String, max_visible_len: usize) -> String {
     
     result
 }
+
+// 将 HTML 转换为简单 RTF（保留粗体、斜体、下划线和换行）
+pub fn html_to_rtf(html: &str) -> String {
+    let mut body = String::with_capacity(html.len());
+    let mut chars = html.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '<' => {
+                let mut tag = String::new();
+                for t in chars.by_ref() {
+                    if t == '>' {
+                        break;
+                    }
+                    tag.push(t);
+                }
+                let tag = tag.trim().to_lowercase();
+                let is_closing = tag.starts_with('/');
+                let name: String = tag
+                    .trim_start_matches('/')
+                    .chars()
+                    .take_while(|c| c.is_alphanumeric())
+                    .collect();
+
+                match (name.as_str(), is_closing) {
+                    ("b" | "strong", false) => body.push_str("\\b "),
+                    ("b" | "strong", true) => body.push_str("\\b0 "),
+                    ("i" | "em", false) => body.push_str("\\i "),
+                    ("i" | "em", true) => body.push_str("\\i0 "),
+                    ("u", false) => body.push_str("\\ul "),
+                    ("u", true) => body.push_str("\\ulnone "),
+                    ("br", _) => body.push_str("\\line "),
+                    ("p" | "div" | "li" | "tr" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6", true) => {
+                        body.push_str("\\par ")
+                    }
+                    _ => {}
+                }
+            }
+            '&' => {
+                for d in decode_entity(&mut chars).chars() {
+                    push_rtf_char(&mut body, d);
+                }
+            }
+            '\r' | '\n' => body.push(' '),
+            _ => push_rtf_char(&mut body, c),
+        }
+    }
+
+    format!("{{\\rtf1\\ansi\\deff0{{\\fonttbl{{\\f0 Calibri;}}}}\\f0 {}}}", body.trim_end())
+}
+
+// 解码 '&' 之后的实体，无法识别时原样返回
+fn decode_entity(chars: &mut Peekable<Chars>) -> String {
+    let mut entity = String::new();
+    while let Some(&t) = chars.peek() {
+        if t == ';' || entity.len() > 8 {
+            break;
+        }
+        entity.push(t);
+        chars.next();
+    }
+    let decoded = match entity.as_str() {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" | "#39" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => None,
+    };
+    match decoded {
+        Some(d) if chars.peek() == Some(&';') => {
+            chars.next();
+            d.to_string()
+        }
+        _ => format!("&{}", entity),
+    }
+}
+
+// 写入 RTF 字符（转义控制字符，非 ASCII 使用 \uN 形式）
+fn push_rtf_char(out: &mut String, c: char) {
+    match c {
+        '\\' | '{' | '}' => {
+            out.push('\\');
+            out.push(c);
+        }
+        c if (c as u32) < 0x80 => out.push(c),
+        c => {
+            let mut buf = [0u16; 2];
+            for unit in c.encode_utf16(&mut buf) {
+                out.push_str(&format!("\\u{}?", *unit as i16));
+            }
+        }
+    }
+}
+
+// 将 HTML 转换为 Markdown（标题、段落、列表、链接、粗体/斜体、代码）
+pub fn html_to_markdown(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut lists: Vec<(bool, usize)> = Vec::new();
+    let mut links: Vec<Option<String>> = Vec::new();
+    let mut in_pre = false;
+    let mut skip_depth = 0usize;
+    let mut chars = html.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '<' => {
+                let mut raw = String::new();
+                for t in chars.by_ref() {
+                    if t == '>' {
+                        break;
+                    }
+                    raw.push(t);
+                }
+                let tag = raw.trim();
+                // 注释与 DOCTYPE
+                if tag.starts_with('!') {
+                    continue;
+                }
+                let is_closing = tag.starts_with('/');
+                let name: String = tag
+                    .trim_start_matches('/')
+                    .chars()
+                    .take_while(|c| c.is_alphanumeric())
+                    .collect::<String>()
+                    .to_lowercase();
+
+                if matches!(name.as_str(), "script" | "style" | "head") {
+                    if is_closing {
+                        skip_depth = skip_depth.saturating_sub(1);
+                    } else {
+                        skip_depth += 1;
+                    }
+                    continue;
+                }
+                if skip_depth > 0 {
+                    continue;
+                }
+
+                let heading_level = match name.as_str() {
+                    "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => name[1..].parse::<usize>().ok(),
+                    _ => None,
+                };
+                if let Some(level) = heading_level {
+                    ensure_blank_line(&mut out);
+                    if !is_closing {
+                        out.push_str(&"#".repeat(level));
+                        out.push(' ');
+                    }
+                    continue;
+                }
+
+                match (name.as_str(), is_closing) {
+                    ("p" | "div" | "table", _) => ensure_blank_line(&mut out),
+                    ("br", _) => {
+                        trim_trailing_spaces(&mut out);
+                        out.push('\n');
+                    }
+                    ("hr", _) => {
+                        ensure_blank_line(&mut out);
+                        out.push_str("---");
+                        ensure_blank_line(&mut out);
+                    }
+                    ("b" | "strong", _) => out.push_str("**"),
+                    ("i" | "em", _) => out.push('*'),
+                    ("code", _) if !in_pre => out.push('`'),
+                    ("pre", false) => {
+                        ensure_blank_line(&mut out);
+                        out.push_str("```\n");
+                        in_pre = true;
+                    }
+                    ("pre", true) => {
+                        if !out.ends_with('\n') {
+                            out.push('\n');
+                        }
+                        out.push_str("```");
+                        in_pre = false;
+                        ensure_blank_line(&mut out);
+                    }
+                    ("ul" | "ol", false) => {
+                        if lists.is_empty() {
+                            ensure_blank_line(&mut out);
+                        } else {
+                            ensure_newline(&mut out);
+                        }
+                        lists.push((name == "ol", 0));
+                    }
+                    ("ul" | "ol", true) => {
+                        lists.pop();
+                        if lists.is_empty() {
+                            ensure_blank_line(&mut out);
+                        } else {
+                            ensure_newline(&mut out);
+                        }
+                    }
+                    ("li", false) => {
+                        ensure_newline(&mut out);
+                        out.push_str(&"  ".repeat(lists.len().saturating_sub(1)));
+                        match lists.last_mut() {
+                            Some((true, counter)) => {
+                                *counter += 1;
+                                out.push_str(&format!("{}. ", counter));
+                            }
+                            _ => out.push_str("- "),
+                        }
+                    }
+                    ("blockquote", false) => {
+                        ensure_blank_line(&mut out);
+                        out.push_str("> ");
+                    }
+                    ("blockquote", true) => ensure_blank_line(&mut out),
+                    ("tr", true) => ensure_newline(&mut out),
+                    ("td" | "th", true) => out.push(' '),
+                    ("a", false) => {
+                        let href = extract_attribute(tag, "href");
+                        if href.is_some() {
+                            out.push('[');
+                        }
+                        links.push(href);
+                    }
+                    ("a", true) => {
+                        if let Some(Some(href)) = links.pop() {
+                            out.push_str(&format!("]({})", href));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ if skip_depth > 0 => {}
+            '&' => {
+                for d in decode_entity(&mut chars).chars() {
+                    push_markdown_char(&mut out, d, in_pre);
+                }
+            }
+            _ => push_markdown_char(&mut out, c, in_pre),
+        }
+    }
+
+    let mut result = out.trim().to_string();
+    while result.contains("\n\n\n") {
+        result = result.replace("\n\n\n", "\n\n");
+    }
+    result
+}
+
+// 写入 Markdown 文本字符（pre 外折叠空白，行首不输出空白）
+fn push_markdown_char(out: &mut String, c: char, in_pre: bool) {
+    if in_pre {
+        out.push(c);
+    } else if c.is_whitespace() {
+        if !(out.is_empty() || out.ends_with(' ') || out.ends_with('\n')) {
+            out.push(' ');
+        }
+    } else {
+        out.push(c);
+    }
+}
+
+fn trim_trailing_spaces(out: &mut String) {
+    while out.ends_with(' ') {
+        out.pop();
+    }
+}
+
+fn ensure_newline(out: &mut String) {
+    trim_trailing_spaces(out);
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+fn ensure_blank_line(out: &mut String) {
+    trim_trailing_spaces(out);
+    if out.is_empty() || out.ends_with("\n\n") {
+        return;
+    }
+    out.push_str(if out.ends_with('\n') { "\n" } else { "\n\n" });
+}
+
+// 读取标签属性值（支持单双引号与无引号）
+fn extract_attribute(tag: &str, attr: &str) -> Option<String> {
+    // 只转换 ASCII 字母，保持字节偏移与原标签一致（非 ASCII 字符小写后长度可能变化）
+    let lower = tag.to_ascii_lowercase();
+    let pattern = format!("{}=", attr);
+    let start = lower
+        .match_indices(&pattern)
+        .find(|(i, _)| *i > 0 && tag[..*i].ends_with(char::is_whitespace))
+        .map(|(i, _)| i + pattern.len())?;
+    let rest = &tag[start..];
+    let value = match rest.chars().next()? {
+        q @ ('"' | '\'') => rest[1..].split(q).next()?,
+        _ => rest.split(char::is_whitespace).next()?,
+    };
+    Some(value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_to_markdown_headings_and_emphasis() {
+        assert_eq!(
+            html_to_markdown("<h1>Title</h1><p>Hello <b>world</b> and <em>you</em></p>"),
+            "# Title\n\nHello **world** and *you*"
+        );
+        assert_eq!(html_to_markdown("<h3>Sub</h3>"), "### Sub");
+    }
+
+    #[test]
+    fn test_html_to_markdown_lists() {
+        assert_eq!(html_to_markdown("<ul>\n  <li>One</li>\n  <li>Two</li>\n</ul>"), "- One\n- Two");
+        assert_eq!(html_to_markdown("<ol><li>A</li><li>B</li></ol>"), "1. A\n2. B");
+        assert_eq!(html_to_markdown("<ul><li>A<ul><li>B</li></ul></li></ul>"), "- A\n  - B");
+    }
+
+    #[test]
+    fn test_html_to_markdown_links_and_code() {
+        assert_eq!(
+            html_to_markdown("<p>See <a class=\"x\" href=\"https://example.com\">docs</a></p>"),
+            "See [docs](https://example.com)"
+        );
+        // 属性前的非 ASCII 文本不影响属性定位
+        assert_eq!(
+            html_to_markdown("<p><a title=\"ẞ straße\" HREF=\"https://example.com\">x</a></p>"),
+            "[x](https://example.com)"
+        );
+        assert_eq!(
+            html_to_markdown("<p><a title=\"İstanbul\" href=\"https://example.com\">x</a></p>"),
+            "[x](https://example.com)"
+        );
+        assert_eq!(
+            html_to_markdown("<p>Run <code>cargo test</code> &amp; done</p>"),
+            "Run `cargo test` & done"
+        );
+        assert_eq!(
+            html_to_markdown("<pre><code>let x = 1;\nlet y = 2;</code></pre>"),
+            "```\nlet x = 1;\nlet y = 2;\n```"
+        );
+    }
+
+    #[test]
+    fn test_html_to_markdown_skips_fragment_markers_and_styles() {
+        assert_eq!(
+            html_to_markdown("<html><head><style>p{}</style></head><body><!--StartFragment--><p>Hi</p><!--EndFragment--></body></html>"),
+            "Hi"
+        );
+    }
+}
@@ -9,7 +9,7 @@ pub mod html;
 
 pub use screen::init_screen_utils;
 pub use system::get_text_scale_factor;
-pub use text::{truncate_string, truncate_around_keyword};
-pub use html::truncate_html;
+pub use text::{truncate_string, truncate_around_keyword, find_match_ranges};
+pub use html::{truncate_html, html_to_rtf, html_to_markdown};
 pub use image::{is_image_file, get_image_dimensions};
 
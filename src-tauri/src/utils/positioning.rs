@@ -1,4 +1,4 @@
-use tauri::{PhysicalPosition, WebviewWindow, Monitor, Manager};
+use tauri::{PhysicalPosition, PhysicalSize, WebviewWindow, Monitor, Manager};
 
 // 将窗口定位到鼠标位置
 pub fn position_at_cursor(window: &WebviewWindow) -> Result<(), String> {
@@ -57,32 +57,38 @@ pub fn center_window(window: &WebviewWindow) -> Result<(), String> {
     window.center().map_err(|e| e.to_string())
 }
 
-// 将窗口中心对齐到鼠标位置
-pub fn center_at_cursor(window: &WebviewWindow) -> Result<(), String> {
+// 逻辑尺寸按显示器缩放比例换算为物理像素（100% 为 1.0，150% 为 1.5）
+fn scaled_size(logical: (u32, u32), scale_factor: f64) -> (u32, u32) {
+    let scale = if scale_factor.is_finite() && scale_factor > 0.0 { scale_factor } else { 1.0 };
+    (
+        (logical.0 as f64 * scale).round() as u32,
+        (logical.1 as f64 * scale).round() as u32,
+    )
+}
+
+// 窗口中心对齐鼠标并限制在显示器范围内（物理坐标），窗口大于显示器时左上角对齐显示器
+fn centered_position(cursor: (i32, i32), size: (u32, u32), monitor_pos: (i32, i32), monitor_size: (u32, u32)) -> (i32, i32) {
+    let (w, h) = (size.0 as i32, size.1 as i32);
+    let x = (cursor.0 - w / 2).min(monitor_pos.0 + monitor_size.0 as i32 - w).max(monitor_pos.0);
+    let y = (cursor.1 - h / 2).min(monitor_pos.1 + monitor_size.1 as i32 - h).max(monitor_pos.1);
+    (x, y)
+}
+
+// 将窗口按鼠标所在显示器的缩放比例设置为 logical_size，并使窗口中心对齐鼠标
+// 窗口原先所在显示器的缩放比例可能不同，尺寸与位置都按目标显示器的物理像素计算
+pub fn center_at_cursor(window: &WebviewWindow, logical_size: (u32, u32)) -> Result<(), String> {
     let monitor = crate::screen::ScreenUtils::get_monitor_at_cursor(window.app_handle())?;
-    let (cursor_x, cursor_y) = crate::mouse::get_cursor_position();
-    let window_size = window.outer_size().map_err(|e| e.to_string())?;
-    
+    let cursor = crate::mouse::get_cursor_position();
+    let (w, h) = scaled_size(logical_size, monitor.scale_factor());
     let monitor_pos = monitor.position();
     let monitor_size = monitor.size();
-    
-    let w = window_size.width as i32;
-    let h = window_size.height as i32;
-    
-    let work_x = monitor_pos.x;
-    let work_y = monitor_pos.y;
-    let work_w = monitor_size.width as i32;
-    let work_h = monitor_size.height as i32;
-    
-    // 窗口中心对齐鼠标
-    let mut x = cursor_x - w / 2;
-    let mut y = cursor_y - h / 2;
-    
-    // 确保不超出屏幕边界
-    x = x.max(work_x).min(work_x + work_w - w);
-    y = y.max(work_y).min(work_y + work_h - h);
-    
-    window.set_position(PhysicalPosition::new(x, y)).map_err(|e| e.to_string())
+    let (x, y) = centered_position(cursor, (w, h), (monitor_pos.x, monitor_pos.y), (monitor_size.width, monitor_size.height));
+
+    // 先移动到目标显示器：跨越缩放比例不同的显示器时系统会按比例调整窗口尺寸，随后设置的物理尺寸覆盖该调整
+    let position = PhysicalPosition::new(x, y);
+    window.set_position(position).map_err(|e| e.to_string())?;
+    window.set_size(PhysicalSize::new(w, h)).map_err(|e| e.to_string())?;
+    window.set_position(position).map_err(|e| e.to_string())
 }
 
 // 获取窗口边界
@@ -92,3 +98,35 @@ pub fn get_window_bounds(window: &WebviewWindow) -> Result<(i32, i32, u32, u32),
     Ok((pos.x, pos.y, size.width, size.height))
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scaled_size() {
+        assert_eq!(scaled_size((360, 500), 1.0), (360, 500));
+        assert_eq!(scaled_size((360, 500), 1.5), (540, 750));
+        assert_eq!(scaled_size((360, 500), 2.0), (720, 1000));
+        assert_eq!(scaled_size((301, 500), 1.25), (376, 625));
+        assert_eq!(scaled_size((360, 500), 0.0), (360, 500));
+    }
+
+    #[test]
+    fn test_centered_position_on_secondary_monitor() {
+        // 位于主显示器右侧、200% 缩放的 4K 显示器
+        let monitor_pos = (1920, 0);
+        let monitor_size = (3840, 2160);
+        let size = scaled_size((360, 500), 2.0);
+        assert_eq!(centered_position((3840, 1080), size, monitor_pos, monitor_size), (3480, 580));
+        // 靠近边缘时限制在显示器内
+        assert_eq!(centered_position((1930, 10), size, monitor_pos, monitor_size), (1920, 0));
+        assert_eq!(centered_position((5750, 2150), size, monitor_pos, monitor_size), (5040, 1160));
+    }
+
+    #[test]
+    fn test_centered_position_larger_than_monitor() {
+        assert_eq!(centered_position((500, 300), (1200, 900), (0, 0), (1024, 768)), (0, 0));
+        // 主显示器左侧的负坐标显示器
+        assert_eq!(centered_position((-800, 400), (540, 750), (-1280, 0), (1280, 1024)), (-1070, 25));
+    }
+}
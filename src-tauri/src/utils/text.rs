@@ -72,3 +72,55 @@ pub fn truncate_around_keyword(s: String, keyword: &str, max_len: usize) -> Stri
     
     result
 }
+
+// 查找关键词在文本中的所有匹配区间（不重叠，ASCII 忽略大小写，与 SQLite LIKE 一致）
+// 返回 [start, end) 区间，按 UTF-16 码元计，前端可直接用于 String.slice
+pub fn find_match_ranges(text: &str, keyword: &str) -> Vec<(usize, usize)> {
+    let keyword: Vec<char> = keyword.chars().collect();
+    if keyword.is_empty() {
+        return vec![];
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut offsets = Vec::with_capacity(chars.len() + 1);
+    let mut offset = 0;
+    for c in &chars {
+        offsets.push(offset);
+        offset += c.len_utf16();
+    }
+    offsets.push(offset);
+
+    let mut ranges = vec![];
+    let mut i = 0;
+    while i + keyword.len() <= chars.len() {
+        let matched = chars[i..i + keyword.len()]
+            .iter()
+            .zip(&keyword)
+            .all(|(a, b)| a.eq_ignore_ascii_case(b));
+        if matched {
+            ranges.push((offsets[i], offsets[i + keyword.len()]));
+            i += keyword.len();
+        } else {
+            i += 1;
+        }
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_match_ranges_ascii_case_insensitive() {
+        assert_eq!(find_match_ranges("Foo bar FOO", "foo"), vec![(0, 3), (8, 11)]);
+        assert_eq!(find_match_ranges("aaaa", "aa"), vec![(0, 2), (2, 4)]);
+        assert!(find_match_ranges("abc", "").is_empty());
+    }
+
+    #[test]
+    fn test_find_match_ranges_utf16_offsets() {
+        // emoji 占两个 UTF-16 码元
+        assert_eq!(find_match_ranges("😀剪贴板😀剪贴", "剪贴"), vec![(2, 4), (7, 9)]);
+    }
+}
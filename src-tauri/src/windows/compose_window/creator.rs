@@ -0,0 +1,26 @@
+use tauri::AppHandle;
+
+// 创建组合粘贴窗口：置顶显示，不占用任务栏
+pub fn create_compose_window(app: &AppHandle) -> Result<(), String> {
+    let _compose_window = tauri::WebviewWindowBuilder::new(
+        app,
+        "compose",
+        tauri::WebviewUrl::App("windows/compose/index.html".into()),
+    )
+    .title("组合粘贴 - 快速剪贴板")
+    .inner_size(760.0, 560.0)
+    .min_inner_size(600.0, 420.0)
+    .center()
+    .resizable(true)
+    .maximizable(false)
+    .decorations(false)
+    .transparent(false)
+    .always_on_top(true)
+    .skip_taskbar(true)
+    .visible(true)
+    .focused(true)
+    .build()
+    .map_err(|e| format!("创建组合粘贴窗口失败: {}", e))?;
+
+    Ok(())
+}
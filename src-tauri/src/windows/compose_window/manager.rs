@@ -0,0 +1,23 @@
+use tauri::{AppHandle, Manager};
+use super::creator::create_compose_window;
+
+pub fn open_compose_window(app: &AppHandle) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window("compose") {
+        window.show().map_err(|e| format!("显示组合粘贴窗口失败: {}", e))?;
+        window.set_focus().map_err(|e| format!("聚焦组合粘贴窗口失败: {}", e))?;
+    } else {
+        create_compose_window(app)?;
+        if let Some(window) = app.get_webview_window("compose") {
+            window.set_focus().map_err(|e| format!("聚焦组合粘贴窗口失败: {}", e))?;
+        }
+    }
+
+    Ok(())
+}
+
+// 隐藏组合粘贴窗口（保留窗口与组合内容，下次打开时恢复）
+pub fn hide_compose_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("compose") {
+        let _ = window.hide();
+    }
+}
@@ -0,0 +1,4 @@
+mod manager;
+mod creator;
+
+pub use manager::*;
@@ -67,7 +67,30 @@ pub fn toggle_main_window_visibility(app: &AppHandle) {
         if should_show {
             show_main_window(&window);
         } else {
-            hide_main_window(&window);
+            hide_main_window_for_toggle(&window);
+        }
+    }
+}
+
+// 切换快捷键隐藏主窗口：按设置隐藏或最小化，并可选将焦点还给之前的前台窗口
+fn hide_main_window_for_toggle(window: &WebviewWindow) {
+    if crate::is_context_menu_visible() {
+        return;
+    }
+
+    let settings = crate::get_settings();
+    let state = super::state::get_window_state();
+
+    // 贴边状态下沿用贴边隐藏，不做最小化
+    if settings.toggle_hide_mode == "minimize" && !state.is_snapped {
+        conceal_normal_window(window, true);
+    } else {
+        hide_main_window(window);
+    }
+
+    if settings.restore_focus_on_hide {
+        if let Err(e) = crate::services::system::restore_last_focus() {
+            eprintln!("恢复之前的焦点窗口失败: {}", e);
         }
     }
 }
@@ -101,6 +124,12 @@ fn show_normal_window(window: &WebviewWindow) {
         }
     }
 
+    // 之前被最小化时恢复窗口并重新移出任务栏
+    if window.is_minimized().unwrap_or(false) {
+        let _ = window.unminimize();
+        let _ = window.set_skip_taskbar(true);
+    }
+
     let _ = window.show();
 
     if !was_visible {
@@ -115,6 +144,11 @@ fn show_normal_window(window: &WebviewWindow) {
 }
 
 fn hide_normal_window(window: &WebviewWindow) {
+    conceal_normal_window(window, false);
+}
+
+// 隐藏或最小化普通状态的主窗口（最小化时显示在任务栏中以便恢复）
+fn conceal_normal_window(window: &WebviewWindow, minimize: bool) {
     use tauri::Emitter;
     use tauri::Manager;
 
@@ -148,7 +182,12 @@ fn hide_normal_window(window: &WebviewWindow) {
         let _ = window.set_always_on_top(false);
     }
 
-    let _ = window.hide();
+    if minimize {
+        let _ = window.set_skip_taskbar(false);
+        let _ = window.minimize();
+    } else {
+        let _ = window.hide();
+    }
     set_window_state(WindowState::Hidden);
 
     crate::input_monitor::disable_mouse_monitoring();
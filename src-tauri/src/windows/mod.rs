@@ -1,6 +1,7 @@
 pub mod main_window;
 pub mod settings_window;
 pub mod text_editor_window;
+pub mod compose_window;
 pub mod quickpaste;
 pub mod tray;
 pub mod plugins;
@@ -8,6 +8,8 @@ use tauri::{AppHandle, Manager, WebviewWindowBuilder};
 pub enum InputType {
     Text,
     Number,
+    // 多行文本（Ctrl+Enter 确认）
+    Multiline,
 }
 
 impl Default for InputType {
@@ -52,6 +54,12 @@ pub async fn show_dialog(
     // 保存配置供前端读取
     super::set_options(options.clone());
 
+    // 多行输入使用更大的窗口
+    let (width, height) = match options.input_type {
+        InputType::Multiline => (420.0, 280.0),
+        _ => (400.0, 200.0),
+    };
+
     // 创建对话框窗口
     let window = WebviewWindowBuilder::new(
         &app,
@@ -59,7 +67,7 @@ pub async fn show_dialog(
         tauri::WebviewUrl::App("plugins/input_dialog/inputDialog.html".into()),
     )
     .title(&options.title)
-    .inner_size(400.0, 200.0)
+    .inner_size(width, height)
     .resizable(false)
     .maximizable(false)
     .minimizable(false)
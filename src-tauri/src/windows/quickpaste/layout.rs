@@ -0,0 +1,105 @@
+// 便捷粘贴窗口布局：按设置的显示项数与网格列数计算窗口尺寸
+
+// 单项高度（与前端 ITEM_HEIGHT 一致）
+const ITEM_HEIGHT: u32 = 52;
+// 窗口内边距（与前端 ITEM_PADDING 一致，上下/左右各一份）
+const ITEM_PADDING: u32 = 8;
+// 标题行高度
+const TITLE_HEIGHT: u32 = 24;
+// 多列网格时每列的宽度
+const GRID_COLUMN_WIDTH: u32 = 240;
+// 网格列数上限
+pub const MAX_GRID_COLUMNS: u32 = 4;
+// 窗口尺寸范围（与窗口创建时的最小/最大尺寸一致）
+const MIN_WIDTH: u32 = 200;
+const MAX_WIDTH: u32 = 800;
+const MIN_HEIGHT: u32 = 300;
+const MAX_HEIGHT: u32 = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuickpasteLayout {
+    pub columns: u32,
+    pub rows: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+// 固定项数时的布局，item_count 为 0 表示按窗口高度自动计算（返回 None，保持用户调整的尺寸）
+pub fn grid_layout(item_count: u32, columns: u32, single_column_width: u32) -> Option<QuickpasteLayout> {
+    if item_count == 0 {
+        return None;
+    }
+    let columns = columns.clamp(1, MAX_GRID_COLUMNS).min(item_count);
+    let rows = item_count.div_ceil(columns);
+    let width = if columns == 1 {
+        single_column_width
+    } else {
+        columns * GRID_COLUMN_WIDTH + ITEM_PADDING * 2
+    };
+    let height = rows * ITEM_HEIGHT + TITLE_HEIGHT + ITEM_PADDING * 2;
+    Some(QuickpasteLayout {
+        columns,
+        rows,
+        width: width.clamp(MIN_WIDTH, MAX_WIDTH),
+        height: height.clamp(MIN_HEIGHT, MAX_HEIGHT),
+    })
+}
+
+// 按当前设置计算布局
+pub fn current_layout() -> Option<QuickpasteLayout> {
+    let settings = crate::get_settings();
+    grid_layout(
+        settings.quickpaste_item_count,
+        settings.quickpaste_grid_columns,
+        settings.quickpaste_window_width,
+    )
+}
+
+// 数字键对应的可见项是否在显示范围内（自动项数时不限制）
+pub fn is_digit_index_visible(index: usize, item_count: u32) -> bool {
+    item_count == 0 || index < item_count as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_layout_keeps_window_size() {
+        assert_eq!(grid_layout(0, 3, 300), None);
+    }
+
+    #[test]
+    fn test_grid_rows_round_up() {
+        let layout = grid_layout(10, 3, 300).unwrap();
+        assert_eq!((layout.columns, layout.rows), (3, 4));
+        assert_eq!(layout.width, 3 * GRID_COLUMN_WIDTH + ITEM_PADDING * 2);
+        // 4 行不足窗口最小高度，按最小高度显示
+        assert_eq!(layout.height, MIN_HEIGHT);
+
+        let layout = grid_layout(16, 3, 300).unwrap();
+        assert_eq!((layout.columns, layout.rows), (3, 6));
+        assert_eq!(layout.height, 6 * ITEM_HEIGHT + TITLE_HEIGHT + ITEM_PADDING * 2);
+    }
+
+    #[test]
+    fn test_layout_clamps_to_window_bounds() {
+        let single = grid_layout(3, 1, 300).unwrap();
+        assert_eq!((single.width, single.height), (300, MIN_HEIGHT));
+
+        let tall = grid_layout(40, 1, 300).unwrap();
+        assert_eq!(tall.height, MAX_HEIGHT);
+
+        // 列数不超过项数与上限
+        assert_eq!(grid_layout(2, 4, 300).unwrap().columns, 2);
+        assert_eq!(grid_layout(20, 9, 300).unwrap().columns, MAX_GRID_COLUMNS);
+    }
+
+    #[test]
+    fn test_digit_index_visible() {
+        // 显示 10 项时 0 键（索引 9）可用
+        assert!(is_digit_index_visible(9, 10));
+        assert!(!is_digit_index_visible(9, 5));
+        assert!(is_digit_index_visible(9, 0));
+    }
+}
@@ -1,7 +1,13 @@
 use tauri::{AppHandle, Manager, Emitter, WebviewUrl, WebviewWindowBuilder};
-use super::state::set_visible;
+use std::time::Duration;
+use super::layout::current_layout;
+use super::state::{set_visible, clear_visible_entries, is_visible, touch_interaction, idle_elapsed, next_show_generation, current_show_generation};
 use crate::utils::positioning::center_at_cursor;
-use crate::services::system::input_monitor::{enable_quickpaste_keyboard_mode, disable_quickpaste_keyboard_mode};
+use crate::services::system::timers;
+use crate::services::system::input_monitor::{enable_quickpaste_keyboard_mode, disable_quickpaste_keyboard_mode, get_modifier_keys_state};
+
+// 空闲检查间隔
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_millis(250);
 
 fn create_window(app: &AppHandle) -> Result<tauri::WebviewWindow, String> {
     let settings = crate::get_settings();
@@ -50,14 +56,22 @@ pub fn show_quickpaste_window(app: &AppHandle) -> Result<(), String> {
     if !settings.quickpaste_enabled {
         return Ok(());
     }
+    // 取消上次显示遗留的延迟隐藏，避免其隐藏本次显示的窗口
+    timers::cancel_timers_of_kind("quickpaste_hide");
     let _ = crate::services::system::save_current_focus(app.clone());
 
     let window = get_or_create_window(app)?;
-    center_at_cursor(&window)?;
+    // 固定项数时使用网格布局的尺寸，否则使用保存的窗口尺寸（均为逻辑尺寸，按鼠标所在显示器的缩放比例换算）
+    let logical_size = current_layout()
+        .map(|layout| (layout.width, layout.height))
+        .unwrap_or((settings.quickpaste_window_width, settings.quickpaste_window_height));
+    center_at_cursor(&window, logical_size)?;
     let _ = window.show();
     let _ = window.set_always_on_top(false);
     let _ = window.set_always_on_top(true);
     set_visible(true);
+    touch_interaction();
+    start_idle_watcher(app, settings.quickpaste_idle_timeout_secs);
     
     if settings.quickpaste_paste_on_modifier_release {
         enable_quickpaste_keyboard_mode();
@@ -73,6 +87,55 @@ pub fn hide_quickpaste_window(app: &AppHandle) -> Result<(), String> {
         let _ = window.hide();
     }
     set_visible(false);
+    clear_visible_entries();
     Ok(())
 }
 
+// 空闲自动隐藏：超过设定秒数无交互时直接隐藏（不粘贴），0 表示不自动隐藏
+fn start_idle_watcher(app: &AppHandle, timeout_secs: u64) {
+    let generation = next_show_generation();
+    if timeout_secs == 0 {
+        return;
+    }
+    let timeout = Duration::from_secs(timeout_secs);
+    let app = app.clone();
+    let timer = timers::register("quickpaste_idle", "便捷粘贴空闲自动隐藏", timeout);
+    std::thread::spawn(move || loop {
+        std::thread::sleep(IDLE_CHECK_INTERVAL);
+        // 已隐藏、已重新显示或被取消时退出（句柄释放即注销）
+        if timer.is_cancelled() || !is_visible() || current_show_generation() != generation {
+            return;
+        }
+        // 按住修饰键期间视为交互中，由松开快捷键/修饰键的流程负责隐藏
+        let (ctrl, alt, shift, meta) = get_modifier_keys_state();
+        if ctrl || alt || shift || meta {
+            touch_interaction();
+            timer.set_remaining(timeout);
+            continue;
+        }
+        let elapsed = idle_elapsed();
+        timer.set_remaining(timeout.saturating_sub(elapsed));
+        if elapsed >= timeout {
+            if let Err(e) = hide_quickpaste_window(&app) {
+                eprintln!("空闲自动隐藏便捷粘贴窗口失败: {}", e);
+            }
+            return;
+        }
+    });
+}
+
+// 按设置的延迟隐藏窗口（在后台线程等待，给前端粘贴留出时间）
+pub fn schedule_hide_quickpaste_window(app: &AppHandle) {
+    let delay = crate::get_settings().quickpaste_hide_delay_ms;
+    let app = app.clone();
+    let generation = current_show_generation();
+    timers::schedule("quickpaste_hide", "隐藏便捷粘贴窗口", Duration::from_millis(delay), move || {
+        // 等待期间窗口已重新显示时不再隐藏
+        if current_show_generation() != generation {
+            return;
+        }
+        if let Err(e) = hide_quickpaste_window(&app) {
+            eprintln!("隐藏便捷粘贴窗口失败: {}", e);
+        }
+    });
+}
@@ -1,6 +1,9 @@
+mod layout;
 mod manager;
 mod state;
 
-pub use manager::{init_quickpaste_window, show_quickpaste_window, hide_quickpaste_window};
+pub use layout::{current_layout, is_digit_index_visible, QuickpasteLayout};
+
+pub use manager::{init_quickpaste_window, show_quickpaste_window, hide_quickpaste_window, schedule_hide_quickpaste_window};
 pub use state::*;
 
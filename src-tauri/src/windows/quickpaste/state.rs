@@ -1,8 +1,17 @@
-use std::sync::atomic::{AtomicBool, Ordering};
+use parking_lot::Mutex;
+use serde::Deserialize;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 
 // 快捷粘贴窗口可见性状态
 pub static QUICKPASTE_VISIBLE: AtomicBool = AtomicBool::new(false);
 
+// 最近一次交互时刻（用于空闲自动隐藏）
+static LAST_INTERACTION: Mutex<Option<Instant>> = Mutex::new(None);
+
+// 每次显示递增，旧的空闲计时线程据此退出
+static SHOW_GENERATION: AtomicU64 = AtomicU64::new(0);
+
 // 初始化快捷粘贴窗口状态
 pub fn init_quickpaste_state() {
     QUICKPASTE_VISIBLE.store(false, Ordering::SeqCst);
@@ -17,3 +26,60 @@ pub fn is_visible() -> bool {
 pub fn set_visible(visible: bool) {
     QUICKPASTE_VISIBLE.store(visible, Ordering::SeqCst);
 }
+
+// 记录一次交互，重置空闲计时
+pub fn touch_interaction() {
+    *LAST_INTERACTION.lock() = Some(Instant::now());
+}
+
+// 距最近一次交互的时长
+pub fn idle_elapsed() -> Duration {
+    LAST_INTERACTION
+        .lock()
+        .map(|at| at.elapsed())
+        .unwrap_or_default()
+}
+
+// 开始新的一次显示，返回本次显示的编号
+pub fn next_show_generation() -> u64 {
+    SHOW_GENERATION.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+pub fn current_show_generation() -> u64 {
+    SHOW_GENERATION.load(Ordering::SeqCst)
+}
+
+// 便捷粘贴窗口中当前显示的项（按显示顺序，由前端在列表变化时上报）
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum VisibleEntry {
+    Clipboard { id: i64 },
+    Favorite { id: String },
+}
+
+// 尚未加载的行为 None，保留其位置使编号与窗口中的行一致
+static VISIBLE_ENTRIES: Mutex<Vec<Option<VisibleEntry>>> = Mutex::new(Vec::new());
+
+pub fn set_visible_entries(entries: Vec<Option<VisibleEntry>>) {
+    *VISIBLE_ENTRIES.lock() = entries;
+}
+
+pub fn clear_visible_entries() {
+    VISIBLE_ENTRIES.lock().clear();
+}
+
+// 窗口可见且已上报显示列表时，返回第 index 个显示项；否则返回 None（按历史记录处理）
+pub fn visible_entry(index: usize) -> Option<Result<VisibleEntry, String>> {
+    if !is_visible() {
+        return None;
+    }
+    let entries = VISIBLE_ENTRIES.lock();
+    if entries.is_empty() {
+        return None;
+    }
+    Some(match entries.get(index) {
+        Some(Some(entry)) => Ok(entry.clone()),
+        Some(None) => Err(format!("便捷粘贴窗口中第 {} 项尚未加载", index + 1)),
+        None => Err(format!("便捷粘贴窗口中没有第 {} 项（共显示 {} 项）", index + 1, entries.len())),
+    })
+}
@@ -149,6 +149,9 @@ pub async fn show_tray_menu(app: AppHandle) -> Result<(), String> {
     
     let hotkeys_label = if settings.hotkeys_enabled { "禁用快捷键" } else { "启用快捷键" };
     let monitor_label = if settings.clipboard_monitor { "禁用剪贴板监听" } else { "启用剪贴板监听" };
+    let incognito_label = if settings.incognito_mode { "关闭无痕模式" } else { "开启无痕模式" };
+    // 以勾选图标表示当前的格式粘贴状态
+    let format_icon = if settings.paste_with_format { "ti ti-square-check" } else { "ti ti-square" };
     
     let items = vec![
         menu_item_with_state("toggle", "显示/隐藏", Some("ti ti-app-window"), is_force_update),
@@ -169,6 +172,8 @@ pub async fn show_tray_menu(app: AppHandle) -> Result<(), String> {
         separator_item(),
         menu_item_with_state("toggle-hotkeys", hotkeys_label, Some("ti ti-keyboard"), is_force_update),
         menu_item_with_state("toggle-clipboard-monitor", monitor_label, Some("ti ti-clipboard"), is_force_update),
+        menu_item_with_state("toggle-incognito", incognito_label, Some("ti ti-spy"), is_force_update),
+        menu_item_with_state("toggle-paste-format", "格式粘贴", Some(format_icon), is_force_update),
         separator_item(),
         menu_item_with_state("low-memory-mode", "进入低占用模式", Some("ti ti-leaf"), is_force_update),
         separator_item(),
@@ -253,12 +258,24 @@ fn handle_tray_menu_selection(app: &AppHandle, selected_id: &str) {
                 eprintln!("切换剪贴板监听状态失败: {}", e);
             }
         }
+        "toggle-incognito" => {
+            if let Err(e) = crate::commands::settings::toggle_incognito(app) {
+                eprintln!("切换无痕模式失败: {}", e);
+            }
+        }
+        "toggle-paste-format" => {
+            if let Err(e) = crate::commands::settings::toggle_paste_with_format(app) {
+                eprintln!("切换格式粘贴状态失败: {}", e);
+            }
+        }
         "low-memory-mode" => {
             if let Err(e) = crate::services::low_memory::enter_low_memory_mode(app) {
                 eprintln!("进入低占用模式失败: {}", e);
             }
         }
         "restart" => {
+            // restart 不会触发 RunEvent::Exit，需先释放快捷键
+            crate::hotkey::shutdown_hotkey_manager();
             app.restart();
         }
         "quit" => {
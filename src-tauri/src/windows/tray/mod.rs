@@ -9,6 +9,22 @@ pub use native_menu::{handle_native_menu_event, is_menu_visible, scroll_page};
 
 use tauri::{AppHandle, tray::TrayIconId};
 
+// 托盘提示文字（无痕模式下醒目提示）
+pub fn tray_tooltip() -> &'static str {
+    if crate::services::clipboard::is_incognito() {
+        "快速剪贴板（无痕模式：不记录复制内容）"
+    } else {
+        "快速剪贴板"
+    }
+}
+
+// 按当前状态刷新托盘提示
+pub fn update_tray_tooltip(app: &AppHandle) {
+    if let Some(tray) = app.tray_by_id(&TrayIconId::new("main-tray")) {
+        let _ = tray.set_tooltip(Some(tray_tooltip()));
+    }
+}
+
 // 切换到原生系统菜单
 pub fn switch_to_native_menu(app: &AppHandle) -> Result<(), String> {
     let tray_id = TrayIconId::new("main-tray");
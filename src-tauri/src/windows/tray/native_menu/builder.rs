@@ -4,7 +4,7 @@ use crate::services::database::{get_clipboard_count, query_clipboard_items, Quer
 use super::state::{self, PAGE_SIZE};
 use super::utils::format_item_label;
 use tauri::{
-    menu::{Menu, MenuItem, PredefinedMenuItem},
+    menu::{CheckMenuItem, Menu, MenuItem, PredefinedMenuItem},
     tray::TrayIconId,
     AppHandle,
 };
@@ -52,6 +52,9 @@ fn build_clipboard_items(app: &AppHandle) -> Result<Vec<MenuItem<tauri::Wry>>, S
         limit: PAGE_SIZE as i64,
         search: None,
         content_type: None,
+        created_before: None,
+        with_highlights: false,
+        exclude_local_only: false,
     })?
     .items;
 
@@ -163,16 +166,27 @@ fn create_menu(app: &AppHandle) -> Result<Menu<tauri::Wry>, String> {
     .map_err(|e| e.to_string())?;
     menu.append(&toggle_monitor).map_err(|e| e.to_string())?;
 
-    let format_label = if settings.paste_with_format {
-        "禁用格式粘贴"
+    let incognito_label = if settings.incognito_mode {
+        "关闭无痕模式"
     } else {
-        "启用格式粘贴"
+        "开启无痕模式"
     };
-    let toggle_format = MenuItem::with_id(
+    let toggle_incognito = MenuItem::with_id(
+        app,
+        "toggle-incognito",
+        incognito_label,
+        true,
+        parse_accelerator(&settings.toggle_incognito_shortcut).as_deref(),
+    )
+    .map_err(|e| e.to_string())?;
+    menu.append(&toggle_incognito).map_err(|e| e.to_string())?;
+
+    let toggle_format = CheckMenuItem::with_id(
         app,
         "toggle-paste-format",
-        format_label,
+        "格式粘贴",
         true,
+        settings.paste_with_format,
         parse_accelerator(&settings.toggle_paste_with_format_shortcut).as_deref(),
     )
     .map_err(|e| e.to_string())?;
@@ -26,6 +26,12 @@ pub fn handle_native_menu_event(app: &AppHandle, event: &MenuEvent) {
             }
             let _ = update_native_menu(app);
         }
+        "toggle-incognito" => {
+            if let Err(e) = crate::commands::settings::toggle_incognito(app) {
+                eprintln!("切换无痕模式失败: {}", e);
+            }
+            let _ = update_native_menu(app);
+        }
         "toggle-paste-format" => {
             if let Err(e) = crate::commands::settings::toggle_paste_with_format(app) {
                 eprintln!("切换格式粘贴状态失败: {}", e);
@@ -33,6 +39,8 @@ pub fn handle_native_menu_event(app: &AppHandle, event: &MenuEvent) {
             let _ = update_native_menu(app);
         }
         "restart" => {
+            // restart 不会触发 RunEvent::Exit，需先释放快捷键
+            crate::hotkey::shutdown_hotkey_manager();
             app.restart();
         }
         "quit" => {
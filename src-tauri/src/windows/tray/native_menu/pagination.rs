@@ -86,6 +86,9 @@ pub fn update_clipboard_items_text() -> Result<(), String> {
         limit: PAGE_SIZE as i64,
         search: None,
         content_type: None,
+        created_before: None,
+        with_highlights: false,
+        exclude_local_only: false,
     })?.items;
 
     let menu_items = state::get_menu_items();
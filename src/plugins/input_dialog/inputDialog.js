@@ -7,7 +7,7 @@ import { invoke } from '@tauri-apps/api/core';
 document.addEventListener('contextmenu', event => event.preventDefault());
 const currentWindow = getCurrentWindow();
 const messageEl = document.getElementById('message');
-const inputEl = document.getElementById('input');
+let inputEl = document.getElementById('input');
 const confirmBtn = document.getElementById('confirmBtn');
 const cancelBtn = document.getElementById('cancelBtn');
 
@@ -32,8 +32,12 @@ function applyOptions(options) {
         inputEl.placeholder = '';
     }
     
-    // 设置输入框类型
-    inputEl.type = options.input_type || 'text';
+    // 设置输入框类型：多行输入替换为文本域
+    if (options.input_type === 'multiline') {
+        useTextarea();
+    } else {
+        inputEl.type = options.input_type || 'text';
+    }
     
     // 设置数字输入的 min/max 属性
     if (options.input_type === 'number') {
@@ -61,6 +65,17 @@ function applyOptions(options) {
     inputEl.focus();
 }
 
+// 将单行输入框替换为多行文本域
+function useTextarea() {
+    const textarea = document.createElement('textarea');
+    textarea.className = 'dialog-input dialog-textarea';
+    textarea.id = 'input';
+    textarea.spellcheck = false;
+    textarea.addEventListener('keydown', handleInputKeydown);
+    inputEl.replaceWith(textarea);
+    inputEl = textarea;
+}
+
 // 验证输入
 function validateInput() {
     const value = inputEl.value.trim();
@@ -134,12 +149,16 @@ cancelBtn.addEventListener('click', async () => {
     }
 });
 
-// 回车确认
-inputEl.addEventListener('keydown', (e) => {
-    if (e.key === 'Enter') {
+// 回车确认（多行输入时 Ctrl+Enter 确认，回车换行）
+function handleInputKeydown(e) {
+    const multiline = currentOptions && currentOptions.input_type === 'multiline';
+    if (e.key === 'Enter' && (!multiline || e.ctrlKey || e.metaKey)) {
+        e.preventDefault();
         confirmBtn.click();
     } else if (e.key === 'Escape') {
         cancelBtn.click();
     }
-});
+}
+
+inputEl.addEventListener('keydown', handleInputKeydown);
 
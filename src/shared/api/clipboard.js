@@ -3,11 +3,12 @@ import { restoreLastFocus } from '@shared/api/window'
 // 获取剪贴板历史列表
 export async function getClipboardHistory(params = {}) {
   try {
-    const { offset = 0, limit = 50, search, contentType } = params
+    const { offset = 0, limit = 50, search, contentType, withHighlights } = params
 
     const invokeParams = { offset, limit }
     if (search) invokeParams.search = search
     if (contentType) invokeParams.contentType = contentType
+    if (withHighlights) invokeParams.withHighlights = true
 
     return await invoke('get_clipboard_history', invokeParams)
   } catch (error) {
@@ -66,6 +67,34 @@ export async function pasteClipboardItem(clipboardId, format = null) {
   }
 }
 
+// 合并粘贴多个剪贴板项（ids 按选择顺序传入）
+// options: { order: 'selection' | 'history', separator: 'newline' | 'comma' | 'space' | 'custom', customSeparator, numbered }
+export async function pasteItemsCombined(ids, options = {}) {
+  try {
+    await restoreLastFocus()
+    await invoke('paste_items_combined', { ids, options })
+    return true
+  } catch (error) {
+    console.error('合并粘贴失败:', error)
+    throw error
+  }
+}
+
+// 激活指定窗口后粘贴，target 为 { kind: 'title', title } 或 { kind: 'handle', hwnd }
+export async function pasteToWindow(target, id, timeoutMs = null) {
+  return await invoke('paste_to_window', { target, id, timeoutMs })
+}
+
+// 冻结最新一条剪贴板项（本次会话置顶，重启后还原），返回被冻结项的 ID
+export async function freezeTopItem() {
+  return await invoke('freeze_top_item')
+}
+
+// 解除全部会话冻结
+export async function unfreezeSessionItems() {
+  return await invoke('unfreeze_session_items')
+}
+
 // 删除剪贴板项
 export async function deleteClipboardItem(id) {
   try {
@@ -191,6 +220,11 @@ export async function updateClipboardItem(id, content) {
   await invoke('emit_clipboard_updated')
 }
 
+// 编辑文本项内容（重新生成预览与类型标记，富文本/图片/文件项会被拒绝）
+export async function updateItemText(id, newText) {
+  await invoke('update_item_text', { id, newText })
+}
+
 // 获取单个收藏项
 export async function getFavoriteItemById(id, maxLength = null) {
   const params = { id };
@@ -217,6 +251,49 @@ export async function copyClipboardItem(id) {
   return await invoke('copy_clipboard_item', { id })
 }
 
+// 应用文本转换后复制到剪贴板，transform 如 'trim'、'uppercase'
+export async function copyItemAs(id, transform) {
+  return await invoke('copy_item_as', { id, transform })
+}
+
+// 标记剪贴板项为仅本机（导出与同步时排除）
+export async function setItemLocalOnly(id, localOnly) {
+  await invoke('set_item_local_only', { id, localOnly })
+  await invoke('emit_clipboard_updated')
+}
+
+// 设置剪贴板项的强制粘贴格式（format 为 null 时清除）
+export async function setItemForcedFormat(id, format) {
+  await invoke('set_item_forced_format', { id, format })
+  await invoke('emit_clipboard_updated')
+}
+
+// 按手动顺序重排置顶项
+export async function reorderPinnedItems(orderedIds) {
+  await invoke('reorder_pinned_items', { orderedIds })
+  await invoke('emit_clipboard_updated')
+}
+
+// 获取最近搜索词
+export async function getRecentSearches() {
+  return await invoke('get_recent_searches')
+}
+
+// 清空最近搜索词
+export async function clearRecentSearches() {
+  return await invoke('clear_recent_searches')
+}
+
+// 获取剪贴板监听状态
+export async function getMonitorStatus() {
+  return await invoke('get_monitor_status')
+}
+
+// 按手动顺序获取全部置顶项
+export async function getPinnedItems() {
+  return await invoke('get_pinned_items')
+}
+
 // 切换剪贴板项置顶状态
 export async function togglePinClipboardItem(id) {
   const isPinned = await invoke('toggle_pin_clipboard_item', { id })
@@ -0,0 +1,58 @@
+import { invoke } from '@tauri-apps/api/core'
+
+// 组合粘贴：条目保存在后端会话中，每个操作返回最新的条目列表 [{ key, sourceId, text }]
+
+export async function openComposeWindow() {
+  await invoke('open_compose_window')
+}
+
+export async function getComposeEntries() {
+  try {
+    return await invoke('get_compose_entries')
+  } catch (error) {
+    console.error('获取组合条目失败:', error)
+    return []
+  }
+}
+
+// 按传入顺序加入剪贴板项（仅文本类项）
+export async function composeAddItems(ids) {
+  return await invoke('compose_add_items', { ids })
+}
+
+// 加入手动输入的片段
+export async function composeAddText(text) {
+  return await invoke('compose_add_text', { text })
+}
+
+export async function composeUpdateEntry(key, text) {
+  return await invoke('compose_update_entry', { key, text })
+}
+
+export async function composeRemoveEntry(key) {
+  return await invoke('compose_remove_entry', { key })
+}
+
+export async function composeMoveEntry(key, toIndex) {
+  return await invoke('compose_move_entry', { key, toIndex })
+}
+
+export async function composeClear() {
+  return await invoke('compose_clear')
+}
+
+// options 与合并粘贴相同：{ separator, customSeparator, numbered }
+export async function composePreview(options = {}) {
+  return await invoke('compose_preview', { options })
+}
+
+// 粘贴组合结果，text 为最终编辑后的文本，为 null 时由后端按 options 组合
+export async function pasteCompose(text = null, options = {}) {
+  try {
+    await invoke('paste_compose', { text, options })
+    return true
+  } catch (error) {
+    console.error('组合粘贴失败:', error)
+    throw error
+  }
+}
@@ -18,6 +18,26 @@ export async function changeStoragePath(newPath, mode = 'source_only') {
   return await invoke('dm_change_storage_path', { payload: { new_path: newPath, mode } })
 }
 
+// 迁移数据库及图片、备份到新目录，目标目录需为空
+export async function moveDatabase(newPath) {
+  return await invoke('dm_move_database', { payload: { new_path: newPath } })
+}
+
+// 检查数据库完整性，返回 { ok, errors }
+export async function checkDatabaseIntegrity() {
+  return await invoke('dm_check_database_integrity')
+}
+
+// 修复损坏的数据库，返回 { backupPath, tables, recoveredRows, skippedSegments }
+export async function repairDatabase() {
+  return await invoke('dm_repair_database')
+}
+
+// 启动检查发现数据库损坏时询问是否修复，返回是否已修复
+export async function promptRepairDatabaseIfNeeded() {
+  return await invoke('dm_prompt_repair_database_if_needed')
+}
+
 //重置存储路径到默认位置
 export async function resetStoragePathToDefault(mode = 'source_only') {
   return await invoke('dm_reset_storage_path_to_default', { payload: { mode } })
@@ -31,6 +51,15 @@ export async function importDataZip(zipPath, mode) {
   return await invoke('dm_import_data_zip', { payload: { zip_path: zipPath, mode } })
 }
 
+//重建派生数据，kinds 可选 'preview'、'charCount'
+export async function rebuildDerivedData(kinds = ['preview', 'charCount']) {
+  return await invoke('dm_rebuild_derived_data', { kinds })
+}
+
+export async function cancelRebuildDerivedData() {
+  return await invoke('dm_cancel_rebuild_derived_data')
+}
+
 export async function resetAllData() {
   return await invoke('dm_reset_all_data')
 }
@@ -2,6 +2,7 @@ export * from './clipboard'
 export * from './favorites'
 export * from './groups'
 export * from './textEditor'
+export * from './compose'
 export * from './window'
 export * from './settings'
 export * from './system'
@@ -55,6 +55,21 @@ export async function restartAsAdmin() {
   return await invoke('restart_as_admin')
 }
 
+// 设置无痕模式（开启后不记录复制内容）
+export async function setIncognito(enabled) {
+  return await invoke('set_incognito', { enabled })
+}
+
+// 获取下一次粘贴实际使用的格式状态（含临时翻转）
+export async function getPasteWithFormat() {
+  return await invoke('get_paste_with_format')
+}
+
+// 校验排除文本的正则规则，返回无效规则列表 [{ index, pattern, message }]
+export async function validateExclusionPatterns(patterns) {
+  return await invoke('validate_exclusion_patterns', { patterns })
+}
+
 // 获取所有快捷键状态
 export async function getShortcutStatuses() {
   return await invoke('get_shortcut_statuses')
@@ -70,6 +85,21 @@ export async function reloadHotkeys() {
   return await invoke('reload_hotkeys')
 }
 
+// 将数字快捷键绑定到剪贴板项（itemId 为 null 时解除绑定）
+export async function bindNumberToItem(num, itemId) {
+  return await invoke('bind_number_to_item', { num, itemId })
+}
+
+// 临时覆盖快捷键组合（不保存到设置）
+export async function pushShortcutOverride(id, shortcut) {
+  return await invoke('push_shortcut_override', { id, shortcut })
+}
+
+// 移除最近一次临时覆盖
+export async function popShortcutOverride(id) {
+  return await invoke('pop_shortcut_override', { id })
+}
+
 // 保存窗口位置
 export async function saveWindowPosition(x, y) {
   return await invoke('save_window_position', { x, y })
@@ -79,3 +79,13 @@ export async function promptEnableWinVHotkey() {
   return await invoke('prompt_enable_win_v_hotkey')
 }
 
+
+// 列出待执行的定时操作
+export async function listPendingTimers() {
+  return await invoke('list_pending_timers')
+}
+
+// 取消定时操作
+export async function cancelTimer(id) {
+  return await invoke('cancel_timer', { id })
+}
@@ -3,6 +3,33 @@ import { useTranslation } from 'react-i18next'
 
 // 检测快捷键内部重复
 
+const MODIFIER_ORDER = ['Control', 'Alt', 'Shift', 'Super']
+const MODIFIER_ALIASES = {
+  ctrl: 'Control',
+  control: 'Control',
+  alt: 'Alt',
+  shift: 'Shift',
+  win: 'Super',
+  super: 'Super',
+  meta: 'Super'
+}
+
+// 比较用的规范形式（与后端 canonical_shortcut 一致：修饰键固定顺序、不区分大小写），
+// 使 "Ctrl+Shift+V" 与 "Shift+Control+v" 被视为同一组合
+function comparisonKey(value) {
+  const parts = value.split('+').map(part => part.trim()).filter(Boolean)
+  if (value.trim().endsWith('++')) parts.push('+')
+  const modifiers = new Set()
+  const keys = []
+  parts.forEach(part => {
+    const modifier = MODIFIER_ALIASES[part.toLowerCase()]
+    if (modifier) modifiers.add(modifier)
+    else keys.push(part.toUpperCase())
+  })
+  const orderedModifiers = MODIFIER_ORDER.filter(m => modifiers.has(m))
+  return [...orderedModifiers, ...keys].join('+')
+}
+
 export function useShortcutDuplicateCheck(settings) {
   const { t } = useTranslation()
   
@@ -17,10 +44,11 @@ export function useShortcutDuplicateCheck(settings) {
       
       if (!value || value.trim() === '') return
       
-      if (!shortcutMap.has(value)) {
-        shortcutMap.set(value, [])
+      const comboKey = comparisonKey(value)
+      if (!shortcutMap.has(comboKey)) {
+        shortcutMap.set(comboKey, [])
       }
-      shortcutMap.get(value).push(key)
+      shortcutMap.get(comboKey).push(key)
     })
     
     shortcutMap.forEach((keys) => {
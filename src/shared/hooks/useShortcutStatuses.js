@@ -8,15 +8,20 @@ export function useShortcutStatuses() {
   const { t } = useTranslation()
   const [statuses, setStatuses] = useState({})
   const [loading, setLoading] = useState(true)
+  // 最近一次重新加载的原因："startup" | "settings-change" | "foreground-sync" | "override"
+  const [lastReason, setLastReason] = useState(null)
+
+  const applyStatusList = (statusList) => {
+    const statusMap = {}
+    statusList.forEach(status => {
+      statusMap[status.id] = status
+    })
+    setStatuses(statusMap)
+  }
 
   const loadStatuses = async () => {
     try {
-      const statusList = await getShortcutStatuses()
-      const statusMap = {}
-      statusList.forEach(status => {
-        statusMap[status.id] = status
-      })
-      setStatuses(statusMap)
+      applyStatusList(await getShortcutStatuses())
     } catch (error) {
       console.error('获取快捷键状态失败:', error)
     } finally {
@@ -33,8 +38,19 @@ export function useShortcutStatuses() {
       }, 100)
     })
 
+    // 后端重新加载快捷键后直接使用事件中的状态
+    const unlistenReloaded = listen('hotkeys-reloaded', event => {
+      const { reason, statuses: statusList } = event.payload || {}
+      setLastReason(reason || null)
+      if (Array.isArray(statusList)) {
+        applyStatusList(statusList)
+        setLoading(false)
+      }
+    })
+
     return () => {
       unlisten.then(fn => fn())
+      unlistenReloaded.then(fn => fn())
     }
   }, [])
 
@@ -43,28 +59,46 @@ export function useShortcutStatuses() {
     return statuses[id] || null
   }
 
-  // 检查快捷键是否失败
+  // 检查快捷键是否失败（已禁用的不算失败）
   const hasError = (id) => {
     const status = statuses[id]
-    return status && !status.success
+    return status && !status.success && status.error !== 'DISABLED'
   }
 
   const getError = (id) => {
     const status = statuses[id]
-    if (!status || !status.error) return null
+    if (!status || !status.error || status.error === 'DISABLED') return null
     
     if (status.error === 'CONFLICT') {
       return t('settings.shortcuts.conflictError')
-    } else if (status.error === 'REGISTRATION_FAILED') {
+    } else if (status.error === 'SELF_CONFLICT') {
+      return t('settings.shortcuts.selfConflictError')
+    } else if (status.error === 'SYSTEM_RESERVED') {
+      return t('settings.shortcuts.systemReservedError')
+    } else if (status.error === 'REGISTRATION_FAILED' || status.error === 'INVALID_SHORTCUT') {
       return t('settings.shortcuts.registrationError')
     }
     
-    return status.error
+    // 其他错误码使用后端按界面语言生成的提示
+    return status.message || status.error
   }
 
+  // 前台切换引起的同步属于静默操作，界面不应就此弹出冲突提示
+  const isSilentReload = lastReason === 'startup' || lastReason === 'foreground-sync'
+
+  // 全局快捷键插件不可用时所有快捷键均无法注册，只能通过托盘操作
+  const pluginUnavailable = Object.values(statuses).some(status => status.error === 'PLUGIN_UNAVAILABLE')
+
+  // 注册失败的备用组合（ID 形如 "quickpaste#1"）
+  const failedAlternates = Object.values(statuses).filter(status => status.id.includes('#') && hasError(status.id))
+
   return {
     statuses,
     loading,
+    lastReason,
+    isSilentReload,
+    pluginUnavailable,
+    failedAlternates,
     getStatus,
     hasError,
     getError,
@@ -1,5 +1,6 @@
 import { useEffect } from 'react'
 import { useSnapshot } from 'valtio'
+import { listen } from '@tauri-apps/api/event'
 import { settingsStore } from '@shared/store/settingsStore'
 import darkThemeCss from '../styles/dark-theme.css?inline'
 import backgroundThemeCss from '../styles/theme-background.css?inline'
@@ -13,6 +14,11 @@ if (typeof window !== 'undefined' && window.matchMedia) {
   systemThemeMediaQuery.addEventListener('change', (e) => {
     settingsStore.systemIsDark = e.matches
   })
+
+  // 后端监听到系统主题变化时通知（WebView 未及时触发媒体查询变化时兜底）
+  listen('system-theme-changed', (event) => {
+    settingsStore.systemIsDark = event.payload?.theme === 'dark'
+  }).catch(() => {})
 }
 
 // 获取实际应用的主题
@@ -1,6 +1,9 @@
 import { listen } from '@tauri-apps/api/event'
-import { refreshClipboardHistory } from '@shared/store/clipboardStore'
+import { clipboardStore, refreshClipboardHistory } from '@shared/store/clipboardStore'
 import { refreshFavorites } from '@shared/store/favoritesStore'
+import { settingsStore } from '@shared/store/settingsStore'
+import { toast, TOAST_SIZES, TOAST_POSITIONS } from '@shared/store/toastStore'
+import i18n from '@shared/i18n'
 
 let unlisteners = []
 
@@ -13,6 +16,30 @@ export async function setupClipboardEventListener() {
     })
     unlisteners.push(unlisten1)
 
+    // 监听自动清理事件：移除已清理项的选中状态，按设置提示清理数量
+    const unlistenExpired = await listen('clipboard-item-expired', (event) => {
+      const ids = event.payload?.ids || []
+      if (ids.length === 0) return
+      ids.forEach(id => clipboardStore.selectedIds.delete(id))
+      refreshClipboardHistory()
+      if (settingsStore.notifyExpiredItems) {
+        toast.info(i18n.t('clipboard.expiredItems', { count: ids.length }), {
+          size: TOAST_SIZES.EXTRA_SMALL,
+          position: TOAST_POSITIONS.BOTTOM_RIGHT
+        })
+      }
+    })
+    unlisteners.push(unlistenExpired)
+
+    // 粘贴目标为本应用窗口时已跳过粘贴
+    const unlistenSelfTarget = await listen('paste-target-self', () => {
+      toast.warning(i18n.t('clipboard.pasteTargetSelf'), {
+        size: TOAST_SIZES.EXTRA_SMALL,
+        position: TOAST_POSITIONS.BOTTOM_RIGHT
+      })
+    })
+    unlisteners.push(unlistenSelfTarget)
+
     // 监听收藏列表更新事件
     const unlisten2 = await listen('quick-texts-updated', () => {
       refreshFavorites()
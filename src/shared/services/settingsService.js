@@ -18,6 +18,7 @@ export const defaultSettings = {
   startHidden: false,
   showStartupNotification: true,
   historyLimit: 100,
+  notifyExpiredItems: false,
   language: 'zh-CN',
   
   // 外观设置
@@ -34,6 +35,10 @@ export const defaultSettings = {
   screenshotShortcut: 'Ctrl+Shift+A',
   numberShortcuts: true,
   numberShortcutsModifier: 'Ctrl',
+  numberShortcutsSource: 'visible',
+  repeatPastePolicy: 'overlap',
+  numberItemBindings: {},
+  numberUnboundBehavior: 'index',
   
   // 剪贴板窗口快捷键
   navigateUpShortcut: 'ArrowUp',
@@ -48,12 +53,32 @@ export const defaultSettings = {
   togglePinShortcut: 'Ctrl+P',
   toggleClipboardMonitorShortcut: 'Ctrl+Shift+Z',
   togglePasteWithFormatShortcut: 'Ctrl+Shift+X',
+  pasteFormatToggleMode: 'sticky',
+  contentTypePasteFormats: {},
+  appPasteFormats: {},
   pastePlainTextShortcut: '',
+  panicShortcut: '',
+  panicHideWindows: true,
+  panicClearClipboard: true,
+  panicEnterIncognito: false,
+  hotkeyConfirmActions: [],
+  grabSelectionShortcut: '',
+  grabSelectionTimeoutMs: 600,
+  grabSelectionShowQuickpaste: false,
+  quickNoteShortcut: '',
+  composeShortcut: '',
   
   // 剪贴板设置
   clipboardMonitor: true,
+  clipboardPollIntervalMs: 0,
+  incognitoMode: false,
   ignoreDuplicates: true,
   saveImages: true,
+  imageCoalesceWindowMs: 0,
+  imageCoalesceSimilarity: 0.95,
+  imagePasteMaxDimension: 1920,
+  imagePasteFormat: 'auto',
+  imagePasteJpegQuality: 85,
   imagePreview: false,
   textPreview: false,
   autoScrollToTopOnShow: false,
@@ -68,6 +93,7 @@ export const defaultSettings = {
   pasteWithFormat: true,
   pasteShortcutMode: 'ctrl_v',
   pasteToTop: false,
+  copyAsStoreResult: false,
   showBadges: true,
   showSourceIcon: true,
   
@@ -89,6 +115,9 @@ export const defaultSettings = {
   quickpastePasteOnModifierRelease: false,
   quickpasteScrollSound: true,
   quickpasteScrollSoundPath: 'sounds/roll.mp3',
+  quickpasteHideMode: 'release',
+  quickpasteItemCount: 0,
+  quickpasteGridColumns: 1,
   
   // 截屏设置
   screenshotEnabled: true,
@@ -130,6 +159,7 @@ export const defaultSettings = {
   appFilterMode: 'blacklist',
   appFilterList: [],
   appFilterEffect: 'clipboard_only',
+  appFilterDisableGraceMs: 0,
   
   // 保存的窗口状态
   savedWindowPosition: null,
@@ -194,8 +194,8 @@ export async function loadClipboardItems() {
   return await refreshClipboardHistory()
 }
 
-// 初始化加载
-export async function initClipboardItems() {
+// 初始化加载，limit 为首次加载的条数
+export async function initClipboardItems(limit = 100) {
   clipboardStore.loading = true
   clipboardStore.error = null
   
@@ -206,7 +206,7 @@ export async function initClipboardItems() {
     if (clipboardStore.contentType !== 'all' || clipboardStore.filter) {
       const result = await getClipboardHistory({
         offset: 0,
-        limit: 100,
+        limit,
         contentType: clipboardStore.contentType !== 'all' ? clipboardStore.contentType : undefined,
         search: clipboardStore.filter || undefined
       })
@@ -218,7 +218,7 @@ export async function initClipboardItems() {
       clipboardStore.totalCount = totalCount
       
       if (totalCount > 0) {
-        const endIndex = Math.min(99, totalCount - 1)
+        const endIndex = Math.min(limit - 1, totalCount - 1)
         await loadClipboardRange(0, endIndex)
       }
     }
@@ -16,7 +16,8 @@ import {
   copyTextToClipboard,
   recognizeImageOcr,
   moveClipboardItemToTop,
-  copyClipboardItem
+  copyClipboardItem,
+  copyItemAs
 } from '@shared/api'
 import { getToolState } from '@shared/services/toolActions'
 import { clipboardStore } from '@shared/store/clipboardStore'
@@ -156,6 +157,26 @@ function createPasteMenuItem(contentType, hasHtmlContent) {
     return pasteMenuItem
   }
 
+  // 图片项可选择粘贴原图或按设置缩放后的图片
+  if (contentType.includes('image')) {
+    const pasteMenuItem = createMenuItem('paste', i18n.t('contextMenu.paste'), { icon: 'ti ti-clipboard' })
+    pasteMenuItem.children = [
+      createMenuItem('paste-image-original', i18n.t('contextMenu.pasteImageOriginal'), { icon: 'ti ti-photo' }),
+      createMenuItem('paste-image-resized', i18n.t('contextMenu.pasteImageResized'), { icon: 'ti ti-resize' })
+    ]
+    return pasteMenuItem
+  }
+
+  // 文件项可选择粘贴文件或粘贴路径文本
+  if (contentType.includes('file')) {
+    const pasteMenuItem = createMenuItem('paste', i18n.t('contextMenu.paste'), { icon: 'ti ti-clipboard' })
+    pasteMenuItem.children = [
+      createMenuItem('paste-files', i18n.t('contextMenu.pasteFiles'), { icon: 'ti ti-files' }),
+      createMenuItem('paste-paths', i18n.t('contextMenu.pastePaths'), { icon: 'ti ti-terminal' })
+    ]
+    return pasteMenuItem
+  }
+
   return createMenuItem('paste', i18n.t('contextMenu.paste'), { icon: 'ti ti-clipboard' })
 }
 
@@ -183,6 +204,100 @@ function createContentTypeMenuItems(contentType) {
   ]
 }
 
+// 创建强制粘贴格式菜单（仅文本类项）
+function createForcedFormatMenuItem(contentType, forcedFormat) {
+  if (contentType.includes('image') || contentType.includes('file')) return null
+
+  const options = [
+    { id: 'forced-format-none', format: null, label: i18n.t('contextMenu.forcedFormatNone') },
+    { id: 'forced-format-plain', format: 'plain', label: i18n.t('contextMenu.forcedFormatPlain') },
+    { id: 'forced-format-formatted', format: 'formatted', label: i18n.t('contextMenu.forcedFormatFormatted') },
+    { id: 'forced-format-markdown', format: 'markdown', label: i18n.t('contextMenu.forcedFormatMarkdown') }
+  ]
+  const menuItem = createMenuItem('forced-format', i18n.t('contextMenu.forcedFormat'), { icon: 'ti ti-lock' })
+  menuItem.children = options.map(option =>
+    createMenuItem(option.id, option.label, {
+      icon: (forcedFormat || null) === option.format ? 'ti ti-check' : undefined
+    })
+  )
+  return menuItem
+}
+
+// 创建"复制为"菜单（应用文本转换后复制，不粘贴）
+const COPY_AS_TRANSFORMS = [
+  'trim',
+  'trim_trailing_whitespace',
+  'tabs_to_spaces',
+  'normalize_line_endings',
+  'collapse_blank_lines',
+  'remove_empty_lines',
+  'lowercase',
+  'uppercase'
+]
+
+function createCopyAsMenuItem(contentType) {
+  if (contentType.includes('image') || contentType.includes('file')) return null
+
+  const menuItem = createMenuItem('copy-as', i18n.t('contextMenu.copyAs'), { icon: 'ti ti-transform' })
+  menuItem.children = COPY_AS_TRANSFORMS.map(transform =>
+    createMenuItem(`copy-as-${transform}`, i18n.t(`contextMenu.copyAsTransforms.${transform}`))
+  )
+  return menuItem
+}
+
+// 创建数字键绑定菜单（将数字快捷键固定到该项）
+function createNumberBindingMenuItem(item) {
+  const bindings = settingsStore.numberItemBindings || {}
+  const boundNum = Object.keys(bindings).find(num => bindings[num] === item.id)
+  const menuItem = createMenuItem('bind-number', i18n.t('contextMenu.bindNumber'), { icon: 'ti ti-number' })
+  menuItem.children = [1, 2, 3, 4, 5, 6, 7, 8, 9].map(num =>
+    createMenuItem(`bind-number-${num}`, String(num), {
+      icon: boundNum === String(num) ? 'ti ti-check' : undefined
+    })
+  )
+  if (boundNum) {
+    menuItem.children.push(createMenuItem('bind-number-none', i18n.t('contextMenu.unbindNumber'), { icon: 'ti ti-unlink' }))
+  }
+  return menuItem
+}
+
+// 处理数字键绑定操作
+async function handleNumberBindingActions(result, item) {
+  if (!result.startsWith('bind-number-')) return false
+
+  const { bindNumberToItem } = await import('@shared/api/settings')
+  const value = result.substring(12)
+  if (value === 'none') {
+    const bindings = settingsStore.numberItemBindings || {}
+    const boundNum = Object.keys(bindings).find(num => bindings[num] === item.id)
+    if (boundNum) await bindNumberToItem(Number(boundNum), null)
+  } else {
+    await bindNumberToItem(Number(value), item.id)
+  }
+  toast.success(i18n.t('contextMenu.bindNumberSaved'), TOAST_CONFIG)
+  return true
+}
+
+// 处理按指定格式复制操作
+async function handleCopyAsActions(result, item) {
+  if (!result.startsWith('copy-as-')) return false
+
+  await copyItemAs(item.id, result.substring(8))
+  toast.success(i18n.t('contextMenu.copied'), TOAST_CONFIG)
+  return true
+}
+
+// 处理强制粘贴格式操作
+async function handleForcedFormatActions(result, item) {
+  if (!result.startsWith('forced-format-')) return false
+
+  const format = result.substring(14)
+  const { setItemForcedFormat } = await import('@shared/api/clipboard')
+  await setItemForcedFormat(item.id, format === 'none' ? null : format)
+  toast.success(i18n.t('contextMenu.forcedFormatSaved'), TOAST_CONFIG)
+  return true
+}
+
 // 处理链接相关操作
 async function handleLinkActions(result, links) {
   if (result === 'open-link' && links.length === 1) {
@@ -230,7 +345,11 @@ async function handlePasteActions(result, item, isClipboard = true, index = unde
   const pasteActions = {
     'paste': null,
     'paste-formatted': 'formatted',
-    'paste-plain': 'plain'
+    'paste-plain': 'plain',
+    'paste-files': null,
+    'paste-paths': 'paths',
+    'paste-image-original': null,
+    'paste-image-resized': 'resized'
   }
   
   if (!(result in pasteActions)) return false
@@ -361,6 +480,10 @@ export async function showClipboardItemContextMenu(event, item, index) {
   const pasteMenuItem = createPasteMenuItem(contentType, !!item.html_content)
   menuItems.push(pasteMenuItem)
   menuItems.push(createMenuItem('copy-item', i18n.t('contextMenu.copy'), { icon: 'ti ti-copy' }))
+  const copyAsMenuItem = createCopyAsMenuItem(contentType)
+  if (copyAsMenuItem) {
+    menuItems.push(copyAsMenuItem)
+  }
   menuItems.push(createSeparator())
 
   const { menuItems: linkMenuItems, links } = createLinkMenuItems(item)
@@ -378,6 +501,14 @@ export async function showClipboardItemContextMenu(event, item, index) {
     menuItems.push(...contentMenuItems)
   }
 
+  const forcedFormatMenuItem = createForcedFormatMenuItem(contentType, item.forced_format)
+  if (forcedFormatMenuItem) {
+    menuItems.push(forcedFormatMenuItem)
+  }
+  if (settingsStore.numberShortcuts) {
+    menuItems.push(createNumberBindingMenuItem(item))
+  }
+
   // 添加分隔线
   if (menuItems.length > 0 && !menuItems[menuItems.length - 1].separator) {
     menuItems.push(createSeparator())
@@ -418,9 +549,18 @@ export async function showClipboardItemContextMenu(event, item, index) {
       return
     }
 
+    // 处理复制为
+    if (await handleCopyAsActions(result, item)) return
+
     // 处理粘贴操作
     if (await handlePasteActions(result, item, true, index)) return
 
+    // 处理强制粘贴格式
+    if (await handleForcedFormatActions(result, item)) return
+
+    // 处理数字键绑定
+    if (await handleNumberBindingActions(result, item)) return
+
     // 处理链接操作
     if (await handleLinkActions(result, links)) {
       toast.success(i18n.t('contextMenu.linkOpened'), TOAST_CONFIG)
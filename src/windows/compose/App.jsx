@@ -0,0 +1,287 @@
+import '@tabler/icons-webfont/dist/tabler-icons.min.css';
+import { useState, useEffect, useCallback } from 'react';
+import { useTranslation } from 'react-i18next';
+import { useSnapshot } from 'valtio';
+import { listen } from '@tauri-apps/api/event';
+import { getCurrentWindow } from '@tauri-apps/api/window';
+import { settingsStore, initSettings } from '@shared/store/settingsStore';
+import { useTheme, applyThemeToBody } from '@shared/hooks/useTheme';
+import { useSettingsSync } from '@shared/hooks/useSettingsSync';
+import { toast, TOAST_SIZES, TOAST_POSITIONS } from '@shared/store/toastStore';
+import { getClipboardHistory, getComposeEntries, composeAddItems, composeAddText, composeUpdateEntry, composeRemoveEntry, composeMoveEntry, composeClear, composePreview, pasteCompose } from '@shared/api';
+import { useSortableList, useSortable, CSS } from '@shared/hooks/useSortable';
+import ToastContainer from '@shared/components/common/ToastContainer';
+
+// 可组合的文本类项
+const isTextItem = item => ['text', 'link', 'rich_text'].includes(item.content_type.split(',')[0]);
+const SEPARATORS = ['newline', 'comma', 'space', 'custom'];
+const iconButton = 'w-7 h-7 flex items-center justify-center rounded text-gray-500 hover:bg-gray-100 disabled:opacity-30 disabled:hover:bg-transparent dark:text-gray-400 dark:hover:bg-gray-700';
+
+// 可拖拽排序的组合条目，拖动序号调整位置
+function SortableEntry({
+  id,
+  index,
+  children
+}) {
+  const {
+    attributes,
+    listeners,
+    setNodeRef,
+    transform,
+    transition,
+    isDragging
+  } = useSortable({
+    id
+  });
+  const style = {
+    transform: CSS.Transform.toString(transform),
+    transition: transition || 'transform 200ms ease',
+    opacity: isDragging ? 0.5 : 1
+  };
+  return <div ref={setNodeRef} style={style} className="flex gap-1 items-start">
+      <span className="text-xs text-gray-400 w-5 pt-1.5 text-right cursor-grab" {...attributes} {...listeners}>{index + 1}</span>
+      {children}
+    </div>;
+}
+function App() {
+  const {
+    t
+  } = useTranslation();
+  const {
+    theme
+  } = useSnapshot(settingsStore);
+  const {
+    isDark,
+    effectiveTheme
+  } = useTheme();
+  const [history, setHistory] = useState([]);
+  const [search, setSearch] = useState('');
+  const [entries, setEntries] = useState([]);
+  // 尚未提交到后端的条目修改（key -> 文本）
+  const [drafts, setDrafts] = useState({});
+  const [separator, setSeparator] = useState('newline');
+  const [customSeparator, setCustomSeparator] = useState('');
+  const [numbered, setNumbered] = useState(false);
+  const [preview, setPreview] = useState('');
+  // 预览被手动修改后，粘贴时使用修改后的文本
+  const [previewEdited, setPreviewEdited] = useState(false);
+  useSettingsSync();
+  useEffect(() => {
+    initSettings();
+  }, []);
+  useEffect(() => {
+    const composeTheme = theme === 'background' ? 'light' : theme;
+    applyThemeToBody(composeTheme, 'compose');
+  }, [theme, effectiveTheme]);
+  const options = {
+    separator,
+    customSeparator,
+    numbered
+  };
+  const showError = error => {
+    toast.error(String(error), {
+      size: TOAST_SIZES.SMALL,
+      position: TOAST_POSITIONS.BOTTOM_RIGHT
+    });
+  };
+  const loadHistory = useCallback(async () => {
+    const result = await getClipboardHistory({
+      limit: 100,
+      search: search || undefined
+    });
+    setHistory(result.items.filter(isTextItem));
+  }, [search]);
+  useEffect(() => {
+    loadHistory();
+  }, [loadHistory]);
+
+  // 条目由后端会话保存，其他窗口的修改通过 compose-updated 同步
+  useEffect(() => {
+    getComposeEntries().then(setEntries);
+    const unlisteners = [listen('compose-updated', event => setEntries(event.payload)), listen('clipboard-updated', () => loadHistory())];
+    return () => {
+      unlisteners.forEach(p => p.then(unlisten => unlisten()));
+    };
+  }, [loadHistory]);
+  useEffect(() => {
+    if (entries.length === 0) {
+      setPreview('');
+      setPreviewEdited(false);
+      return;
+    }
+    composePreview(options).then(text => {
+      setPreview(text);
+      setPreviewEdited(false);
+    }).catch(() => setPreview(''));
+  }, [entries, separator, customSeparator, numbered]);
+  const run = async action => {
+    try {
+      setEntries(await action());
+    } catch (error) {
+      showError(error);
+    }
+  };
+  const commitDraft = (key, text) => {
+    setDrafts(prev => {
+      const next = {
+        ...prev
+      };
+      delete next[key];
+      return next;
+    });
+    const entry = entries.find(item => item.key === key);
+    if (entry && entry.text !== text) {
+      run(() => composeUpdateEntry(key, text));
+    }
+  };
+
+  // 粘贴前提交所有未保存的修改，避免 Ctrl+Enter 时正在编辑的内容丢失
+  const flushDrafts = async () => {
+    const pending = Object.entries(drafts).filter(([key, text]) => entries.some(entry => entry.key === Number(key) && entry.text !== text));
+    setDrafts({});
+    for (const [key, text] of pending) {
+      await composeUpdateEntry(Number(key), text);
+    }
+  };
+  const {
+    DndContext,
+    SortableContext,
+    sensors,
+    handleDragStart,
+    handleDragEnd,
+    handleDragCancel,
+    strategy,
+    modifiers,
+    collisionDetection
+  } = useSortableList({
+    items: entries.map(entry => ({
+      ...entry,
+      _sortId: entry.key
+    })),
+    onDragEnd: (oldIndex, newIndex) => run(() => composeMoveEntry(entries[oldIndex].key, newIndex))
+  });
+  const handleClose = () => {
+    getCurrentWindow().hide();
+  };
+  const handlePaste = async () => {
+    if (entries.length === 0) return;
+    try {
+      await flushDrafts();
+      await pasteCompose(previewEdited ? preview : null, options);
+      setEntries([]);
+    } catch (error) {
+      showError(error);
+    }
+  };
+  useEffect(() => {
+    const handleKeyDown = e => {
+      if (e.key === 'Escape') {
+        e.preventDefault();
+        handleClose();
+      } else if (e.key === 'Enter' && e.ctrlKey) {
+        e.preventDefault();
+        handlePaste();
+      }
+    };
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  });
+  const containerClasses = `
+    h-screen w-screen
+    flex flex-col
+    bg-white dark:bg-gray-900 text-gray-800 dark:text-gray-100
+    ${isDark ? 'dark' : ''}
+  `.trim().replace(/\s+/g, ' ');
+  return <div className={containerClasses}>
+      <div className="h-11 flex items-center justify-between px-4 border-b border-gray-200 dark:border-gray-700" data-tauri-drag-region>
+        <div className="flex items-center gap-2 pointer-events-none">
+          <i className="ti ti-stack-2" style={{
+          fontSize: 18
+        }}></i>
+          <h1 className="text-sm font-semibold">{t('compose.title')}</h1>
+        </div>
+        <button className="w-8 h-8 flex items-center justify-center rounded hover:bg-red-500 hover:text-white text-gray-600 dark:text-gray-300 transition-colors" onClick={handleClose} title={t('common.close')}>
+          <i className="ti ti-x" style={{
+          fontSize: 16
+        }}></i>
+        </button>
+      </div>
+
+      <div className="flex-1 flex min-h-0">
+        <div className="w-2/5 flex flex-col border-r border-gray-200 dark:border-gray-700">
+          <input className="m-2 px-2 py-1 text-sm rounded border border-gray-300 bg-transparent dark:border-gray-600" value={search} onChange={e => setSearch(e.target.value)} placeholder={t('compose.searchPlaceholder')} />
+          <div className="flex-1 overflow-y-auto px-2 pb-2 space-y-1">
+            {history.length === 0 && <div className="text-xs text-gray-400 text-center py-4">{t('compose.noHistory')}</div>}
+            {history.map(item => <button key={item.id} className="w-full text-left text-xs px-2 py-1.5 rounded hover:bg-blue-50 dark:hover:bg-gray-800 flex items-start gap-2" onClick={() => run(() => composeAddItems([item.id]))} title={t('compose.addItem')}>
+                <i className="ti ti-plus text-blue-500 mt-0.5"></i>
+                <span className="line-clamp-2 break-all">{item.content}</span>
+              </button>)}
+          </div>
+        </div>
+
+        <div className="flex-1 flex flex-col min-w-0">
+          <div className="flex items-center justify-between px-3 py-2 text-xs text-gray-500 dark:text-gray-400">
+            <span>{t('compose.entryCount', {
+              count: entries.length
+            })}</span>
+            <div className="flex gap-2">
+              <button className="hover:text-blue-500" onClick={() => run(() => composeAddText(''))}>{t('compose.addSnippet')}</button>
+              <button className="hover:text-red-500 disabled:opacity-30" disabled={entries.length === 0} onClick={() => run(composeClear)}>{t('compose.clear')}</button>
+            </div>
+          </div>
+          <div className="flex-1 overflow-y-auto px-3 space-y-2">
+            {entries.length === 0 && <div className="text-xs text-gray-400 text-center py-8">{t('compose.empty')}</div>}
+            <DndContext sensors={sensors} collisionDetection={collisionDetection} onDragStart={handleDragStart} onDragEnd={handleDragEnd} onDragCancel={handleDragCancel} modifiers={modifiers}>
+              <SortableContext items={entries.map(entry => entry.key)} strategy={strategy}>
+                {entries.map((entry, index) => <SortableEntry key={entry.key} id={entry.key} index={index}>
+                    <textarea className="flex-1 text-sm px-2 py-1 rounded border border-gray-300 bg-transparent resize-y dark:border-gray-600" rows={2} value={drafts[entry.key] ?? entry.text} onChange={e => setDrafts(prev => ({
+                      ...prev,
+                      [entry.key]: e.target.value
+                    }))} onBlur={e => commitDraft(entry.key, e.target.value)} />
+                    <div className="flex flex-col">
+                      <button className={iconButton} disabled={index === 0} onClick={() => run(() => composeMoveEntry(entry.key, index - 1))} title={t('compose.moveUp')}>
+                        <i className="ti ti-chevron-up"></i>
+                      </button>
+                      <button className={iconButton} disabled={index === entries.length - 1} onClick={() => run(() => composeMoveEntry(entry.key, index + 1))} title={t('compose.moveDown')}>
+                        <i className="ti ti-chevron-down"></i>
+                      </button>
+                    </div>
+                    <button className={iconButton} onClick={() => run(() => composeRemoveEntry(entry.key))} title={t('compose.remove')}>
+                      <i className="ti ti-trash"></i>
+                    </button>
+                  </SortableEntry>)}
+              </SortableContext>
+            </DndContext>
+          </div>
+
+          <div className="border-t border-gray-200 dark:border-gray-700 p-3 space-y-2">
+            <div className="flex items-center gap-3 text-xs">
+              <label className="flex items-center gap-1">
+                {t('compose.separator')}
+                <select className="px-1 py-0.5 rounded border border-gray-300 bg-transparent dark:border-gray-600" value={separator} onChange={e => setSeparator(e.target.value)}>
+                  {SEPARATORS.map(value => <option key={value} value={value}>{t(`compose.separators.${value}`)}</option>)}
+                </select>
+              </label>
+              {separator === 'custom' && <input className="w-24 px-1 py-0.5 rounded border border-gray-300 bg-transparent dark:border-gray-600" value={customSeparator} onChange={e => setCustomSeparator(e.target.value)} placeholder="\n" />}
+              <label className="flex items-center gap-1">
+                <input type="checkbox" checked={numbered} onChange={e => setNumbered(e.target.checked)} />
+                {t('compose.numbered')}
+              </label>
+            </div>
+            <textarea className="w-full h-24 text-sm px-2 py-1 rounded border border-gray-300 bg-gray-50 resize-none dark:border-gray-600 dark:bg-gray-800" value={preview} onChange={e => {
+            setPreview(e.target.value);
+            setPreviewEdited(true);
+          }} placeholder={t('compose.previewPlaceholder')} />
+            <div className="flex justify-end">
+              <button className="px-4 py-1.5 text-sm rounded bg-blue-500 text-white hover:bg-blue-600 disabled:opacity-40" disabled={entries.length === 0} onClick={handlePaste} title="Ctrl+Enter">
+                {t('compose.paste')}
+              </button>
+            </div>
+          </div>
+        </div>
+      </div>
+
+      <ToastContainer />
+    </div>;
+}
+export default App;
@@ -14,6 +14,8 @@ import { useNavigationKeyboard } from '@shared/hooks/useNavigationKeyboard';
 import { useWindowAnimation } from '@shared/hooks/useWindowAnimation';
 import { applyBackgroundImage, clearBackgroundImage } from '@shared/utils/backgroundManager';
 import { promptDisableWinVHotkeyIfNeeded } from '@shared/api/system';
+import { promptRepairDatabaseIfNeeded } from '@shared/api/dataManagement';
+import { reloadAllWindows } from '@shared/api/window';
 import TitleBar from './components/TitleBar';
 import TabNavigation from './components/TabNavigation';
 import ClipboardTab from './components/ClipboardTab';
@@ -22,6 +24,7 @@ const EmojiTab = lazy(() => import('./components/EmojiTab'));
 import FooterBar from './components/FooterBar';
 import GroupsPopup from './components/GroupsPopup';
 import ToastContainer from '@shared/components/common/ToastContainer';
+import { toast, TOAST_SIZES, TOAST_POSITIONS } from '@shared/store/toastStore';
 
 function App() {
   const {
@@ -42,6 +45,7 @@ function App() {
   const [contentFilter, setContentFilter] = useState('all');
   const [searchQuery, setSearchQuery] = useState('');
   const [emojiMode, setEmojiMode] = useState('emoji'); // 'emoji' | 'symbols' | 'images'
+  const [feedbackFlash, setFeedbackFlash] = useState(false);
   const clipboardTabRef = useRef(null);
   const favoritesTabRef = useRef(null);
   const groupsPopupRef = useRef(null);
@@ -65,6 +69,21 @@ function App() {
     checkWinV();
   }, []);
 
+  // 启动时数据库完整性检查未通过则提示修复
+  useEffect(() => {
+    const checkDatabase = async () => {
+      try {
+        if (await promptRepairDatabaseIfNeeded()) {
+          await reloadAllWindows();
+        }
+      } catch (error) {
+        console.error('数据库修复提示失败:', error);
+      }
+    };
+
+    checkDatabase();
+  }, []);
+
   // 窗口动画
   useWindowAnimation();
 
@@ -111,6 +130,35 @@ function App() {
     return () => cleanup.then(fn => fn());
   }, [activeTab]);
 
+  // 快捷键触发反馈：窗口边缘短暂闪烁
+  useEffect(() => {
+    let flashTimer = null;
+    const unlisten = listen('hotkey-feedback', () => {
+      setFeedbackFlash(true);
+      clearTimeout(flashTimer);
+      flashTimer = setTimeout(() => setFeedbackFlash(false), 300);
+    });
+    return () => {
+      clearTimeout(flashTimer);
+      unlisten.then(fn => fn());
+    };
+  }, []);
+
+  // 模拟粘贴回退为直接输入时提示（失败由调用方的错误处理提示）
+  useEffect(() => {
+    const unlisten = listen('paste-result', event => {
+      if (event.payload?.success && event.payload?.fallback) {
+        toast.warning(t('common.pasteFallbackUsed'), {
+          size: TOAST_SIZES.EXTRA_SMALL,
+          position: TOAST_POSITIONS.BOTTOM_RIGHT
+        });
+      }
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [t]);
+
   useEffect(() => {
     const handleMouseEnter = async () => {
       try {
@@ -408,6 +456,7 @@ function App() {
     }}>
         {renderLayout()}
         <ToastContainer />
+        {feedbackFlash && <div className="fixed inset-0 pointer-events-none rounded-lg ring-4 ring-inset ring-blue-500/70" />}
       </div>
     </div>;
 }
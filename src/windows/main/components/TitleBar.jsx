@@ -4,6 +4,8 @@ import { useSnapshot } from 'valtio';
 import { useEffect, useRef, useState, forwardRef, useImperativeHandle } from 'react';
 import { useWindowDrag } from '@shared/hooks/useWindowDrag';
 import { toolsStore } from '@shared/store/toolsStore';
+import { settingsStore } from '@shared/store/settingsStore';
+import { setIncognito } from '@shared/api/settings';
 import { useSortableList, useSortable, CSS } from '@shared/hooks/useSortable';
 import { DragOverlay, useDroppable } from '@dnd-kit/core';
 import { MAX_TITLEBAR_TOOLS } from '@shared/config/tools';
@@ -66,6 +68,9 @@ const TitleBar = forwardRef(({
     layout,
     isExpanded
   } = useSnapshot(toolsStore);
+  const {
+    incognitoMode
+  } = useSnapshot(settingsStore);
   const [activeId, setActiveId] = useState(null);
   const containerRef = useRef(null);
   const searchRef = useRef(null);
@@ -171,6 +176,12 @@ const TitleBar = forwardRef(({
         </div>
       </div>
 
+      {/* 无痕模式指示（点击关闭） */}
+      {incognitoMode && <button data-no-drag className="flex items-center gap-1 px-1.5 h-6 rounded-md text-xs font-medium text-white bg-red-500 hover:bg-red-600 flex-shrink-0" title={t('clipboard.incognitoTooltip')} onClick={() => setIncognito(false)}>
+          <i className="ti ti-spy" />
+          {!isVertical && <span>{t('clipboard.incognito')}</span>}
+        </button>}
+
       {/* 搜索 + 工具按钮容器 */}
       <div className={`flex ${isVertical ? 'flex-col items-center gap-2' : 'flex-row items-center gap-1'} relative ${isVertical ? '' : 'flex-shrink-0'}`} ref={containerRef}>
         {/* 搜索框 */}
@@ -3,6 +3,7 @@ import { useState, useRef, useEffect, forwardRef, useImperativeHandle } from 're
 import { useInputFocus, focusWindowImmediately } from '@shared/hooks/useInputFocus';
 import { useSnapshot } from 'valtio';
 import { settingsStore } from '@shared/store/settingsStore';
+import { getRecentSearches } from '@shared/api/clipboard';
 const TitleBarSearch = forwardRef(({
   value,
   onChange,
@@ -13,6 +14,7 @@ const TitleBarSearch = forwardRef(({
 }, ref) => {
   const [isFocused, setIsFocused] = useState(false);
   const [isExpanded, setIsExpanded] = useState(false);
+  const [recentSearches, setRecentSearches] = useState([]);
   const inputRef = useInputFocus();
   const searchRef = useRef(null);
   const settings = useSnapshot(settingsStore);
@@ -49,6 +51,8 @@ const TitleBarSearch = forwardRef(({
   };
   const handleFocus = () => {
     setIsFocused(true);
+    // 聚焦时刷新最近搜索词作为输入建议
+    getRecentSearches().then(setRecentSearches).catch(() => setRecentSearches([]));
     if (inputRef.current && value) {
       setTimeout(() => {
         inputRef.current.select();
@@ -122,7 +126,10 @@ const TitleBarSearch = forwardRef(({
             <style>{searchInputStyle}</style>
             <div ref={searchRef} className={`titlebar-search relative flex ${isVertical ? 'flex-col items-center justify-end h-7' : 'flex-row items-center justify-end w-7'}`}>
                 {/* 输入框 - 根据方向展开 */}
-                <input ref={inputRef} type="search" value={value} onChange={e => onChange(e.target.value)} onFocus={handleFocus} onBlur={() => setIsFocused(false)} onKeyDown={handleKeyDown} placeholder={placeholder} style={isVertical ? {
+                <datalist id="titlebar-recent-searches">
+                    {recentSearches.map(term => <option key={term} value={term} />)}
+                </datalist>
+                <input ref={inputRef} type="search" list="titlebar-recent-searches" value={value} onChange={e => onChange(e.target.value)} onFocus={handleFocus} onBlur={() => setIsFocused(false)} onKeyDown={handleKeyDown} placeholder={placeholder} style={isVertical ? {
         writingMode: 'vertical-rl',
         textAlign: 'start'
       } : {}} className={`absolute ${isVertical ? 'bottom-6 left-0 w-7 py-2' : 'right-6 h-7 px-2'} text-sm bg-gray-100 dark:bg-gray-700 border border-gray-300/50 dark:border-gray-600/50 rounded-lg outline-none focus:ring-1 focus:ring-blue-500 dark:focus:ring-blue-600 focus:border-blue-500 dark:focus:border-blue-600 text-gray-900 dark:text-gray-100 placeholder-gray-400 dark:placeholder-gray-500 shadow-sm ${uiAnimationEnabled ? 'transition-all duration-300 ease-in-out' : ''} ${isExpanded ? isVertical ? 'h-48 opacity-100 mb-1' : 'w-30 opacity-100 mr-1' : (isVertical ? 'h-0' : 'w-0') + ' opacity-0 pointer-events-none border-0'}`} />
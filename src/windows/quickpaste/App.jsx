@@ -17,6 +17,14 @@ import { playScrollSound } from '@shared/api';
 
 const ITEM_HEIGHT = 52;
 const ITEM_PADDING = 8;
+const MAX_GRID_COLUMNS = 4;
+
+// 可见位置对应的数字键（1-9 对应前九项，0 对应第十项，与后端映射一致）
+const digitKeyLabel = (position) => {
+  if (position < 9) return String(position + 1);
+  if (position === 9) return '0';
+  return null;
+};
 
 function QuickPasteWindow() {
   const { t } = useTranslation();
@@ -35,12 +43,23 @@ function QuickPasteWindow() {
 
   const isClipboardTab = navSnap.activeTab === 'clipboard';
   const currentItems = isClipboardTab ? clipSnap.items : favSnap.items;
-  const totalCount = isClipboardTab ? clipSnap.totalCount : favSnap.totalCount;
+  const fixedItemCount = settings.quickpasteItemCount || 0;
+  // 固定项数时只显示并查询前 N 项
+  const storeCount = isClipboardTab ? clipSnap.totalCount : favSnap.totalCount;
+  const totalCount = fixedItemCount > 0 ? Math.min(storeCount, fixedItemCount) : storeCount;
   const itemsArray = useMemo(() => Array.from({ length: totalCount }, (_, i) => currentItems[i] || null), [currentItems, totalCount]);
   const title = isClipboardTab ? t('settings.quickpaste.window.clipboardHistory') : groupSnap.currentGroup;
+  const gridColumns = fixedItemCount > 0
+    ? Math.min(Math.max(1, settings.quickpasteGridColumns || 1), MAX_GRID_COLUMNS, fixedItemCount)
+    : 1;
 
-  // 计算可见项目数量
+  // 计算可见项目数量（设置了固定项数时直接使用，窗口尺寸由后端按布局调整）
   useEffect(() => {
+    if (fixedItemCount > 0) {
+      setVisibleCount(fixedItemCount);
+      return;
+    }
+
     const updateVisibleCount = () => {
       if (containerRef.current) {
         const height = containerRef.current.clientHeight;
@@ -52,7 +71,7 @@ function QuickPasteWindow() {
     updateVisibleCount();
     window.addEventListener('resize', updateVisibleCount);
     return () => window.removeEventListener('resize', updateVisibleCount);
-  }, []);
+  }, [fixedItemCount]);
 
   // 计算可见的项目范围
   const visibleItems = useMemo(() => {
@@ -116,9 +135,37 @@ function QuickPasteWindow() {
       document.documentElement.removeEventListener('mouseenter', handleMouseEnter);
     };
   }, []);
-  const handleItemClick = useCallback((index) => {
-    setActiveIndex(index);
+  // 选中项变化或鼠标悬停时通知后端，重置空闲自动隐藏计时
+  useEffect(() => {
+    invoke('quickpaste_interaction').catch(() => {});
+  }, [activeIndex]);
+
+  useEffect(() => {
+    let lastNotify = 0;
+    const handleMouseMove = () => {
+      const now = Date.now();
+      if (now - lastNotify < 500) return;
+      lastNotify = now;
+      invoke('quickpaste_interaction').catch(() => {});
+    };
+
+    document.addEventListener('mousemove', handleMouseMove);
+    return () => document.removeEventListener('mousemove', handleMouseMove);
   }, []);
+
+  const handleItemClick = useCallback(async (index) => {
+    setActiveIndex(index);
+    // 点击外部隐藏模式下，鼠标点击即粘贴该项
+    if (settings.quickpasteHideMode !== 'outside_click') return;
+    const item = itemsArray[index];
+    if (!item) return;
+    try {
+      isClipboardTab ? await pasteClipboardItem(item.id) : await pasteFavorite(item.id);
+    } catch (error) {
+      console.error('粘贴失败:', error);
+    }
+    await invoke('hide_quickpaste_window');
+  }, [settings.quickpasteHideMode, itemsArray, isClipboardTab]);
   useEffect(() => {
     applyThemeToBody(theme, 'quickpaste');
   }, [theme, effectiveTheme]);
@@ -137,11 +184,32 @@ function QuickPasteWindow() {
     });
     return () => unlisten.then(fn => fn());
   }, [isHoveringCancel, activeIndex, itemsArray, isClipboardTab]);
+
+  // 上报当前显示的项，数字快捷键按窗口中的顺序粘贴（未加载的行上报 null，保持编号与行一致）
+  useEffect(() => {
+    const entries = visibleItems
+      .map(({ item }) => item ? { kind: isClipboardTab ? 'clipboard' : 'favorite', id: item.id } : null);
+    invoke('set_quickpaste_visible_items', { items: entries }).catch(() => {});
+  }, [visibleItems, isClipboardTab]);
+
+  // 键盘模式下按数字键直接粘贴对应可见项
+  useEffect(() => {
+    const unlisten = listen('quickpaste-paste-index', async (event) => {
+      const item = visibleItems[event.payload]?.item;
+      if (!item) return;
+      try {
+        isClipboardTab ? await pasteClipboardItem(item.id) : await pasteFavorite(item.id);
+      } catch (error) {
+        console.error('粘贴失败:', error);
+      }
+    });
+    return () => unlisten.then(fn => fn());
+  }, [visibleItems, isClipboardTab]);
   useEffect(() => {
     const unlisten = listen('quickpaste-show', async () => {
       try {
         if (navigationStore.activeTab === 'clipboard') {
-          await initClipboardItems();
+          await initClipboardItems(settingsStore.quickpasteItemCount || undefined);
         } else {
           await initFavorites();
         }
@@ -163,7 +231,7 @@ function QuickPasteWindow() {
         groupsStore.currentGroup = currentGroup;
       }
       if (activeTab === 'clipboard') {
-        await initClipboardItems();
+        await initClipboardItems(settingsStore.quickpasteItemCount || undefined);
       } else {
         await initFavorites();
       }
@@ -202,9 +270,21 @@ function QuickPasteWindow() {
     });
     return () => unlisten.then(fn => fn());
   }, [totalCount]);
+  useEffect(() => {
+    const unlisten = listen('quickpaste-prev', () => {
+      playScrollSound();
+      setActiveIndex(prev => {
+        const max = totalCount - 1;
+        return prev > 0 ? prev - 1 : max;
+      });
+    });
+    return () => unlisten.then(fn => fn());
+  }, [totalCount]);
   useEffect(() => {
     let resizeTimeout;
     const handleResize = async () => {
+      // 固定项数时尺寸由布局决定，不覆盖用户保存的窗口尺寸
+      if (settingsStore.quickpasteItemCount > 0) return;
       const window = getCurrentWebviewWindow();
       const size = await window.innerSize();
       const scaleFactor = await window.scaleFactor();
@@ -337,8 +417,13 @@ function QuickPasteWindow() {
             </div>
             
             {/* 项目列表 */}
-            {visibleItems.map(({ index, item }) => {
+            <div
+              className="w-full grid gap-1"
+              style={{ gridTemplateColumns: `repeat(${gridColumns}, minmax(0, 1fr))` }}
+            >
+            {visibleItems.map(({ index, item }, position) => {
               const active = activeIndex === index;
+              const keyLabel = digitKeyLabel(position);
               
               return (
                 <div
@@ -365,7 +450,7 @@ function QuickPasteWindow() {
                       : 'bg-gray-100 dark:bg-gray-700 text-gray-500 dark:text-gray-400'
                     }
                   `}>
-                    {index + 1}
+                    {keyLabel ?? index + 1}
                   </div>
 
                   {/* 内容区域 */}
@@ -396,6 +481,7 @@ function QuickPasteWindow() {
                 </div>
               );
             })}
+            </div>
           </div>
         )}
       </div>
@@ -5,6 +5,7 @@ import { Virtuoso } from 'react-virtuoso';
 import SettingsSection from '../components/SettingsSection';
 import SettingItem from '../components/SettingItem';
 import Toggle from '@shared/components/ui/Toggle';
+import Slider from '@shared/components/ui/Slider';
 import Input from '@shared/components/ui/Input';
 import Button from '@shared/components/ui/Button';
 
@@ -223,6 +224,11 @@ function AppFilterSection({
             </div>
           </label>
         </div>
+        {settings.appFilterEffect === 'global_disable' && (
+          <SettingItem label={t('settings.appFilter.disableGrace')} description={t('settings.appFilter.disableGraceDesc')}>
+            <Slider value={settings.appFilterDisableGraceMs ?? 0} onChange={value => onSettingChange('appFilterDisableGraceMs', value)} min={0} max={3000} step={100} unit="ms" />
+          </SettingItem>
+        )}
       </SettingsSection>
 
       <SettingsSection title={t('settings.appFilter.manageTitle')} description={t('settings.appFilter.manageDesc')}>
@@ -1,4 +1,7 @@
+import { useState, useEffect } from 'react';
 import { useTranslation } from 'react-i18next';
+import { listen } from '@tauri-apps/api/event';
+import { getMonitorStatus } from '@shared/api/clipboard';
 import SettingsSection from '../components/SettingsSection';
 import SettingItem from '../components/SettingItem';
 import Toggle from '@shared/components/ui/Toggle';
@@ -12,6 +15,14 @@ function ClipboardSection({
   const {
     t
   } = useTranslation();
+  const [monitorStatus, setMonitorStatus] = useState(null);
+  useEffect(() => {
+    getMonitorStatus().then(setMonitorStatus).catch(error => console.error('获取剪贴板监听状态失败:', error));
+    const unlisten = listen('monitor-error', event => setMonitorStatus(event.payload));
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [settings.clipboardMonitor]);
   const positionOptions = [{
     value: 'smart',
     label: t('settings.clipboard.positionSmart')
@@ -32,6 +43,16 @@ function ClipboardSection({
     value: 'right',
     label: t('settings.clipboard.positionRight')
   }];
+  const imagePasteFormatOptions = [{
+    value: 'auto',
+    label: t('settings.clipboard.imagePasteFormatAuto')
+  }, {
+    value: 'png',
+    label: 'PNG'
+  }, {
+    value: 'jpeg',
+    label: 'JPEG'
+  }];
   const pasteShortcutModeOptions = [{
     value: 'ctrl_v',
     label: t('settings.clipboard.pasteShortcutCtrlV')
@@ -45,10 +66,34 @@ function ClipboardSection({
           <Toggle checked={settings.clipboardMonitor} onChange={checked => onSettingChange('clipboardMonitor', checked)} />
         </SettingItem>
 
+        {settings.clipboardMonitor && monitorStatus?.consecutiveFailures > 0 && <div className="px-4 py-2 text-sm text-amber-600 dark:text-amber-400 bg-amber-50 dark:bg-amber-900/20 rounded-md">
+            <span className="font-medium">{t('settings.clipboard.monitorError')}：</span>
+            {monitorStatus.lastError}
+          </div>}
+
+        {settings.clipboardMonitor && <SettingItem label={t('settings.clipboard.pollFallback')} description={t('settings.clipboard.pollFallbackDesc')}>
+            <Input type="number" value={settings.clipboardPollIntervalMs ?? 0} onChange={e => onSettingChange('clipboardPollIntervalMs', Math.max(0, parseInt(e.target.value) || 0))} min={0} max={60000} step={500} className="w-24" suffix="ms" />
+          </SettingItem>}
+
         <SettingItem label={t('settings.clipboard.saveImages')} description={t('settings.clipboard.saveImagesDesc')}>
           <Toggle checked={settings.saveImages} onChange={checked => onSettingChange('saveImages', checked)} />
         </SettingItem>
 
+        <SettingItem label={t('settings.clipboard.imageCoalesce')} description={t('settings.clipboard.imageCoalesceDesc')}>
+          <div className="flex items-center gap-2">
+            <Input type="number" value={settings.imageCoalesceWindowMs ?? 0} onChange={e => onSettingChange('imageCoalesceWindowMs', Math.max(0, parseInt(e.target.value) || 0))} min={0} max={10000} step={100} className="w-24" suffix="ms" />
+            <Input type="number" value={Math.round((settings.imageCoalesceSimilarity ?? 0.95) * 100)} onChange={e => onSettingChange('imageCoalesceSimilarity', Math.min(100, Math.max(50, parseInt(e.target.value) || 95)) / 100)} min={50} max={100} className="w-24" suffix="%" />
+          </div>
+        </SettingItem>
+
+        <SettingItem label={t('settings.clipboard.imagePasteResize')} description={t('settings.clipboard.imagePasteResizeDesc')}>
+          <div className="flex items-center gap-2">
+            <Input type="number" value={settings.imagePasteMaxDimension ?? 1920} onChange={e => onSettingChange('imagePasteMaxDimension', Math.max(0, parseInt(e.target.value) || 0))} min={0} max={16384} step={100} className="w-24" suffix="px" />
+            <Select value={settings.imagePasteFormat} onChange={value => onSettingChange('imagePasteFormat', value)} options={imagePasteFormatOptions} className="w-32" />
+            {settings.imagePasteFormat !== 'png' && <Input type="number" value={settings.imagePasteJpegQuality ?? 85} onChange={e => onSettingChange('imagePasteJpegQuality', Math.min(100, Math.max(1, parseInt(e.target.value) || 85)))} min={1} max={100} className="w-24" suffix="%" />}
+          </div>
+        </SettingItem>
+
         <SettingItem label={t('settings.clipboard.imagePreview')} description={t('settings.clipboard.imagePreviewDesc')}>
           <Toggle checked={settings.imagePreview} onChange={checked => onSettingChange('imagePreview', checked)} />
         </SettingItem>
@@ -105,6 +150,10 @@ function ClipboardSection({
           <Toggle checked={settings.pasteToTop} onChange={checked => onSettingChange('pasteToTop', checked)} />
         </SettingItem>
 
+        <SettingItem label={t('settings.clipboard.copyAsStoreResult')} description={t('settings.clipboard.copyAsStoreResultDesc')}>
+          <Toggle checked={settings.copyAsStoreResult} onChange={checked => onSettingChange('copyAsStoreResult', checked)} />
+        </SettingItem>
+
         <SettingItem label={t('settings.clipboard.showBadges')} description={t('settings.clipboard.showBadgesDesc')}>
           <Toggle checked={settings.showBadges !== false} onChange={checked => onSettingChange('showBadges', checked)} />
         </SettingItem>
@@ -7,7 +7,7 @@ import SettingItem from '../components/SettingItem';
 import Button from '@shared/components/ui/Button';
 import { open, save } from '@tauri-apps/plugin-dialog';
 import { openPath } from '@tauri-apps/plugin-opener';
-import { getCurrentStoragePath, getDefaultStoragePath, changeStoragePath, resetStoragePathToDefault, exportDataZip, importDataZip, resetAllData, checkTargetHasData, listBackups } from '@shared/api/dataManagement';
+import { getCurrentStoragePath, getDefaultStoragePath, changeStoragePath, resetStoragePathToDefault, exportDataZip, importDataZip, resetAllData, checkTargetHasData, listBackups, checkDatabaseIntegrity, repairDatabase } from '@shared/api/dataManagement';
 import { showError, showMessage, showConfirm } from '@shared/utils/dialog';
 import { reloadAllWindows } from '@shared/api/window';
 import { resetSettingsToDefault } from '@shared/api/settings';
@@ -220,6 +220,35 @@ function DataManagementSection() {
     }
   };
 
+  const handleCheckDatabase = async () => {
+    try {
+      setBusyText(t('settings.dataManagement.overlayCheckingDatabase'));
+      setBusy(true);
+      const report = await checkDatabaseIntegrity();
+      setBusy(false);
+      if (report.ok) {
+        await showMessage(t('settings.dataManagement.databaseHealthy'));
+        return;
+      }
+      const ok = await showConfirm(t('settings.dataManagement.databaseCorruptConfirm', { message: report.errors.join('\n') }));
+      if (!ok) return;
+      setBusyText(t('settings.dataManagement.overlayRepairingDatabase'));
+      setBusy(true);
+      const result = await repairDatabase();
+      await showMessage(t('settings.dataManagement.repairSuccess', {
+        recovered: result.recoveredRows,
+        skipped: result.skippedSegments,
+        path: result.backupPath
+      }));
+      try { await reloadAllWindows(); } catch (_) {}
+    } catch (e) {
+      await showError(t('settings.dataManagement.repairFailed', { message: e?.message || e }));
+    } finally {
+      setBusy(false);
+      setBusyText('');
+    }
+  };
+
   const handleClearHistory = async () => {
     const ok = await showConfirm(t('settings.dataManagement.clearConfirm'));
     if (!ok) return;
@@ -346,6 +375,12 @@ function DataManagementSection() {
             {t('settings.dataManagement.resetPathButton')}
           </Button>
         </SettingItem>
+
+        <SettingItem label={t('settings.dataManagement.checkDatabase')} description={t('settings.dataManagement.checkDatabaseDesc')}>
+          <Button onClick={handleCheckDatabase} disabled={busy} variant="secondary" icon={<i className="ti ti-database-cog"></i>}>
+            {t('settings.dataManagement.checkDatabaseButton')}
+          </Button>
+        </SettingItem>
       </SettingsSection>
 
       {/* 数据清理 */}
@@ -162,6 +162,10 @@ function GeneralSection({
       <SettingItem label={t('settings.general.historyLimit')} description={t('settings.general.historyLimitDesc')}>
         <Select value={settings.historyLimit} onChange={value => onSettingChange('historyLimit', parseInt(value))} options={historyLimitOptions} />
       </SettingItem>
+
+      <SettingItem label={t('settings.general.notifyExpiredItems')} description={t('settings.general.notifyExpiredItemsDesc')}>
+        <Toggle checked={settings.notifyExpiredItems} onChange={checked => onSettingChange('notifyExpiredItems', checked)} />
+      </SettingItem>
     </SettingsSection>;
 }
 export default GeneralSection;
\ No newline at end of file
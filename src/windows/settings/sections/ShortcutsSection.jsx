@@ -18,7 +18,7 @@ function ShortcutsSection({ settings, onSettingChange, activeTab }) {
   const globalSettings = useSnapshot(settingsStore);
   const uiAnimationEnabled = globalSettings.uiAnimationEnabled !== false;
   
-  const { statuses, hasError: hasBackendError, getError: getBackendError, reload } = useShortcutStatuses();
+  const { statuses, pluginUnavailable, failedAlternates, hasError: hasBackendError, getError: getBackendError, reload } = useShortcutStatuses();
   const { hasDuplicate, getDuplicateError } = useShortcutDuplicateCheck(settings);
 
   const handleShortcutChange = async (key, value) => {
@@ -46,6 +46,13 @@ function ShortcutsSection({ settings, onSettingChange, activeTab }) {
     return duplicateError || backendError;
   };
 
+  // 连按两次确认的动作列表
+  const requiresConfirm = id => (settings.hotkeyConfirmActions || []).includes(id);
+  const handleConfirmChange = (id, checked) => {
+    const others = (settings.hotkeyConfirmActions || []).filter(a => a !== id);
+    onSettingChange('hotkeyConfirmActions', checked ? [...others, id] : others);
+  };
+
   const hasErrorStatus = (key, backendId) => {
     return hasDuplicate(key) || (backendId && hasBackendError(backendId));
   };
@@ -54,7 +61,24 @@ function ShortcutsSection({ settings, onSettingChange, activeTab }) {
     { value: '1~9', label: '1~9' },
     { value: 'F', label: 'F1~F9' },
   ];
+  const numberSourceOptions = [
+    { value: 'visible', label: t('settings.shortcuts.numberSourceVisible') },
+    { value: 'history', label: t('settings.shortcuts.numberSourceHistory') }
+  ];
+  const numberUnboundOptions = [
+    { value: 'index', label: t('settings.shortcuts.numberUnboundIndex') },
+    { value: 'none', label: t('settings.shortcuts.numberUnboundNone') }
+  ];
+  const repeatPastePolicyOptions = [
+    { value: 'overlap', label: t('settings.shortcuts.repeatPastePolicyOverlap') },
+    { value: 'drop', label: t('settings.shortcuts.repeatPastePolicyDrop') },
+    { value: 'queue', label: t('settings.shortcuts.repeatPastePolicyQueue') }
+  ];
   const mouseModifierOptions = ['Ctrl', 'Shift', 'Alt'];
+  const pasteFormatToggleModeOptions = [
+    { value: 'sticky', label: t('settings.shortcuts.pasteFormatToggleSticky') },
+    { value: 'momentary', label: t('settings.shortcuts.pasteFormatToggleMomentary') }
+  ];
   const mouseTriggerOptions = [
     { value: 'short_press', label: t('settings.shortcuts.mouseMiddleTriggerShortPress') },
     { value: 'long_press', label: t('settings.shortcuts.mouseMiddleTriggerLongPress') }
@@ -101,9 +125,40 @@ function ShortcutsSection({ settings, onSettingChange, activeTab }) {
             <SettingItem label={t('settings.shortcuts.togglePasteWithFormat')} description={t('settings.shortcuts.togglePasteWithFormatDesc')}>
               <ShortcutInput value={settings.togglePasteWithFormatShortcut} onChange={value => handleShortcutChange('togglePasteWithFormatShortcut', value)} onReset={() => handleShortcutChange('togglePasteWithFormatShortcut', 'Ctrl+Shift+X')} hasError={hasErrorStatus('togglePasteWithFormatShortcut', 'toggle_paste_with_format')} errorMessage={getErrorMessage('togglePasteWithFormatShortcut', 'toggle_paste_with_format')} />
             </SettingItem>
+            <SettingItem label={t('settings.shortcuts.pasteFormatToggleMode')} description={t('settings.shortcuts.pasteFormatToggleModeDesc')}>
+              <Select value={settings.pasteFormatToggleMode} onChange={value => onSettingChange('pasteFormatToggleMode', value)} options={pasteFormatToggleModeOptions} className="w-56" />
+            </SettingItem>
             <SettingItem label={t('settings.shortcuts.pastePlainText')} description={t('settings.shortcuts.pastePlainTextDesc')}>
               <ShortcutComboInput value={settings.pastePlainTextShortcut} onChange={value => handleShortcutChange('pastePlainTextShortcut', value)} modifierOptions={['Ctrl', 'Shift']} fixedModifiers={['Ctrl']} disabledKeys={['V', 'C', 'X', 'A', 'Z', 'Y']} hasError={hasErrorStatus('pastePlainTextShortcut', 'paste_plain_text')} errorMessage={getErrorMessage('pastePlainTextShortcut', 'paste_plain_text')} />
             </SettingItem>
+            <SettingItem label={t('settings.shortcuts.panic')} description={t('settings.shortcuts.panicDesc')}>
+              <ShortcutInput value={settings.panicShortcut} onChange={value => handleShortcutChange('panicShortcut', value)} onReset={() => handleShortcutChange('panicShortcut', '')} hasError={hasErrorStatus('panicShortcut', 'panic')} errorMessage={getErrorMessage('panicShortcut', 'panic')} />
+            </SettingItem>
+            <SettingItem label={t('settings.shortcuts.panicHideWindows')} description={t('settings.shortcuts.panicHideWindowsDesc')}>
+              <Toggle checked={settings.panicHideWindows} onChange={checked => onSettingChange('panicHideWindows', checked)} />
+            </SettingItem>
+            <SettingItem label={t('settings.shortcuts.panicClearClipboard')} description={t('settings.shortcuts.panicClearClipboardDesc')}>
+              <Toggle checked={settings.panicClearClipboard} onChange={checked => onSettingChange('panicClearClipboard', checked)} />
+            </SettingItem>
+            <SettingItem label={t('settings.shortcuts.panicEnterIncognito')} description={t('settings.shortcuts.panicEnterIncognitoDesc')}>
+              <Toggle checked={settings.panicEnterIncognito} onChange={checked => onSettingChange('panicEnterIncognito', checked)} />
+            </SettingItem>
+            <SettingItem label={t('settings.shortcuts.panicConfirm')} description={t('settings.shortcuts.panicConfirmDesc')}>
+              <Toggle checked={requiresConfirm('panic')} onChange={checked => handleConfirmChange('panic', checked)} />
+            </SettingItem>
+            <SettingItem label={t('settings.shortcuts.grabSelection')} description={t('settings.shortcuts.grabSelectionDesc')}>
+              <ShortcutInput value={settings.grabSelectionShortcut} onChange={value => handleShortcutChange('grabSelectionShortcut', value)} onReset={() => handleShortcutChange('grabSelectionShortcut', '')} hasError={hasErrorStatus('grabSelectionShortcut', 'grab_selection')} errorMessage={getErrorMessage('grabSelectionShortcut', 'grab_selection')} />
+            </SettingItem>
+            <SettingItem label={t('settings.shortcuts.grabSelectionShowQuickpaste')} description={t('settings.shortcuts.grabSelectionShowQuickpasteDesc')}>
+              <Toggle checked={settings.grabSelectionShowQuickpaste} onChange={checked => onSettingChange('grabSelectionShowQuickpaste', checked)} />
+            </SettingItem>
+            <SettingItem label={t('settings.shortcuts.quickNote')} description={t('settings.shortcuts.quickNoteDesc')}>
+              <ShortcutInput value={settings.quickNoteShortcut} onChange={value => handleShortcutChange('quickNoteShortcut', value)} onReset={() => handleShortcutChange('quickNoteShortcut', '')} hasError={hasErrorStatus('quickNoteShortcut', 'quick_note')} errorMessage={getErrorMessage('quickNoteShortcut', 'quick_note')} />
+            </SettingItem>
+
+            <SettingItem label={t('settings.shortcuts.compose')} description={t('settings.shortcuts.composeDesc')}>
+              <ShortcutInput value={settings.composeShortcut} onChange={value => handleShortcutChange('composeShortcut', value)} onReset={() => handleShortcutChange('composeShortcut', '')} hasError={hasErrorStatus('composeShortcut', 'compose')} errorMessage={getErrorMessage('composeShortcut', 'compose')} />
+            </SettingItem>
           </SettingsSection>
         );
 
@@ -244,6 +299,15 @@ function ShortcutsSection({ settings, onSettingChange, activeTab }) {
               <SettingItem label={t('settings.shortcuts.numberModifier')} description={t('settings.shortcuts.numberModifierDesc')}>
                 <ShortcutComboInput value={settings.numberShortcutsModifier} onChange={value => onSettingChange('numberShortcutsModifier', value)} modifierOptions={['Ctrl', 'Shift']} fixedKeyOptions={numberKeyTypeOptions} />
               </SettingItem>
+              <SettingItem label={t('settings.shortcuts.numberSource')} description={t('settings.shortcuts.numberSourceDesc')}>
+                <Select value={settings.numberShortcutsSource} onChange={value => onSettingChange('numberShortcutsSource', value)} options={numberSourceOptions} className="w-56" />
+              </SettingItem>
+              <SettingItem label={t('settings.shortcuts.numberUnbound')} description={t('settings.shortcuts.numberUnboundDesc')}>
+                <Select value={settings.numberUnboundBehavior} onChange={value => onSettingChange('numberUnboundBehavior', value)} options={numberUnboundOptions} className="w-56" />
+              </SettingItem>
+              <SettingItem label={t('settings.shortcuts.repeatPastePolicy')} description={t('settings.shortcuts.repeatPastePolicyDesc')}>
+                <Select value={settings.repeatPastePolicy} onChange={value => onSettingChange('repeatPastePolicy', value)} options={repeatPastePolicyOptions} className="w-56" />
+              </SettingItem>
               {hasBackendError('number_shortcuts') && (
                 <div className="px-4 py-2 text-sm text-amber-600 dark:text-amber-400 bg-amber-50 dark:bg-amber-900/20 rounded-md">
                   <span className="font-medium">{t('settings.shortcuts.numberRegistrationFailed')}：</span>
@@ -279,6 +343,24 @@ function ShortcutsSection({ settings, onSettingChange, activeTab }) {
 
   return (
     <div className={uiAnimationEnabled ? 'animate-slide-in-left-fast' : ''} key={activeTab}>
+      {pluginUnavailable && (
+        <div className="mb-3 px-4 py-2 text-sm text-amber-600 dark:text-amber-400 bg-amber-50 dark:bg-amber-900/20 rounded-md">
+          {t('settings.shortcuts.pluginUnavailable')}
+        </div>
+      )}
+      {statuses['registration_limit'] && (
+        <div className="mb-3 px-4 py-2 text-sm text-amber-600 dark:text-amber-400 bg-amber-50 dark:bg-amber-900/20 rounded-md">
+          {getBackendError('registration_limit')}
+        </div>
+      )}
+      {failedAlternates.length > 0 && (
+        <div className="mb-3 px-4 py-2 text-sm text-amber-600 dark:text-amber-400 bg-amber-50 dark:bg-amber-900/20 rounded-md">
+          <div className="font-medium">{t('settings.shortcuts.alternateFailed')}</div>
+          {failedAlternates.map(status => (
+            <div key={status.id}>{status.shortcut}（{status.id}）：{getBackendError(status.id)}</div>
+          ))}
+        </div>
+      )}
       {renderTabContent()}
     </div>
   );
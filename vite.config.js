@@ -76,6 +76,7 @@ export default defineConfig({
           settings: resolve(__dirname, 'src/windows/settings/index.html'),
           quickpaste: resolve(__dirname, 'src/windows/quickpaste/index.html'),
           textEditor: resolve(__dirname, 'src/windows/textEditor/index.html'),
+          compose: resolve(__dirname, 'src/windows/compose/index.html'),
           contextMenu: resolve(__dirname, 'src/plugins/context_menu/contextMenu.html'),
           inputDialog: resolve(__dirname, 'src/plugins/input_dialog/inputDialog.html'),
           pinImage: resolve(__dirname, 'src/windows/pinImage/pinImage.html'),